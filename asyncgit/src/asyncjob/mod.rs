@@ -1,7 +1,11 @@
-//! provides `AsyncJob` trait and `AsyncSingleJob` struct
+//! provides `AsyncJob` trait, `AsyncSingleJob` and `AsyncMultiJob` structs
 
 #![deny(clippy::expect_used)]
 
+mod multi;
+
+pub use multi::AsyncMultiJob;
+
 use crate::error::Result;
 use crossbeam_channel::Sender;
 use std::sync::{Arc, Mutex, RwLock};
@@ -0,0 +1,252 @@
+use super::{AsyncJob, RunParams};
+use crate::error::Result;
+use crossbeam_channel::Sender;
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Mutex, RwLock},
+};
+
+struct MultiJobState<J> {
+	queue: VecDeque<J>,
+	in_flight: usize,
+	finished: Vec<J>,
+}
+
+/// Abstraction for a FIFO task queue that keeps **all** queued jobs
+/// and runs up to `max_in_flight` of them at the same time.
+/// The notification of each job is sent as soon as it completes
+#[derive(Clone)]
+pub struct AsyncMultiJob<J: AsyncJob> {
+	state: Arc<Mutex<MultiJobState<J>>>,
+	progress: Arc<RwLock<J::Progress>>,
+	max_in_flight: usize,
+	sender: Sender<J::Notification>,
+}
+
+impl<J: 'static + AsyncJob> AsyncMultiJob<J> {
+	/// `max_in_flight` is at least one
+	pub fn new(
+		sender: Sender<J::Notification>,
+		max_in_flight: usize,
+	) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(MultiJobState {
+				queue: VecDeque::new(),
+				in_flight: 0,
+				finished: Vec::new(),
+			})),
+			progress: Arc::new(RwLock::new(J::Progress::default())),
+			max_in_flight: max_in_flight.max(1),
+			sender,
+		}
+	}
+
+	/// number of jobs queued or running
+	pub fn pending_count(&self) -> usize {
+		self.state
+			.lock()
+			.map(|state| state.queue.len() + state.in_flight)
+			.unwrap_or_default()
+	}
+
+	///
+	pub fn is_pending(&self) -> bool {
+		self.pending_count() > 0
+	}
+
+	/// drops all jobs that did not start yet and returns how many,
+	/// running jobs still finish and notify
+	pub fn clear(&mut self) -> usize {
+		self.state
+			.lock()
+			.map(|mut state| state.queue.drain(..).count())
+			.unwrap_or_default()
+	}
+
+	/// latest progress reported by any of the running jobs
+	pub fn progress(&self) -> Option<J::Progress> {
+		self.progress.read().ok().map(|d| (*d).clone())
+	}
+
+	/// take out all jobs finished since the last call
+	pub fn take_finished(&self) -> Vec<J> {
+		self.state
+			.lock()
+			.map(|mut state| std::mem::take(&mut state.finished))
+			.unwrap_or_default()
+	}
+
+	/// queues `task` and starts it right away if less than
+	/// `max_in_flight` jobs are running.
+	/// returns `true` if the new task gets started right away.
+	pub fn spawn(&mut self, task: J) -> bool {
+		if let Ok(mut state) = self.state.lock() {
+			state.queue.push_back(task);
+		}
+
+		self.check_for_jobs();
+
+		// jobs start in order, so `task` started if nothing is left
+		self.state
+			.lock()
+			.map(|state| state.queue.is_empty())
+			.unwrap_or_default()
+	}
+
+	/// starts queued jobs until `max_in_flight` is reached
+	fn check_for_jobs(&self) {
+		while let Some(task) = self.take_next() {
+			let self_clone = (*self).clone();
+			rayon_core::spawn(move || {
+				if let Err(e) = self_clone.run_job(task) {
+					log::error!("async job error: {}", e);
+				}
+			});
+		}
+	}
+
+	fn run_job(&self, mut task: J) -> Result<()> {
+		let result = task.run(RunParams {
+			progress: self.progress.clone(),
+			sender: self.sender.clone(),
+		});
+
+		if let Ok(mut state) = self.state.lock() {
+			state.in_flight = state.in_flight.saturating_sub(1);
+			if result.is_ok() {
+				state.finished.push(task);
+			}
+		}
+
+		self.check_for_jobs();
+
+		self.sender.send(result?)?;
+
+		Ok(())
+	}
+
+	/// takes the next job and counts it as running,
+	/// `None` if the queue is empty or enough jobs are running
+	fn take_next(&self) -> Option<J> {
+		let mut state = self.state.lock().ok()?;
+
+		if state.in_flight >= self.max_in_flight {
+			return None;
+		}
+
+		let task = state.queue.pop_front()?;
+		state.in_flight += 1;
+
+		Some(task)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crossbeam_channel::unbounded;
+	use pretty_assertions::assert_eq;
+	use std::{
+		sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+		thread,
+		time::Duration,
+	};
+
+	#[derive(Clone)]
+	struct TestJob {
+		running: Arc<AtomicUsize>,
+		max_running: Arc<AtomicUsize>,
+		done: Arc<AtomicUsize>,
+		finish: Arc<AtomicBool>,
+	}
+
+	impl TestJob {
+		fn new() -> Self {
+			Self {
+				running: Arc::new(AtomicUsize::new(0)),
+				max_running: Arc::new(AtomicUsize::new(0)),
+				done: Arc::new(AtomicUsize::new(0)),
+				finish: Arc::new(AtomicBool::new(false)),
+			}
+		}
+	}
+
+	impl AsyncJob for TestJob {
+		type Notification = ();
+		type Progress = ();
+
+		fn run(
+			&mut self,
+			_params: RunParams<Self::Notification, Self::Progress>,
+		) -> Result<Self::Notification> {
+			let running = self.running.fetch_add(1, Ordering::SeqCst);
+			self.max_running.fetch_max(running + 1, Ordering::SeqCst);
+
+			while !self.finish.load(Ordering::SeqCst) {
+				std::thread::yield_now();
+			}
+
+			thread::sleep(Duration::from_millis(10));
+
+			self.running.fetch_sub(1, Ordering::SeqCst);
+			self.done.fetch_add(1, Ordering::SeqCst);
+
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_max_in_flight() {
+		let (sender, receiver) = unbounded();
+
+		let mut job: AsyncMultiJob<TestJob> =
+			AsyncMultiJob::new(sender, 2);
+
+		let task = TestJob::new();
+
+		assert!(job.spawn(task.clone()));
+		assert!(job.spawn(task.clone()));
+		for _ in 0..3 {
+			assert!(!job.spawn(task.clone()));
+		}
+		assert_eq!(job.pending_count(), 5);
+
+		task.finish.store(true, Ordering::SeqCst);
+
+		for _ in 0..5 {
+			receiver.recv().unwrap();
+		}
+
+		assert_eq!(task.done.load(Ordering::SeqCst), 5);
+		assert!(task.max_running.load(Ordering::SeqCst) <= 2);
+		assert_eq!(job.take_finished().len(), 5);
+		assert!(!job.is_pending());
+	}
+
+	#[test]
+	fn test_clear() {
+		let (sender, receiver) = unbounded();
+
+		let mut job: AsyncMultiJob<TestJob> =
+			AsyncMultiJob::new(sender, 1);
+
+		let task = TestJob::new();
+
+		assert!(job.spawn(task.clone()));
+		for _ in 0..3 {
+			assert!(!job.spawn(task.clone()));
+		}
+
+		assert_eq!(job.clear(), 3);
+		assert_eq!(job.pending_count(), 1);
+
+		task.finish.store(true, Ordering::SeqCst);
+
+		receiver.recv().unwrap();
+		thread::sleep(Duration::from_millis(50));
+
+		assert!(receiver.is_empty());
+		assert_eq!(task.done.load(Ordering::SeqCst), 1);
+		assert!(!job.is_pending());
+	}
+}
@@ -0,0 +1,76 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{branches_compare_upstream, BranchCompare},
+	AsyncGitNotification, CWD,
+};
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+
+enum JobState {
+	Request(Vec<String>),
+	Response(Result<HashMap<String, BranchCompare>>),
+}
+
+/// ahead/behind of local branches against their upstream,
+/// see `sync::branches_compare_upstream`
+#[derive(Clone, Default)]
+pub struct AsyncBranchesCompareJob {
+	state: Arc<Mutex<Option<JobState>>>,
+}
+
+///
+impl AsyncBranchesCompareJob {
+	///
+	pub fn new(branches: Vec<String>) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				branches,
+			)))),
+		}
+	}
+
+	///
+	pub fn result(
+		&self,
+	) -> Option<Result<HashMap<String, BranchCompare>>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncBranchesCompareJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(branches) => JobState::Response(
+					branches_compare_upstream(CWD, &branches),
+				),
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::BranchesCompare)
+	}
+}
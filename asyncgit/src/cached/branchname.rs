@@ -1,12 +1,14 @@
 use crate::{
-	error::Result,
-	sync::{self, branch::get_branch_name},
+	error::{Error, Result},
+	sync::{self, branch::get_branch_name, CommitId},
 };
 use sync::Head;
 
 ///
 pub struct BranchName {
 	last_result: Option<(Head, String)>,
+	/// commit `HEAD` points to while detached
+	detached: Option<CommitId>,
 	repo_path: String,
 }
 
@@ -16,6 +18,7 @@ impl BranchName {
 		Self {
 			repo_path: path.to_string(),
 			last_result: None,
+			detached: None,
 		}
 	}
 
@@ -35,14 +38,31 @@ impl BranchName {
 		self.fetch(current_head)
 	}
 
-	///
+	/// `None` while `HEAD` is detached
 	pub fn last(&self) -> Option<String> {
 		self.last_result.as_ref().map(|last| last.1.clone())
 	}
 
+	/// the commit `HEAD` points to if it is detached
+	pub const fn detached(&self) -> Option<CommitId> {
+		self.detached
+	}
+
 	fn fetch(&mut self, head: Head) -> Result<String> {
-		let name = get_branch_name(self.repo_path.as_str())?;
-		self.last_result = Some((head, name.clone()));
-		Ok(name)
+		let head_id = head.id;
+
+		match get_branch_name(self.repo_path.as_str()) {
+			Ok(name) => {
+				self.detached = None;
+				self.last_result = Some((head, name.clone()));
+				Ok(name)
+			}
+			Err(e) => {
+				self.last_result = None;
+				self.detached =
+					matches!(e, Error::DetachedHead).then(|| head_id);
+				Err(e)
+			}
+		}
 	}
 }
@@ -19,11 +19,17 @@ pub struct CommitFilesParams {
 	pub id: CommitId,
 	///
 	pub other: Option<CommitId>,
+	/// index of the parent `id` is compared to without `other`
+	pub parent: usize,
 }
 
 impl From<CommitId> for CommitFilesParams {
 	fn from(id: CommitId) -> Self {
-		Self { id, other: None }
+		Self {
+			id,
+			other: None,
+			parent: 0,
+		}
 	}
 }
 
@@ -32,6 +38,7 @@ impl From<(CommitId, CommitId)> for CommitFilesParams {
 		Self {
 			id,
 			other: Some(other),
+			parent: 0,
 		}
 	}
 }
@@ -112,8 +119,15 @@ impl AsyncCommitFiles {
 			Mutex<Option<Request<CommitFilesParams, ResultType>>>,
 		>,
 	) -> Result<()> {
-		let res =
-			sync::get_commit_files(CWD, params.id, params.other)?;
+		let res = if params.other.is_some() {
+			sync::get_commit_files(CWD, params.id, params.other)?
+		} else {
+			sync::get_commit_parent_files(
+				CWD,
+				params.id,
+				params.parent,
+			)?
+		};
 
 		log::trace!("get_commit_files: {:?} ({})", params, res.len());
 
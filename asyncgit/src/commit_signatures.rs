@@ -0,0 +1,77 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{verify_commit_signature, CommitId, SignatureStatus},
+	AsyncGitNotification, CWD,
+};
+
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request(CommitId),
+	Response((CommitId, SignatureStatus)),
+}
+
+/// verifies the signature of a single commit, each spawns `gpg`
+/// so run several of them side by side using an `AsyncMultiJob`
+#[derive(Clone)]
+pub struct AsyncSignaturesJob {
+	state: Arc<Mutex<Option<JobState>>>,
+}
+
+///
+impl AsyncSignaturesJob {
+	///
+	pub fn new(id: CommitId) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(JobState::Request(id)))),
+		}
+	}
+
+	///
+	pub fn result(&self) -> Option<(CommitId, SignatureStatus)> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncSignaturesJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(id) => {
+					let status = verify_commit_signature(CWD, id)
+						.unwrap_or_else(|e| {
+							log::error!(
+								"verify signature error: {}",
+								e
+							);
+							SignatureStatus::Unverifiable
+						});
+					JobState::Response((id, status))
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::Signatures)
+	}
+}
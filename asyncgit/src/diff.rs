@@ -6,6 +6,7 @@ use crate::{
 };
 use crossbeam_channel::Sender;
 use std::{
+	collections::VecDeque,
 	hash::Hash,
 	sync::{
 		atomic::{AtomicUsize, Ordering},
@@ -18,12 +19,15 @@ use std::{
 pub enum DiffType {
 	/// diff two commits
 	Commits((CommitId, CommitId)),
-	/// diff in a given commit
-	Commit(CommitId),
+	/// diff in a given commit against its parent of the given
+	/// index (`0` for the first)
+	Commit(CommitId, usize),
 	/// diff against staged file
 	Stage,
 	/// diff against file in workdir
 	WorkDir,
+	/// diff between both sides of a conflicted file
+	Conflict,
 }
 
 ///
@@ -45,10 +49,62 @@ struct LastResult<P, R> {
 	result: R,
 }
 
+/// number of diffs kept around for switching back and forth
+const DIFF_CACHE_SIZE: usize = 8;
+
+/// least recently used diffs keyed by the hash of their params
+#[derive(Default)]
+struct DiffCache {
+	entries: VecDeque<(u64, LastResult<DiffParams, FileDiff>)>,
+	/// bumped on `clear` so diffs requested before it are not
+	/// put back once they finish
+	generation: u64,
+}
+
+impl DiffCache {
+	fn get(
+		&mut self,
+		hash: u64,
+	) -> Option<LastResult<DiffParams, FileDiff>> {
+		let idx = self.entries.iter().position(|e| e.0 == hash)?;
+		let entry = self.entries.remove(idx)?;
+		let res = entry.1.clone();
+		self.entries.push_front(entry);
+
+		Some(res)
+	}
+
+	const fn generation(&self) -> u64 {
+		self.generation
+	}
+
+	/// ignored if the cache got cleared since `generation`
+	fn insert(
+		&mut self,
+		generation: u64,
+		hash: u64,
+		entry: LastResult<DiffParams, FileDiff>,
+	) {
+		if generation != self.generation {
+			return;
+		}
+
+		self.entries.retain(|e| e.0 != hash);
+		self.entries.push_front((hash, entry));
+		self.entries.truncate(DIFF_CACHE_SIZE);
+	}
+
+	fn clear(&mut self) {
+		self.entries.clear();
+		self.generation = self.generation.wrapping_add(1);
+	}
+}
+
 ///
 pub struct AsyncDiff {
 	current: Arc<Mutex<Request<u64, FileDiff>>>,
 	last: Arc<Mutex<Option<LastResult<DiffParams, FileDiff>>>>,
+	cache: Arc<Mutex<DiffCache>>,
 	sender: Sender<AsyncGitNotification>,
 	pending: Arc<AtomicUsize>,
 }
@@ -59,6 +115,7 @@ impl AsyncDiff {
 		Self {
 			current: Arc::new(Mutex::new(Request(0, None))),
 			last: Arc::new(Mutex::new(None)),
+			cache: Arc::new(Mutex::new(DiffCache::default())),
 			sender: sender.clone(),
 			pending: Arc::new(AtomicUsize::new(0)),
 		}
@@ -71,8 +128,10 @@ impl AsyncDiff {
 		Ok(last.clone().map(|res| (res.params, res.result)))
 	}
 
-	///
+	/// drops all cached diffs and requests the last one again
 	pub fn refresh(&mut self) -> Result<()> {
+		self.clear_cache()?;
+
 		if let Ok(Some(param)) = self.get_last_param() {
 			self.clear_current()?;
 			self.request(param)?;
@@ -85,6 +144,12 @@ impl AsyncDiff {
 		self.pending.load(Ordering::Relaxed) > 0
 	}
 
+	/// forget cached diffs, needed whenever the files might have changed
+	pub fn clear_cache(&mut self) -> Result<()> {
+		self.cache.lock()?.clear();
+		Ok(())
+	}
+
 	///
 	pub fn request(
 		&mut self,
@@ -94,19 +159,33 @@ impl AsyncDiff {
 
 		let hash = hash(&params);
 
-		{
+		let generation = {
 			let mut current = self.current.lock()?;
 
 			if current.0 == hash {
 				return Ok(current.1.clone());
 			}
 
+			let mut cache = self.cache.lock()?;
+			if let Some(cached) = cache.get(hash) {
+				let diff = cached.result.clone();
+
+				current.0 = hash;
+				current.1 = Some(diff.clone());
+				*self.last.lock()? = Some(cached);
+
+				return Ok(Some(diff));
+			}
+
 			current.0 = hash;
 			current.1 = None;
-		}
+
+			cache.generation()
+		};
 
 		let arc_current = Arc::clone(&self.current);
 		let arc_last = Arc::clone(&self.last);
+		let arc_cache = Arc::clone(&self.cache);
 		let sender = self.sender.clone();
 		let arc_pending = Arc::clone(&self.pending);
 
@@ -117,6 +196,8 @@ impl AsyncDiff {
 				params,
 				&arc_last,
 				&arc_current,
+				&arc_cache,
+				generation,
 				hash,
 			);
 
@@ -148,6 +229,8 @@ impl AsyncDiff {
 			Mutex<Option<LastResult<DiffParams, FileDiff>>>,
 		>,
 		arc_current: &Arc<Mutex<Request<u64, FileDiff>>>,
+		arc_cache: &Arc<Mutex<DiffCache>>,
+		generation: u64,
 		hash: u64,
 	) -> Result<bool> {
 		let res = match params.diff_type {
@@ -163,11 +246,19 @@ impl AsyncDiff {
 				false,
 				Some(params.options),
 			)?,
-			DiffType::Commit(id) => sync::diff::get_diff_commit(
+			DiffType::Conflict => sync::diff::get_diff_conflict(
 				CWD,
-				id,
-				params.path.clone(),
+				&params.path,
+				Some(params.options),
 			)?,
+			DiffType::Commit(id, parent) => {
+				sync::diff::get_diff_commit(
+					CWD,
+					id,
+					parent,
+					params.path.clone(),
+				)?
+			}
 			DiffType::Commits(ids) => sync::diff::get_diff_commits(
 				CWD,
 				ids,
@@ -184,12 +275,16 @@ impl AsyncDiff {
 			}
 		}
 
+		let entry = LastResult {
+			result: res,
+			params,
+		};
+
+		arc_cache.lock()?.insert(generation, hash, entry.clone());
+
 		{
 			let mut last = arc_last.lock()?;
-			*last = Some(LastResult {
-				result: res,
-				params,
-			});
+			*last = Some(entry);
 		}
 
 		Ok(notify)
@@ -206,3 +301,64 @@ impl AsyncDiff {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(path: &str) -> (u64, LastResult<DiffParams, FileDiff>) {
+		let params = DiffParams {
+			path: path.to_string(),
+			diff_type: DiffType::WorkDir,
+			options: DiffOptions::default(),
+		};
+
+		(
+			hash(&params),
+			LastResult {
+				params,
+				result: FileDiff::default(),
+			},
+		)
+	}
+
+	#[test]
+	fn test_cache_lru() {
+		let mut cache = DiffCache::default();
+
+		let (first, first_entry) = entry("0");
+		cache.insert(cache.generation(), first, first_entry);
+
+		for i in 1..DIFF_CACHE_SIZE {
+			let (hash, entry) = entry(&i.to_string());
+			cache.insert(cache.generation(), hash, entry);
+		}
+
+		// touching the oldest one keeps it around
+		assert_eq!(cache.get(first).unwrap().params.path, "0");
+
+		let (hash, new_entry) = entry("new");
+		cache.insert(cache.generation(), hash, new_entry);
+
+		assert!(cache.get(first).is_some());
+		assert!(cache.get(entry("1").0).is_none());
+		assert_eq!(cache.entries.len(), DIFF_CACHE_SIZE);
+	}
+
+	#[test]
+	fn test_cache_insert_after_clear() {
+		let mut cache = DiffCache::default();
+
+		let generation = cache.generation();
+		cache.clear();
+
+		let (hash, entry) = entry("a");
+		cache.insert(generation, hash, entry.clone());
+
+		assert!(cache.get(hash).is_none());
+
+		cache.insert(cache.generation(), hash, entry);
+
+		assert!(cache.get(hash).is_some());
+	}
+}
@@ -0,0 +1,148 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{
+		diff_stats, diff_stats_workdir, DiffStats,
+		ShowUntrackedFilesConfig,
+	},
+	AsyncGitNotification, CommitFilesParams, CWD,
+};
+
+use std::sync::{Arc, Mutex};
+
+enum JobState<R> {
+	Request(R),
+	Response(Result<DiffStats>),
+}
+
+/// stats of all changes in stage and workdir compared to `HEAD`,
+/// see `sync::diff_stats_workdir`
+#[derive(Clone, Default)]
+pub struct AsyncDiffStatsJob {
+	state: Arc<
+		Mutex<Option<JobState<Option<ShowUntrackedFilesConfig>>>>,
+	>,
+}
+
+///
+impl AsyncDiffStatsJob {
+	///
+	pub fn new(
+		show_untracked: Option<ShowUntrackedFilesConfig>,
+	) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				show_untracked,
+			)))),
+		}
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<DiffStats>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncDiffStatsJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(show_untracked) => {
+					JobState::Response(diff_stats_workdir(
+						CWD,
+						show_untracked,
+					))
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::DiffStats)
+	}
+}
+
+/// stats of a commit or between two commits,
+/// see `sync::diff_stats`
+#[derive(Clone)]
+pub struct AsyncCommitDiffStatsJob {
+	params: CommitFilesParams,
+	state: Arc<Mutex<Option<JobState<CommitFilesParams>>>>,
+}
+
+///
+impl AsyncCommitDiffStatsJob {
+	///
+	pub fn new(params: CommitFilesParams) -> Self {
+		Self {
+			params,
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				params,
+			)))),
+		}
+	}
+
+	/// the params the stats were requested for and the result
+	pub fn result(
+		&self,
+	) -> Option<(CommitFilesParams, Result<DiffStats>)> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => {
+						Some((self.params, result))
+					}
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncCommitDiffStatsJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(params) => {
+					JobState::Response(diff_stats(
+						CWD,
+						params.id,
+						params.other,
+						params.parent,
+					))
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::CommitDiffStats)
+	}
+}
@@ -14,6 +14,10 @@ pub enum Error {
 	#[error("git: no head found")]
 	NoHead,
 
+	///
+	#[error("git: HEAD is detached")]
+	DetachedHead,
+
 	///
 	#[error("git: conflict during rebase")]
 	RebaseConflict,
@@ -34,6 +38,10 @@ pub enum Error {
 	#[error("git: uncommitted changes")]
 	UncommittedChanges,
 
+	/// paths with local changes the checkout would overwrite
+	#[error("git: local changes would be overwritten:\n{}", .0.join("\n"))]
+	CheckoutConflicts(Vec<String>),
+
 	///
 	#[error("git: can\u{2019}t run blame on a binary file")]
 	NoBlameOnBinaryFile,
@@ -24,9 +24,12 @@
 
 pub mod asyncjob;
 mod blame;
+pub mod branches_compare;
 pub mod cached;
 mod commit_files;
+pub mod commit_signatures;
 mod diff;
+pub mod diff_stats;
 mod error;
 mod fetch;
 mod progress;
@@ -36,6 +39,7 @@ pub mod remote_progress;
 pub mod remote_tags;
 mod revlog;
 mod status;
+pub mod submodule_update;
 pub mod sync;
 mod tags;
 
@@ -87,6 +91,16 @@ pub enum AsyncGitNotification {
 	Blame,
 	///
 	RemoteTags,
+	///
+	Signatures,
+	///
+	SubmoduleUpdate,
+	///
+	DiffStats,
+	///
+	CommitDiffStats,
+	///
+	BranchesCompare,
 }
 
 /// current working directory `./`
@@ -1,7 +1,7 @@
 //!
 
 use easy_cast::{Conv, ConvFloat};
-use std::cmp;
+use std::{cmp, convert::TryFrom};
 
 ///
 #[derive(Clone, Copy, Default, Debug, PartialEq)]
@@ -18,6 +18,22 @@ impl ProgressPercent {
 		let progress = u8::try_conv_nearest(progress).unwrap_or(100);
 		Self { progress }
 	}
+	/// `current` of `total` mapped into the `from..=to` percent band
+	pub fn new_in_band(
+		current: usize,
+		total: usize,
+		from: u8,
+		to: u8,
+	) -> Self {
+		let span = u16::from(to.saturating_sub(from));
+		let offset = u16::from(Self::new(current, total).progress)
+			.saturating_mul(span)
+			/ 100;
+		let progress = u8::try_from(u16::from(from) + offset)
+			.unwrap_or(to)
+			.min(100);
+		Self { progress }
+	}
 	///
 	pub const fn empty() -> Self {
 		Self { progress: 0 }
@@ -45,6 +61,26 @@ mod tests {
 		assert_eq!(prog.progress, 100);
 	}
 
+	#[test]
+	fn test_progress_in_band() {
+		assert_eq!(
+			ProgressPercent::new_in_band(0, 10, 10, 40).progress,
+			10
+		);
+		assert_eq!(
+			ProgressPercent::new_in_band(5, 10, 10, 40).progress,
+			25
+		);
+		assert_eq!(
+			ProgressPercent::new_in_band(10, 10, 10, 40).progress,
+			40
+		);
+		assert_eq!(
+			ProgressPercent::new_in_band(1, 0, 40, 100).progress,
+			100
+		);
+	}
+
 	#[test]
 	fn test_progress_rounding() {
 		let prog = ProgressPercent::new(2, 10);
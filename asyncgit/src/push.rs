@@ -1,8 +1,10 @@
 use crate::{
 	error::{Error, Result},
 	sync::{
-		cred::BasicAuthCredential, remotes::push::push,
-		remotes::push::ProgressNotification,
+		cred::BasicAuthCredential,
+		remotes::push::{
+			push, push_with_lease, ProgressNotification,
+		},
 	},
 	AsyncGitNotification, RemoteProgress, CWD,
 };
@@ -21,6 +23,9 @@ pub struct PushRequest {
 	pub branch: String,
 	///
 	pub force: bool,
+	/// force only if the remote branch did not move since the last
+	/// fetch
+	pub lease: bool,
 	///
 	pub delete: bool,
 	///
@@ -94,15 +99,25 @@ impl AsyncPush {
 				arc_progress,
 			);
 
-			let res = push(
-				CWD,
-				params.remote.as_str(),
-				params.branch.as_str(),
-				params.force,
-				params.delete,
-				params.basic_credential.clone(),
-				Some(progress_sender.clone()),
-			);
+			let res = if params.lease {
+				push_with_lease(
+					CWD,
+					params.remote.as_str(),
+					params.branch.as_str(),
+					params.basic_credential.clone(),
+					Some(progress_sender.clone()),
+				)
+			} else {
+				push(
+					CWD,
+					params.remote.as_str(),
+					params.branch.as_str(),
+					params.force,
+					params.delete,
+					params.basic_credential.clone(),
+					Some(progress_sender.clone()),
+				)
+			};
 
 			progress_sender
 				.send(ProgressNotification::Done)
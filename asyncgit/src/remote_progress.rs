@@ -28,6 +28,20 @@ pub enum RemoteProgressState {
 	Done,
 }
 
+impl RemoteProgressState {
+	/// part of the overall gauge (in percent) this state covers,
+	/// so the push stages advance one bar instead of restarting
+	const fn band(&self) -> (u8, u8) {
+		match self {
+			Self::PackingAddingObject => (0, 10),
+			Self::PackingDeltafiction => (10, 40),
+			Self::Pushing => (40, 100),
+			Self::Transfer => (0, 100),
+			Self::Done => (100, 100),
+		}
+	}
+}
+
 ///
 #[derive(Clone, Debug)]
 pub struct RemoteProgress {
@@ -44,9 +58,12 @@ impl RemoteProgress {
 		current: usize,
 		total: usize,
 	) -> Self {
+		let (from, to) = state.band();
 		Self {
 			state,
-			progress: ProgressPercent::new(current, total),
+			progress: ProgressPercent::new_in_band(
+				current, total, from, to,
+			),
 		}
 	}
 
@@ -4,11 +4,11 @@ use crate::{
 	AsyncGitNotification, CWD,
 };
 use crossbeam_channel::Sender;
-use git2::Oid;
 use scopetime::scope_time;
 use std::{
+	cmp::Reverse,
 	sync::{
-		atomic::{AtomicBool, Ordering},
+		atomic::{AtomicBool, AtomicUsize, Ordering},
 		Arc, Mutex,
 	},
 	thread,
@@ -29,10 +29,13 @@ pub enum FetchStatus {
 ///
 pub struct AsyncLog {
 	current: Arc<Mutex<Vec<CommitId>>>,
+	/// length of `current`, readable without waiting for the walk
+	count: Arc<AtomicUsize>,
 	sender: Sender<AsyncGitNotification>,
 	pending: Arc<AtomicBool>,
 	background: Arc<AtomicBool>,
 	filter: Option<LogWalkerFilter>,
+	head: Option<CommitId>,
 }
 
 static LIMIT_COUNT: usize = 3000;
@@ -47,16 +50,24 @@ impl AsyncLog {
 	) -> Self {
 		Self {
 			current: Arc::new(Mutex::new(Vec::new())),
+			count: Arc::new(AtomicUsize::new(0)),
 			sender: sender.clone(),
 			pending: Arc::new(AtomicBool::new(false)),
 			background: Arc::new(AtomicBool::new(false)),
 			filter,
+			head: None,
 		}
 	}
 
+	/// replaces the filter, the next `fetch` walks the log again
+	pub fn set_filter(&mut self, filter: Option<LogWalkerFilter>) {
+		self.filter = filter;
+		self.head = None;
+	}
+
 	///
-	pub fn count(&mut self) -> Result<usize> {
-		Ok(self.current.lock()?.len())
+	pub fn count(&self) -> usize {
+		self.count.load(Ordering::Relaxed)
 	}
 
 	///
@@ -81,6 +92,25 @@ impl AsyncLog {
 		Ok(position)
 	}
 
+	/// `ids` ordered by their position in the log, oldest first.
+	/// ids that are not part of the log (yet) come first
+	pub fn oldest_first(
+		&self,
+		ids: &[CommitId],
+	) -> Result<Vec<CommitId>> {
+		let list = self.current.lock()?;
+
+		let mut ids = ids
+			.iter()
+			.map(|id| (list.iter().position(|x| x == id), *id))
+			.collect::<Vec<_>>();
+
+		// the log is walked newest first
+		ids.sort_by_key(|(position, _)| position.map(Reverse));
+
+		Ok(ids.into_iter().map(|(_, id)| id).collect())
+	}
+
 	///
 	pub fn is_pending(&self) -> bool {
 		self.pending.load(Ordering::Relaxed)
@@ -91,23 +121,18 @@ impl AsyncLog {
 		self.background.store(true, Ordering::Relaxed);
 	}
 
-	///
-	fn current_head(&self) -> Result<CommitId> {
-		Ok(self
-			.current
-			.lock()?
-			.first()
-			.map_or(Oid::zero().into(), |f| *f))
-	}
-
-	///
-	fn head_changed(&self) -> Result<bool> {
+	/// returns the new head if it differs from the last walked one,
+	/// with a filter set the first entry is not necessarily the head
+	fn head_changed(&self) -> Result<Option<CommitId>> {
 		if let Ok(head) = repo(CWD)?.head() {
 			if let Some(head) = head.target() {
-				return Ok(head != self.current_head()?.into());
+				let head = CommitId::from(head);
+				if self.head != Some(head) {
+					return Ok(Some(head));
+				}
 			}
 		}
-		Ok(false)
+		Ok(None)
 	}
 
 	///
@@ -118,13 +143,17 @@ impl AsyncLog {
 			return Ok(FetchStatus::Pending);
 		}
 
-		if !self.head_changed()? {
+		let head = if let Some(head) = self.head_changed()? {
+			head
+		} else {
 			return Ok(FetchStatus::NoChange);
-		}
+		};
 
+		self.head = Some(head);
 		self.clear()?;
 
 		let arc_current = Arc::clone(&self.current);
+		let arc_count = Arc::clone(&self.count);
 		let sender = self.sender.clone();
 		let arc_pending = Arc::clone(&self.pending);
 		let arc_background = Arc::clone(&self.background);
@@ -138,6 +167,7 @@ impl AsyncLog {
 
 			Self::fetch_helper(
 				&arc_current,
+				&arc_count,
 				&arc_background,
 				&sender,
 				filter,
@@ -154,6 +184,7 @@ impl AsyncLog {
 
 	fn fetch_helper(
 		arc_current: &Arc<Mutex<Vec<CommitId>>>,
+		arc_count: &Arc<AtomicUsize>,
 		arc_background: &Arc<AtomicBool>,
 		sender: &Sender<AsyncGitNotification>,
 		filter: Option<LogWalkerFilter>,
@@ -164,14 +195,17 @@ impl AsyncLog {
 			LogWalker::new(&r, LIMIT_COUNT)?.filter(filter);
 		loop {
 			entries.clear();
-			let res_is_err = walker.read(&mut entries).is_err();
+			let walked = walker.read(&mut entries);
 
-			if !res_is_err {
+			if walked.is_ok() {
 				let mut current = arc_current.lock()?;
 				current.extend(entries.iter());
+				arc_count.store(current.len(), Ordering::Relaxed);
 			}
 
-			if res_is_err || entries.len() <= 1 {
+			// a filter can drop most of the walked commits, so only
+			// the walk itself running dry ends the loop
+			if walked.map_or(true, |count| count <= 1) {
 				break;
 			}
 			Self::notify(sender);
@@ -190,6 +224,7 @@ impl AsyncLog {
 
 	fn clear(&mut self) -> Result<()> {
 		self.current.lock()?.clear();
+		self.count.store(0, Ordering::Relaxed);
 		Ok(())
 	}
 
@@ -1,16 +1,17 @@
 use crate::{
 	error::Result,
 	hash,
-	sync::{self, status::StatusType, ShowUntrackedFilesConfig},
+	sync::{
+		self, status::StatusType, FileSizeHint,
+		ShowUntrackedFilesConfig,
+	},
 	AsyncGitNotification, StatusItem, CWD,
 };
 use crossbeam_channel::Sender;
 use std::{
+	collections::HashMap,
 	hash::Hash,
-	sync::{
-		atomic::{AtomicUsize, Ordering},
-		Arc, Mutex,
-	},
+	sync::{Arc, Mutex},
 	time::{SystemTime, UNIX_EPOCH},
 };
 
@@ -21,9 +22,11 @@ fn current_tick() -> u128 {
 		.as_millis()
 }
 
-#[derive(Default, Hash, Clone)]
+#[derive(Default, Clone)]
 pub struct Status {
 	pub items: Vec<StatusItem>,
+	/// large files and lfs pointers among `items`
+	pub size_hints: HashMap<String, FileSizeHint>,
 }
 
 ///
@@ -32,6 +35,7 @@ pub struct StatusParams {
 	tick: u128,
 	status_type: StatusType,
 	config: Option<ShowUntrackedFilesConfig>,
+	large_file_threshold: Option<u64>,
 }
 
 impl StatusParams {
@@ -44,18 +48,56 @@ impl StatusParams {
 			tick: current_tick(),
 			status_type,
 			config,
+			large_file_threshold: None,
 		}
 	}
+
+	/// also looks for large files and lfs pointers,
+	/// see `sync::file_size_hints`
+	pub const fn with_size_hints(mut self, threshold: u64) -> Self {
+		self.large_file_threshold = Some(threshold);
+		self
+	}
 }
 
 struct Request<R, A>(R, Option<A>);
 
+/// the fetch in flight and the latest request made meanwhile, kept
+/// under one lock so no request can slip in between a fetch
+/// finishing and the queue being checked
+#[derive(Default)]
+struct FetchQueue {
+	in_flight: bool,
+	queued: Option<StatusParams>,
+}
+
+impl FetchQueue {
+	/// keeps `params` to run next if a fetch is in flight already
+	fn queue_if_in_flight(&mut self, params: StatusParams) -> bool {
+		if self.in_flight {
+			self.queued = Some(params);
+		}
+		self.in_flight
+	}
+
+	fn start(&mut self) {
+		self.in_flight = true;
+	}
+
+	/// marks the fetch as done, returns the request to run next
+	fn finish(&mut self) -> Option<StatusParams> {
+		self.in_flight = false;
+		self.queued.take()
+	}
+}
+
 ///
+#[derive(Clone)]
 pub struct AsyncStatus {
 	current: Arc<Mutex<Request<u64, Status>>>,
 	last: Arc<Mutex<Status>>,
+	queue: Arc<Mutex<FetchQueue>>,
 	sender: Sender<AsyncGitNotification>,
-	pending: Arc<AtomicUsize>,
 }
 
 impl AsyncStatus {
@@ -64,8 +106,8 @@ impl AsyncStatus {
 		Self {
 			current: Arc::new(Mutex::new(Request(0, None))),
 			last: Arc::new(Mutex::new(Status::default())),
+			queue: Arc::new(Mutex::new(FetchQueue::default())),
 			sender,
-			pending: Arc::new(AtomicUsize::new(0)),
 		}
 	}
 
@@ -77,16 +119,18 @@ impl AsyncStatus {
 
 	///
 	pub fn is_pending(&self) -> bool {
-		self.pending.load(Ordering::Relaxed) > 0
+		self.queue.lock().map_or(false, |queue| queue.in_flight)
 	}
 
-	///
+	/// requests made while a fetch is in flight are collapsed into
+	/// one that runs as soon as the current one is done
 	pub fn fetch(
 		&mut self,
 		params: &StatusParams,
 	) -> Result<Option<Status>> {
-		if self.is_pending() {
-			log::trace!("request blocked, still pending");
+		let mut queue = self.queue.lock()?;
+		if queue.queue_if_in_flight(*params) {
+			log::trace!("request queued, still pending");
 			return Ok(None);
 		}
 
@@ -109,49 +153,55 @@ impl AsyncStatus {
 			current.1 = None;
 		}
 
-		let arc_current = Arc::clone(&self.current);
-		let arc_last = Arc::clone(&self.last);
-		let sender = self.sender.clone();
-		let arc_pending = Arc::clone(&self.pending);
-		let status_type = params.status_type;
-		let config = params.config;
+		queue.start();
+		drop(queue);
 
-		self.pending.fetch_add(1, Ordering::Relaxed);
+		let mut self_clone = self.clone();
+		let params = *params;
 
 		rayon_core::spawn(move || {
 			let ok = Self::fetch_helper(
-				status_type,
-				config,
+				params,
 				hash_request,
-				&arc_current,
-				&arc_last,
+				&self_clone.current,
+				&self_clone.last,
 			)
 			.is_ok();
 
-			arc_pending.fetch_sub(1, Ordering::Relaxed);
+			let queued = self_clone
+				.queue
+				.lock()
+				.ok()
+				.and_then(|mut queue| queue.finish());
 
 			if ok {
-				sender
+				self_clone
+					.sender
 					.send(AsyncGitNotification::Status)
 					.expect("error sending status");
 			}
+
+			if let Some(params) = queued {
+				if let Err(e) = self_clone.fetch(&params) {
+					log::error!("queued status fetch error: {}", e);
+				}
+			}
 		});
 
 		Ok(None)
 	}
 
 	fn fetch_helper(
-		status_type: StatusType,
-		config: Option<ShowUntrackedFilesConfig>,
+		params: StatusParams,
 		hash_request: u64,
 		arc_current: &Arc<Mutex<Request<u64, Status>>>,
 		arc_last: &Arc<Mutex<Status>>,
 	) -> Result<()> {
-		let res = Self::get_status(status_type, config)?;
+		let res = Self::get_status(params)?;
 		log::trace!(
 			"status fetched: {} (type: {:?})",
 			hash_request,
-			status_type,
+			params.status_type,
 		);
 
 		{
@@ -169,16 +219,47 @@ impl AsyncStatus {
 		Ok(())
 	}
 
-	fn get_status(
-		status_type: StatusType,
-		config: Option<ShowUntrackedFilesConfig>,
-	) -> Result<Status> {
-		Ok(Status {
-			items: sync::status::get_status(
+	fn get_status(params: StatusParams) -> Result<Status> {
+		let items = sync::status::get_status(
+			CWD,
+			params.status_type,
+			params.config,
+		)?;
+
+		let size_hints = match params.large_file_threshold {
+			Some(threshold) => sync::file_size_hints(
 				CWD,
-				status_type,
-				config,
+				items.iter().map(|item| item.path.as_str()),
+				threshold,
 			)?,
-		})
+			None => HashMap::new(),
+		};
+
+		Ok(Status { items, size_hints })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fetch_queue() {
+		let mut queue = FetchQueue::default();
+		let first = StatusParams::new(StatusType::WorkingDir, None);
+		let second = StatusParams::new(StatusType::Stage, None);
+
+		assert!(!queue.queue_if_in_flight(first));
+		queue.start();
+
+		assert!(queue.queue_if_in_flight(first));
+		assert!(queue.queue_if_in_flight(second));
+		assert!(queue.finish() == Some(second));
+
+		// a request right after the fetch finished is not stranded
+		assert!(!queue.queue_if_in_flight(first));
+		queue.start();
+		assert!(queue.finish().is_none());
+		assert!(!queue.in_flight);
 	}
 }
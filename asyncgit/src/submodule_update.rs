@@ -0,0 +1,72 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::submodule_update,
+	AsyncGitNotification, CWD,
+};
+
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request(Vec<String>, bool),
+	Response(Result<()>),
+}
+
+/// inits/updates the given submodules one after another
+#[derive(Clone, Default)]
+pub struct AsyncSubmoduleUpdateJob {
+	state: Arc<Mutex<Option<JobState>>>,
+}
+
+///
+impl AsyncSubmoduleUpdateJob {
+	///
+	pub fn new(names: Vec<String>, init: bool) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				names, init,
+			)))),
+		}
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<()>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(..) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncSubmoduleUpdateJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(names, init) => {
+					JobState::Response(names.iter().try_for_each(
+						|name| submodule_update(CWD, name, init),
+					))
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::SubmoduleUpdate)
+	}
+}
@@ -0,0 +1,146 @@
+//! applying patch files
+
+use super::utils::repo;
+use crate::error::{Error, Result};
+use git2::{ApplyLocation, ApplyOptions, Diff, Repository};
+use scopetime::scope_time;
+use std::{fs, path::Path};
+
+const fn location(to_index: bool) -> ApplyLocation {
+	if to_index {
+		ApplyLocation::Index
+	} else {
+		ApplyLocation::WorkDir
+	}
+}
+
+/// applies the patch file at `patch_path` to the workdir or the
+/// index, nothing is applied unless all hunks apply cleanly
+pub fn apply_patch(
+	repo_path: &str,
+	patch_path: &Path,
+	to_index: bool,
+) -> Result<()> {
+	scope_time!("apply_patch");
+
+	let repo = repo(repo_path)?;
+	let diff = Diff::from_buffer(&fs::read(patch_path)?)?;
+
+	let mut opts = ApplyOptions::new();
+	opts.check(true);
+
+	if repo
+		.apply(&diff, location(to_index), Some(&mut opts))
+		.is_err()
+	{
+		return Err(Error::Generic(format!(
+			"patch does not apply to:\n{}",
+			rejected_files(&repo, &diff, to_index).join("\n")
+		)));
+	}
+
+	repo.apply(&diff, location(to_index), None)?;
+
+	Ok(())
+}
+
+/// paths of the files whose hunks do not apply
+fn rejected_files(
+	repo: &Repository,
+	diff: &Diff,
+	to_index: bool,
+) -> Vec<String> {
+	diff.deltas()
+		.filter_map(|delta| {
+			delta
+				.new_file()
+				.path()
+				.or_else(|| delta.old_file().path())
+				.map(Path::to_path_buf)
+		})
+		.filter(|path| {
+			let mut opts = ApplyOptions::new();
+			opts.check(true);
+			opts.delta_callback(|delta| {
+				delta.map_or(false, |delta| {
+					delta.new_file().path() == Some(path.as_path())
+						|| delta.old_file().path()
+							== Some(path.as_path())
+				})
+			});
+
+			repo.apply(diff, location(to_index), Some(&mut opts))
+				.is_err()
+		})
+		.map(|path| path.to_string_lossy().to_string())
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::apply_patch;
+	use crate::sync::{
+		export_patch,
+		tests::{get_statuses, repo_init, write_commit_file},
+		utils::{repo_read_file, repo_write_file},
+		PatchSource,
+	};
+	use tempfile::TempDir;
+
+	#[test]
+	fn test_apply_patch() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+		let patch_dir = TempDir::new().unwrap();
+		let patch = patch_dir.path().join("test.patch");
+
+		write_commit_file(&repo, "test.txt", "old\n", "c1");
+		repo_write_file(&repo, "test.txt", "new\n").unwrap();
+		export_patch(
+			repo_path,
+			&PatchSource::File(String::from("test.txt"), false),
+			&patch,
+		)
+		.unwrap();
+		repo_write_file(&repo, "test.txt", "old\n").unwrap();
+
+		apply_patch(repo_path, &patch, false).unwrap();
+
+		assert_eq!(
+			repo_read_file(&repo, "test.txt").unwrap(),
+			"new\n"
+		);
+		assert_eq!(get_statuses(repo_path), (1, 0));
+
+		let err = apply_patch(repo_path, &patch, false).unwrap_err();
+		assert!(err.to_string().contains("test.txt"));
+	}
+
+	#[test]
+	fn test_apply_patch_to_index() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+		let patch_dir = TempDir::new().unwrap();
+		let patch = patch_dir.path().join("test.patch");
+
+		write_commit_file(&repo, "test.txt", "old\n", "c1");
+		repo_write_file(&repo, "test.txt", "new\n").unwrap();
+		export_patch(
+			repo_path,
+			&PatchSource::File(String::from("test.txt"), false),
+			&patch,
+		)
+		.unwrap();
+		repo_write_file(&repo, "test.txt", "old\n").unwrap();
+
+		apply_patch(repo_path, &patch, true).unwrap();
+
+		assert_eq!(
+			repo_read_file(&repo, "test.txt").unwrap(),
+			"old\n"
+		);
+		assert_eq!(get_statuses(repo_path), (1, 1));
+	}
+}
@@ -0,0 +1,300 @@
+//! manual bisect, keeping its state in the same refs and files
+//! `git bisect` uses (`refs/bisect/*` and `BISECT_START`)
+
+use crate::{
+	error::{Error, Result},
+	sync::{utils, CommitId},
+};
+use git2::{build::CheckoutBuilder, BranchType, Oid, Repository};
+use scopetime::scope_time;
+use std::{fs, path::PathBuf};
+
+const BISECT_START: &str = "BISECT_START";
+const REF_BAD: &str = "refs/bisect/bad";
+const REF_GOOD_PREFIX: &str = "refs/bisect/good-";
+
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BisectState {
+	///
+	pub bad: Option<CommitId>,
+	///
+	pub good: Vec<CommitId>,
+	/// commits that might still be the first bad one
+	pub remaining: usize,
+	/// set once only one candidate is left
+	pub first_bad: Option<CommitId>,
+}
+
+/// `None` if there is no bisect in progress
+pub fn bisect_state(repo_path: &str) -> Result<Option<BisectState>> {
+	scope_time!("bisect_state");
+
+	let repo = utils::repo(repo_path)?;
+
+	if !start_file(&repo).exists() {
+		return Ok(None);
+	}
+
+	let bad = bad_commit(&repo);
+	let good = good_commits(&repo)?;
+
+	let (remaining, first_bad) = match bad {
+		Some(bad) if !good.is_empty() => {
+			let candidates = candidates(&repo, bad, &good)?;
+			let first_bad = if candidates.len() == 1 {
+				candidates.first().copied()
+			} else {
+				None
+			};
+
+			(candidates.len(), first_bad)
+		}
+		_ => (0, None),
+	};
+
+	Ok(Some(BisectState {
+		bad,
+		good,
+		remaining,
+		first_bad,
+	}))
+}
+
+/// remembers what `HEAD` points to, to get back there on reset
+pub fn bisect_start(repo_path: &str) -> Result<()> {
+	scope_time!("bisect_start");
+
+	let repo = utils::repo(repo_path)?;
+
+	if start_file(&repo).exists() {
+		return Err(Error::Generic(String::from(
+			"bisect already in progress",
+		)));
+	}
+
+	let head = repo.head()?;
+	let start = match head.shorthand() {
+		Some(name) if head.is_branch() => name.to_string(),
+		_ => utils::get_head_repo(&repo)?.to_string(),
+	};
+
+	fs::write(start_file(&repo), format!("{}\n", start))?;
+
+	Ok(())
+}
+
+/// marks `commit` as good and checks out the next candidate,
+/// returns the candidate if there was one to check out
+pub fn bisect_good(
+	repo_path: &str,
+	commit: CommitId,
+) -> Result<Option<CommitId>> {
+	scope_time!("bisect_good");
+
+	let repo = utils::repo(repo_path)?;
+
+	repo.reference(
+		&format!("{}{}", REF_GOOD_PREFIX, commit.to_string()),
+		commit.into(),
+		true,
+		"bisect good",
+	)?;
+
+	bisect_next(&repo)
+}
+
+/// marks `commit` as bad and checks out the next candidate,
+/// returns the candidate if there was one to check out
+pub fn bisect_bad(
+	repo_path: &str,
+	commit: CommitId,
+) -> Result<Option<CommitId>> {
+	scope_time!("bisect_bad");
+
+	let repo = utils::repo(repo_path)?;
+
+	repo.reference(REF_BAD, commit.into(), true, "bisect bad")?;
+
+	bisect_next(&repo)
+}
+
+/// ends the bisect and checks out what `HEAD` pointed to on start
+pub fn bisect_reset(repo_path: &str) -> Result<()> {
+	scope_time!("bisect_reset");
+
+	let repo = utils::repo(repo_path)?;
+
+	let file = start_file(&repo);
+	let start = fs::read_to_string(&file)?;
+	let start = start.trim();
+
+	if let Ok(branch) = repo.find_branch(start, BranchType::Local) {
+		let name = branch.get().name().ok_or(Error::NoHead)?;
+		checkout(&repo, |repo| repo.set_head(name))?;
+	} else {
+		let id = Oid::from_str(start)?;
+		checkout(&repo, |repo| repo.set_head_detached(id))?;
+	}
+
+	for reference in repo.references_glob("refs/bisect/*")? {
+		reference?.delete()?;
+	}
+
+	fs::remove_file(file)?;
+
+	Ok(())
+}
+
+fn start_file(repo: &Repository) -> PathBuf {
+	repo.path().join(BISECT_START)
+}
+
+fn bad_commit(repo: &Repository) -> Option<CommitId> {
+	repo.refname_to_id(REF_BAD).ok().map(CommitId::new)
+}
+
+fn good_commits(repo: &Repository) -> Result<Vec<CommitId>> {
+	let mut good = Vec::new();
+
+	for reference in
+		repo.references_glob(&format!("{}*", REF_GOOD_PREFIX))?
+	{
+		if let Some(id) = reference?.target() {
+			good.push(CommitId::new(id));
+		}
+	}
+
+	Ok(good)
+}
+
+/// commits reachable from `bad` but not from any of the `good` ones,
+/// newest first
+fn candidates(
+	repo: &Repository,
+	bad: CommitId,
+	good: &[CommitId],
+) -> Result<Vec<CommitId>> {
+	let mut walk = repo.revwalk()?;
+	walk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+	walk.push(bad.into())?;
+	for id in good {
+		walk.hide((*id).into())?;
+	}
+
+	walk.map(|id| Ok(CommitId::new(id?))).collect()
+}
+
+/// checks out the midpoint of the remaining candidates
+/// once both a good and a bad commit are known
+fn bisect_next(repo: &Repository) -> Result<Option<CommitId>> {
+	let good = good_commits(repo)?;
+
+	let bad = match bad_commit(repo) {
+		Some(bad) if !good.is_empty() => bad,
+		_ => return Ok(None),
+	};
+
+	let candidates = candidates(repo, bad, &good)?;
+
+	if candidates.len() < 2 {
+		return Ok(None);
+	}
+
+	let next = candidates[candidates.len() / 2];
+
+	checkout(repo, |repo| repo.set_head_detached(next.into()))?;
+
+	Ok(Some(next))
+}
+
+/// moves `HEAD` via `set_head` and checks it out,
+/// does not work if there are uncommitted changes
+fn checkout(
+	repo: &Repository,
+	set_head: impl FnOnce(
+		&Repository,
+	) -> std::result::Result<(), git2::Error>,
+) -> Result<()> {
+	let statuses = repo.statuses(Some(
+		git2::StatusOptions::new().include_ignored(false),
+	))?;
+
+	if !statuses.is_empty() {
+		return Err(Error::UncommittedChanges);
+	}
+
+	set_head(repo)?;
+	repo.checkout_head(Some(CheckoutBuilder::new().force()))?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		tests::{repo_init, write_commit_file},
+		utils::get_head_repo,
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_bisect() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let commits = (1..=8)
+			.map(|i| {
+				write_commit_file(
+					&repo,
+					"test.txt",
+					&i.to_string(),
+					&format!("commit{}", i),
+				)
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(bisect_state(repo_path).unwrap(), None);
+
+		bisect_start(repo_path).unwrap();
+		assert!(bisect_start(repo_path).is_err());
+
+		assert_eq!(bisect_bad(repo_path, commits[7]).unwrap(), None);
+		assert_eq!(
+			bisect_good(repo_path, commits[0]).unwrap(),
+			Some(commits[4])
+		);
+		assert_eq!(get_head_repo(&repo).unwrap(), commits[4]);
+		assert_eq!(
+			bisect_state(repo_path).unwrap().unwrap().remaining,
+			7
+		);
+
+		assert_eq!(
+			bisect_bad(repo_path, commits[4]).unwrap(),
+			Some(commits[2])
+		);
+		assert_eq!(
+			bisect_good(repo_path, commits[2]).unwrap(),
+			Some(commits[3])
+		);
+		assert_eq!(bisect_bad(repo_path, commits[3]).unwrap(), None);
+
+		let state = bisect_state(repo_path).unwrap().unwrap();
+		assert_eq!(state.first_bad, Some(commits[3]));
+		assert_eq!(state.bad, Some(commits[3]));
+		assert_eq!(state.good.len(), 2);
+
+		bisect_reset(repo_path).unwrap();
+
+		assert_eq!(bisect_state(repo_path).unwrap(), None);
+		assert_eq!(repo.head().unwrap().shorthand(), Some("master"));
+		assert_eq!(get_head_repo(&repo).unwrap(), commits[7]);
+		assert_eq!(
+			repo.references_glob("refs/bisect/*").unwrap().count(),
+			0
+		);
+	}
+}
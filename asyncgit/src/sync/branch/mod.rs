@@ -5,20 +5,28 @@ pub mod merge_ff;
 pub mod merge_rebase;
 pub mod rename;
 
-use std::collections::HashSet;
+use std::{
+	collections::{HashMap, HashSet},
+	path::Path,
+};
 
 use super::{
-	remotes::get_default_remote_in_repo, utils::bytes2string,
+	remotes::get_default_remote_in_repo,
+	stash::{stash_apply, stash_drop, stash_pop, stash_save},
+	utils::bytes2string,
 };
 use crate::{
 	error::{Error, Result},
 	sync::{utils, CommitId},
 };
-use git2::{Branch, BranchType, Repository};
+use git2::{
+	Branch, BranchType, CheckoutNotificationType, Oid, Repository,
+};
 use scopetime::scope_time;
 use utils::get_head_repo;
 
-/// returns the branch-name head is currently pointing to
+/// returns the branch-name head is currently pointing to,
+/// `Error::DetachedHead` if it points to a commit instead
 /// this might be expensive, see `cached::BranchName`
 pub(crate) fn get_branch_name(repo_path: &str) -> Result<String> {
 	let repo = utils::repo(repo_path)?;
@@ -32,6 +40,10 @@ pub(crate) fn get_branch_name_repo(
 ) -> Result<String> {
 	scope_time!("get_branch_name_repo");
 
+	if repo.head_detached()? {
+		return Err(Error::DetachedHead);
+	}
+
 	let iter = repo.branches(None)?;
 
 	for b in iter {
@@ -46,13 +58,20 @@ pub(crate) fn get_branch_name_repo(
 	Err(Error::NoHead)
 }
 
+///
+#[derive(Debug)]
+pub struct UpstreamBranch {
+	/// shorthand like `origin/master`
+	pub name: String,
+}
+
 ///
 #[derive(Debug)]
 pub struct LocalBranch {
 	///
 	pub is_head: bool,
 	///
-	pub has_upstream: bool,
+	pub upstream: Option<UpstreamBranch>,
 	///
 	pub remote: Option<String>,
 }
@@ -62,6 +81,8 @@ pub struct LocalBranch {
 pub struct RemoteBranch {
 	///
 	pub has_tracking: bool,
+	/// the remote this branch belongs to
+	pub remote: Option<String>,
 }
 
 ///
@@ -97,6 +118,19 @@ impl BranchInfo {
 
 		None
 	}
+
+	/// the upstream remote of a local branch
+	/// or the remote a remote branch belongs to
+	pub fn remote(&self) -> Option<&str> {
+		match &self.details {
+			BranchDetails::Local(details) => {
+				details.remote.as_deref()
+			}
+			BranchDetails::Remote(details) => {
+				details.remote.as_deref()
+			}
+		}
+	}
 }
 
 ///
@@ -144,25 +178,36 @@ pub fn get_branches_info(
 			let reference = bytes2string(branch.get().name_bytes())?;
 			let upstream = branch.upstream();
 
-			let remote = repo
-				.branch_upstream_remote(&reference)
-				.ok()
-				.as_ref()
-				.and_then(git2::Buf::as_str)
-				.map(String::from);
+			let remote = if local {
+				repo.branch_upstream_remote(&reference)
+			} else {
+				repo.branch_remote_name(&reference)
+			}
+			.ok()
+			.as_ref()
+			.and_then(git2::Buf::as_str)
+			.map(String::from);
 
 			let name_bytes = branch.name_bytes()?;
 
 			let details = if local {
+				let upstream = match upstream {
+					Ok(upstream) => Some(UpstreamBranch {
+						name: bytes2string(upstream.name_bytes()?)?,
+					}),
+					Err(_) => None,
+				};
+
 				BranchDetails::Local(LocalBranch {
 					is_head: branch.is_head(),
-					has_upstream: upstream.is_ok(),
+					upstream,
 					remote,
 				})
 			} else {
 				BranchDetails::Remote(RemoteBranch {
 					has_tracking: remotes_with_tracking
 						.contains(name_bytes),
+					remote,
 				})
 			};
 
@@ -212,6 +257,35 @@ pub(crate) fn branch_set_upstream(
 	Ok(())
 }
 
+/// sets the upstream of the local `branch` to the branch `upstream`
+/// (like `origin/master`) or unsets it on `None`
+pub fn set_upstream(
+	repo_path: &str,
+	branch: &str,
+	upstream: Option<&str>,
+) -> Result<()> {
+	scope_time!("set_upstream");
+
+	let repo = utils::repo(repo_path)?;
+
+	let mut branch = repo.find_branch(branch, BranchType::Local)?;
+
+	if let Some(upstream) = upstream {
+		if repo.find_branch(upstream, BranchType::Remote).is_err()
+			&& repo.find_branch(upstream, BranchType::Local).is_err()
+		{
+			return Err(Error::Generic(format!(
+				"upstream branch not found: {}",
+				upstream
+			)));
+		}
+	}
+
+	branch.set_upstream(upstream)?;
+
+	Ok(())
+}
+
 /// returns remote of the upstream tracking branch for `branch`
 pub fn get_branch_remote(
 	repo_path: &str,
@@ -251,6 +325,66 @@ pub fn branch_compare_upstream(
 
 	let repo = utils::repo(repo_path)?;
 
+	let (branch_commit, upstream_commit) =
+		upstream_tips(&repo, branch)?;
+
+	compare_commits(&repo, branch_commit, upstream_commit)
+}
+
+/// `branch_compare_upstream` for many local branches at once,
+/// branches without upstream are left out
+pub fn branches_compare_upstream(
+	repo_path: &str,
+	branches: &[String],
+) -> Result<HashMap<String, BranchCompare>> {
+	scope_time!("branches_compare_upstream");
+
+	let repo = utils::repo(repo_path)?;
+
+	let mut compares = HashMap::new();
+
+	for branch in branches {
+		if let Ok((branch_commit, upstream_commit)) =
+			upstream_tips(&repo, branch)
+		{
+			compares.insert(
+				branch.clone(),
+				compare_commits(
+					&repo,
+					branch_commit,
+					upstream_commit,
+				)?,
+			);
+		}
+	}
+
+	Ok(compares)
+}
+
+/// tip of the local `branch` and of its upstream
+pub fn branch_upstream_tips(
+	repo_path: &str,
+	branch: &str,
+) -> Result<(CommitId, CommitId)> {
+	scope_time!("branch_upstream_tips");
+
+	let repo = utils::repo(repo_path)?;
+
+	let (branch_commit, upstream_commit) =
+		upstream_tips(&repo, branch)?;
+
+	Ok(
+		(
+			CommitId::new(branch_commit),
+			CommitId::new(upstream_commit),
+		),
+	)
+}
+
+fn upstream_tips(
+	repo: &Repository,
+	branch: &str,
+) -> Result<(Oid, Oid)> {
 	let branch = repo.find_branch(branch, BranchType::Local)?;
 
 	let upstream = branch.upstream()?;
@@ -261,43 +395,190 @@ pub fn branch_compare_upstream(
 	let upstream_commit =
 		upstream.into_reference().peel_to_commit()?.id();
 
-	let (ahead, behind) =
-		repo.graph_ahead_behind(branch_commit, upstream_commit)?;
+	Ok((branch_commit, upstream_commit))
+}
+
+fn compare_commits(
+	repo: &Repository,
+	local: Oid,
+	upstream: Oid,
+) -> Result<BranchCompare> {
+	let (ahead, behind) = repo.graph_ahead_behind(local, upstream)?;
 
 	Ok(BranchCompare { ahead, behind })
 }
 
-/// Modify HEAD to point to a branch then checkout head, does not work if there are uncommitted changes
+/// commits only reachable from `left` and the ones only reachable
+/// from `right`, newest first and at most `limit` per side
+pub fn branch_compare_commits(
+	repo_path: &str,
+	left: CommitId,
+	right: CommitId,
+	limit: usize,
+) -> Result<(Vec<CommitId>, Vec<CommitId>)> {
+	scope_time!("branch_compare_commits");
+
+	let repo = utils::repo(repo_path)?;
+
+	Ok((
+		commits_unique_to(&repo, left, right, limit)?,
+		commits_unique_to(&repo, right, left, limit)?,
+	))
+}
+
+/// commits reachable from `tip` but not from `base`
+fn commits_unique_to(
+	repo: &Repository,
+	tip: CommitId,
+	base: CommitId,
+	limit: usize,
+) -> Result<Vec<CommitId>> {
+	let mut walk = repo.revwalk()?;
+	walk.set_sorting(git2::Sort::TIME)?;
+	walk.push(tip.into())?;
+	walk.hide(base.into())?;
+
+	walk.take(limit).map(|id| Ok(CommitId::new(id?))).collect()
+}
+
+/// branch whose tip contains a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchContaining {
+	/// like `master` or `origin/master`
+	pub name: String,
+	///
+	pub local: bool,
+	///
+	pub is_head: bool,
+}
+
+/// local and remote branches whose tip is `id` or descends from it
+/// (like `git branch -a --contains`), the checked out branch first,
+/// then local before remote branches
+pub fn get_branches_containing(
+	repo_path: &str,
+	id: CommitId,
+) -> Result<Vec<BranchContaining>> {
+	scope_time!("get_branches_containing");
+
+	let repo = utils::repo(repo_path)?;
+	let id = id.into();
+
+	let mut res = Vec::new();
+	for branch in repo.branches(None)? {
+		let (branch, branch_type) = branch?;
+
+		// symbolic refs like `origin/HEAD`
+		let tip = if let Some(tip) = branch.get().target() {
+			tip
+		} else {
+			continue;
+		};
+
+		if tip == id || repo.graph_descendant_of(tip, id)? {
+			res.push(BranchContaining {
+				name: bytes2string(branch.name_bytes()?)?,
+				local: branch_type == BranchType::Local,
+				is_head: branch.is_head(),
+			});
+		}
+	}
+
+	res.sort_by(|a, b| {
+		b.is_head
+			.cmp(&a.is_head)
+			.then(b.local.cmp(&a.local))
+			.then_with(|| a.name.cmp(&b.name))
+	});
+
+	Ok(res)
+}
+
+/// local changes are carried over unless they conflict with the
+/// branch, then nothing is touched and `Error::CheckoutConflicts`
+/// lists the conflicting paths
 pub fn checkout_branch(
 	repo_path: &str,
 	branch_ref: &str,
 ) -> Result<()> {
 	scope_time!("checkout_branch");
 
-	// This defaults to a safe checkout, so don't delete anything that
-	// hasn't been committed or stashed, in this case it will Err
 	let repo = utils::repo(repo_path)?;
-	let cur_ref = repo.head()?;
-	let statuses = repo.statuses(Some(
-		git2::StatusOptions::new().include_ignored(false),
-	))?;
-
-	if statuses.is_empty() {
-		repo.set_head(branch_ref)?;
-
-		if let Err(e) = repo.checkout_head(Some(
-			git2::build::CheckoutBuilder::new().force(),
-		)) {
-			// This is safe beacuse cur_ref was just found
-			repo.set_head(
-				bytes2string(cur_ref.name_bytes())?.as_str(),
-			)?;
-			return Err(Error::Git(e));
-		}
-		Ok(())
-	} else {
-		Err(Error::UncommittedChanges)
+	let target = repo.find_reference(branch_ref)?.peel_to_commit()?;
+
+	let mut conflicts = Vec::new();
+	let res = {
+		let mut checkout = git2::build::CheckoutBuilder::new();
+		checkout
+			.safe()
+			.notify_on(CheckoutNotificationType::CONFLICT)
+			.notify(|_, path, _, _, _| {
+				if let Some(path) = path.and_then(Path::to_str) {
+					conflicts.push(path.to_string());
+				}
+				true
+			});
+
+		repo.checkout_tree(target.as_object(), Some(&mut checkout))
+	};
+
+	if !conflicts.is_empty() {
+		return Err(Error::CheckoutConflicts(conflicts));
+	}
+	res?;
+
+	repo.set_head(branch_ref)?;
+
+	Ok(())
+}
+
+/// stashes local changes (untracked files too) to checkout the
+/// branch and restores them afterwards, if that conflicts they are
+/// kept in the stash
+pub fn checkout_branch_stashed(
+	repo_path: &str,
+	branch_ref: &str,
+) -> Result<()> {
+	scope_time!("checkout_branch_stashed");
+
+	let stash = stash_save(
+		repo_path,
+		Some(&format!("checkout {}", branch_ref)),
+		true,
+		false,
+	)?;
+
+	if let Err(e) = checkout_branch(repo_path, branch_ref) {
+		stash_pop(repo_path, stash)?;
+		return Err(e);
 	}
+
+	stash_apply(repo_path, stash, false).map_err(|e| {
+		Error::Generic(format!(
+			"restoring local changes failed, they are kept in the stash: {}",
+			e
+		))
+	})?;
+	stash_drop(repo_path, stash)?;
+
+	Ok(())
+}
+
+/// detaches `HEAD` at the commit `id`, local changes are carried
+/// over unless they conflict with it, like `git checkout <commit>`
+pub fn checkout_commit(repo_path: &str, id: CommitId) -> Result<()> {
+	scope_time!("checkout_commit");
+
+	let repo = utils::repo(repo_path)?;
+	let commit = repo.find_commit(id.into())?;
+
+	repo.checkout_tree(
+		commit.as_object(),
+		Some(git2::build::CheckoutBuilder::new().safe()),
+	)?;
+	repo.set_head_detached(id.into())?;
+
+	Ok(())
 }
 
 ///
@@ -377,6 +658,25 @@ pub fn create_branch(repo_path: &str, name: &str) -> Result<String> {
 	Ok(branch_ref_name)
 }
 
+/// creates the branch `name` pointing at `start` without checking
+/// it out, like `git branch <name> <start>`
+pub fn create_branch_at(
+	repo_path: &str,
+	name: &str,
+	start: CommitId,
+) -> Result<String> {
+	scope_time!("create_branch_at");
+
+	let repo = utils::repo(repo_path)?;
+	let commit = repo.find_commit(start.into())?;
+
+	let branch = repo.branch(name, &commit, false)?;
+	let branch_ref = branch.into_reference();
+	let branch_ref_name = bytes2string(branch_ref.name_bytes())?;
+
+	Ok(branch_ref_name)
+}
+
 #[cfg(test)]
 mod tests_branch_name {
 	use super::*;
@@ -405,12 +705,71 @@ mod tests_branch_name {
 			Err(Error::NoHead)
 		));
 	}
+
+	#[test]
+	fn test_detached_head() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let head = get_head_repo(&repo).unwrap();
+		repo.set_head_detached(head.into()).unwrap();
+
+		assert!(matches!(
+			get_branch_name(repo_path),
+			Err(Error::DetachedHead)
+		));
+	}
+}
+
+#[cfg(test)]
+mod tests_checkout_commit {
+	use super::*;
+	use crate::sync::tests::{repo_init, write_commit_file};
+	use std::fs;
+
+	#[test]
+	fn test_checkout_commit() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let first = write_commit_file(&repo, "a.txt", "1", "first");
+		write_commit_file(&repo, "a.txt", "2", "second");
+		fs::write(root.join("b.txt"), "local").unwrap();
+
+		checkout_commit(repo_path, first).unwrap();
+
+		assert!(repo.head_detached().unwrap());
+		assert_eq!(get_head_repo(&repo).unwrap(), first);
+		assert_eq!(
+			fs::read_to_string(root.join("a.txt")).unwrap(),
+			"1"
+		);
+		// untracked files are carried over
+		assert!(root.join("b.txt").exists());
+	}
+
+	#[test]
+	fn test_checkout_commit_conflicting_changes() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let first = write_commit_file(&repo, "a.txt", "1", "first");
+		let second = write_commit_file(&repo, "a.txt", "2", "second");
+		fs::write(root.join("a.txt"), "local").unwrap();
+
+		assert!(checkout_commit(repo_path, first).is_err());
+		assert!(!repo.head_detached().unwrap());
+		assert_eq!(get_head_repo(&repo).unwrap(), second);
+	}
 }
 
 #[cfg(test)]
 mod tests_create_branch {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
 
 	#[test]
 	fn test_smoke() {
@@ -425,12 +784,36 @@ mod tests_create_branch {
 			"branch1"
 		);
 	}
+
+	#[test]
+	fn test_create_branch_at() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let first = write_commit_file(&repo, "a.txt", "1", "first");
+		write_commit_file(&repo, "a.txt", "2", "second");
+
+		assert_eq!(
+			create_branch_at(repo_path, "old", first).unwrap(),
+			"refs/heads/old"
+		);
+
+		let branch =
+			repo.find_branch("old", BranchType::Local).unwrap();
+		assert_eq!(branch.get().target(), Some(first.into()));
+		// stays on the current branch
+		assert_eq!(
+			get_branch_name(repo_path).unwrap().as_str(),
+			"master"
+		);
+	}
 }
 
 #[cfg(test)]
 mod tests_branch_compare {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
 
 	#[test]
 	fn test_smoke() {
@@ -443,6 +826,76 @@ mod tests_branch_compare {
 		let res = branch_compare_upstream(repo_path, "test");
 
 		assert_eq!(res.is_err(), true);
+		assert!(branch_upstream_tips(repo_path, "test").is_err());
+	}
+
+	#[test]
+	fn test_compare_commits() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let base = write_commit_file(&repo, "a.txt", "a", "c1");
+
+		create_branch(repo_path, "foo").unwrap();
+		let foo = write_commit_file(&repo, "b.txt", "b", "c2");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		let master1 = write_commit_file(&repo, "c.txt", "c", "c3");
+		let master2 = write_commit_file(&repo, "d.txt", "d", "c4");
+
+		let (left, right) =
+			branch_compare_commits(repo_path, master2, foo, 10)
+				.unwrap();
+
+		assert_eq!(left, vec![master2, master1]);
+		assert_eq!(right, vec![foo]);
+
+		let (left, right) =
+			branch_compare_commits(repo_path, master2, foo, 1)
+				.unwrap();
+
+		assert_eq!(left, vec![master2]);
+		assert_eq!(right, vec![foo]);
+
+		let (left, right) =
+			branch_compare_commits(repo_path, base, master2, 10)
+				.unwrap();
+
+		assert!(left.is_empty());
+		assert_eq!(right, vec![master2, master1]);
+	}
+
+	#[test]
+	fn test_branches_containing() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let base = write_commit_file(&repo, "a.txt", "a", "c1");
+
+		create_branch(repo_path, "foo").unwrap();
+		let foo = write_commit_file(&repo, "b.txt", "b", "c2");
+		create_branch(repo_path, "bar").unwrap();
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		let master = write_commit_file(&repo, "c.txt", "c", "c3");
+
+		let names = |id| {
+			get_branches_containing(repo_path, id)
+				.unwrap()
+				.into_iter()
+				.map(|b| b.name)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(names(base), vec!["master", "bar", "foo"]);
+		assert_eq!(names(foo), vec!["bar", "foo"]);
+		assert_eq!(names(master), vec!["master"]);
+
+		let head =
+			&get_branches_containing(repo_path, base).unwrap()[0];
+		assert!(head.is_head && head.local);
 	}
 }
 
@@ -569,6 +1022,8 @@ mod tests_branches {
 			"r2"
 		);
 
+		assert_eq!(branches[2].remote(), Some("r2"));
+
 		assert_eq!(
 			get_branch_remote(repo_path, "r1branch")
 				.unwrap()
@@ -609,7 +1064,8 @@ mod tests_branches {
 #[cfg(test)]
 mod tests_checkout {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
+	use std::fs;
 
 	#[test]
 	fn test_smoke() {
@@ -639,6 +1095,72 @@ mod tests_checkout {
 		);
 		assert!(checkout_branch(repo_path, "refs/heads/test").is_ok());
 	}
+
+	#[test]
+	fn test_local_changes() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "a.txt", "1", "first");
+		create_branch(repo_path, "test").unwrap();
+		write_commit_file(&repo, "a.txt", "2", "second");
+
+		// not touched by the checkout, carried over
+		fs::write(root.join("b.txt"), "local").unwrap();
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		assert_eq!(
+			fs::read_to_string(root.join("b.txt")).unwrap(),
+			"local"
+		);
+
+		fs::write(root.join("a.txt"), "local").unwrap();
+		let res = checkout_branch(repo_path, "refs/heads/test");
+		assert!(matches!(
+			res,
+			Err(Error::CheckoutConflicts(files)) if files == ["a.txt"]
+		));
+		assert_eq!(
+			get_branch_name(repo_path).unwrap().as_str(),
+			"master"
+		);
+		assert_eq!(
+			fs::read_to_string(root.join("a.txt")).unwrap(),
+			"local"
+		);
+	}
+
+	#[test]
+	fn test_checkout_stashed() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "a.txt", "1\n2\n3\n", "first");
+		create_branch(repo_path, "test").unwrap();
+		write_commit_file(&repo, "a.txt", "x\n2\n3\n", "second");
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		fs::write(root.join("a.txt"), "1\n2\ny\n").unwrap();
+		assert!(
+			checkout_branch(repo_path, "refs/heads/test").is_err()
+		);
+
+		checkout_branch_stashed(repo_path, "refs/heads/test")
+			.unwrap();
+
+		assert_eq!(
+			get_branch_name(repo_path).unwrap().as_str(),
+			"test"
+		);
+		assert_eq!(
+			fs::read_to_string(root.join("a.txt")).unwrap(),
+			"x\n2\ny\n"
+		);
+		assert!(crate::sync::get_stashes(repo_path)
+			.unwrap()
+			.is_empty());
+	}
 }
 
 #[cfg(test)]
@@ -748,6 +1270,7 @@ mod test_remote_branches {
 		assert_eq!(&branches[0].name, "origin/HEAD");
 		assert_eq!(&branches[1].name, "origin/foo");
 		assert_eq!(&branches[2].name, "origin/master");
+		assert_eq!(branches[1].remote(), Some("origin"));
 	}
 
 	#[test]
@@ -793,6 +1316,66 @@ mod test_remote_branches {
 		);
 
 		assert_eq!(&get_branch_name(clone2_dir).unwrap(), "foo");
+
+		let local_branches =
+			get_branches_info(clone2_dir, true).unwrap();
+		let upstream = local_branches[0]
+			.local_details()
+			.unwrap()
+			.upstream
+			.as_ref()
+			.unwrap();
+		assert_eq!(&upstream.name, "origin/foo");
+
+		create_branch(clone2_dir, "bar").unwrap();
+
+		let compares = branches_compare_upstream(
+			clone2_dir,
+			&[String::from("foo"), String::from("bar")],
+		)
+		.unwrap();
+		assert_eq!(compares.len(), 1);
+		assert_eq!(compares["foo"].ahead, 0);
+		assert_eq!(compares["foo"].behind, 0);
+	}
+
+	#[test]
+	fn test_set_upstream() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+
+		let (clone1_dir, clone1) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+		let clone1_dir = clone1_dir.path().to_str().unwrap();
+
+		write_commit_file(&clone1, "test.txt", "test", "commit1");
+		push(
+			clone1_dir, "origin", "master", false, false, None, None,
+		)
+		.unwrap();
+
+		let upstream_name = || {
+			get_branches_info(clone1_dir, true).unwrap()[0]
+				.local_details()
+				.unwrap()
+				.upstream
+				.as_ref()
+				.map(|upstream| upstream.name.clone())
+		};
+
+		set_upstream(clone1_dir, "master", None).unwrap();
+		assert_eq!(upstream_name(), None);
+
+		assert!(set_upstream(
+			clone1_dir,
+			"master",
+			Some("origin/foo")
+		)
+		.is_err());
+		assert_eq!(upstream_name(), None);
+
+		set_upstream(clone1_dir, "master", Some("origin/master"))
+			.unwrap();
+		assert_eq!(upstream_name().as_deref(), Some("origin/master"));
 	}
 
 	#[test]
@@ -0,0 +1,177 @@
+use crate::{
+	error::Result,
+	sync::{commit::signature_allow_undefined_name, utils, CommitId},
+};
+use git2::ResetType;
+use scopetime::scope_time;
+
+/// cherry-picks `ids` onto `HEAD` in the given order, each one
+/// committed with its original author and message.
+/// stops at the first conflicting commit which is left pending in
+/// `RepoState::CherryPick`, returns the commits created before that
+pub fn cherry_pick_commits(
+	repo_path: &str,
+	ids: &[CommitId],
+) -> Result<Vec<CommitId>> {
+	scope_time!("cherry_pick_commits");
+
+	let repo = utils::repo(repo_path)?;
+	let signature = signature_allow_undefined_name(&repo)?;
+
+	let mut picked = Vec::with_capacity(ids.len());
+
+	for id in ids {
+		let commit = repo.find_commit((*id).into())?;
+		repo.cherrypick(&commit, None)?;
+
+		let mut index = repo.index()?;
+		if index.has_conflicts() {
+			break;
+		}
+
+		let tree = repo.find_tree(index.write_tree()?)?;
+		let head = repo.head()?.peel_to_commit()?;
+
+		let new_id = repo.commit(
+			Some("HEAD"),
+			&commit.author(),
+			&signature,
+			commit.message().unwrap_or_default(),
+			&tree,
+			&[&head],
+		)?;
+
+		repo.cleanup_state()?;
+		picked.push(new_id.into());
+	}
+
+	Ok(picked)
+}
+
+/// like `git cherry-pick --abort`: resets index and workdir to
+/// `HEAD` and removes the cherry-pick state (`CHERRY_PICK_HEAD`)
+pub fn abort_cherry_pick(repo_path: &str) -> Result<()> {
+	scope_time!("abort_cherry_pick");
+
+	let repo = utils::repo(repo_path)?;
+
+	let head = repo.head()?.peel_to_commit()?;
+	repo.reset(head.as_object(), ResetType::Hard, None)?;
+
+	repo.cleanup_state()?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		checkout_branch, create_branch, get_commit_details,
+		repo_state,
+		tests::{repo_init, write_commit_file},
+		RepoState,
+	};
+	use std::fs;
+
+	#[test]
+	fn test_cherry_pick_commits() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "base", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		let c2 = write_commit_file(&repo, "a.txt", "a", "commit2");
+		let c3 = write_commit_file(&repo, "b.txt", "b", "commit3");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(&repo, "test.txt", "ours", "commit4");
+
+		let picked =
+			cherry_pick_commits(repo_path, &[c2, c3]).unwrap();
+
+		assert_eq!(picked.len(), 2);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(
+			fs::read_to_string(root.join("a.txt")).unwrap(),
+			"a"
+		);
+		assert_eq!(
+			fs::read_to_string(root.join("b.txt")).unwrap(),
+			"b"
+		);
+
+		let details =
+			get_commit_details(repo_path, picked[1]).unwrap();
+		assert_eq!(details.message.unwrap().subject, "commit3");
+		let parent = repo
+			.find_commit(picked[1].into())
+			.unwrap()
+			.parent_id(0)
+			.unwrap();
+		assert_eq!(CommitId::from(parent), picked[0]);
+	}
+
+	#[test]
+	fn test_cherry_pick_commits_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "base", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		let c2 = write_commit_file(&repo, "a.txt", "a", "commit2");
+		let c3 =
+			write_commit_file(&repo, "test.txt", "theirs", "commit3");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(&repo, "test.txt", "ours", "commit4");
+
+		let picked =
+			cherry_pick_commits(repo_path, &[c2, c3]).unwrap();
+
+		assert_eq!(picked.len(), 1);
+		assert_eq!(
+			repo_state(repo_path).unwrap(),
+			RepoState::CherryPick
+		);
+	}
+
+	#[test]
+	fn test_abort_cherry_pick() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "base", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		let theirs =
+			write_commit_file(&repo, "test.txt", "theirs", "commit2");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(&repo, "test.txt", "ours", "commit3");
+
+		let commit = repo.find_commit(theirs.into()).unwrap();
+		repo.cherrypick(&commit, None).unwrap();
+
+		assert_eq!(
+			repo_state(repo_path).unwrap(),
+			RepoState::CherryPick
+		);
+
+		abort_cherry_pick(repo_path).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		let mut index = repo.index().unwrap();
+		index.read(true).unwrap();
+		assert!(!index.has_conflicts());
+		assert_eq!(
+			fs::read_to_string(root.join("test.txt")).unwrap(),
+			"ours"
+		);
+	}
+}
@@ -1,13 +1,65 @@
-use super::{utils::repo, CommitId};
+use super::{
+	commit_details::CommitSignature,
+	config::get_config_string_repo,
+	utils::{repo, work_dir},
+	CommitId, LogWalker,
+};
 use crate::{error::Result, sync::utils::get_head_repo};
-use git2::{ErrorCode, ObjectType, Repository, Signature};
+use git2::{
+	ErrorCode, Index, ObjectType, Repository, Signature, Time,
+};
 use scopetime::scope_time;
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+};
+
+const SIGN_OFF_TRAILER: &str = "Signed-off-by:";
+const CO_AUTHOR_TRAILER: &str = "Co-authored-by:";
+
+/// author of a commit, unset fields fall back to the configured
+/// user and the current time
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CommitAuthor {
+	///
+	pub name: Option<String>,
+	///
+	pub email: Option<String>,
+	/// seconds since the epoch and the offset to utc in minutes
+	pub time: Option<(i64, i32)>,
+}
 
-///
+fn author_signature(
+	repo: &Repository,
+	author: &CommitAuthor,
+) -> Result<Signature<'static>> {
+	let user = signature_allow_undefined_name(repo)?;
+	let time = author.time.map_or_else(
+		|| user.when(),
+		|(seconds, offset)| Time::new(seconds, offset),
+	);
+
+	Ok(Signature::new(
+		author
+			.name
+			.as_deref()
+			.or_else(|| user.name())
+			.unwrap_or_default(),
+		author
+			.email
+			.as_deref()
+			.or_else(|| user.email())
+			.unwrap_or_default(),
+		&time,
+	)?)
+}
+
+/// `author` of `None` keeps the original author of the commit
 pub fn amend(
 	repo_path: &str,
 	id: CommitId,
 	msg: &str,
+	author: Option<&CommitAuthor>,
 ) -> Result<CommitId> {
 	scope_time!("amend");
 
@@ -18,9 +70,13 @@ pub fn amend(
 	let tree_id = index.write_tree()?;
 	let tree = repo.find_tree(tree_id)?;
 
+	let author = author
+		.map(|author| author_signature(&repo, author))
+		.transpose()?;
+
 	let new_id = commit.amend(
 		Some("HEAD"),
-		None,
+		author.as_ref(),
 		None,
 		None,
 		Some(msg),
@@ -30,6 +86,36 @@ pub fn amend(
 	Ok(CommitId::new(new_id))
 }
 
+/// only rewrites the message of `HEAD`, tree and parents stay
+/// untouched no matter what is currently staged
+pub fn reword_head(repo_path: &str, msg: &str) -> Result<CommitId> {
+	scope_time!("reword_head");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.head()?.peel_to_commit()?;
+
+	let new_id = commit.amend(
+		Some("HEAD"),
+		None,
+		None,
+		None,
+		Some(msg),
+		None,
+	)?;
+
+	Ok(CommitId::new(new_id))
+}
+
+/// true if `HEAD` has more than one parent
+pub fn is_head_merge_commit(repo_path: &str) -> Result<bool> {
+	scope_time!("is_head_merge_commit");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.head()?.peel_to_commit()?;
+
+	Ok(commit.parent_count() > 1)
+}
+
 /// Wrap `Repository::signature` to allow unknown user.name.
 ///
 /// See <https://github.com/extrawurst/gitui/issues/79>.
@@ -57,13 +143,34 @@ pub(crate) fn signature_allow_undefined_name(
 	signature
 }
 
+/// the configured user, author and committer of new commits
+pub fn user_signature(repo_path: &str) -> Result<CommitSignature> {
+	scope_time!("user_signature");
+
+	let repo = repo(repo_path)?;
+	let signature = signature_allow_undefined_name(&repo)?;
+
+	Ok(CommitSignature::from(&signature))
+}
+
 /// this does not run any git hooks
 pub fn commit(repo_path: &str, msg: &str) -> Result<CommitId> {
+	commit_with_author(repo_path, msg, &CommitAuthor::default())
+}
+
+/// like `commit` but authored by `author`, the committer stays
+/// the configured user
+pub fn commit_with_author(
+	repo_path: &str,
+	msg: &str,
+	author: &CommitAuthor,
+) -> Result<CommitId> {
 	scope_time!("commit");
 
 	let repo = repo(repo_path)?;
 
 	let signature = signature_allow_undefined_name(&repo)?;
+	let author = author_signature(&repo, author)?;
 	let mut index = repo.index()?;
 	let tree_id = index.write_tree()?;
 	let tree = repo.find_tree(tree_id)?;
@@ -76,6 +183,60 @@ pub fn commit(repo_path: &str, msg: &str) -> Result<CommitId> {
 
 	let parents = parents.iter().collect::<Vec<_>>();
 
+	Ok(repo
+		.commit(
+			Some("HEAD"),
+			&author,
+			&signature,
+			msg,
+			&tree,
+			parents.as_slice(),
+		)?
+		.into())
+}
+
+/// commits only the staged changes of `paths` on top of `HEAD`,
+/// staged changes of other paths remain in the index.
+/// this does not run any git hooks
+pub fn commit_paths(
+	repo_path: &str,
+	msg: &str,
+	paths: &[String],
+) -> Result<CommitId> {
+	scope_time!("commit_paths");
+
+	let repo = repo(repo_path)?;
+
+	let signature = signature_allow_undefined_name(&repo)?;
+	let index = repo.index()?;
+
+	let parent = if let Ok(id) = get_head_repo(&repo) {
+		Some(repo.find_commit(id.into())?)
+	} else {
+		None
+	};
+
+	// in-memory index starting at `HEAD` that only takes over
+	// the staged state of `paths`
+	let mut partial = Index::new()?;
+	if let Some(parent) = &parent {
+		partial.read_tree(&parent.tree()?)?;
+	}
+
+	for path in paths {
+		let path = Path::new(path);
+		if let Some(entry) = index.get_path(path, 0) {
+			partial.add(&entry)?;
+		} else {
+			partial.remove_path(path)?;
+		}
+	}
+
+	let tree_id = partial.write_tree_to(&repo)?;
+	let tree = repo.find_tree(tree_id)?;
+
+	let parents = parent.iter().collect::<Vec<_>>();
+
 	Ok(repo
 		.commit(
 			Some("HEAD"),
@@ -88,6 +249,146 @@ pub fn commit(repo_path: &str, msg: &str) -> Result<CommitId> {
 		.into())
 }
 
+/// appends a `Signed-off-by` trailer (built from the repos signature)
+/// to `msg` unless the very same trailer is already present
+pub fn sign_off_msg(repo_path: &str, msg: &str) -> Result<String> {
+	scope_time!("sign_off_msg");
+
+	let repo = repo(repo_path)?;
+
+	let signature = signature_allow_undefined_name(&repo)?;
+	let trailer = format!(
+		"{} {} <{}>",
+		SIGN_OFF_TRAILER,
+		signature.name().unwrap_or_default(),
+		signature.email().unwrap_or_default()
+	);
+
+	Ok(append_trailer(msg, &trailer))
+}
+
+/// appends a `Co-authored-by` trailer for `name` and `email` to `msg`
+/// unless the very same trailer is already present
+pub fn co_author_msg(msg: &str, name: &str, email: &str) -> String {
+	append_trailer(
+		msg,
+		&format!("{} {} <{}>", CO_AUTHOR_TRAILER, name, email),
+	)
+}
+
+/// distinct authors of the last `max_commits` commits, most recent
+/// first and leaving out the user
+pub fn recent_authors(
+	repo_path: &str,
+	max_commits: usize,
+) -> Result<Vec<CommitSignature>> {
+	scope_time!("recent_authors");
+
+	let repo = repo(repo_path)?;
+
+	if get_head_repo(&repo).is_err() {
+		return Ok(Vec::new());
+	}
+
+	let user = CommitSignature::from(
+		&signature_allow_undefined_name(&repo)?,
+	);
+
+	let mut ids = Vec::new();
+	LogWalker::new(&repo, max_commits)?.read(&mut ids)?;
+
+	let mut res: Vec<CommitSignature> = Vec::new();
+	for id in ids {
+		let author = CommitSignature::from(
+			&repo.find_commit(id.into())?.author(),
+		);
+
+		if author.email != user.email
+			&& !res.iter().any(|a| a.email == author.email)
+		{
+			res.push(author);
+		}
+	}
+
+	Ok(res)
+}
+
+/// content of the file configured in `commit.template`, falls back to
+/// a `.gitmessage` in the root of the working dir
+pub fn commit_template(repo_path: &str) -> Result<Option<String>> {
+	scope_time!("commit_template");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+
+	let path = get_config_string_repo(&repo, "commit.template")?
+		.map_or_else(
+			|| work_dir.join(".gitmessage"),
+			|path| work_dir.join(expand_home(&path)),
+		);
+
+	Ok(fs::read_to_string(path).ok())
+}
+
+fn expand_home(path: &str) -> PathBuf {
+	match (path.strip_prefix("~/"), env::var_os("HOME")) {
+		(Some(rest), Some(home)) => Path::new(&home).join(rest),
+		_ => PathBuf::from(path),
+	}
+}
+
+/// `core.commentChar` of the repo, `#` if unset or `auto`
+pub fn comment_char(repo_path: &str) -> Result<char> {
+	scope_time!("comment_char");
+
+	let repo = repo(repo_path)?;
+
+	Ok(get_config_string_repo(&repo, "core.commentChar")?
+		.filter(|c| c != "auto")
+		.and_then(|c| c.chars().next())
+		.unwrap_or('#'))
+}
+
+/// drops all lines starting with `comment_char` and surrounding
+/// whitespace like git does before committing
+pub fn strip_comments(msg: &str, comment_char: char) -> String {
+	msg.lines()
+		.filter(|line| !line.starts_with(comment_char))
+		.map(str::trim_end)
+		.collect::<Vec<_>>()
+		.join("\n")
+		.trim()
+		.to_string()
+}
+
+fn append_trailer(msg: &str, trailer: &str) -> String {
+	if msg.lines().any(|line| line.trim() == trailer) {
+		return msg.to_string();
+	}
+
+	let msg = msg.trim_end();
+
+	if msg.is_empty() {
+		trailer.to_string()
+	} else if ends_with_trailers(msg) {
+		format!("{}\n{}", msg, trailer)
+	} else {
+		format!("{}\n\n{}", msg, trailer)
+	}
+}
+
+/// trailers are `Token: value` lines in the last paragraph, which
+/// can not be the subject
+fn ends_with_trailers(msg: &str) -> bool {
+	msg.rfind("\n\n").map_or(false, |pos| {
+		msg[pos..].trim().lines().all(|line| {
+			line.find(": ").map_or(false, |end| {
+				end > 0 && !line[..end].contains(char::is_whitespace)
+			})
+		})
+	})
+}
+
 /// Tag a commit.
 ///
 /// This function will return an `Err(…)` variant if the tag’s name is refused
@@ -120,7 +421,13 @@ mod tests {
 		utils::get_head,
 		LogWalker,
 	};
-	use commit::{amend, tag};
+	use commit::{
+		amend, append_trailer, co_author_msg, comment_char,
+		commit_paths, commit_template, commit_with_author,
+		is_head_merge_commit, recent_authors, reword_head,
+		sign_off_msg, signature_allow_undefined_name, strip_comments,
+		tag, CommitAuthor,
+	};
 	use git2::Repository;
 	use std::{fs::File, io::Write, path::Path};
 
@@ -154,6 +461,40 @@ mod tests {
 		assert_eq!(get_statuses(repo_path), (0, 0));
 	}
 
+	#[test]
+	fn test_commit_paths() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join("foo"))?.write_all(b"foo")?;
+		File::create(&root.join("bar"))?.write_all(b"bar")?;
+
+		stage_add_file(repo_path, Path::new("foo"))?;
+		stage_add_file(repo_path, Path::new("bar"))?;
+
+		assert_eq!(get_statuses(repo_path), (0, 2));
+
+		let id = commit_paths(
+			repo_path,
+			"only foo",
+			&[String::from("foo")],
+		)?;
+
+		assert_eq!(get_statuses(repo_path), (0, 1));
+
+		let files = get_commit_files(repo_path, id, None)?;
+		assert_eq!(files.len(), 1);
+		assert_eq!(files[0].path, "foo");
+
+		commit_paths(repo_path, "now bar", &[String::from("bar")])?;
+
+		assert_eq!(get_statuses(repo_path), (0, 0));
+		assert_eq!(count_commits(&repo, 10), 3);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_commit_in_empty_repo() {
 		let file_path = Path::new("foo");
@@ -198,7 +539,7 @@ mod tests {
 
 		stage_add_file(repo_path, file_path2)?;
 
-		let new_id = amend(repo_path, id, "amended")?;
+		let new_id = amend(repo_path, id, "amended", None)?;
 
 		assert_eq!(count_commits(&repo, 10), 1);
 
@@ -216,6 +557,113 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_amend_message_only() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"test1")?;
+
+		stage_add_file(repo_path, file_path)?;
+		let id = commit(repo_path, "commit msg")?;
+
+		let new_id = amend(repo_path, id, "amended", None)?;
+
+		assert_eq!(count_commits(&repo, 10), 1);
+		assert_eq!(get_head(repo_path)?, new_id);
+		assert_eq!(
+			repo.find_commit(id.into())?.tree_id(),
+			repo.find_commit(new_id.into())?.tree_id()
+		);
+
+		let details = get_commit_details(repo_path, new_id)?;
+		assert_eq!(details.message.unwrap().subject, "amended");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_commit_and_amend_author() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"test1")?;
+		stage_add_file(repo_path, file_path)?;
+
+		let author = CommitAuthor {
+			name: Some(String::from("other")),
+			email: Some(String::from("other@example.com")),
+			time: Some((1_000_000, 60)),
+		};
+		let id =
+			commit_with_author(repo_path, "commit msg", &author)?;
+
+		let commit = repo.find_commit(id.into())?;
+		assert_eq!(commit.author().name(), Some("other"));
+		assert_eq!(commit.author().when().seconds(), 1_000_000);
+		assert_eq!(commit.author().when().offset_minutes(), 60);
+		assert_eq!(commit.committer().name(), Some("name"));
+
+		// keeps the original author
+		let id = amend(repo_path, id, "amended", None)?;
+		let commit = repo.find_commit(id.into())?;
+		assert_eq!(
+			commit.author().email(),
+			Some("other@example.com")
+		);
+		assert_eq!(commit.author().when().seconds(), 1_000_000);
+
+		// only overrides the date
+		let author = CommitAuthor {
+			time: Some((2_000_000, 0)),
+			..CommitAuthor::default()
+		};
+		let id = amend(repo_path, id, "amended", Some(&author))?;
+		let commit = repo.find_commit(id.into())?;
+		assert_eq!(commit.author().name(), Some("name"));
+		assert_eq!(commit.author().when().seconds(), 2_000_000);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_reword_head() -> Result<()> {
+		let file_path1 = Path::new("foo");
+		let file_path2 = Path::new("foo2");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path1))?.write_all(b"test1")?;
+
+		stage_add_file(repo_path, file_path1)?;
+		commit(repo_path, "commit msg")?;
+
+		File::create(&root.join(file_path2))?.write_all(b"test2")?;
+
+		stage_add_file(repo_path, file_path2)?;
+
+		let new_id = reword_head(repo_path, "reworded")?;
+
+		assert_eq!(count_commits(&repo, 10), 1);
+		assert_eq!(get_head(repo_path)?, new_id);
+		assert!(!is_head_merge_commit(repo_path)?);
+
+		let details = get_commit_details(repo_path, new_id)?;
+		assert_eq!(details.message.unwrap().subject, "reworded");
+
+		// staged file is not part of the reworded commit
+		let files = get_commit_files(repo_path, new_id, None)?;
+		assert_eq!(files.len(), 1);
+		assert_eq!(get_statuses(repo_path), (0, 1));
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_tag() -> Result<()> {
 		let file_path = Path::new("foo");
@@ -335,4 +783,220 @@ mod tests {
 
 		Ok(())
 	}
+
+	/// identities split into included files, like per directory ones
+	/// via `includeIf`, are part of the config we read
+	#[test]
+	fn test_signature_from_included_config() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let included = repo.path().join("identity.gitconfig");
+		File::create(&included)?
+			.write_all(b"[user]\n\temail = included@example.com\n")?;
+
+		let conditional = repo.path().join("name.gitconfig");
+		File::create(&conditional)?
+			.write_all(b"[user]\n\tname = conditional\n")?;
+
+		{
+			let mut config = repo.config()?;
+			config.remove("user.name")?;
+			config.remove("user.email")?;
+			config.set_str(
+				"include.path",
+				included.to_str().unwrap(),
+			)?;
+		}
+
+		let repo = Repository::open(repo_path)?;
+		let signature = signature_allow_undefined_name(&repo)?;
+
+		assert_eq!(signature.name(), Some("unknown"));
+		assert_eq!(signature.email(), Some("included@example.com"));
+
+		// a trailing slash matches everything below, the gitdir too
+		repo.config()?.set_str(
+			&format!("includeIf.gitdir:{}/.path", repo_path),
+			conditional.to_str().unwrap(),
+		)?;
+
+		let repo = Repository::open(repo_path)?;
+		let signature = signature_allow_undefined_name(&repo)?;
+
+		assert_eq!(signature.name(), Some("conditional"));
+		assert_eq!(signature.email(), Some("included@example.com"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_append_trailer() {
+		let trailer = "Signed-off-by: name <email>";
+
+		assert_eq!(append_trailer("", trailer), trailer);
+		assert_eq!(
+			append_trailer("subject\n", trailer),
+			"subject\n\nSigned-off-by: name <email>"
+		);
+		assert_eq!(
+			append_trailer(
+				"subject\n\nSigned-off-by: other <other>",
+				trailer
+			),
+			"subject\n\nSigned-off-by: other <other>\nSigned-off-by: name <email>"
+		);
+		assert_eq!(
+			append_trailer(
+				"subject\n\nSigned-off-by: name <email>",
+				trailer
+			),
+			"subject\n\nSigned-off-by: name <email>"
+		);
+	}
+
+	#[test]
+	fn test_co_author_msg() {
+		assert_eq!(
+			co_author_msg("subject", "foo", "foo@bar.com"),
+			"subject\n\nCo-authored-by: foo <foo@bar.com>"
+		);
+		assert_eq!(
+			co_author_msg("fix: subject", "foo", "foo@bar.com"),
+			"fix: subject\n\nCo-authored-by: foo <foo@bar.com>"
+		);
+		assert_eq!(
+			co_author_msg(
+				"subject\n\nSigned-off-by: name <email>",
+				"foo",
+				"foo@bar.com"
+			),
+			"subject\n\nSigned-off-by: name <email>\nCo-authored-by: foo <foo@bar.com>"
+		);
+		assert_eq!(
+			co_author_msg(
+				"subject\n\nsome body text",
+				"foo",
+				"foo@bar.com"
+			),
+			"subject\n\nsome body text\n\nCo-authored-by: foo <foo@bar.com>"
+		);
+	}
+
+	#[test]
+	fn test_recent_authors() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert!(recent_authors(repo_path, 10)?.is_empty());
+
+		let other = |name: &str| CommitAuthor {
+			name: Some(name.to_string()),
+			email: Some(format!("{}@example.com", name)),
+			time: None,
+		};
+
+		for (i, author) in
+			[other("a"), other("b"), other("a")].iter().enumerate()
+		{
+			File::create(&root.join(file_path))?
+				.write_all(format!("{}", i).as_bytes())?;
+			stage_add_file(repo_path, file_path)?;
+			commit_with_author(repo_path, "msg", author)?;
+		}
+		File::create(&root.join(file_path))?.write_all(b"mine")?;
+		stage_add_file(repo_path, file_path)?;
+		commit(repo_path, "msg")?;
+
+		let authors = recent_authors(repo_path, 10)?;
+		let mut names = authors
+			.iter()
+			.map(|a| a.name.as_str())
+			.collect::<Vec<_>>();
+		// commits of the same second have no defined order
+		names.sort_unstable();
+		assert_eq!(names, vec!["a", "b"]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_sign_off_unknown_name() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		repo.config()?.remove("user.name")?;
+
+		assert_eq!(
+			sign_off_msg(repo_path, "msg")?,
+			"msg\n\nSigned-off-by: unknown <email>"
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_commit_template() -> Result<()> {
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert_eq!(commit_template(repo_path)?, None);
+
+		File::create(&root.join(".gitmessage"))?
+			.write_all(b"fallback")?;
+
+		assert_eq!(
+			commit_template(repo_path)?,
+			Some(String::from("fallback"))
+		);
+
+		File::create(&root.join("template"))?
+			.write_all(b"subject")?;
+		repo.config()?.set_str("commit.template", "template")?;
+
+		assert_eq!(
+			commit_template(repo_path)?,
+			Some(String::from("subject"))
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_comment_char() -> Result<()> {
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert_eq!(comment_char(repo_path)?, '#');
+
+		repo.config()?.set_str("core.commentChar", "auto")?;
+		assert_eq!(comment_char(repo_path)?, '#');
+
+		repo.config()?.set_str("core.commentChar", ";")?;
+		assert_eq!(comment_char(repo_path)?, ';');
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_strip_comments() {
+		assert_eq!(
+			strip_comments(
+				"# comment\nsubject  \n\nbody\n# foo\n",
+				'#'
+			),
+			"subject\n\nbody"
+		);
+		assert_eq!(
+			strip_comments("#1 fix\n; comment", ';'),
+			"#1 fix"
+		);
+		assert_eq!(strip_comments("# only comments", '#'), "");
+	}
 }
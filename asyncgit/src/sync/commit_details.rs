@@ -1,4 +1,8 @@
-use super::{commits_info::get_message, utils::repo, CommitId};
+use super::{
+	commits_info::{get_message, mailmapped_author},
+	utils::repo,
+	CommitId,
+};
 use crate::error::Result;
 use git2::Signature;
 use scopetime::scope_time;
@@ -78,6 +82,9 @@ pub struct CommitDetails {
 	pub message: Option<CommitMessage>,
 	///
 	pub hash: String,
+	/// two or more for a merge commit, the first one is the
+	/// branch merged into
+	pub parents: Vec<CommitId>,
 }
 
 impl CommitDetails {
@@ -98,8 +105,20 @@ pub fn get_commit_details(
 
 	let commit = repo.find_commit(id.into())?;
 
-	let author = CommitSignature::from(&commit.author());
-	let committer = CommitSignature::from(&commit.committer());
+	let mailmap = repo.mailmap().ok();
+
+	let author = CommitSignature::from(&mailmapped_author(
+		&commit,
+		mailmap.as_ref(),
+	));
+	let committer = CommitSignature::from(
+		&mailmap
+			.as_ref()
+			.and_then(|mailmap| {
+				commit.committer_with_mailmap(mailmap).ok()
+			})
+			.unwrap_or_else(|| commit.committer().to_owned()),
+	);
 	let committer = if author == committer {
 		None
 	} else {
@@ -114,6 +133,7 @@ pub fn get_commit_details(
 		committer,
 		message: Some(msg),
 		hash: id.to_string(),
+		parents: commit.parent_ids().map(CommitId::from).collect(),
 	};
 
 	Ok(details)
@@ -125,7 +145,8 @@ mod tests {
 	use super::{get_commit_details, CommitMessage};
 	use crate::error::Result;
 	use crate::sync::{
-		commit, stage_add_file, tests::repo_init_empty,
+		commit, stage_add_file,
+		tests::{repo_init, repo_init_empty, write_commit_file},
 	};
 	use std::{fs::File, io::Write, path::Path};
 
@@ -157,6 +178,20 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_parents() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let c1 = write_commit_file(&repo, "a.txt", "a", "commit1");
+		let c2 = write_commit_file(&repo, "b.txt", "b", "commit2");
+
+		let res = get_commit_details(repo_path, c2).unwrap();
+
+		assert_eq!(res.parents, vec![c1]);
+	}
+
 	#[test]
 	fn test_msg_linefeeds() -> Result<()> {
 		let msg = CommitMessage::from("foo\nbar\r\ntest");
@@ -1,6 +1,9 @@
 use std::cmp::Ordering;
 
-use super::{stash::is_stash_commit, utils::repo, CommitId};
+use super::{
+	diff::delta_mode_change, stash::is_stash_commit, utils::repo,
+	CommitId,
+};
 use crate::{
 	error::Error, error::Result, StatusItem, StatusItemType,
 };
@@ -23,6 +26,26 @@ pub fn get_commit_files(
 		get_commit_diff(&repo, id, None)?
 	};
 
+	diff_files(&diff)
+}
+
+/// like `get_commit_files` but against the parent of index `parent`
+/// (`0` for the first), this shows what a merge brought in from
+/// each side
+pub fn get_commit_parent_files(
+	repo_path: &str,
+	id: CommitId,
+	parent: usize,
+) -> Result<Vec<StatusItem>> {
+	scope_time!("get_commit_parent_files");
+
+	let repo = repo(repo_path)?;
+	let diff = get_commit_diff_to_parent(&repo, id, parent, None)?;
+
+	diff_files(&diff)
+}
+
+fn diff_files(diff: &Diff<'_>) -> Result<Vec<StatusItem>> {
 	let mut res = Vec::new();
 
 	diff.foreach(
@@ -34,6 +57,7 @@ pub fn get_commit_files(
 					.map(|p| p.to_str().unwrap_or("").to_string())
 					.unwrap_or_default(),
 				status: StatusItemType::from(delta.status()),
+				mode_change: delta_mode_change(&delta),
 			});
 			true
 		},
@@ -88,6 +112,17 @@ pub(crate) fn get_commit_diff(
 	repo: &Repository,
 	id: CommitId,
 	pathspec: Option<String>,
+) -> Result<Diff<'_>> {
+	get_commit_diff_to_parent(repo, id, 0, pathspec)
+}
+
+/// fails if the commit has no parent of index `parent`
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn get_commit_diff_to_parent(
+	repo: &Repository,
+	id: CommitId,
+	parent: usize,
+	pathspec: Option<String>,
 ) -> Result<Diff<'_>> {
 	// scope_time!("get_commit_diff");
 
@@ -95,7 +130,7 @@ pub(crate) fn get_commit_diff(
 	let commit_tree = commit.tree()?;
 
 	let parent = if commit.parent_count() > 0 {
-		repo.find_commit(commit.parent_id(0)?)
+		repo.find_commit(commit.parent_id(parent)?)
 			.ok()
 			.and_then(|c| c.tree().ok())
 	} else {
@@ -137,17 +172,52 @@ pub(crate) fn get_commit_diff(
 
 #[cfg(test)]
 mod tests {
-	use super::get_commit_files;
+	use super::{get_commit_files, get_commit_parent_files};
 	use crate::{
 		error::Result,
 		sync::{
-			commit, stage_add_file, stash_save,
-			tests::{get_statuses, repo_init},
+			checkout_branch, commit, create_branch,
+			merge_branch_with, stage_add_file, stash_save,
+			tests::{get_statuses, repo_init, write_commit_file},
+			MergeMode,
 		},
 		StatusItemType,
 	};
 	use std::{fs::File, io::Write, path::Path};
 
+	#[test]
+	fn test_merge_parent_files() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "test", "c1");
+		create_branch(repo_path, "foo").unwrap();
+		write_commit_file(&repo, "b.txt", "b", "c2");
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(&repo, "a.txt", "a", "c3");
+
+		let merge = merge_branch_with(
+			repo_path,
+			"foo",
+			MergeMode::NoFastForward,
+		)
+		.unwrap()
+		.unwrap();
+
+		let first =
+			get_commit_parent_files(repo_path, merge, 0).unwrap();
+		assert_eq!(first.len(), 1);
+		assert_eq!(first[0].path, "b.txt");
+
+		let second =
+			get_commit_parent_files(repo_path, merge, 1).unwrap();
+		assert_eq!(second.len(), 1);
+		assert_eq!(second[0].path, "a.txt");
+
+		assert!(get_commit_parent_files(repo_path, merge, 2).is_err());
+	}
+
 	#[test]
 	fn test_smoke() -> Result<()> {
 		let file_path = Path::new("file1.txt");
@@ -0,0 +1,60 @@
+use crate::{error::Result, sync::utils};
+use git2::ResetType;
+use scopetime::scope_time;
+
+/// like `git revert --abort`: resets index and workdir to `HEAD`
+/// and removes the revert state (`REVERT_HEAD`)
+pub fn abort_revert(repo_path: &str) -> Result<()> {
+	scope_time!("abort_revert");
+
+	let repo = utils::repo(repo_path)?;
+
+	let head = repo.head()?.peel_to_commit()?;
+	repo.reset(head.as_object(), ResetType::Hard, None)?;
+
+	repo.cleanup_state()?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		repo_state,
+		tests::{repo_init, write_commit_file},
+		RepoState,
+	};
+	use std::fs;
+
+	#[test]
+	fn test_abort_conflicting_revert() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "a", "commit1");
+		let reverted =
+			write_commit_file(&repo, "test.txt", "b", "commit2");
+		write_commit_file(&repo, "test.txt", "c", "commit3");
+
+		let commit = repo.find_commit(reverted.into()).unwrap();
+		repo.revert(&commit, None).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Revert);
+		assert!(repo.index().unwrap().has_conflicts());
+
+		abort_revert(repo_path).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert!(!root.join(".git/REVERT_HEAD").exists());
+		let mut index = repo.index().unwrap();
+		index.read(true).unwrap();
+		assert!(!index.has_conflicts());
+		assert!(repo.statuses(None).unwrap().is_empty());
+		assert_eq!(
+			fs::read_to_string(root.join("test.txt")).unwrap(),
+			"c"
+		);
+	}
+}
@@ -1,6 +1,6 @@
 use super::utils::repo;
 use crate::error::Result;
-use git2::{Commit, Error, Oid};
+use git2::{Commit, Error, Mailmap, Oid, Signature};
 use scopetime::scope_time;
 use unicode_truncate::UnicodeTruncateStr;
 
@@ -69,6 +69,7 @@ pub fn get_commits_info(
 	scope_time!("get_commits_info");
 
 	let repo = repo(repo_path)?;
+	let mailmap = repo.mailmap().ok();
 
 	let commits = ids
 		.iter()
@@ -79,10 +80,12 @@ pub fn get_commits_info(
 	let res = commits
 		.map(|c: Commit| {
 			let message = get_message(&c, Some(message_length_limit));
-			let author = c.author().name().map_or_else(
-				|| String::from("<unknown>"),
-				String::from,
-			);
+			let author = mailmapped_author(&c, mailmap.as_ref())
+				.name()
+				.map_or_else(
+					|| String::from("<unknown>"),
+					String::from,
+				);
 			CommitInfo {
 				message,
 				author,
@@ -105,7 +108,8 @@ pub fn get_commit_info(
 	let repo = repo(repo_path)?;
 
 	let commit = repo.find_commit((*commit_id).into())?;
-	let author = commit.author();
+	let author =
+		mailmapped_author(&commit, repo.mailmap().ok().as_ref());
 
 	Ok(CommitInfo {
 		message: commit.message().unwrap_or("").into(),
@@ -115,6 +119,17 @@ pub fn get_commit_info(
 	})
 }
 
+/// author as canonicalized by the repo's `.mailmap`, the raw one
+/// without a mailmap
+pub(crate) fn mailmapped_author(
+	commit: &Commit,
+	mailmap: Option<&Mailmap>,
+) -> Signature<'static> {
+	mailmap
+		.and_then(|mailmap| commit.author_with_mailmap(mailmap).ok())
+		.unwrap_or_else(|| commit.author().to_owned())
+}
+
 /// if `message_limit` is set the message will be
 /// limited to the first line and truncated to fit
 pub fn get_message(
@@ -135,7 +150,7 @@ pub fn get_message(
 
 #[cfg(test)]
 mod tests {
-	use super::get_commits_info;
+	use super::{get_commit_info, get_commits_info};
 	use crate::error::Result;
 	use crate::sync::{
 		commit, stage_add_file, tests::repo_init_empty,
@@ -168,6 +183,29 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_log_mailmap() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "commit1").unwrap();
+
+		File::create(&root.join(".mailmap"))?
+			.write_all(b"Real Name <real@mail.com> <email>\n")?;
+
+		let res = get_commits_info(repo_path, &vec![c1], 50).unwrap();
+		assert_eq!(res[0].author.as_str(), "Real Name");
+
+		let res = get_commit_info(repo_path, &c1).unwrap();
+		assert_eq!(res.author.as_str(), "Real Name");
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_log_first_msg_line() -> Result<()> {
 		let file_path = Path::new("foo");
@@ -187,6 +225,36 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_log_msg_limit_multibyte() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "aé😀x").unwrap();
+
+		let msg = |limit| {
+			get_commits_info(repo_path, &vec![c1], limit).unwrap()[0]
+				.message
+				.clone()
+		};
+
+		// byte at `limit` is in the middle of `é`
+		assert_eq!(msg(1), "a");
+		// `limit` ends in the middle of the wide `😀`
+		assert_eq!(msg(3), "aé");
+		assert_eq!(msg(4), "aé😀");
+		// `limit` ends exactly on the last multibyte char
+		let c2 = commit(repo_path, "abé").unwrap();
+		let res = get_commits_info(repo_path, &vec![c2], 3).unwrap();
+		assert_eq!(res[0].message.as_str(), "abé");
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_invalid_utf8() -> Result<()> {
 		let file_path = Path::new("foo");
@@ -96,6 +96,82 @@ pub fn get_config_string_repo(
 	}
 }
 
+/// represents the `core.autocrlf` git config state
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AutoCrlf {
+	///
+	False,
+	/// convert to `LF` on commit and to `CRLF` on checkout
+	True,
+	/// convert to `LF` on commit only
+	Input,
+}
+
+impl Default for AutoCrlf {
+	fn default() -> Self {
+		Self::False
+	}
+}
+
+/// the git config values gitui honors, resolved like git does
+/// (repo config overriding global and system config)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RepoConfig {
+	/// `core.autocrlf`
+	pub autocrlf: AutoCrlf,
+	/// `commit.gpgsign`
+	pub gpgsign: bool,
+	/// `user.signingkey`
+	pub signing_key: Option<String>,
+	/// `core.editor`
+	pub editor: Option<String>,
+}
+
+///
+pub fn repo_config(repo_path: &str) -> Result<RepoConfig> {
+	scope_time!("repo_config");
+
+	let repo = repo(repo_path)?;
+
+	let autocrlf = if get_config_string_repo(&repo, "core.autocrlf")?
+		.map_or(false, |value| value.eq_ignore_ascii_case("input"))
+	{
+		AutoCrlf::Input
+	} else if get_config_bool_repo(&repo, "core.autocrlf")?
+		.unwrap_or_default()
+	{
+		AutoCrlf::True
+	} else {
+		AutoCrlf::False
+	};
+
+	Ok(RepoConfig {
+		autocrlf,
+		gpgsign: get_config_bool_repo(&repo, "commit.gpgsign")?
+			.unwrap_or_default(),
+		signing_key: get_config_string_repo(
+			&repo,
+			"user.signingkey",
+		)?,
+		editor: get_config_string_repo(&repo, "core.editor")?,
+	})
+}
+
+/// parses booleans the way git does (`yes`, `on`, `1`, ..),
+/// values that are no valid boolean are treated as unset
+pub fn get_config_bool_repo(
+	repo: &Repository,
+	key: &str,
+) -> Result<Option<bool>> {
+	scope_time!("get_config_bool_repo");
+
+	if get_config_string_repo(repo, key)?.is_none() {
+		return Ok(None);
+	}
+
+	Ok(repo.config()?.get_bool(key).ok())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -118,4 +194,35 @@ mod tests {
 		assert!(good_cfg.is_ok());
 		assert!(good_cfg.unwrap().is_some());
 	}
+
+	#[test]
+	fn test_repo_config() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let cfg = repo_config(repo_path).unwrap();
+		assert_eq!(cfg.autocrlf, AutoCrlf::False);
+		assert_eq!(cfg.gpgsign, false);
+		assert_eq!(cfg.editor, None);
+
+		let mut config = repo.config().unwrap();
+		config.set_str("core.autocrlf", "input").unwrap();
+		config.set_str("commit.gpgsign", "yes").unwrap();
+		config.set_str("user.signingkey", "ABCD").unwrap();
+		config.set_str("core.editor", "nano -w").unwrap();
+
+		let cfg = repo_config(repo_path).unwrap();
+		assert_eq!(cfg.autocrlf, AutoCrlf::Input);
+		assert_eq!(cfg.gpgsign, true);
+		assert_eq!(cfg.signing_key, Some(String::from("ABCD")));
+		assert_eq!(cfg.editor, Some(String::from("nano -w")));
+
+		config.set_str("core.autocrlf", "true").unwrap();
+		config.set_str("commit.gpgsign", "garbage").unwrap();
+
+		let cfg = repo_config(repo_path).unwrap();
+		assert_eq!(cfg.autocrlf, AutoCrlf::True);
+		assert_eq!(cfg.gpgsign, false);
+	}
 }
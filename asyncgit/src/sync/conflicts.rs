@@ -0,0 +1,187 @@
+//! resolving merge conflicts
+
+use super::utils::{literal_pathspec, repo, work_dir};
+use crate::error::{Error, Result};
+use git2::{build::CheckoutBuilder, Index, IndexConflict};
+use scopetime::scope_time;
+use std::{fs, path::Path};
+
+/// which version of a conflicted file to keep
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ConflictSide {
+	/// the version of the branch being merged into
+	Ours,
+	/// the version of the branch being merged
+	Theirs,
+}
+
+/// looks up the conflict entries of `path` in `index`
+pub(crate) fn find_conflict(
+	index: &Index,
+	path: &str,
+) -> Result<IndexConflict> {
+	index
+		.conflicts()?
+		.filter_map(std::result::Result::ok)
+		.find(|conflict| {
+			[&conflict.ancestor, &conflict.our, &conflict.their]
+				.iter()
+				.filter_map(|entry| entry.as_ref())
+				.any(|entry| entry.path == path.as_bytes())
+		})
+		.ok_or_else(|| {
+			Error::Generic(format!("no conflict found: {}", path))
+		})
+}
+
+/// true if the index still holds unresolved conflicts
+pub fn has_conflicts(repo_path: &str) -> Result<bool> {
+	scope_time!("has_conflicts");
+
+	let repo = repo(repo_path)?;
+
+	Ok(repo.index()?.has_conflicts())
+}
+
+/// writes the `side` version of the conflicted file at `path` to the
+/// workdir and marks it resolved in the index, if that side deleted
+/// the file it gets removed
+pub fn checkout_conflict_side(
+	repo_path: &str,
+	path: &str,
+	side: ConflictSide,
+) -> Result<()> {
+	scope_time!("checkout_conflict_side");
+
+	let repo = repo(repo_path)?;
+	let mut index = repo.index()?;
+
+	let conflict = find_conflict(&index, path)?;
+
+	let entry = match side {
+		ConflictSide::Ours => conflict.our,
+		ConflictSide::Theirs => conflict.their,
+	};
+
+	if entry.is_some() {
+		// checkout applies the file mode and filters like eol
+		let mut opts = CheckoutBuilder::new();
+		opts.force().path(literal_pathspec(path));
+		match side {
+			ConflictSide::Ours => opts.use_ours(true),
+			ConflictSide::Theirs => opts.use_theirs(true),
+		};
+
+		repo.checkout_index(Some(&mut index), Some(&mut opts))?;
+		index.add_path(Path::new(path))?;
+	} else {
+		let file_path = work_dir(&repo)?.join(path);
+		if file_path.exists() {
+			fs::remove_file(&file_path)?;
+		}
+		index.remove_path(Path::new(path))?;
+	}
+
+	index.write()?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		checkout_conflict_side, has_conflicts, ConflictSide,
+	};
+	use crate::sync::{
+		checkout_branch, commit, create_branch, merge_branch,
+		stage_add_file,
+		status::{get_status, StatusItemType, StatusType},
+		tests::{conflicting_merge, repo_init, write_commit_file},
+		utils::{repo_read_file, repo_write_file},
+	};
+
+	#[test]
+	fn test_checkout_conflict_side() {
+		for (side, content) in &[
+			(ConflictSide::Ours, "ours"),
+			(ConflictSide::Theirs, "theirs"),
+		] {
+			let (_td, repo) = conflicting_merge();
+			let root = repo.path().parent().unwrap();
+			let repo_path = root.as_os_str().to_str().unwrap();
+
+			let status =
+				get_status(repo_path, StatusType::WorkingDir, None)
+					.unwrap();
+			assert_eq!(status[0].status, StatusItemType::Conflicted);
+			assert!(has_conflicts(repo_path).unwrap());
+
+			checkout_conflict_side(repo_path, "test.txt", *side)
+				.unwrap();
+
+			assert_eq!(
+				repo_read_file(&repo, "test.txt").unwrap(),
+				*content
+			);
+			assert!(!has_conflicts(repo_path).unwrap());
+		}
+	}
+
+	#[test]
+	#[cfg(not(windows))]
+	fn test_checkout_conflict_side_mode() {
+		use std::{fs, os::unix::fs::PermissionsExt, path::Path};
+
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+		let file_path = root.join("run.sh");
+
+		write_commit_file(&repo, "run.sh", "base", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		repo_write_file(&repo, "run.sh", "theirs").unwrap();
+		fs::set_permissions(
+			&file_path,
+			fs::Permissions::from_mode(0o755),
+		)
+		.unwrap();
+		stage_add_file(repo_path, Path::new("run.sh")).unwrap();
+		commit(repo_path, "commit2").unwrap();
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(&repo, "run.sh", "ours", "commit3");
+
+		merge_branch(repo_path, "foo").unwrap();
+		assert!(has_conflicts(repo_path).unwrap());
+
+		checkout_conflict_side(
+			repo_path,
+			"run.sh",
+			ConflictSide::Theirs,
+		)
+		.unwrap();
+
+		let mode =
+			fs::metadata(&file_path).unwrap().permissions().mode();
+		assert_eq!(mode & 0o111, 0o111);
+		assert_eq!(
+			repo_read_file(&repo, "run.sh").unwrap(),
+			"theirs"
+		);
+	}
+
+	#[test]
+	fn test_checkout_conflict_side_no_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert!(checkout_conflict_side(
+			repo_path,
+			"test.txt",
+			ConflictSide::Ours
+		)
+		.is_err());
+	}
+}
@@ -5,7 +5,7 @@ use crate::{
 	error::{Error, Result},
 	CWD,
 };
-use git2::{Config, CredentialHelper};
+use git2::CredentialHelper;
 
 /// basic Authentication Credentials
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -52,7 +52,9 @@ pub fn extract_username_password() -> Result<BasicAuthCredential> {
 		.to_owned();
 	let mut helper = CredentialHelper::new(&url);
 
-	if let Ok(config) = Config::open_default() {
+	// the repo config includes the global one and resolves
+	// `includeIf.gitdir` which needs to know the repo
+	if let Ok(config) = repo.config() {
 		helper.config(&config);
 	}
 	Ok(match helper.execute() {
@@ -1,14 +1,19 @@
 //! sync git api for fetching a diff
 
 use super::{
-	commit_files::{get_commit_diff, get_compare_commits_diff},
+	commit_files::{
+		get_commit_diff_to_parent, get_compare_commits_diff,
+	},
+	config::untracked_files_config_repo,
+	conflicts::find_conflict,
 	utils::{self, get_head_repo, work_dir},
-	CommitId,
+	CommitId, ShowUntrackedFilesConfig,
 };
 use crate::{error::Error, error::Result, hash};
 use easy_cast::Conv;
 use git2::{
-	Delta, Diff, DiffDelta, DiffFormat, DiffHunk, Patch, Repository,
+	Delta, Diff, DiffDelta, DiffFormat, DiffHunk, IndexEntry, Patch,
+	Repository,
 };
 use scopetime::scope_time;
 use std::{cell::RefCell, fs, path::Path, rc::Rc};
@@ -24,6 +29,12 @@ pub enum DiffLineType {
 	Add,
 	/// line deleted
 	Delete,
+	/// start, separator or end of a conflict region
+	ConflictMarker,
+	/// line of our version inside a conflict region
+	ConflictOurs,
+	/// line of their version inside a conflict region
+	ConflictTheirs,
 }
 
 impl From<git2::DiffLineType> for DiffLineType {
@@ -107,6 +118,9 @@ pub struct Hunk {
 	pub header_hash: u64,
 	/// list of `DiffLine`s
 	pub lines: Vec<DiffLine>,
+	/// the hunk exactly as git prints it (untrimmed, including
+	/// `\ No newline at end of file` markers)
+	pub patch: String,
 }
 
 /// collection of hunks, sum of all diff lines
@@ -118,10 +132,39 @@ pub struct FileDiff {
 	pub lines: usize,
 	///
 	pub untracked: bool,
+	/// diff between both sides of a conflicted file
+	pub conflict: bool,
+	/// either side is binary, there are no hunks to show then
+	pub is_binary: bool,
 	/// old and new file size in bytes
 	pub sizes: (u64, u64),
 	/// size delta in bytes
 	pub size_delta: i64,
+	/// old and new file mode if they differ (e.g. `chmod +x`)
+	pub mode_change: Option<(u32, u32)>,
+	/// file header of the patch (`diff --git`, `---`, `+++` ..)
+	pub patch_header: String,
+}
+
+/// summary of a diff as printed by `git diff --shortstat`
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiffStats {
+	///
+	pub files_changed: usize,
+	///
+	pub insertions: usize,
+	///
+	pub deletions: usize,
+}
+
+impl From<git2::DiffStats> for DiffStats {
+	fn from(stats: git2::DiffStats) -> Self {
+		Self {
+			files_changed: stats.files_changed(),
+			insertions: stats.insertions(),
+			deletions: stats.deletions(),
+		}
+	}
 }
 
 /// see <https://libgit2.org/libgit2/#HEAD/type/git_diff_options>
@@ -206,22 +249,166 @@ pub fn get_diff(
 	raw_diff_to_file_diff(&diff, work_dir)
 }
 
-/// returns diff of a specific file inside a commit
-/// see `get_commit_diff`
+/// returns diff of a specific file inside a commit against its
+/// parent of index `parent` (`0` for the first)
+/// see `get_commit_diff_to_parent`
 pub fn get_diff_commit(
 	repo_path: &str,
 	id: CommitId,
+	parent: usize,
 	p: String,
 ) -> Result<FileDiff> {
 	scope_time!("get_diff_commit");
 
 	let repo = utils::repo(repo_path)?;
 	let work_dir = work_dir(&repo)?;
-	let diff = get_commit_diff(&repo, id, Some(p))?;
+	let diff = get_commit_diff_to_parent(&repo, id, parent, Some(p))?;
 
 	raw_diff_to_file_diff(&diff, work_dir)
 }
 
+/// returns the conflict regions of a conflicted file by diffing our
+/// version (index stage 2) against their version (index stage 3)
+pub fn get_diff_conflict(
+	repo_path: &str,
+	p: &str,
+	options: Option<DiffOptions>,
+) -> Result<FileDiff> {
+	scope_time!("get_diff_conflict");
+
+	let repo = utils::repo(repo_path)?;
+	let conflict = find_conflict(&repo.index()?, p)?;
+
+	let content = |entry: Option<IndexEntry>| -> Result<Vec<u8>> {
+		entry.map_or_else(
+			|| Ok(Vec::new()),
+			|entry| Ok(repo.find_blob(entry.id)?.content().to_vec()),
+		)
+	};
+	let ours = content(conflict.our)?;
+	let theirs = content(conflict.their)?;
+
+	let mut opt = git2::DiffOptions::new();
+	if let Some(options) = options {
+		opt.context_lines(options.context);
+		opt.ignore_whitespace(options.ignore_whitespace);
+		opt.interhunk_lines(options.interhunk_lines);
+	}
+
+	let path = Path::new(p);
+	let patch = Patch::from_buffers(
+		&ours,
+		Some(path),
+		&theirs,
+		Some(path),
+		Some(&mut opt),
+	)?;
+
+	let mut res = FileDiff {
+		conflict: true,
+		is_binary: patch.delta().flags().is_binary(),
+		sizes: (u64::conv(ours.len()), u64::conv(theirs.len())),
+		patch_header: format!("--- a/{0}\n+++ b/{0}\n", p),
+		..FileDiff::default()
+	};
+	res.size_delta = (i64::conv(res.sizes.1))
+		.saturating_sub(i64::conv(res.sizes.0));
+
+	for hunk_idx in 0..patch.num_hunks() {
+		let (hunk, hunk_lines) = patch.hunk(hunk_idx)?;
+
+		let mut lines = vec![DiffLine {
+			content: String::from_utf8_lossy(hunk.header())
+				.trim_matches(is_newline)
+				.into(),
+			line_type: DiffLineType::Header,
+			position: DiffLinePosition::default(),
+		}];
+		let mut our_lines = Vec::new();
+		let mut their_lines = Vec::new();
+		let mut hunk_patch =
+			String::from_utf8_lossy(hunk.header()).into_owned();
+
+		for line_idx in 0..hunk_lines {
+			let line = patch.line_in_hunk(hunk_idx, line_idx)?;
+			push_patch_line(&mut hunk_patch, &line);
+			if matches!(
+				line.origin_value(),
+				git2::DiffLineType::AddEOFNL
+					| git2::DiffLineType::DeleteEOFNL
+			) {
+				continue;
+			}
+
+			let mut diff_line = DiffLine {
+				position: DiffLinePosition::from(&line),
+				content: String::from_utf8_lossy(line.content())
+					.trim_matches(is_newline)
+					.into(),
+				line_type: line.origin_value().into(),
+			};
+
+			match diff_line.line_type {
+				DiffLineType::Delete => {
+					diff_line.line_type = DiffLineType::ConflictOurs;
+					our_lines.push(diff_line);
+				}
+				DiffLineType::Add => {
+					diff_line.line_type =
+						DiffLineType::ConflictTheirs;
+					their_lines.push(diff_line);
+				}
+				_ => {
+					push_conflict_region(
+						&mut lines,
+						&mut our_lines,
+						&mut their_lines,
+					);
+					lines.push(diff_line);
+				}
+			}
+		}
+
+		push_conflict_region(
+			&mut lines,
+			&mut our_lines,
+			&mut their_lines,
+		);
+
+		res.lines += lines.len();
+		res.hunks.push(Hunk {
+			header_hash: hash(&HunkHeader::from(hunk)),
+			lines,
+			patch: hunk_patch,
+		});
+	}
+
+	Ok(res)
+}
+
+/// wraps the pending lines of both sides into conflict markers
+fn push_conflict_region(
+	lines: &mut Vec<DiffLine>,
+	ours: &mut Vec<DiffLine>,
+	theirs: &mut Vec<DiffLine>,
+) {
+	if ours.is_empty() && theirs.is_empty() {
+		return;
+	}
+
+	let marker = |content: &str| DiffLine {
+		content: content.into(),
+		line_type: DiffLineType::ConflictMarker,
+		position: DiffLinePosition::default(),
+	};
+
+	lines.push(marker("<<<<<<< ours"));
+	lines.append(ours);
+	lines.push(marker("======="));
+	lines.append(theirs);
+	lines.push(marker(">>>>>>> theirs"));
+}
+
 /// get file changes of a diff between two commits
 pub fn get_diff_commits(
 	repo_path: &str,
@@ -238,6 +425,62 @@ pub fn get_diff_commits(
 	raw_diff_to_file_diff(&diff, work_dir)
 }
 
+/// stats of the changes of a commit (against its parent of index
+/// `parent`) or between two commits
+/// see `get_commit_files`
+pub fn diff_stats(
+	repo_path: &str,
+	id: CommitId,
+	other: Option<CommitId>,
+	parent: usize,
+) -> Result<DiffStats> {
+	scope_time!("diff_stats");
+
+	let repo = utils::repo(repo_path)?;
+	let diff = if let Some(other) = other {
+		get_compare_commits_diff(&repo, (id, other), None)?
+	} else {
+		get_commit_diff_to_parent(&repo, id, parent, None)?
+	};
+
+	Ok(diff.stats()?.into())
+}
+
+/// stats of all changes in stage and workdir compared to `HEAD`,
+/// untracked files are counted (as `status.showUntrackedFiles` or
+/// `show_untracked` says) but their content is not read
+pub fn diff_stats_workdir(
+	repo_path: &str,
+	show_untracked: Option<ShowUntrackedFilesConfig>,
+) -> Result<DiffStats> {
+	scope_time!("diff_stats_workdir");
+
+	let repo = utils::repo(repo_path)?;
+	let head_tree = match get_head_repo(&repo) {
+		Ok(id) => Some(repo.find_commit(id.into())?.tree()?),
+		Err(_) => None,
+	};
+
+	let show_untracked = if let Some(config) = show_untracked {
+		config
+	} else {
+		untracked_files_config_repo(&repo)?
+	};
+
+	let mut opt = git2::DiffOptions::new();
+	opt.include_untracked(show_untracked.include_untracked());
+	opt.recurse_untracked_dirs(
+		show_untracked.recurse_untracked_dirs(),
+	);
+
+	let diff = repo.diff_tree_to_workdir_with_index(
+		head_tree.as_ref(),
+		Some(&mut opt),
+	)?;
+
+	Ok(diff.stats()?.into())
+}
+
 ///
 //TODO: refactor into helper type with the inline closures as dedicated functions
 #[allow(clippy::too_many_lines)]
@@ -248,15 +491,18 @@ fn raw_diff_to_file_diff<'a>(
 	let res = Rc::new(RefCell::new(FileDiff::default()));
 	{
 		let mut current_lines = Vec::new();
+		let mut current_patch = String::new();
 		let mut current_hunk: Option<HunkHeader> = None;
 
 		let res_cell = Rc::clone(&res);
 		let adder = move |header: &HunkHeader,
-		                  lines: &Vec<DiffLine>| {
+		                  lines: &Vec<DiffLine>,
+		                  patch: &str| {
 			let mut res = res_cell.borrow_mut();
 			res.hunks.push(Hunk {
 				header_hash: hash(header),
 				lines: lines.clone(),
+				patch: patch.to_string(),
 			});
 			res.lines += lines.len();
 		};
@@ -274,6 +520,14 @@ fn raw_diff_to_file_diff<'a>(
 				//TODO: use try_conv
 				res.size_delta = (i64::conv(res.sizes.1))
 					.saturating_sub(i64::conv(res.sizes.0));
+				// only known once the content got loaded
+				res.is_binary = delta.flags().is_binary();
+
+				if line.origin_value()
+					== git2::DiffLineType::FileHeader
+				{
+					push_patch_line(&mut res.patch_header, &line);
+				}
 			}
 			if let Some(hunk) = hunk {
 				let hunk_header = HunkHeader::from(hunk);
@@ -282,13 +536,16 @@ fn raw_diff_to_file_diff<'a>(
 					None => current_hunk = Some(hunk_header),
 					Some(h) => {
 						if h != hunk_header {
-							adder(&h, &current_lines);
+							adder(&h, &current_lines, &current_patch);
 							current_lines.clear();
+							current_patch.clear();
 							current_hunk = Some(hunk_header);
 						}
 					}
 				}
 
+				push_patch_line(&mut current_patch, &line);
+
 				let diff_line = DiffLine {
 					position: DiffLinePosition::from(&line),
 					content: String::from_utf8_lossy(line.content())
@@ -335,6 +592,12 @@ fn raw_diff_to_file_diff<'a>(
 							},
 						)?;
 
+						// the buffers were diffed using the absolute path
+						res.borrow_mut().patch_header = format!(
+							"--- /dev/null\n+++ b/{}\n",
+							relative_path.to_string_lossy()
+						);
+
 						true
 					} else {
 						false
@@ -366,18 +629,50 @@ fn raw_diff_to_file_diff<'a>(
 					Ok,
 				)?,
 				&current_lines,
+				&current_patch,
 			);
 		}
 
 		if new_file_diff {
 			res.borrow_mut().untracked = true;
 		}
+
+		// mode only changes come without any lines to `put`
+		if diff.deltas().len() == 1 {
+			res.borrow_mut().mode_change = diff
+				.deltas()
+				.next()
+				.and_then(|delta| delta_mode_change(&delta));
+		}
 	}
 	let res = Rc::try_unwrap(res)
 		.map_err(|_| Error::Generic("rc unwrap error".to_owned()))?;
 	Ok(res.into_inner())
 }
 
+/// old and new mode of a modified file if they differ, like
+/// `0o100644` and `0o100755` after `chmod +x`
+pub(crate) fn delta_mode_change(
+	delta: &DiffDelta,
+) -> Option<(u32, u32)> {
+	let old = u32::from(delta.old_file().mode());
+	let new = u32::from(delta.new_file().mode());
+
+	let modified =
+		matches!(delta.status(), Delta::Modified | Delta::Typechange);
+
+	(modified && old != new).then(|| (old, new))
+}
+
+/// appends `line` the way `git diff` prints it, only content lines
+/// get their origin prepended
+fn push_patch_line(patch: &mut String, line: &git2::DiffLine) {
+	if matches!(line.origin(), '+' | '-' | ' ') {
+		patch.push(line.origin());
+	}
+	patch.push_str(&String::from_utf8_lossy(line.content()));
+}
+
 const fn is_newline(c: char) -> bool {
 	c == '\n' || c == '\r'
 }
@@ -402,12 +697,19 @@ fn new_file_content(path: &Path) -> Option<Vec<u8>> {
 
 #[cfg(test)]
 mod tests {
-	use super::{get_diff, get_diff_commit};
+	use super::{
+		diff_stats, diff_stats_workdir, get_diff, get_diff_commit,
+		get_diff_conflict, DiffLineType, DiffStats,
+	};
 	use crate::error::Result;
 	use crate::sync::{
-		commit, stage_add_file,
+		commit, stage_add_file, stage_addremoved,
 		status::{get_status, StatusType},
-		tests::{get_statuses, repo_init, repo_init_empty},
+		tests::{
+			conflicting_merge, get_statuses, repo_init,
+			repo_init_empty,
+		},
+		ShowUntrackedFilesConfig,
 	};
 	use std::{
 		fs::{self, File},
@@ -533,6 +835,7 @@ mod tests {
 		let res =
 			get_diff(repo_path, "bar.txt", false, None).unwrap();
 
+		assert!(!res.is_binary);
 		assert_eq!(res.hunks.len(), 2)
 	}
 
@@ -588,6 +891,8 @@ mod tests {
 		dbg!(&diff);
 		assert_eq!(diff.sizes, (1, 2));
 		assert_eq!(diff.size_delta, 1);
+		assert!(diff.is_binary);
+		assert!(diff.hunks.is_empty());
 
 		Ok(())
 	}
@@ -613,6 +918,7 @@ mod tests {
 		dbg!(&diff);
 		assert_eq!(diff.sizes, (0, 2));
 		assert_eq!(diff.size_delta, 2);
+		assert!(diff.is_binary);
 
 		Ok(())
 	}
@@ -638,7 +944,7 @@ mod tests {
 		let id = commit(repo_path, "").unwrap();
 
 		let diff =
-			get_diff_commit(repo_path, id, String::new()).unwrap();
+			get_diff_commit(repo_path, id, 0, String::new()).unwrap();
 
 		dbg!(&diff);
 		assert_eq!(diff.sizes, (1, 2));
@@ -646,4 +952,190 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	#[cfg(not(windows))]
+	fn test_diff_mode_change() -> Result<()> {
+		use std::os::unix::fs::PermissionsExt;
+
+		let file_path = Path::new("run.sh");
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"test")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		commit(repo_path, "").unwrap();
+
+		fs::set_permissions(
+			root.join(file_path),
+			fs::Permissions::from_mode(0o755),
+		)?;
+
+		let diff = get_diff(repo_path, "run.sh", false, None)?;
+
+		assert!(diff.hunks.is_empty());
+		assert_eq!(diff.mode_change, Some((0o100_644, 0o100_755)));
+
+		let status =
+			get_status(repo_path, StatusType::WorkingDir, None)?;
+
+		assert_eq!(
+			status[0].mode_change,
+			Some((0o100_644, 0o100_755))
+		);
+
+		stage_add_file(repo_path, file_path).unwrap();
+		let id = commit(repo_path, "").unwrap();
+
+		let diff = get_diff_commit(repo_path, id, 0, String::new())?;
+
+		assert_eq!(diff.mode_change, Some((0o100_644, 0o100_755)));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_diff_stats() -> Result<()> {
+		let file_path = Path::new("foo.txt");
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?
+			.write_all(b"test\nfoo\n")?;
+
+		// untracked content is not read
+		assert_eq!(
+			diff_stats_workdir(repo_path, None)?,
+			DiffStats {
+				files_changed: 1,
+				insertions: 0,
+				deletions: 0,
+			}
+		);
+		assert_eq!(
+			diff_stats_workdir(
+				repo_path,
+				Some(ShowUntrackedFilesConfig::No)
+			)?,
+			DiffStats::default()
+		);
+
+		stage_add_file(repo_path, file_path)?;
+
+		assert_eq!(
+			diff_stats_workdir(repo_path, None)?,
+			DiffStats {
+				files_changed: 1,
+				insertions: 2,
+				deletions: 0,
+			}
+		);
+
+		let id = commit(repo_path, "commit msg")?;
+
+		assert_eq!(
+			diff_stats_workdir(repo_path, None)?,
+			DiffStats::default()
+		);
+
+		File::create(&root.join(file_path))?.write_all(b"test\n")?;
+		stage_add_file(repo_path, file_path)?;
+		let id2 = commit(repo_path, "commit msg 2")?;
+
+		assert_eq!(
+			diff_stats(repo_path, id2, None, 0)?,
+			DiffStats {
+				files_changed: 1,
+				insertions: 0,
+				deletions: 1,
+			}
+		);
+		assert_eq!(
+			diff_stats(repo_path, id, Some(id2), 0)?,
+			diff_stats(repo_path, id2, None, 0)?
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_diff_patch() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join("a.txt"))?
+			.write_all(b"a\r\nb\r\n")?;
+		File::create(&root.join("b.txt"))?.write_all(b"b\n")?;
+		stage_add_file(repo_path, Path::new("a.txt"))?;
+		stage_add_file(repo_path, Path::new("b.txt"))?;
+		commit(repo_path, "commit")?;
+
+		File::create(&root.join("a.txt"))?.write_all(b"a\r\nc")?;
+
+		let diff = get_diff(repo_path, "a.txt", false, None)?;
+
+		assert!(diff
+			.patch_header
+			.ends_with("--- a/a.txt\n+++ b/a.txt\n"));
+		assert_eq!(
+			diff.hunks[0].patch,
+			"@@ -1,2 +1,2 @@\n a\r\n-b\r\n+c\n\\ No newline at end of file\n"
+		);
+
+		fs::remove_file(&root.join("b.txt"))?;
+		stage_addremoved(repo_path, Path::new("b.txt"))?;
+
+		let diff = get_diff(repo_path, "b.txt", true, None)?;
+
+		assert!(diff
+			.patch_header
+			.ends_with("--- a/b.txt\n+++ /dev/null\n"));
+		assert_eq!(diff.hunks[0].patch, "@@ -1 +0,0 @@\n-b\n");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_diff_conflict() {
+		let (_td, repo) = conflicting_merge();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let diff =
+			get_diff_conflict(repo_path, "test.txt", None).unwrap();
+
+		assert!(diff.conflict);
+		assert_eq!(diff.hunks.len(), 1);
+
+		let lines = diff.hunks[0]
+			.lines
+			.iter()
+			.map(|line| (line.line_type, &*line.content))
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			lines[1..],
+			[
+				(DiffLineType::ConflictMarker, "<<<<<<< ours"),
+				(DiffLineType::ConflictOurs, "ours"),
+				(DiffLineType::ConflictMarker, "======="),
+				(DiffLineType::ConflictTheirs, "theirs"),
+				(DiffLineType::ConflictMarker, ">>>>>>> theirs"),
+			]
+		);
+	}
+
+	#[test]
+	fn test_diff_conflict_no_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert!(
+			get_diff_conflict(repo_path, "test.txt", None).is_err()
+		);
+	}
 }
@@ -0,0 +1,143 @@
+//! writing diffs to patch files
+
+use super::{
+	commit_files::get_commit_diff, diff::get_diff_raw, utils::repo,
+	CommitId,
+};
+use crate::error::{Error, Result};
+use git2::{Diff, DiffFormat};
+use scopetime::scope_time;
+use std::{fs, path::Path};
+
+/// what to write into a patch file
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatchSource {
+	/// changes of a file in the stage (`true`) or workdir (`false`)
+	File(String, bool),
+	/// a whole commit as mailbox patch (including its message)
+	Commit(CommitId),
+}
+
+/// writes the diff of `source` as patch to `target`, commits are
+/// written in mailbox format so they can be applied with `git am`
+pub fn export_patch(
+	repo_path: &str,
+	source: &PatchSource,
+	target: &Path,
+) -> Result<()> {
+	scope_time!("export_patch");
+
+	let repo = repo(repo_path)?;
+
+	let content = match source {
+		PatchSource::File(path, stage) => {
+			let diff =
+				get_diff_raw(&repo, path, *stage, false, None)?;
+			print_patch(&diff)?
+		}
+		PatchSource::Commit(id) => {
+			let commit = repo.find_commit((*id).into())?;
+			let mut diff = get_commit_diff(&repo, *id, None)?;
+			diff.format_email(1, 1, &commit, None)?.to_vec()
+		}
+	};
+
+	if content.is_empty() {
+		return Err(Error::Generic(String::from(
+			"no changes to export",
+		)));
+	}
+
+	fs::write(target, content)?;
+
+	Ok(())
+}
+
+fn print_patch(diff: &Diff) -> Result<Vec<u8>> {
+	let mut content = Vec::new();
+
+	diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
+		if matches!(line.origin(), '+' | '-' | ' ') {
+			content.push(line.origin() as u8);
+		}
+		content.extend_from_slice(line.content());
+		true
+	})?;
+
+	Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{export_patch, PatchSource};
+	use crate::sync::{
+		stage_add_file,
+		tests::{repo_init, write_commit_file},
+		utils::repo_write_file,
+	};
+	use std::{fs, path::Path};
+
+	#[test]
+	fn test_export_file_patch() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+		let target = root.join("test.patch");
+
+		write_commit_file(&repo, "test.txt", "old\n", "c1");
+		repo_write_file(&repo, "test.txt", "new\n").unwrap();
+
+		export_patch(
+			repo_path,
+			&PatchSource::File(String::from("test.txt"), false),
+			&target,
+		)
+		.unwrap();
+
+		let patch = fs::read_to_string(&target).unwrap();
+		assert!(patch.starts_with("diff --git a/test.txt b/test.txt"));
+		assert!(patch.contains("-old\n+new\n"));
+
+		// nothing staged yet
+		assert!(export_patch(
+			repo_path,
+			&PatchSource::File(String::from("test.txt"), true),
+			&target,
+		)
+		.is_err());
+
+		stage_add_file(repo_path, Path::new("test.txt")).unwrap();
+
+		export_patch(
+			repo_path,
+			&PatchSource::File(String::from("test.txt"), true),
+			&target,
+		)
+		.unwrap();
+
+		let patch = fs::read_to_string(&target).unwrap();
+		assert!(patch.contains("-old\n+new\n"));
+	}
+
+	#[test]
+	fn test_export_commit_patch() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+		let target = root.join("commit.patch");
+
+		write_commit_file(&repo, "test.txt", "old\n", "c1");
+		let id =
+			write_commit_file(&repo, "test.txt", "new\n", "subject");
+
+		export_patch(repo_path, &PatchSource::Commit(id), &target)
+			.unwrap();
+
+		let patch = fs::read_to_string(&target).unwrap();
+		assert!(
+			patch.starts_with(&format!("From {}", id.to_string()))
+		);
+		assert!(patch.contains("Subject: [PATCH] subject"));
+		assert!(patch.contains("-old\n+new\n"));
+	}
+}
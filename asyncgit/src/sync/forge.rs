@@ -0,0 +1,275 @@
+//! web urls of commits and lines on code forges (github, gitlab)
+
+use super::{
+	config::get_config_string_repo,
+	remotes::get_default_remote_in_repo, utils::repo, CommitId,
+};
+use crate::error::{Error, Result};
+use git2::Repository;
+use scopetime::scope_time;
+
+const GITHUB_COMMIT_URL: &str = "https://{host}/{path}/commit/{sha}";
+const GITLAB_COMMIT_URL: &str =
+	"https://{host}/{path}/-/commit/{sha}";
+const GITHUB_LINE_URL: &str =
+	"https://{host}/{path}/blob/{sha}/{file}#L{line}";
+const GITLAB_LINE_URL: &str =
+	"https://{host}/{path}/-/blob/{sha}/{file}#L{line}";
+
+/// splits a remote url in either `https://host/org/repo.git` or
+/// `git@host:org/repo.git` form into host and repo path
+fn parse_remote_url(url: &str) -> Option<(String, String)> {
+	let (host, path) = if let Some(idx) = url.find("://") {
+		let scheme = &url[..idx];
+		let rest = &url[idx + 3..];
+		let (host, path) = rest.split_at(rest.find('/')?);
+		let host = host.rsplit('@').next()?;
+
+		// ports of ssh urls have nothing to do with the web ui
+		let host = if scheme.starts_with("http") {
+			host
+		} else {
+			host.split(':').next()?
+		};
+
+		(host, path)
+	} else {
+		let (host, path) = url.split_at(url.find(':')?);
+		(host.rsplit('@').next()?, &path[1..])
+	};
+
+	let path = path.trim_matches('/');
+	let path = path.strip_suffix(".git").unwrap_or(path);
+
+	if host.is_empty() || path.is_empty() {
+		return None;
+	}
+
+	Some((host.to_string(), path.to_string()))
+}
+
+/// remote url of the default remote split into host and repo path
+fn default_remote_host_path(
+	repo: &Repository,
+) -> Result<(String, String, String)> {
+	let remote = get_default_remote_in_repo(repo)?;
+	let url = repo
+		.find_remote(&remote)?
+		.url()
+		.map(String::from)
+		.ok_or_else(|| {
+			Error::Generic(format!("remote '{}' has no url", remote))
+		})?;
+
+	let (host, path) = parse_remote_url(&url).ok_or_else(|| {
+		Error::Generic(format!("unsupported remote url: {}", url))
+	})?;
+
+	Ok((url, host, path))
+}
+
+/// url template for `host`, `gitui.<host>.<key>` in the git config
+/// wins over the built in github and gitlab ones
+fn url_template(
+	repo: &Repository,
+	url: &str,
+	host: &str,
+	key: &str,
+	github: &str,
+	gitlab: &str,
+) -> Result<String> {
+	get_config_string_repo(repo, &format!("gitui.{}.{}", host, key))?
+		.or_else(|| {
+			if host.contains("github") {
+				Some(github.to_string())
+			} else if host.contains("gitlab") {
+				Some(gitlab.to_string())
+			} else {
+				None
+			}
+		})
+		.ok_or_else(|| {
+			Error::Generic(format!(
+				"remote is not a recognized forge: {}\nset `gitui.{}.{}` to browse it",
+				url, host, key
+			))
+		})
+}
+
+/// builds the url to view `id` on the forge the default remote
+/// points to, `gitui.<host>.commitUrl` in the git config overrides
+/// the url template (placeholders: `{host}`, `{path}`, `{sha}`)
+pub fn commit_web_url(
+	repo_path: &str,
+	id: CommitId,
+) -> Result<String> {
+	scope_time!("commit_web_url");
+
+	let repo = repo(repo_path)?;
+	let (url, host, path) = default_remote_host_path(&repo)?;
+
+	let template = url_template(
+		&repo,
+		&url,
+		&host,
+		"commitUrl",
+		GITHUB_COMMIT_URL,
+		GITLAB_COMMIT_URL,
+	)?;
+
+	Ok(template
+		.replace("{host}", &host)
+		.replace("{path}", &path)
+		.replace("{sha}", &id.to_string()))
+}
+
+/// permalink to `line` of `file` as of commit `id` on the forge the
+/// default remote points to, `gitui.<host>.lineUrl` overrides the
+/// template (additional placeholders: `{file}`, `{line}`)
+pub fn line_web_url(
+	repo_path: &str,
+	id: CommitId,
+	file: &str,
+	line: u32,
+) -> Result<String> {
+	scope_time!("line_web_url");
+
+	let repo = repo(repo_path)?;
+	let (url, host, path) = default_remote_host_path(&repo)?;
+
+	let template = url_template(
+		&repo,
+		&url,
+		&host,
+		"lineUrl",
+		GITHUB_LINE_URL,
+		GITLAB_LINE_URL,
+	)?;
+
+	Ok(template
+		.replace("{host}", &host)
+		.replace("{path}", &path)
+		.replace("{sha}", &id.to_string())
+		.replace("{file}", file)
+		.replace("{line}", &line.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{commit_web_url, line_web_url, parse_remote_url};
+	use crate::sync::{tests::repo_init, CommitId};
+	use git2::Oid;
+
+	fn parsed(url: &str) -> Option<(String, String)> {
+		parse_remote_url(url)
+	}
+
+	fn host_path(host: &str, path: &str) -> Option<(String, String)> {
+		Some((host.to_string(), path.to_string()))
+	}
+
+	#[test]
+	fn test_parse_remote_url() {
+		assert_eq!(
+			parsed("git@github.com:org/repo.git"),
+			host_path("github.com", "org/repo")
+		);
+		assert_eq!(
+			parsed("https://github.com/org/repo.git"),
+			host_path("github.com", "org/repo")
+		);
+		assert_eq!(
+			parsed("https://user@gitlab.com/group/sub/repo/"),
+			host_path("gitlab.com", "group/sub/repo")
+		);
+		assert_eq!(
+			parsed("ssh://git@gitlab.corp:2222/org/repo.git"),
+			host_path("gitlab.corp", "org/repo")
+		);
+		assert_eq!(
+			parsed("http://localhost:8080/org/repo"),
+			host_path("localhost:8080", "org/repo")
+		);
+		assert_eq!(parsed("/some/local/path"), None);
+		assert_eq!(parsed("https://github.com"), None);
+	}
+
+	#[test]
+	fn test_commit_web_url() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let id = CommitId::new(
+			Oid::from_str("0123456789012345678901234567890123456789")
+				.unwrap(),
+		);
+
+		assert!(commit_web_url(repo_path, id).is_err());
+
+		repo.remote("origin", "git@gitlab.com:org/repo.git")
+			.unwrap();
+
+		assert_eq!(
+			commit_web_url(repo_path, id).unwrap(),
+			"https://gitlab.com/org/repo/-/commit/0123456789012345678901234567890123456789"
+		);
+
+		repo.remote_set_url("origin", "git@git.corp:org/repo.git")
+			.unwrap();
+
+		assert!(commit_web_url(repo_path, id).is_err());
+
+		repo.config()
+			.unwrap()
+			.set_str(
+				"gitui.git.corp.commitUrl",
+				"https://web.corp/{path}/commits/{sha}",
+			)
+			.unwrap();
+
+		assert_eq!(
+			commit_web_url(repo_path, id).unwrap(),
+			"https://web.corp/org/repo/commits/0123456789012345678901234567890123456789"
+		);
+	}
+	#[test]
+	fn test_line_web_url() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let id = CommitId::new(
+			Oid::from_str("0123456789012345678901234567890123456789")
+				.unwrap(),
+		);
+
+		repo.remote("origin", "git@github.com:org/repo.git")
+			.unwrap();
+
+		assert_eq!(
+			line_web_url(repo_path, id, "src/lib.rs", 42).unwrap(),
+			"https://github.com/org/repo/blob/0123456789012345678901234567890123456789/src/lib.rs#L42"
+		);
+
+		repo.remote_set_url("origin", "git@git.corp:org/repo.git")
+			.unwrap();
+
+		assert!(
+			line_web_url(repo_path, id, "src/lib.rs", 42).is_err()
+		);
+
+		repo.config()
+			.unwrap()
+			.set_str(
+				"gitui.git.corp.lineUrl",
+				"https://web.corp/{path}/src/{sha}/{file}?line={line}",
+			)
+			.unwrap();
+
+		assert_eq!(
+			line_web_url(repo_path, id, "a.txt", 3).unwrap(),
+			"https://web.corp/org/repo/src/0123456789012345678901234567890123456789/a.txt?line=3"
+		);
+	}
+}
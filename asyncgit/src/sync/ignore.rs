@@ -1,15 +1,278 @@
 use super::utils::{repo, work_dir};
 use crate::error::{Error, Result};
+use git2::Repository;
 use scopetime::scope_time;
 use std::{
-	fs::{File, OpenOptions},
+	env,
+	fs::{self, File, OpenOptions},
 	io::{Read, Seek, SeekFrom, Write},
-	path::Path,
+	path::{Path, PathBuf},
 };
 
 static GITIGNORE: &str = ".gitignore";
 
-/// add file or path to root ignore file
+/// the ignore rule deciding about a path
+#[derive(Debug, Clone, PartialEq)]
+pub struct IgnoreRule {
+	/// ignore file the rule is from
+	pub file: PathBuf,
+	/// 1-based
+	pub line: usize,
+	/// as written, including a leading `!`
+	pub pattern: String,
+}
+
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct IgnoreCheck {
+	///
+	pub ignored: bool,
+	/// last rule matching the path or one of its folders,
+	/// `None` if no rule matched
+	pub rule: Option<IgnoreRule>,
+}
+
+/// like `git check-ignore -v`, `path` is relative to the workdir
+pub fn check_ignore(
+	repo_path: &str,
+	path: &str,
+) -> Result<IgnoreCheck> {
+	scope_time!("check_ignore");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+
+	let path = path.trim_start_matches("./").trim_end_matches('/');
+
+	// libgit2 decides, the rules are only parsed to explain it.
+	// git does not look into ignored folders but libgit2 still
+	// applies negated rules inside of them, so check those first
+	let mut ignored = false;
+	for (idx, _) in path.match_indices('/') {
+		if repo.is_path_ignored(&path[..=idx])? {
+			ignored = true;
+			break;
+		}
+	}
+	let ignored = ignored || repo.is_path_ignored(path)?;
+
+	let mut sources = Vec::new();
+	if let Some(file) = global_excludes_file(&repo) {
+		sources.push((String::new(), file));
+	}
+	sources.push((String::new(), repo.path().join("info/exclude")));
+	sources.push((String::new(), work_dir.join(GITIGNORE)));
+
+	let mut folder = String::new();
+	for component in path.split('/') {
+		folder.push_str(component);
+		folder.push('/');
+		sources.push((
+			folder.clone(),
+			work_dir.join(&folder).join(GITIGNORE),
+		));
+	}
+
+	let mut rules = Vec::new();
+	for (base, file) in sources {
+		if let Ok(content) = fs::read_to_string(&file) {
+			for (idx, line) in content.lines().enumerate() {
+				let pattern = line.trim_end();
+				if !pattern.is_empty() && !pattern.starts_with('#') {
+					rules.push((
+						base.clone(),
+						IgnoreRule {
+							file: file.clone(),
+							line: idx + 1,
+							pattern: pattern.to_string(),
+						},
+					));
+				}
+			}
+		}
+	}
+
+	// a path inside an ignored folder cannot be re-included
+	let mut prefix = String::new();
+	let mut components = path.split('/').peekable();
+	while let Some(component) = components.next() {
+		prefix.push_str(component);
+
+		let is_last = components.peek().is_none();
+		let is_dir = !is_last || work_dir.join(path).is_dir();
+
+		let rule = rules
+			.iter()
+			.rev()
+			.find(|(base, rule)| {
+				rule_matches(base, &rule.pattern, &prefix, is_dir)
+			})
+			.map(|(_, rule)| rule.clone());
+
+		if is_last
+			|| rule
+				.as_ref()
+				.map_or(false, |rule| !rule.pattern.starts_with('!'))
+		{
+			return Ok(IgnoreCheck { ignored, rule });
+		}
+
+		prefix.push('/');
+	}
+
+	Ok(IgnoreCheck {
+		ignored,
+		rule: None,
+	})
+}
+
+/// keeps the repo open to tell about many paths whether they are
+/// ignored, like those of file system events
+pub struct IgnoreMatcher {
+	repo: Repository,
+	work_dir: PathBuf,
+}
+
+impl IgnoreMatcher {
+	///
+	pub fn new(repo_path: &str) -> Result<Self> {
+		let repo = repo(repo_path)?;
+		let work_dir = work_dir(&repo)?;
+		let work_dir = work_dir
+			.canonicalize()
+			.unwrap_or_else(|_| work_dir.to_path_buf());
+
+		Ok(Self { repo, work_dir })
+	}
+
+	/// `path` is absolute, paths outside of the workdir or in `.git`
+	/// are never ignored
+	pub fn is_ignored(&self, path: &Path) -> bool {
+		path.strip_prefix(&self.work_dir).map_or(false, |path| {
+			!path.starts_with(".git")
+				&& self.repo.is_path_ignored(path).unwrap_or(false)
+		})
+	}
+}
+
+/// `core.excludesFile` or its default `$XDG_CONFIG_HOME/git/ignore`
+fn global_excludes_file(repo: &Repository) -> Option<PathBuf> {
+	repo.config()
+		.ok()
+		.and_then(|config| config.get_path("core.excludesFile").ok())
+		.or_else(|| {
+			env::var_os("XDG_CONFIG_HOME")
+				.map(PathBuf::from)
+				.or_else(|| {
+					env::var_os("HOME")
+						.map(|home| Path::new(&home).join(".config"))
+				})
+				.map(|config| config.join("git/ignore"))
+		})
+}
+
+/// whether the rule `pattern` from the ignore file in folder `base`
+/// matches `path`
+fn rule_matches(
+	base: &str,
+	pattern: &str,
+	path: &str,
+	is_dir: bool,
+) -> bool {
+	let path = match path.strip_prefix(base) {
+		Some(path) if !path.is_empty() => path,
+		_ => return false,
+	};
+
+	let pattern = pattern.strip_prefix('!').unwrap_or(pattern);
+	let dir_only = pattern.ends_with('/');
+	let pattern = pattern.trim_end_matches('/');
+
+	if dir_only && !is_dir {
+		return false;
+	}
+
+	// without a `/` a pattern matches the name at any depth
+	if pattern.contains('/') {
+		let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+		wildmatch(pattern.as_bytes(), path.as_bytes())
+	} else {
+		let name = path.rsplit('/').next().unwrap_or(path);
+		wildmatch(pattern.as_bytes(), name.as_bytes())
+	}
+}
+
+/// glob match where `*` and `?` stop at `/` and `**` does not
+fn wildmatch(pattern: &[u8], text: &[u8]) -> bool {
+	match pattern {
+		[] => text.is_empty(),
+		[b'*', b'*', b'/', rest @ ..] => {
+			wildmatch(rest, text)
+				|| text.iter().enumerate().any(|(idx, c)| {
+					*c == b'/' && wildmatch(rest, &text[idx + 1..])
+				})
+		}
+		[b'*', b'*', rest @ ..] => {
+			(0..=text.len()).any(|idx| wildmatch(rest, &text[idx..]))
+		}
+		[b'*', rest @ ..] => {
+			for idx in 0..=text.len() {
+				if wildmatch(rest, &text[idx..]) {
+					return true;
+				}
+				if text.get(idx) == Some(&b'/') {
+					break;
+				}
+			}
+			false
+		}
+		[b'?', rest @ ..] => match text {
+			[c, text @ ..] if *c != b'/' => wildmatch(rest, text),
+			_ => false,
+		},
+		[b'[', class @ ..] => {
+			match (class.iter().position(|c| *c == b']'), text) {
+				(Some(end), [c, text @ ..]) if end > 0 => {
+					class_matches(&class[..end], *c)
+						&& wildmatch(&class[end + 1..], text)
+				}
+				(Some(_), _) => false,
+				(None, [b'[', text @ ..]) => wildmatch(class, text),
+				(None, _) => false,
+			}
+		}
+		[b'\\', c, rest @ ..] | [c, rest @ ..] => match text {
+			[t, text @ ..] if t == c => wildmatch(rest, text),
+			_ => false,
+		},
+	}
+}
+
+/// `class` is the inside of `[...]`
+fn class_matches(class: &[u8], c: u8) -> bool {
+	let (negate, class) = match class {
+		[b'!', class @ ..] | [b'^', class @ ..] => (true, class),
+		_ => (false, class),
+	};
+
+	let mut matched = false;
+	let mut idx = 0;
+	while idx < class.len() {
+		if class.get(idx + 1) == Some(&b'-') && idx + 2 < class.len()
+		{
+			matched |= (class[idx]..=class[idx + 2]).contains(&c);
+			idx += 3;
+		} else {
+			matched |= class[idx] == c;
+			idx += 1;
+		}
+	}
+
+	matched != negate
+}
+
+/// add file or folder to the nearest ignore file up the tree,
+/// the root one is created if there is none
 pub fn add_to_ignore(
 	repo_path: &str,
 	path_to_ignore: &str,
@@ -17,16 +280,34 @@ pub fn add_to_ignore(
 	scope_time!("add_to_ignore");
 
 	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
 
-	if Path::new(path_to_ignore).file_name()
-		== Path::new(GITIGNORE).file_name()
-	{
+	let path = Path::new(path_to_ignore);
+
+	if path.file_name() == Path::new(GITIGNORE).file_name() {
 		return Err(Error::Generic(String::from(
 			"cannot ignore gitignore",
 		)));
 	}
 
-	let ignore_file = work_dir(&repo)?.join(GITIGNORE);
+	let folder = path
+		.ancestors()
+		.skip(1)
+		.find(|folder| work_dir.join(folder).join(GITIGNORE).exists())
+		.unwrap_or_else(|| Path::new(""));
+
+	let ignore_file = work_dir.join(folder).join(GITIGNORE);
+
+	let mut pattern = path
+		.strip_prefix(folder)
+		.unwrap_or(path)
+		.to_string_lossy()
+		.into_owned();
+
+	// only match the folder, not files with the same name
+	if work_dir.join(path).is_dir() && !pattern.ends_with('/') {
+		pattern.push('/');
+	}
 
 	let optional_newline = ignore_file.exists()
 		&& !file_ends_with_newline(&ignore_file)?;
@@ -40,7 +321,7 @@ pub fn add_to_ignore(
 		file,
 		"{}{}",
 		if optional_newline { "\n" } else { "" },
-		path_to_ignore
+		pattern
 	)?;
 
 	Ok(())
@@ -60,11 +341,33 @@ fn file_ends_with_newline(file: &Path) -> Result<bool> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::sync::{tests::repo_init, utils::repo_write_file};
+	use crate::sync::{
+		tests::{get_statuses, repo_init},
+		utils::repo_write_file,
+	};
 	use io::BufRead;
 	use pretty_assertions::assert_eq;
 	use std::{fs::File, io, path::Path};
 
+	#[test]
+	fn test_ignore_matcher() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		repo_write_file(&repo, ".gitignore", "target/\n")?;
+
+		let matcher = IgnoreMatcher::new(repo_path)?;
+		let root = root.canonicalize()?;
+
+		assert!(matcher.is_ignored(&root.join("target/debug/foo")));
+		assert!(!matcher.is_ignored(&root.join("src/main.rs")));
+		assert!(!matcher.is_ignored(&root.join(".git/index")));
+		assert!(!matcher.is_ignored(Path::new("/target/foo")));
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_empty() -> Result<()> {
 		let ignore_file_path = Path::new(".gitignore");
@@ -134,6 +437,29 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_nearest_ignore_file() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		fs::create_dir_all(root.join("a/b/c"))?;
+		File::create(&root.join("a/b/c/foo.txt"))?
+			.write_all(b"test")?;
+		File::create(&root.join("a/.gitignore"))?
+			.write_all(b"bar")?;
+
+		add_to_ignore(repo_path, "a/b/c/foo.txt")?;
+		add_to_ignore(repo_path, "a/b")?;
+
+		let lines = read_lines(&root.join("a/.gitignore"))?
+			.collect::<io::Result<Vec<_>>>()?;
+		assert_eq!(lines, vec!["bar", "b/c/foo.txt", "b/"]);
+		assert_eq!(root.join(".gitignore").exists(), false);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_ignore_ignore() {
 		let ignore_file_path = Path::new(".gitignore");
@@ -149,4 +475,81 @@ mod tests {
 		let lines = read_lines(&root.join(ignore_file_path)).unwrap();
 		assert_eq!(lines.count(), 1);
 	}
+
+	#[test]
+	fn test_status_honors_excludes_file() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let excludes = repo.path().join("excludes");
+		fs::write(&excludes, "# global\n*.log\n")?;
+		repo.config()?.set_str(
+			"core.excludesFile",
+			excludes.to_str().unwrap(),
+		)?;
+
+		File::create(&root.join("foo.log"))?.write_all(b"test")?;
+		File::create(&root.join("foo.txt"))?.write_all(b"test")?;
+
+		assert_eq!(get_statuses(repo_path), (1, 0));
+
+		assert_eq!(
+			check_ignore(repo_path, "foo.log")?,
+			IgnoreCheck {
+				ignored: true,
+				rule: Some(IgnoreRule {
+					file: excludes,
+					line: 2,
+					pattern: String::from("*.log"),
+				}),
+			}
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_check_ignore() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		repo_write_file(
+			&repo,
+			".gitignore",
+			"target/\n*.txt\n!keep.txt\n",
+		)?;
+		fs::create_dir_all(root.join("target"))?;
+		File::create(&root.join("target/keep.txt"))?
+			.write_all(b"test")?;
+		File::create(&root.join("keep.txt"))?.write_all(b"test")?;
+
+		let check = check_ignore(repo_path, "target/keep.txt")?;
+		assert!(check.ignored);
+		assert_eq!(check.rule.unwrap().pattern, "target/");
+
+		let check = check_ignore(repo_path, "keep.txt")?;
+		assert!(!check.ignored);
+		assert_eq!(check.rule.unwrap().line, 3);
+
+		let check = check_ignore(repo_path, "foo.txt")?;
+		assert!(check.ignored);
+		assert_eq!(check.rule.unwrap().pattern, "*.txt");
+
+		assert_eq!(check_ignore(repo_path, "foo.rs")?.rule, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_wildmatch() {
+		assert!(wildmatch(b"*.log", b"foo.log"));
+		assert!(!wildmatch(b"*.log", b"a/foo.log"));
+		assert!(wildmatch(b"**/foo", b"a/b/foo"));
+		assert!(wildmatch(b"a/**/b", b"a/b"));
+		assert!(wildmatch(b"a/**", b"a/b/c"));
+		assert!(wildmatch(b"f?o.[a-c]", b"foo.b"));
+		assert!(!wildmatch(b"f?o.[!a-c]", b"foo.b"));
+	}
 }
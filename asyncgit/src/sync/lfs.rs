@@ -0,0 +1,129 @@
+use super::utils::{repo, work_dir};
+use crate::error::Result;
+use scopetime::scope_time;
+use std::{collections::HashMap, fs::File, io::Read, path::Path};
+
+/// first line of every git-lfs pointer file
+static LFS_POINTER_HEADER: &[u8] =
+	b"version https://git-lfs.github.com/spec/v1";
+
+/// pointer files are tiny, anything bigger is real content
+const LFS_POINTER_MAX_SIZE: u64 = 1024;
+
+/// returns true if `content` is a git-lfs pointer file
+pub fn is_lfs_pointer(content: &[u8]) -> bool {
+	content.starts_with(LFS_POINTER_HEADER)
+}
+
+/// why a file in the working dir deserves a second look
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FileSizeHint {
+	/// file is bigger than the threshold (size in bytes)
+	Large(u64),
+	/// file is a git-lfs pointer
+	LfsPointer,
+}
+
+/// checks for every one of `paths` whether the working dir file is
+/// larger than `threshold` bytes or is a git-lfs pointer,
+/// a `threshold` of `0` disables the size check
+pub fn file_size_hints<'a>(
+	repo_path: &str,
+	paths: impl IntoIterator<Item = &'a str>,
+	threshold: u64,
+) -> Result<HashMap<String, FileSizeHint>> {
+	scope_time!("file_size_hints");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+
+	let mut hints = HashMap::new();
+
+	for path in paths {
+		// a file we cannot read is not worth failing the others for
+		if let Ok(Some(hint)) =
+			file_size_hint(&work_dir.join(path), threshold)
+		{
+			hints.insert(path.to_string(), hint);
+		}
+	}
+
+	Ok(hints)
+}
+
+fn file_size_hint(
+	file_path: &Path,
+	threshold: u64,
+) -> Result<Option<FileSizeHint>> {
+	let size = match file_path.metadata() {
+		Ok(meta) if meta.is_file() => meta.len(),
+		// deleted files and folders have nothing to report
+		_ => return Ok(None),
+	};
+
+	if size <= LFS_POINTER_MAX_SIZE {
+		let mut content = Vec::new();
+		File::open(file_path)?.read_to_end(&mut content)?;
+
+		if is_lfs_pointer(&content) {
+			return Ok(Some(FileSizeHint::LfsPointer));
+		}
+	}
+
+	if threshold > 0 && size > threshold {
+		return Ok(Some(FileSizeHint::Large(size)));
+	}
+
+	Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{file_size_hints, is_lfs_pointer, FileSizeHint};
+	use crate::error::Result;
+	use crate::sync::tests::repo_init;
+	use std::{fs::File, io::Write};
+
+	const POINTER: &[u8] = b"version https://git-lfs.github.com/spec/v1
+oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393
+size 12345
+";
+
+	#[test]
+	fn test_is_lfs_pointer() {
+		assert!(is_lfs_pointer(POINTER));
+		assert!(!is_lfs_pointer(b"version 1\n"));
+		assert!(!is_lfs_pointer(b""));
+	}
+
+	#[test]
+	fn test_file_size_hints() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join("small.txt"))?.write_all(b"test")?;
+		File::create(&root.join("large.bin"))?
+			.write_all(&[0_u8; 2048])?;
+		File::create(&root.join("pointer.bin"))?
+			.write_all(POINTER)?;
+
+		let paths =
+			["small.txt", "large.bin", "pointer.bin", "missing"];
+
+		let hints =
+			file_size_hints(repo_path, paths.iter().copied(), 1000)?;
+
+		assert_eq!(hints.len(), 2);
+		assert_eq!(hints["large.bin"], FileSizeHint::Large(2048));
+		assert_eq!(hints["pointer.bin"], FileSizeHint::LfsPointer);
+
+		let hints =
+			file_size_hints(repo_path, paths.iter().copied(), 0)?;
+
+		assert_eq!(hints.len(), 1);
+		assert_eq!(hints["pointer.bin"], FileSizeHint::LfsPointer);
+
+		Ok(())
+	}
+}
@@ -1,6 +1,7 @@
-use super::CommitId;
+use super::{utils::repo, CommitId};
 use crate::error::Result;
-use git2::{Commit, Oid, Repository};
+use git2::{Commit, DiffOptions, Oid, Repository};
+use scopetime::scope_time;
 use std::{
 	cmp::Ordering,
 	collections::{BinaryHeap, HashSet},
@@ -34,6 +35,83 @@ pub type LogWalkerFilter = Arc<
 	Box<dyn Fn(&Repository, &CommitId) -> Result<bool> + Send + Sync>,
 >;
 
+/// matches commits whose author name or email contains `author`
+/// (ignoring case)
+pub fn filter_by_author(author: &str) -> LogWalkerFilter {
+	let author = author.to_lowercase();
+
+	Arc::new(Box::new(move |repo: &Repository, id: &CommitId| {
+		let commit = repo.find_commit((*id).into())?;
+		let signature = commit.author();
+
+		Ok([signature.name(), signature.email()]
+			.iter()
+			.flatten()
+			.any(|s| s.to_lowercase().contains(&author)))
+	}))
+}
+
+/// matches commits whose diff against their first parent touches
+/// `pathspec`
+pub fn filter_by_path(pathspec: &str) -> LogWalkerFilter {
+	let pathspec = pathspec.to_string();
+
+	Arc::new(Box::new(move |repo: &Repository, id: &CommitId| {
+		let commit = repo.find_commit((*id).into())?;
+		let tree = commit.tree()?;
+		let parent_tree = match commit.parents().next() {
+			Some(parent) => Some(parent.tree()?),
+			None => None,
+		};
+
+		let mut opts = DiffOptions::new();
+		opts.pathspec(&pathspec);
+
+		let diff = repo.diff_tree_to_tree(
+			parent_tree.as_ref(),
+			Some(&tree),
+			Some(&mut opts),
+		)?;
+
+		Ok(diff.deltas().len() > 0)
+	}))
+}
+
+/// matches commits whose time (seconds since epoch) lies within
+/// `since` and `until` (both inclusive), a `None` bound is open
+pub fn filter_by_time(
+	since: Option<i64>,
+	until: Option<i64>,
+) -> LogWalkerFilter {
+	Arc::new(Box::new(move |repo: &Repository, id: &CommitId| {
+		let time = repo.find_commit((*id).into())?.time().seconds();
+
+		Ok(since.map_or(true, |since| time >= since)
+			&& until.map_or(true, |until| time <= until))
+	}))
+}
+
+/// combines `filters` into one that matches only commits matched
+/// by all of them, `None` if there is nothing to filter by
+pub fn filter_all(
+	mut filters: Vec<LogWalkerFilter>,
+) -> Option<LogWalkerFilter> {
+	if filters.len() > 1 {
+		Some(Arc::new(Box::new(
+			move |repo: &Repository, id: &CommitId| {
+				for filter in &filters {
+					if !filter(repo, id)? {
+						return Ok(false);
+					}
+				}
+				Ok(true)
+			},
+		)))
+	} else {
+		filters.pop()
+	}
+}
+
 ///
 pub struct LogWalker<'a> {
 	commits: BinaryHeap<TimeOrderedCommit<'a>>,
@@ -104,6 +182,22 @@ impl<'a> LogWalker<'a> {
 	}
 }
 
+/// ids of the last `max_count` commits reachable from `HEAD`,
+/// newest first
+pub fn get_log_ids(
+	repo_path: &str,
+	max_count: usize,
+) -> Result<Vec<CommitId>> {
+	scope_time!("get_log_ids");
+
+	let repo = repo(repo_path)?;
+
+	let mut ids = Vec::new();
+	LogWalker::new(&repo, max_count)?.read(&mut ids)?;
+
+	Ok(ids)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -136,6 +230,9 @@ mod tests {
 		assert_eq!(items.len(), 1);
 		assert_eq!(items[0], oid2.into());
 
+		assert_eq!(get_log_ids(repo_path, 1)?, items);
+		assert_eq!(get_log_ids(repo_path, 10)?.len(), 2);
+
 		Ok(())
 	}
 
@@ -245,4 +342,154 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_filter_by_author() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		commit(repo_path, "commit1").unwrap();
+
+		repo.config()?.set_str("user.name", "Other Author")?;
+
+		File::create(&root.join(file_path))?.write_all(b"b")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let oid2 = commit(repo_path, "commit2").unwrap();
+
+		let mut items = Vec::new();
+		let mut walker = LogWalker::new(&repo, 100)?
+			.filter(Some(filter_by_author("other")));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![oid2]);
+
+		let mut items = Vec::new();
+		let mut walker = LogWalker::new(&repo, 100)?
+			.filter(Some(filter_by_author("EMAIL")));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(items.len(), 2);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_filter_by_path() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		std::fs::create_dir(&root.join("dir"))?;
+		File::create(&root.join("foo"))?.write_all(b"a")?;
+		stage_add_file(repo_path, Path::new("foo")).unwrap();
+		let oid1 = commit(repo_path, "commit1").unwrap();
+
+		File::create(&root.join("dir/bar"))?.write_all(b"a")?;
+		stage_add_file(repo_path, Path::new("dir/bar")).unwrap();
+		let oid2 = commit(repo_path, "commit2").unwrap();
+
+		let mut items = Vec::new();
+		let mut walker = LogWalker::new(&repo, 100)?
+			.filter(Some(filter_by_path("foo")));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![oid1]);
+
+		let mut items = Vec::new();
+		let mut walker = LogWalker::new(&repo, 100)?
+			.filter(Some(filter_by_path("dir")));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![oid2]);
+
+		let mut items = Vec::new();
+		let mut walker =
+			LogWalker::new(&repo, 100)?.filter(filter_all(vec![
+				filter_by_path("foo"),
+				filter_by_author("other"),
+			]));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(items.len(), 0);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_filter_by_time() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let oid = commit(repo_path, "commit1").unwrap();
+
+		let time = repo.find_commit(oid.into())?.time().seconds();
+
+		let walk = |filter| -> Result<Vec<CommitId>> {
+			let mut items = Vec::new();
+			LogWalker::new(&repo, 100)?
+				.filter(Some(filter))
+				.read(&mut items)?;
+			Ok(items)
+		};
+
+		assert_eq!(
+			walk(filter_by_time(Some(time), Some(time)))?,
+			vec![oid]
+		);
+		assert_eq!(walk(filter_by_time(None, None))?, vec![oid]);
+		assert_eq!(
+			walk(filter_by_time(Some(time + 1), None))?.len(),
+			0
+		);
+		assert_eq!(
+			walk(filter_by_time(None, Some(time - 1)))?.len(),
+			0
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_missing_parent() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let oid1 = commit(repo_path, "commit1").unwrap();
+		File::create(&root.join(file_path))?.write_all(b"b")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let oid2 = commit(repo_path, "commit2").unwrap();
+
+		// like the boundary of a shallow clone
+		let hash = oid1.to_string();
+		std::fs::remove_file(
+			repo.path()
+				.join("objects")
+				.join(&hash[..2])
+				.join(&hash[2..]),
+		)?;
+
+		let mut items = Vec::new();
+		LogWalker::new(&repo, 100)?.read(&mut items)?;
+
+		assert_eq!(items, vec![oid2]);
+		assert_eq!(get_commits_info(repo_path, &items, 50)?.len(), 1);
+		assert_eq!(
+			get_commit_diff(&repo, oid2, None)?.deltas().len(),
+			1
+		);
+
+		Ok(())
+	}
 }
@@ -53,6 +53,83 @@ pub fn merge_branch(repo_path: &str, branch: &str) -> Result<()> {
 	Ok(())
 }
 
+/// how `merge_branch_with` combines a branch into HEAD
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeMode {
+	/// only moves HEAD forward, fails if the branches diverged
+	FastForwardOnly,
+	/// always creates a merge commit
+	NoFastForward,
+	/// stages the combined changes without committing them
+	Squash,
+}
+
+/// returns the new HEAD or `None` if nothing got committed:
+/// a conflicting merge is left in `RepoState::Merge` to be resolved,
+/// a squash leaves its changes (and conflicts) in the index like git
+pub fn merge_branch_with(
+	repo_path: &str,
+	branch: &str,
+	mode: MergeMode,
+) -> Result<Option<CommitId>> {
+	scope_time!("merge_branch_with");
+
+	let repo = utils::repo(repo_path)?;
+
+	let branch_ref = repo
+		.find_branch(branch, BranchType::Local)?
+		.into_reference();
+	let commit = branch_ref.peel_to_commit()?;
+	let annotated =
+		repo.reference_to_annotated_commit(&branch_ref)?;
+
+	let (analysis, _) = repo.merge_analysis(&[&annotated])?;
+
+	//TODO: support merge on unborn
+	if analysis.is_unborn() {
+		return Err(Error::Generic("head is unborn".into()));
+	}
+
+	if analysis.is_up_to_date() {
+		return Err(Error::Generic("already up to date".into()));
+	}
+
+	match mode {
+		MergeMode::FastForwardOnly => {
+			if !analysis.is_fast_forward() {
+				return Err(Error::Generic(
+					"fast forward merge not possible".into(),
+				));
+			}
+
+			repo.checkout_tree(commit.as_object(), None)?;
+			repo.head()?.set_target(
+				commit.id(),
+				&format!("merge {}: Fast-forward", branch),
+			)?;
+
+			Ok(Some(commit.id().into()))
+		}
+		MergeMode::NoFastForward => {
+			repo.merge(&[&annotated], None, None)?;
+
+			if repo.index()?.has_conflicts() {
+				return Ok(None);
+			}
+
+			let msg = repo.message()?;
+
+			commit_merge_with_head(&repo, &[commit], &msg).map(Some)
+		}
+		MergeMode::Squash => {
+			repo.merge(&[&annotated], None, None)?;
+			repo.cleanup_state()?;
+
+			Ok(None)
+		}
+	}
+}
+
 ///
 pub fn rebase_branch(
 	repo_path: &str,
@@ -137,8 +214,12 @@ pub fn merge_commit(
 mod tests {
 	use super::*;
 	use crate::sync::{
-		create_branch,
+		checkout_branch, create_branch, get_commit_details,
+		repo_state,
+		status::{get_status, StatusType},
 		tests::{repo_init, write_commit_file},
+		utils::get_head,
+		RepoState,
 	};
 	use pretty_assertions::assert_eq;
 
@@ -165,4 +246,134 @@ mod tests {
 
 		assert_eq!(mergeheads[0], c1);
 	}
+
+	/// `master` and `foo` both with a commit on top of `base`
+	fn diverged(repo: &Repository, ours: &str, theirs: &str) {
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(repo, "base.txt", "base", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		write_commit_file(repo, theirs, "theirs", "commit2");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(repo, ours, "ours", "commit3");
+	}
+
+	#[test]
+	fn test_merge_fast_forward_only() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "test", "commit1");
+		create_branch(repo_path, "foo").unwrap();
+		let c2 = write_commit_file(&repo, "test.txt", "test2", "c2");
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let head = merge_branch_with(
+			repo_path,
+			"foo",
+			MergeMode::FastForwardOnly,
+		)
+		.unwrap();
+
+		assert_eq!(head, Some(c2));
+		assert_eq!(get_head(repo_path).unwrap(), c2);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		diverged(&repo, "a.txt", "b.txt");
+
+		assert!(merge_branch_with(
+			repo_path,
+			"foo",
+			MergeMode::FastForwardOnly
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn test_merge_no_fast_forward() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let c1 = write_commit_file(&repo, "test.txt", "test", "c1");
+		create_branch(repo_path, "foo").unwrap();
+		let c2 = write_commit_file(&repo, "test.txt", "test2", "c2");
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let head = merge_branch_with(
+			repo_path,
+			"foo",
+			MergeMode::NoFastForward,
+		)
+		.unwrap()
+		.unwrap();
+
+		let parents = repo
+			.find_commit(head.into())
+			.unwrap()
+			.parent_ids()
+			.map(CommitId::from)
+			.collect::<Vec<_>>();
+
+		assert_eq!(parents, vec![c1, c2]);
+		assert_eq!(
+			&get_commit_details(repo_path, head)
+				.unwrap()
+				.message
+				.unwrap()
+				.subject[0..12],
+			"Merge branch"
+		);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+	}
+
+	#[test]
+	fn test_merge_no_fast_forward_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		diverged(&repo, "test.txt", "test.txt");
+
+		let head = merge_branch_with(
+			repo_path,
+			"foo",
+			MergeMode::NoFastForward,
+		)
+		.unwrap();
+
+		assert_eq!(head, None);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Merge);
+	}
+
+	#[test]
+	fn test_merge_squash() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		diverged(&repo, "a.txt", "b.txt");
+		let head = get_head(repo_path).unwrap();
+
+		let res =
+			merge_branch_with(repo_path, "foo", MergeMode::Squash)
+				.unwrap();
+
+		assert_eq!(res, None);
+		assert_eq!(get_head(repo_path).unwrap(), head);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+
+		let staged =
+			get_status(repo_path, StatusType::Stage, None).unwrap();
+		assert_eq!(staged.len(), 1);
+		assert_eq!(staged[0].path, "b.txt");
+	}
 }
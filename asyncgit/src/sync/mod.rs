@@ -3,88 +3,142 @@
 //TODO: remove once we have this activated on the toplevel
 #![deny(clippy::expect_used)]
 
+mod apply_patch;
+mod bisect;
 pub mod blame;
 pub mod branch;
+mod cherry_pick;
 mod commit;
 mod commit_details;
 mod commit_files;
+mod commit_revert;
 mod commits_info;
 mod config;
+mod conflicts;
 pub mod cred;
 pub mod diff;
+mod export_patch;
+mod forge;
 mod hooks;
 mod hunks;
 mod ignore;
+mod lfs;
 mod logwalker;
 mod merge;
 mod patches;
 mod rebase;
 pub mod remotes;
 mod reset;
+mod signature;
 mod staging;
 mod stash;
 mod state;
 pub mod status;
+mod submodules;
 mod tags;
 mod tree;
 pub mod utils;
+mod worktree;
 
+pub use apply_patch::apply_patch;
+pub use bisect::{
+	bisect_bad, bisect_good, bisect_reset, bisect_start,
+	bisect_state, BisectState,
+};
 pub use blame::{blame_file, BlameHunk, FileBlame};
 pub use branch::{
-	branch_compare_upstream, checkout_branch, config_is_pull_rebase,
-	create_branch, delete_branch, get_branch_remote,
-	get_branches_info, merge_commit::merge_upstream_commit,
+	branch_compare_commits, branch_compare_upstream,
+	branch_upstream_tips, branches_compare_upstream, checkout_branch,
+	checkout_branch_stashed, checkout_commit, config_is_pull_rebase,
+	create_branch, create_branch_at, delete_branch,
+	get_branch_remote, get_branches_containing, get_branches_info,
+	merge_commit::merge_upstream_commit,
 	merge_ff::branch_merge_upstream_fastforward,
 	merge_rebase::merge_upstream_rebase, rename::rename_branch,
-	validate_branch_name, BranchCompare, BranchInfo,
+	set_upstream, validate_branch_name, BranchCompare,
+	BranchContaining, BranchInfo, UpstreamBranch,
+};
+pub use cherry_pick::{abort_cherry_pick, cherry_pick_commits};
+pub use commit::{
+	amend, co_author_msg, comment_char, commit, commit_paths,
+	commit_template, commit_with_author, is_head_merge_commit,
+	recent_authors, reword_head, sign_off_msg, strip_comments, tag,
+	user_signature, CommitAuthor,
 };
-pub use commit::{amend, commit, tag};
 pub use commit_details::{
 	get_commit_details, CommitDetails, CommitMessage, CommitSignature,
 };
-pub use commit_files::get_commit_files;
+pub use commit_files::{get_commit_files, get_commit_parent_files};
+pub use commit_revert::abort_revert;
 pub use commits_info::{
 	get_commit_info, get_commits_info, CommitId, CommitInfo,
 };
 pub use config::{
-	get_config_string, untracked_files_config,
-	ShowUntrackedFilesConfig,
+	get_config_string, repo_config, untracked_files_config, AutoCrlf,
+	RepoConfig, ShowUntrackedFilesConfig,
+};
+pub use conflicts::{
+	checkout_conflict_side, has_conflicts, ConflictSide,
+};
+pub use diff::{
+	diff_stats, diff_stats_workdir, get_diff_commit,
+	get_diff_conflict, DiffStats,
 };
-pub use diff::get_diff_commit;
+pub use export_patch::{export_patch, PatchSource};
+pub use forge::{commit_web_url, line_web_url};
 pub use hooks::{
 	hooks_commit_msg, hooks_post_commit, hooks_pre_commit, HookResult,
 };
 pub use hunks::{reset_hunk, stage_hunk, unstage_hunk};
-pub use ignore::add_to_ignore;
-pub use logwalker::{LogWalker, LogWalkerFilter};
+pub use ignore::{
+	add_to_ignore, check_ignore, IgnoreCheck, IgnoreMatcher,
+	IgnoreRule,
+};
+pub use lfs::{file_size_hints, is_lfs_pointer, FileSizeHint};
+pub use logwalker::{
+	filter_all, filter_by_author, filter_by_path, filter_by_time,
+	get_log_ids, LogWalker, LogWalkerFilter,
+};
 pub use merge::{
-	abort_merge, merge_branch, merge_commit, merge_msg,
-	mergehead_ids, rebase_branch,
+	abort_merge, merge_branch, merge_branch_with, merge_commit,
+	merge_msg, mergehead_ids, rebase_branch, MergeMode,
 };
 pub use remotes::{
-	get_default_remote, get_remotes, push::AsyncProgress,
-	tags::PushTagsProgress,
+	add_remote, get_default_remote, get_remotes, get_remotes_info,
+	last_fetch_time, push::AsyncProgress, remove_remote,
+	rename_remote, set_remote_url, tags::PushTagsProgress,
+	RemoteInfo,
 };
-pub use reset::{reset_stage, reset_workdir};
+pub use reset::{
+	checkout_file, reset_stage, reset_stage_all, reset_workdir,
+	reset_workdir_all,
+};
+pub use signature::{verify_commit_signature, SignatureStatus};
 pub use staging::{discard_lines, stage_lines};
 pub use stash::{
 	get_stashes, stash_apply, stash_drop, stash_pop, stash_save,
 };
-pub use state::{repo_state, RepoState};
+pub use state::{pending_op_ids, repo_state, RepoState};
+pub use submodules::{
+	get_submodules, submodule_update, SubmoduleInfo,
+};
 pub use tags::{
-	delete_tag, get_tags, get_tags_with_metadata, CommitTags,
-	TagWithMetadata, Tags,
+	delete_tag, describe, get_tags, get_tags_with_metadata,
+	tag_version_cmp, CommitTags, TagWithMetadata, Tags,
 };
 pub use tree::{tree_file_content, tree_files, TreeFile};
 pub use utils::{
-	get_head, get_head_tuple, is_bare_repo, is_repo, repo_dir,
+	get_head, get_head_tuple, init_repo, is_bare_repo, is_repo,
+	orig_head, repo_dir, reset_to_orig_head, shallow_boundaries,
 	stage_add_all, stage_add_file, stage_addremoved, Head,
 };
+pub use worktree::{add_worktree, get_worktrees, WorktreeInfo};
 
 #[cfg(test)]
 mod tests {
 	use super::{
-		commit, stage_add_file,
+		checkout_branch, commit, create_branch, merge_branch,
+		stage_add_file,
 		status::{get_status, StatusType},
 		utils::{get_head_repo, repo, repo_write_file},
 		CommitId, LogWalker,
@@ -135,6 +189,25 @@ mod tests {
 			.unwrap()
 	}
 
+	/// repo with an unfinished merge that conflicts in `test.txt`
+	pub fn conflicting_merge() -> (TempDir, Repository) {
+		let (td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "test.txt", "base", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		write_commit_file(&repo, "test.txt", "theirs", "commit2");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		write_commit_file(&repo, "test.txt", "ours", "commit3");
+
+		merge_branch(repo_path, "foo").unwrap();
+
+		(td, repo)
+	}
+
 	/// write, stage and commit a file giving the commit a specific timestamp
 	pub fn write_commit_file_at(
 		repo: &Repository,
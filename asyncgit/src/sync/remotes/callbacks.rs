@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 
 use super::push::ProgressNotification;
-use crate::{error::Result, sync::cred::BasicAuthCredential};
+use crate::{
+	error::Result, progress::ProgressPercent,
+	sync::cred::BasicAuthCredential,
+};
 use crossbeam_channel::Sender;
 use git2::{Cred, Error as GitError, RemoteCallbacks};
 use std::sync::{
@@ -22,6 +25,8 @@ pub struct Callbacks {
 	basic_credential: Option<BasicAuthCredential>,
 	stats: Arc<Mutex<CallbackStats>>,
 	first_call_to_credentials: Arc<AtomicBool>,
+	/// last transfer progress sent, to only notify on changes
+	last_transfer: Arc<Mutex<Option<ProgressPercent>>>,
 }
 
 impl Callbacks {
@@ -39,6 +44,7 @@ impl Callbacks {
 			first_call_to_credentials: Arc::new(AtomicBool::new(
 				true,
 			)),
+			last_transfer: Arc::new(Mutex::new(None)),
 		}
 	}
 
@@ -135,6 +141,20 @@ impl Callbacks {
 			p.received_objects(),
 			p.total_objects()
 		);
+
+		// called for every received object, skip updates that
+		// would not move the gauge
+		let progress = ProgressPercent::new(
+			p.received_objects(),
+			p.total_objects(),
+		);
+		if let Ok(mut last) = self.last_transfer.lock() {
+			if *last == Some(progress) {
+				return;
+			}
+			*last = Some(progress);
+		}
+
 		self.sender.clone().map(|sender| {
 			sender.send(ProgressNotification::Transfer {
 				objects: p.received_objects(),
@@ -12,8 +12,9 @@ use crate::{
 	},
 };
 use crossbeam_channel::Sender;
-use git2::{BranchType, FetchOptions, Repository};
+use git2::{BranchType, FetchOptions, Remote, Repository};
 use scopetime::scope_time;
+use std::{fs, io, time::SystemTime};
 use utils::bytes2string;
 
 pub use callbacks::Callbacks;
@@ -22,6 +23,8 @@ pub use tags::tags_missing_remote;
 /// origin
 pub const DEFAULT_REMOTE_NAME: &str = "origin";
 
+const FETCH_HEAD: &str = "FETCH_HEAD";
+
 ///
 pub fn get_remotes(repo_path: &str) -> Result<Vec<String>> {
 	scope_time!("get_remotes");
@@ -34,6 +37,109 @@ pub fn get_remotes(repo_path: &str) -> Result<Vec<String>> {
 	Ok(remotes)
 }
 
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteInfo {
+	///
+	pub name: String,
+	///
+	pub url: Option<String>,
+}
+
+/// remotes with their urls
+pub fn get_remotes_info(repo_path: &str) -> Result<Vec<RemoteInfo>> {
+	scope_time!("get_remotes_info");
+
+	let repo = utils::repo(repo_path)?;
+
+	repo.remotes()?
+		.iter()
+		.flatten()
+		.map(|name| {
+			let remote = repo.find_remote(name)?;
+
+			Ok(RemoteInfo {
+				name: name.to_string(),
+				url: remote.url().map(String::from),
+			})
+		})
+		.collect()
+}
+
+///
+pub fn add_remote(
+	repo_path: &str,
+	name: &str,
+	url: &str,
+) -> Result<()> {
+	scope_time!("add_remote");
+
+	let repo = utils::repo(repo_path)?;
+
+	validate_remote_name(name)?;
+	repo.remote(name, url)?;
+
+	Ok(())
+}
+
+/// renames the remote along with its tracking branches and the
+/// branches using it as upstream
+pub fn rename_remote(
+	repo_path: &str,
+	name: &str,
+	new_name: &str,
+) -> Result<()> {
+	scope_time!("rename_remote");
+
+	let repo = utils::repo(repo_path)?;
+
+	validate_remote_name(new_name)?;
+
+	let problems = repo.remote_rename(name, new_name)?;
+	for refspec in problems.iter().flatten() {
+		log::warn!("rename remote: refspec not updated: {}", refspec);
+	}
+
+	Ok(())
+}
+
+///
+pub fn set_remote_url(
+	repo_path: &str,
+	name: &str,
+	url: &str,
+) -> Result<()> {
+	scope_time!("set_remote_url");
+
+	let repo = utils::repo(repo_path)?;
+
+	// fails if there is no such remote
+	repo.find_remote(name)?;
+	repo.remote_set_url(name, url)?;
+
+	Ok(())
+}
+
+/// deletes the remote along with its tracking branches and
+/// unsets it as upstream of local branches
+pub fn remove_remote(repo_path: &str, name: &str) -> Result<()> {
+	scope_time!("remove_remote");
+
+	let repo = utils::repo(repo_path)?;
+
+	repo.remote_delete(name)?;
+
+	Ok(())
+}
+
+fn validate_remote_name(name: &str) -> Result<()> {
+	if Remote::is_valid_name(name) {
+		Ok(())
+	} else {
+		Err(Error::Generic(format!("invalid remote name: {}", name)))
+	}
+}
+
 /// tries to find origin or the only remote that is defined if any
 /// in case of multiple remotes and none named *origin* we fail
 pub fn get_default_remote(repo_path: &str) -> Result<String> {
@@ -75,6 +181,63 @@ pub(crate) fn get_default_remote_in_repo(
 	Err(Error::NoDefaultRemoteFound)
 }
 
+/// time of the last successful fetch from `remote`.
+/// this is the modification time of `FETCH_HEAD` as long as
+/// the last fetch written into it was from `remote`
+pub fn last_fetch_time(
+	repo_path: &str,
+	remote: &str,
+) -> Result<Option<SystemTime>> {
+	scope_time!("last_fetch_time");
+
+	let repo = utils::repo(repo_path)?;
+	let remote = repo.find_remote(remote)?;
+	let url = match remote.url() {
+		Some(url) => url,
+		None => return Ok(None),
+	};
+
+	let fetch_head = repo.path().join(FETCH_HEAD);
+	let content = match fs::read_to_string(&fetch_head) {
+		Ok(content) => content,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => {
+			return Ok(None)
+		}
+		Err(e) => return Err(e.into()),
+	};
+
+	let url = trim_fetch_url(url);
+	if content
+		.lines()
+		.filter_map(fetch_head_url)
+		.any(|fetched| trim_fetch_url(fetched) == url)
+	{
+		Ok(Some(fs::metadata(&fetch_head)?.modified()?))
+	} else {
+		Ok(None)
+	}
+}
+
+/// remote url of a `FETCH_HEAD` line, those look like
+/// `<oid>\t[not-for-merge]\t[<kind> '<ref>' of ]<url>`
+fn fetch_head_url(line: &str) -> Option<&str> {
+	let description = line.splitn(3, '\t').nth(2)?;
+
+	// ref names cannot contain spaces so the first `' of ` closes it
+	Some(
+		description
+			.find("' of ")
+			.map_or(description, |idx| &description[idx + 5..]),
+	)
+}
+
+/// git drops trailing slashes and `.git` from the url it writes
+/// into `FETCH_HEAD`
+fn trim_fetch_url(url: &str) -> &str {
+	let url = url.trim_end_matches('/');
+	url.strip_suffix(".git").unwrap_or(url)
+}
+
 /// fetches from upstream/remote for `branch`
 pub(crate) fn fetch(
 	repo_path: &str,
@@ -105,8 +268,9 @@ pub(crate) fn fetch(
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::sync::tests::{
-		debug_cmd_print, repo_clone, repo_init,
+	use crate::sync::{
+		get_branch_remote,
+		tests::{debug_cmd_print, repo_clone, repo_init},
 	};
 
 	#[test]
@@ -123,6 +287,94 @@ mod tests {
 		fetch(repo_path, "master", None, None).unwrap();
 	}
 
+	#[test]
+	fn test_manage_remotes() {
+		let (remote_dir, _remote) = repo_init().unwrap();
+		let remote_path = remote_dir.path().to_str().unwrap();
+		let (repo_dir, _repo) = repo_clone(remote_path).unwrap();
+		let repo_path = repo_dir.path().as_os_str().to_str().unwrap();
+
+		assert!(add_remote(repo_path, "in valid", "foo").is_err());
+		add_remote(repo_path, "second", "https://foo/bar.git")
+			.unwrap();
+
+		rename_remote(repo_path, "origin", "first").unwrap();
+		assert!(rename_remote(repo_path, "first", "second").is_err());
+		assert_eq!(
+			get_branch_remote(repo_path, "master").unwrap(),
+			Some(String::from("first"))
+		);
+
+		set_remote_url(repo_path, "second", "git@foo:bar.git")
+			.unwrap();
+		assert!(set_remote_url(repo_path, "third", "foo").is_err());
+
+		assert_eq!(
+			get_remotes_info(repo_path).unwrap(),
+			vec![
+				RemoteInfo {
+					name: String::from("first"),
+					url: Some(String::from(remote_path)),
+				},
+				RemoteInfo {
+					name: String::from("second"),
+					url: Some(String::from("git@foo:bar.git")),
+				},
+			]
+		);
+
+		remove_remote(repo_path, "first").unwrap();
+		assert_eq!(get_remotes(repo_path).unwrap(), vec!["second"]);
+		assert_eq!(
+			get_branch_remote(repo_path, "master").unwrap(),
+			None
+		);
+	}
+
+	#[test]
+	fn test_last_fetch_time() {
+		let (remote_dir, _remote) = repo_init().unwrap();
+		let remote_path = remote_dir.path().to_str().unwrap();
+		let (repo_dir, _repo) = repo_clone(remote_path).unwrap();
+		let repo_path = repo_dir.path().as_os_str().to_str().unwrap();
+
+		debug_cmd_print(
+			repo_path,
+			&format!("git remote add second {}/other", remote_path)[..],
+		);
+
+		fetch(repo_path, "master", None, None).unwrap();
+
+		assert!(last_fetch_time(repo_path, "origin")
+			.unwrap()
+			.is_some());
+		assert!(last_fetch_time(repo_path, "second")
+			.unwrap()
+			.is_none());
+	}
+
+	#[test]
+	fn test_fetch_head_url() {
+		assert_eq!(
+			fetch_head_url(
+				"abc\t\tbranch 'master' of https://host/repo.git"
+			),
+			Some("https://host/repo.git")
+		);
+		assert_eq!(
+			fetch_head_url(
+				"abc\tnot-for-merge\tbranch 'x' of /tmp/a' of b"
+			),
+			Some("/tmp/a' of b")
+		);
+		assert_eq!(
+			fetch_head_url("abc\t\t/tmp/remote"),
+			Some("/tmp/remote")
+		);
+		assert_eq!(fetch_head_url("abc"), None);
+		assert_eq!(trim_fetch_url("/tmp/remote.git/"), "/tmp/remote");
+	}
+
 	#[test]
 	fn test_default_remote() {
 		let (remote_dir, _remote) = repo_init().unwrap();
@@ -6,9 +6,10 @@ use crate::{
 		branch::branch_set_upstream, cred::BasicAuthCredential,
 		remotes::Callbacks, CommitId,
 	},
+	RemoteProgress,
 };
 use crossbeam_channel::Sender;
-use git2::{PackBuilderStage, PushOptions};
+use git2::{Direction, PackBuilderStage, PushOptions, Repository};
 use scopetime::scope_time;
 
 ///
@@ -65,27 +66,7 @@ impl AsyncProgress for ProgressNotification {
 		*self == Self::Done
 	}
 	fn progress(&self) -> ProgressPercent {
-		match *self {
-			Self::Packing {
-				stage,
-				current,
-				total,
-			} => match stage {
-				PackBuilderStage::AddingObjects
-				| PackBuilderStage::Deltafication => {
-					ProgressPercent::new(current, total)
-				}
-			},
-			Self::PushTransfer { current, total, .. } => {
-				ProgressPercent::new(current, total)
-			}
-			Self::Transfer {
-				objects,
-				total_objects,
-				..
-			} => ProgressPercent::new(objects, total_objects),
-			_ => ProgressPercent::full(),
-		}
+		RemoteProgress::from(self.clone()).progress
 	}
 }
 
@@ -136,6 +117,79 @@ pub(crate) fn push(
 	Ok(())
 }
 
+/// where `branch` is on `remote` right now, `None` if it is not there
+fn remote_branch_head(
+	repo: &Repository,
+	remote: &str,
+	branch: &str,
+	basic_credential: Option<BasicAuthCredential>,
+) -> Result<Option<CommitId>> {
+	let mut remote = repo.find_remote(remote)?;
+	let callbacks = Callbacks::new(None, basic_credential);
+	let conn = remote.connect_auth(
+		Direction::Fetch,
+		Some(callbacks.callbacks()),
+		None,
+	)?;
+
+	let branch_ref = format!("refs/heads/{}", branch);
+	let head = conn
+		.list()?
+		.iter()
+		.find(|head| head.name() == branch_ref)
+		.map(|head| CommitId::new(head.oid()));
+
+	Ok(head)
+}
+
+/// force pushes `branch` only if the remote still has it where our
+/// remote tracking branch expects it (like `--force-with-lease`),
+/// otherwise someone pushed since the last fetch and we abort
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn push_with_lease(
+	repo_path: &str,
+	remote: &str,
+	branch: &str,
+	basic_credential: Option<BasicAuthCredential>,
+	progress_sender: Option<Sender<ProgressNotification>>,
+) -> Result<()> {
+	scope_time!("push_with_lease");
+
+	let repo = utils::repo(repo_path)?;
+
+	let expected = repo
+		.find_reference(&format!(
+			"refs/remotes/{}/{}",
+			remote, branch
+		))
+		.ok()
+		.and_then(|reference| reference.target())
+		.map(CommitId::new);
+	let actual = remote_branch_head(
+		&repo,
+		remote,
+		branch,
+		basic_credential.clone(),
+	)?;
+
+	if expected != actual {
+		return Err(Error::Generic(format!(
+			"push to '{}' rejected: the remote branch changed since the last fetch",
+			branch
+		)));
+	}
+
+	push(
+		repo_path,
+		remote,
+		branch,
+		true,
+		false,
+		basic_credential,
+		progress_sender,
+	)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -477,4 +531,70 @@ mod tests {
 			false
 		);
 	}
+
+	#[test]
+	fn test_force_push_with_lease() {
+		let (upstream_dir, _) = repo_init_bare().unwrap();
+		let upstream_path = upstream_dir.path().to_str().unwrap();
+
+		let (tmp_repo_dir, repo) = repo_clone(upstream_path).unwrap();
+		let repo_path = tmp_repo_dir.path().to_str().unwrap();
+
+		write_commit_file(&repo, "temp_file.txt", "a", "commit 1");
+		push(repo_path, "origin", "master", false, false, None, None)
+			.unwrap();
+
+		let (tmp_other_repo_dir, other_repo) =
+			repo_clone(upstream_path).unwrap();
+		let other_repo_path =
+			tmp_other_repo_dir.path().to_str().unwrap();
+
+		// someone else pushes in the meantime
+		write_commit_file(&repo, "temp_file.txt", "b", "commit 2");
+		push(repo_path, "origin", "master", false, false, None, None)
+			.unwrap();
+
+		let rewritten = write_commit_file(
+			&other_repo,
+			"temp_file.txt",
+			"c",
+			"commit 3",
+		);
+
+		// our tracking branch is outdated, so the lease fails
+		assert!(push_with_lease(
+			other_repo_path,
+			"origin",
+			"master",
+			None,
+			None
+		)
+		.is_err());
+		assert!(!get_commit_ids(
+			&Repository::open_bare(upstream_path).unwrap(),
+			1
+		)
+		.contains(&rewritten));
+
+		other_repo
+			.find_remote("origin")
+			.unwrap()
+			.fetch(&[] as &[&str], None, None)
+			.unwrap();
+
+		// after a fetch we know what we clobber
+		push_with_lease(
+			other_repo_path,
+			"origin",
+			"master",
+			None,
+			None,
+		)
+		.unwrap();
+		assert!(get_commit_ids(
+			&Repository::open_bare(upstream_path).unwrap(),
+			1
+		)
+		.contains(&rewritten));
+	}
 }
@@ -1,7 +1,10 @@
-use super::utils::{get_head_repo, repo};
+use super::utils::{get_head_repo, literal_pathspec, repo};
 use crate::error::Result;
-use git2::{build::CheckoutBuilder, ObjectType};
+use git2::{
+	build::CheckoutBuilder, ObjectType, StatusOptions, StatusShow,
+};
 use scopetime::scope_time;
+use std::path::Path;
 
 ///
 pub fn reset_stage(repo_path: &str, path: &str) -> Result<()> {
@@ -21,6 +24,47 @@ pub fn reset_stage(repo_path: &str, path: &str) -> Result<()> {
 	Ok(())
 }
 
+/// resets the whole index to `HEAD`, renamed files are reset
+/// on both their old and new path
+pub fn reset_stage_all(repo_path: &str) -> Result<()> {
+	scope_time!("reset_stage_all");
+
+	let repo = repo(repo_path)?;
+
+	let mut options = StatusOptions::default();
+	options.show(StatusShow::Index).renames_head_to_index(true);
+
+	let statuses = repo.statuses(Some(&mut options))?;
+
+	let mut paths = Vec::with_capacity(statuses.len());
+	for e in statuses.iter() {
+		if let Some(diff) = e.head_to_index() {
+			paths.extend(
+				diff.old_file().path().map(Path::to_path_buf),
+			);
+			paths.extend(
+				diff.new_file().path().map(Path::to_path_buf),
+			);
+		}
+	}
+	paths.dedup();
+
+	if paths.is_empty() {
+		return Ok(());
+	}
+
+	if let Ok(id) = get_head_repo(&repo) {
+		let obj =
+			repo.find_object(id.into(), Some(ObjectType::Commit))?;
+
+		repo.reset_default(Some(&obj), &paths)?;
+	} else {
+		repo.reset_default(None, &paths)?;
+	}
+
+	Ok(())
+}
+
 ///
 pub fn reset_workdir(repo_path: &str, path: &str) -> Result<()> {
 	scope_time!("reset_workdir");
@@ -38,17 +82,67 @@ pub fn reset_workdir(repo_path: &str, path: &str) -> Result<()> {
 	Ok(())
 }
 
+/// discards all unstaged changes and removes untracked files
+pub fn reset_workdir_all(repo_path: &str) -> Result<()> {
+	scope_time!("reset_workdir_all");
+
+	let repo = repo(repo_path)?;
+
+	let mut checkout_opts = CheckoutBuilder::new();
+	checkout_opts
+		.update_index(true)
+		.remove_untracked(true)
+		.force();
+
+	repo.checkout_index(None, Some(&mut checkout_opts))?;
+	Ok(())
+}
+
+/// restores `path` as it is in `rev` (a commit id, branch or any
+/// other revspec) into the workdir, and into the index if `stage`
+/// is set, leaving all other files untouched
+pub fn checkout_file(
+	repo_path: &str,
+	rev: &str,
+	path: &str,
+	stage: bool,
+) -> Result<()> {
+	scope_time!("checkout_file");
+
+	let repo = repo(repo_path)?;
+
+	let tree = repo.revparse_single(rev)?.peel_to_tree()?;
+	// fails if `path` does not exist in `rev`
+	tree.get_path(Path::new(path))?;
+
+	let mut checkout_opts = CheckoutBuilder::new();
+	checkout_opts
+		.update_index(stage)
+		.force()
+		.path(literal_pathspec(path));
+
+	repo.checkout_tree(tree.as_object(), Some(&mut checkout_opts))?;
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{reset_stage, reset_workdir};
+	use super::{
+		checkout_file, reset_stage, reset_stage_all, reset_workdir,
+		reset_workdir_all,
+	};
 	use crate::error::Result;
 	use crate::sync::{
 		commit,
 		status::{get_status, StatusType},
 		tests::{
-			debug_cmd_print, get_statuses, repo_init, repo_init_empty,
+			debug_cmd_print, get_statuses, repo_init,
+			repo_init_empty, write_commit_file,
+		},
+		utils::{
+			repo_read_file, repo_write_file, stage_add_all,
+			stage_add_file, stage_addremoved,
 		},
-		utils::{stage_add_all, stage_add_file},
 	};
 	use std::{
 		fs::{self, File},
@@ -126,6 +220,40 @@ mod tests {
 		assert_eq!(get_statuses(repo_path), (0, 1));
 	}
 
+	#[test]
+	fn test_reset_all() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join("foo.txt"))?
+			.write_all(HUNK_A.as_bytes())?;
+		stage_add_all(repo_path, "*")?;
+		commit(repo_path, "add foo")?;
+
+		fs::rename(root.join("foo.txt"), root.join("bar.txt"))?;
+		stage_add_file(repo_path, Path::new("bar.txt"))?;
+		stage_addremoved(repo_path, Path::new("foo.txt"))?;
+
+		File::create(&root.join("bar.txt"))?
+			.write_all(HUNK_B.as_bytes())?;
+		File::create(&root.join("baz.txt"))?
+			.write_all(HUNK_A.as_bytes())?;
+
+		assert_eq!(get_statuses(repo_path), (2, 1));
+
+		reset_stage_all(repo_path)?;
+
+		assert_eq!(get_statuses(repo_path), (3, 0));
+
+		reset_workdir_all(repo_path)?;
+
+		assert_eq!(get_statuses(repo_path), (0, 0));
+		assert!(root.join("foo.txt").exists());
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_reset_untracked_in_subdir() {
 		let (_td, repo) = repo_init().unwrap();
@@ -311,4 +439,73 @@ mod tests {
 
 		assert_eq!(get_statuses(repo_path), (0, 0));
 	}
+
+	#[test]
+	fn test_checkout_file() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let old =
+			write_commit_file(&repo, "config.toml", "old", "c1");
+		write_commit_file(&repo, "config.toml", "new", "c2");
+		write_commit_file(&repo, "other.txt", "other", "c3");
+		repo_write_file(&repo, "other.txt", "changed").unwrap();
+
+		checkout_file(
+			repo_path,
+			&old.to_string(),
+			"config.toml",
+			false,
+		)
+		.unwrap();
+
+		assert_eq!(
+			repo_read_file(&repo, "config.toml").unwrap(),
+			"old"
+		);
+		assert_eq!(
+			repo_read_file(&repo, "other.txt").unwrap(),
+			"changed"
+		);
+		assert_eq!(get_statuses(repo_path), (2, 0));
+
+		reset_workdir(repo_path, "config.toml").unwrap();
+		checkout_file(repo_path, "HEAD~2", "config.toml", true)
+			.unwrap();
+
+		assert_eq!(get_statuses(repo_path), (1, 1));
+
+		assert!(checkout_file(
+			repo_path,
+			"HEAD",
+			"missing.txt",
+			false
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn test_checkout_file_glob_name() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		write_commit_file(&repo, "file[1].txt", "a", "c1");
+		write_commit_file(&repo, "file1.txt", "b", "c2");
+		repo_write_file(&repo, "file[1].txt", "changed").unwrap();
+		repo_write_file(&repo, "file1.txt", "changed").unwrap();
+
+		checkout_file(repo_path, "HEAD", "file[1].txt", false)
+			.unwrap();
+
+		assert_eq!(
+			repo_read_file(&repo, "file[1].txt").unwrap(),
+			"a"
+		);
+		assert_eq!(
+			repo_read_file(&repo, "file1.txt").unwrap(),
+			"changed"
+		);
+	}
 }
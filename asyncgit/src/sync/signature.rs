@@ -0,0 +1,144 @@
+use super::{get_config_string, utils::repo, CommitId};
+use crate::error::Result;
+use git2::ErrorCode;
+use scopetime::scope_time;
+use std::{
+	io::{self, Write},
+	process::{Command, Stdio},
+};
+use tempfile::NamedTempFile;
+
+const GPG_PROGRAM_DEFAULT: &str = "gpg";
+
+/// outcome of verifying the signature of a commit
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SignatureStatus {
+	/// commit is not signed
+	None,
+	/// signature was verified by gpg
+	Good,
+	/// signature did not pass verification
+	Bad,
+	/// commit is signed but gpg is not available to check it
+	Unverifiable,
+}
+
+/// verifies the gpg signature of `id` using the program configured
+/// in `gpg.program` (defaults to `gpg`).
+/// this spawns a process and should not be called on the main thread.
+pub fn verify_commit_signature(
+	repo_path: &str,
+	id: CommitId,
+) -> Result<SignatureStatus> {
+	scope_time!("verify_commit_signature");
+
+	let repo = repo(repo_path)?;
+
+	let (signature, signed_data) =
+		match repo.extract_signature(&id.into(), None) {
+			Ok(res) => res,
+			Err(e) if e.code() == ErrorCode::NotFound => {
+				return Ok(SignatureStatus::None);
+			}
+			Err(e) => return Err(e.into()),
+		};
+
+	let program = get_config_string(repo_path, "gpg.program")?
+		.unwrap_or_else(|| GPG_PROGRAM_DEFAULT.to_string());
+
+	// gpg wants the detached signature as a file and reads the
+	// signed payload from stdin
+	let mut sig_file = NamedTempFile::new()?;
+	sig_file.write_all(&signature)?;
+	sig_file.flush()?;
+
+	let res = Command::new(program)
+		.arg("--verify")
+		.arg(sig_file.path())
+		.arg("-")
+		.stdin(Stdio::piped())
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.spawn()
+		.and_then(|mut child| {
+			if let Some(mut stdin) = child.stdin.take() {
+				stdin.write_all(&signed_data)?;
+			}
+			child.wait()
+		});
+
+	drop(sig_file);
+
+	let status = match res {
+		Ok(status) => status,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => {
+			log::warn!("gpg not found, cannot verify signatures");
+			return Ok(SignatureStatus::Unverifiable);
+		}
+		Err(e) => return Err(e.into()),
+	};
+
+	Ok(if status.success() {
+		SignatureStatus::Good
+	} else {
+		SignatureStatus::Bad
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{commit, stage_add_file, tests::repo_init};
+	use std::{fs::File, path::Path};
+
+	#[test]
+	fn test_unsigned_commit() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path)?;
+		let id = commit(repo_path, "commit msg")?;
+
+		assert_eq!(
+			verify_commit_signature(repo_path, id)?,
+			SignatureStatus::None
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_missing_gpg() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		repo.config()?
+			.set_str("gpg.program", "gitui-no-such-gpg-program")?;
+
+		let head = repo.head()?.peel_to_commit()?;
+		let sig = repo.signature()?;
+		let buffer = repo.commit_create_buffer(
+			&sig,
+			&sig,
+			"signed",
+			&head.tree()?,
+			&[&head],
+		)?;
+		let id = repo.commit_signed(
+			buffer.as_str().unwrap(),
+			"-----BEGIN PGP SIGNATURE-----\n\nabc\n-----END PGP SIGNATURE-----",
+			None,
+		)?;
+
+		assert_eq!(
+			verify_commit_signature(repo_path, id.into())?,
+			SignatureStatus::Unverifiable
+		);
+
+		Ok(())
+	}
+}
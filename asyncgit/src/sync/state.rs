@@ -1,23 +1,46 @@
-use crate::{error::Result, sync::utils};
-use git2::RepositoryState;
+use crate::{
+	error::Result,
+	sync::{mergehead_ids, utils, CommitId},
+};
+use git2::{Repository, RepositoryState};
 use scopetime::scope_time;
 
 ///
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum RepoState {
 	///
 	Clean,
 	///
 	Merge,
 	///
+	CherryPick,
+	///
+	Revert,
+	///
+	Rebase,
+	///
 	Other,
 }
 
+impl RepoState {
+	/// a pending operation that can be aborted
+	pub const fn is_abortable(self) -> bool {
+		matches!(self, Self::Merge | Self::CherryPick | Self::Revert)
+	}
+}
+
 impl From<RepositoryState> for RepoState {
 	fn from(state: RepositoryState) -> Self {
 		match state {
 			RepositoryState::Clean => Self::Clean,
 			RepositoryState::Merge => Self::Merge,
+			RepositoryState::CherryPick
+			| RepositoryState::CherryPickSequence => Self::CherryPick,
+			RepositoryState::Revert
+			| RepositoryState::RevertSequence => Self::Revert,
+			RepositoryState::Rebase
+			| RepositoryState::RebaseInteractive
+			| RepositoryState::RebaseMerge => Self::Rebase,
 			_ => Self::Other,
 		}
 	}
@@ -31,3 +54,31 @@ pub fn repo_state(repo_path: &str) -> Result<RepoState> {
 
 	Ok(repo.state().into())
 }
+
+/// commits the pending merge, cherry-pick or revert is about
+pub fn pending_op_ids(repo_path: &str) -> Result<Vec<CommitId>> {
+	scope_time!("pending_op_ids");
+
+	let repo = utils::repo(repo_path)?;
+
+	match RepoState::from(repo.state()) {
+		RepoState::Merge => mergehead_ids(repo_path),
+		RepoState::CherryPick => {
+			head_ref_id(&repo, "CHERRY_PICK_HEAD")
+		}
+		RepoState::Revert => head_ref_id(&repo, "REVERT_HEAD"),
+		RepoState::Clean | RepoState::Rebase | RepoState::Other => {
+			Ok(Vec::new())
+		}
+	}
+}
+
+fn head_ref_id(
+	repo: &Repository,
+	name: &str,
+) -> Result<Vec<CommitId>> {
+	Ok(repo
+		.refname_to_id(name)
+		.map(|id| vec![CommitId::new(id)])
+		.unwrap_or_default())
+}
@@ -3,7 +3,10 @@
 use crate::{
 	error::Error,
 	error::Result,
-	sync::{config::untracked_files_config_repo, utils},
+	sync::{
+		config::untracked_files_config_repo, diff::delta_mode_change,
+		utils,
+	},
 };
 use git2::{Delta, Status, StatusOptions, StatusShow};
 use scopetime::scope_time;
@@ -65,6 +68,8 @@ pub struct StatusItem {
 	pub path: String,
 	///
 	pub status: StatusItemType,
+	/// old and new file mode if they differ
+	pub mode_change: Option<(u32, u32)>,
 }
 
 ///
@@ -110,10 +115,13 @@ pub fn get_status(
 		untracked_files_config_repo(&repo)?
 	};
 
+	// only the workdir scan refreshes the stat cache of the index,
+	// this way stage and workdir can be scanned in parallel without
+	// fighting over the index lock
 	let mut options = StatusOptions::default();
 	options
 		.show(status_type.into())
-		.update_index(true)
+		.update_index(status_type != StatusType::Stage)
 		.include_untracked(show_untracked.include_untracked())
 		.renames_head_to_index(true)
 		.recurse_untracked_dirs(
@@ -150,6 +158,10 @@ pub fn get_status(
 		res.push(StatusItem {
 			path,
 			status: StatusItemType::from(status),
+			mode_change: [e.head_to_index(), e.index_to_workdir()]
+				.iter()
+				.flatten()
+				.find_map(delta_mode_change),
 		});
 	}
 
@@ -159,3 +171,61 @@ pub fn get_status(
 
 	Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		stage_add_file,
+		tests::{repo_init, write_commit_file},
+		utils::repo_write_file,
+	};
+	use std::thread;
+
+	#[test]
+	fn test_stage_and_workdir_in_parallel() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path =
+			root.as_os_str().to_str().unwrap().to_string();
+
+		for i in 0..20 {
+			write_commit_file(
+				&repo,
+				&format!("file{}.txt", i),
+				"a",
+				"commit",
+			);
+		}
+		for i in 0..10 {
+			repo_write_file(&repo, &format!("file{}.txt", i), "b")
+				.unwrap();
+		}
+		stage_add_file(&repo_path, Path::new("file0.txt")).unwrap();
+
+		let scans = [StatusType::Stage, StatusType::WorkingDir]
+			.iter()
+			.map(|status_type| {
+				let status_type = *status_type;
+				let repo_path = repo_path.clone();
+				thread::spawn(move || {
+					(0..10)
+						.map(|_| {
+							get_status(&repo_path, status_type, None)
+								.unwrap()
+								.len()
+						})
+						.collect::<Vec<_>>()
+				})
+			})
+			.collect::<Vec<_>>();
+
+		let res = scans
+			.into_iter()
+			.map(|scan| scan.join().unwrap())
+			.collect::<Vec<_>>();
+
+		assert!(res[0].iter().all(|len| *len == 1));
+		assert!(res[1].iter().all(|len| *len == 9));
+	}
+}
@@ -0,0 +1,105 @@
+//! git submodules
+
+use super::{utils::repo, CommitId};
+use crate::error::Result;
+use git2::{
+	SubmoduleIgnore, SubmoduleStatus, SubmoduleUpdateOptions,
+};
+use scopetime::scope_time;
+use std::path::PathBuf;
+
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmoduleInfo {
+	///
+	pub name: String,
+	/// relative to the workdir
+	pub path: PathBuf,
+	///
+	pub url: Option<String>,
+	/// commit recorded in the index of the parent repo
+	pub id: Option<CommitId>,
+	/// false until `submodule_update` with `init` ran once
+	pub initialized: bool,
+}
+
+///
+pub fn get_submodules(repo_path: &str) -> Result<Vec<SubmoduleInfo>> {
+	scope_time!("get_submodules");
+
+	let repo = repo(repo_path)?;
+
+	let res = repo
+		.submodules()?
+		.iter()
+		.filter_map(|sub| {
+			let name = sub.name()?.to_string();
+			let status = repo
+				.submodule_status(&name, SubmoduleIgnore::Unspecified)
+				.unwrap_or_else(|_| SubmoduleStatus::empty());
+
+			Some(SubmoduleInfo {
+				path: sub.path().to_path_buf(),
+				url: sub.url().map(String::from),
+				id: sub.index_id().map(CommitId::new),
+				initialized: !status
+					.contains(SubmoduleStatus::WD_UNINITIALIZED),
+				name,
+			})
+		})
+		.collect();
+
+	Ok(res)
+}
+
+/// clones (if needed) and checks out the commit recorded for the
+/// submodule `name`, `init` copies its url into the repo config first
+/// which is required for submodules not yet initialized
+pub fn submodule_update(
+	repo_path: &str,
+	name: &str,
+	init: bool,
+) -> Result<()> {
+	scope_time!("submodule_update");
+
+	let repo = repo(repo_path)?;
+	let mut sub = repo.find_submodule(name)?;
+
+	let mut opts = SubmoduleUpdateOptions::new();
+	sub.update(init, Some(&mut opts))?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{get_submodules, submodule_update};
+	use crate::sync::tests::repo_init;
+	use std::path::Path;
+
+	#[test]
+	fn test_get_submodules() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert!(get_submodules(repo_path).unwrap().is_empty());
+		assert!(submodule_update(repo_path, "sub", true).is_err());
+
+		let (_td_sub, sub_repo) = repo_init().unwrap();
+		let sub_url = sub_repo.path().parent().unwrap();
+
+		repo.submodule(
+			sub_url.as_os_str().to_str().unwrap(),
+			Path::new("sub"),
+			true,
+		)
+		.unwrap();
+
+		let subs = get_submodules(repo_path).unwrap();
+
+		assert_eq!(subs.len(), 1);
+		assert_eq!(subs[0].name, "sub");
+		assert_eq!(subs[0].path, Path::new("sub"));
+	}
+}
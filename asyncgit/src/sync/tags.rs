@@ -1,7 +1,12 @@
 use super::{get_commits_info, utils::repo, CommitId};
 use crate::error::Result;
+use git2::{DescribeFormatOptions, DescribeOptions};
 use scopetime::scope_time;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::{
+	cmp::Ordering,
+	collections::{BTreeMap, HashMap, HashSet},
+	str::Chars,
+};
 
 /// all tags pointing to a single commit
 pub type CommitTags = Vec<String>;
@@ -18,6 +23,8 @@ pub struct TagWithMetadata {
 	pub time: i64,
 	///
 	pub message: String,
+	/// message of an annotated tag
+	pub annotation: Option<String>,
 	///
 	pub commit_id: CommitId,
 }
@@ -98,6 +105,18 @@ pub fn get_tags_with_metadata(
 		.map(|commit_info| (commit_info.id, commit_info))
 		.collect();
 
+	let repo = repo(repo_path)?;
+	let annotation = |name: &str| {
+		repo.revparse_single(&format!("refs/tags/{}", name))
+			.ok()
+			.and_then(|obj| {
+				obj.as_tag()
+					.and_then(git2::Tag::message)
+					.map(|msg| msg.trim().to_string())
+			})
+			.filter(|msg| !msg.is_empty())
+	};
+
 	let mut tags: Vec<TagWithMetadata> = tags_with_commit_id
 		.into_iter()
 		.filter_map(|(tag, commit_id)| {
@@ -107,6 +126,7 @@ pub fn get_tags_with_metadata(
 					author: commit_info.author.clone(),
 					time: commit_info.time,
 					message: commit_info.message.clone(),
+					annotation: annotation(tag),
 					commit_id: *commit_id,
 				}
 			})
@@ -118,6 +138,90 @@ pub fn get_tags_with_metadata(
 	Ok(tags)
 }
 
+/// leading digits of `chars` without leading zeros, consumes them
+fn take_number(chars: &mut Chars) -> String {
+	let digits = chars
+		.as_str()
+		.chars()
+		.take_while(char::is_ascii_digit)
+		.collect::<String>();
+
+	for _ in 0..digits.len() {
+		chars.next();
+	}
+
+	digits.trim_start_matches('0').to_string()
+}
+
+/// `v1.0` -> `1.0`
+fn strip_version_prefix(name: &str) -> &str {
+	name.strip_prefix('v')
+		.filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+		.unwrap_or(name)
+}
+
+/// orders tag names like version numbers (`v1.10` after `v1.9`,
+/// `v1.0-rc1` before `v1.0`) comparing runs of digits by value and
+/// everything else as text
+pub fn tag_version_cmp(a: &str, b: &str) -> Ordering {
+	let mut a = strip_version_prefix(a).chars();
+	let mut b = strip_version_prefix(b).chars();
+
+	loop {
+		let ordering = match (a.clone().next(), b.clone().next()) {
+			(None, None) => return Ordering::Equal,
+			// a pre-release suffix comes before the release
+			(None, Some(c)) => {
+				return if c == '-' {
+					Ordering::Greater
+				} else {
+					Ordering::Less
+				}
+			}
+			(Some(c), None) => {
+				return if c == '-' {
+					Ordering::Less
+				} else {
+					Ordering::Greater
+				}
+			}
+			(Some(ca), Some(cb))
+				if ca.is_ascii_digit() && cb.is_ascii_digit() =>
+			{
+				let na = take_number(&mut a);
+				let nb = take_number(&mut b);
+
+				na.len().cmp(&nb.len()).then_with(|| na.cmp(&nb))
+			}
+			(Some(ca), Some(cb)) => {
+				a.next();
+				b.next();
+
+				ca.cmp(&cb)
+			}
+		};
+
+		if ordering != Ordering::Equal {
+			return ordering;
+		}
+	}
+}
+
+/// nearest tag reachable from `HEAD` like `git describe --tags`,
+/// e.g. `v1.2.0-5-gabc1234` five commits past `v1.2.0`
+pub fn describe(repo_path: &str) -> Result<String> {
+	scope_time!("describe");
+
+	let repo = repo(repo_path)?;
+
+	let describe =
+		repo.describe(DescribeOptions::new().describe_tags())?;
+
+	Ok(describe.format(Some(
+		DescribeFormatOptions::new().abbreviated_size(7),
+	))?)
+}
+
 ///
 pub fn delete_tag(repo_path: &str, tag_name: &str) -> Result<()> {
 	scope_time!("delete_tag");
@@ -131,7 +235,7 @@ pub fn delete_tag(repo_path: &str, tag_name: &str) -> Result<()> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
 	use git2::ObjectType;
 
 	#[test]
@@ -174,6 +278,7 @@ mod tests {
 		assert_eq!(tags[1].name, "b");
 		assert_eq!(tags[1].message, "initial");
 		assert_eq!(tags[0].commit_id, tags[1].commit_id);
+		assert_eq!(tags[0].annotation, None);
 
 		delete_tag(repo_path, "a").unwrap();
 
@@ -187,4 +292,93 @@ mod tests {
 
 		assert_eq!(tags.len(), 0);
 	}
+
+	#[test]
+	fn test_describe() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert!(describe(repo_path).is_err());
+
+		let sig = repo.signature().unwrap();
+		let target = repo
+			.find_object(
+				repo.head().unwrap().target().unwrap(),
+				Some(ObjectType::Commit),
+			)
+			.unwrap();
+		repo.tag("v1.0", &target, &sig, "release", false).unwrap();
+
+		assert_eq!(describe(repo_path).unwrap(), "v1.0");
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+		let head = write_commit_file(&repo, "b.txt", "b", "c2");
+
+		assert_eq!(
+			describe(repo_path).unwrap(),
+			format!("v1.0-2-g{}", head.get_short_string())
+		);
+	}
+
+	#[test]
+	fn test_annotation() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let sig = repo.signature().unwrap();
+		let target = repo
+			.find_object(
+				repo.head().unwrap().target().unwrap(),
+				Some(ObjectType::Commit),
+			)
+			.unwrap();
+
+		repo.tag("a", &target, &sig, "release a\n", false).unwrap();
+		repo.tag_lightweight("b", &target, false).unwrap();
+
+		let tags = get_tags_with_metadata(repo_path).unwrap();
+		let annotation = |name: &str| {
+			tags.iter()
+				.find(|tag| tag.name == name)
+				.unwrap()
+				.annotation
+				.clone()
+		};
+
+		assert_eq!(annotation("a"), Some(String::from("release a")));
+		assert_eq!(annotation("b"), None);
+	}
+
+	#[test]
+	fn test_tag_version_cmp() {
+		let mut tags = vec![
+			"v1.10.0",
+			"v1.9.1",
+			"v1.9.0",
+			"v2.0.0",
+			"v1.9.0-rc1",
+			"1.0",
+			"nightly",
+			"v0.9",
+		];
+
+		tags.sort_by(|a, b| tag_version_cmp(a, b));
+
+		assert_eq!(
+			tags,
+			vec![
+				"v0.9",
+				"1.0",
+				"v1.9.0-rc1",
+				"v1.9.0",
+				"v1.9.1",
+				"v1.10.0",
+				"v2.0.0",
+				"nightly",
+			]
+		);
+		assert_eq!(tag_version_cmp("v01", "v1"), Ordering::Equal);
+	}
 }
@@ -5,14 +5,19 @@ use crate::{
 	error::{Error, Result},
 	sync::config::untracked_files_config_repo,
 };
-use git2::{IndexAddOption, Repository, RepositoryOpenFlags};
+use git2::{
+	ErrorCode, IndexAddOption, Oid, Repository, RepositoryOpenFlags,
+};
 use scopetime::scope_time;
 use std::{
-	fs::File,
+	collections::HashSet,
+	fs::{self, File},
 	io::Write,
 	path::{Path, PathBuf},
 };
 
+const ORIG_HEAD: &str = "ORIG_HEAD";
+
 ///
 #[derive(PartialEq, Debug, Clone)]
 pub struct Head {
@@ -43,6 +48,15 @@ pub fn is_bare_repo(repo_path: &str) -> Result<bool> {
 	Ok(repo.is_bare())
 }
 
+/// creates a new non-bare repo at `repo_path` (like `git init`)
+pub fn init_repo(repo_path: &str) -> Result<()> {
+	scope_time!("init_repo");
+
+	Repository::init(repo_path)?;
+
+	Ok(())
+}
+
 ///
 pub(crate) fn repo(repo_path: &str) -> Result<Repository> {
 	let repo = Repository::open_ext(
@@ -118,7 +132,13 @@ pub fn stage_add_file(repo_path: &str, path: &Path) -> Result<()> {
 
 	let mut index = repo.index()?;
 
-	index.add_path(path)?;
+	// untracked folders summarized by `ShowUntrackedFilesConfig::Normal`
+	// come as a single item ending in `/`
+	if path.to_str().map_or(false, |path| path.ends_with('/')) {
+		index.add_all(vec![path], IndexAddOption::DEFAULT, None)?;
+	} else {
+		index.add_path(path)?;
+	}
 	index.write()?;
 
 	Ok(())
@@ -142,6 +162,8 @@ pub fn stage_add_all(repo_path: &str, pattern: &str) -> Result<()> {
 			IndexAddOption::DEFAULT,
 			None,
 		)?;
+		// stage deletions as well, like `git add <folder>`
+		index.update_all(vec![pattern], None)?;
 	}
 
 	index.write()?;
@@ -154,6 +176,8 @@ pub fn undo_last_commit(repo_path: &str) -> Result<()> {
 	let repo = repo(repo_path)?;
 	let previous_commit = repo.revparse_single("HEAD~")?;
 
+	set_orig_head(&repo)?;
+
 	Repository::reset(
 		&repo,
 		&previous_commit,
@@ -164,6 +188,83 @@ pub fn undo_last_commit(repo_path: &str) -> Result<()> {
 	Ok(())
 }
 
+/// remember current `HEAD` as `ORIG_HEAD` like git does before
+/// moving `HEAD` around
+fn set_orig_head(repo: &Repository) -> Result<()> {
+	let head = get_head_repo(repo)?;
+
+	repo.reference(
+		ORIG_HEAD,
+		head.into(),
+		true,
+		"gitui: updating ORIG_HEAD",
+	)?;
+
+	Ok(())
+}
+
+/// commit `ORIG_HEAD` points to (set by reset, rebase and merge)
+pub fn orig_head(repo_path: &str) -> Result<Option<CommitId>> {
+	scope_time!("orig_head");
+
+	let repo = repo(repo_path)?;
+
+	let reference = match repo.find_reference(ORIG_HEAD) {
+		Ok(reference) => reference,
+		Err(e) if e.code() == ErrorCode::NotFound => return Ok(None),
+		Err(e) => return Err(e.into()),
+	};
+
+	let commit = reference.peel_to_commit()?;
+
+	Ok(Some(commit.id().into()))
+}
+
+/// commits of a shallow clone whose parents were not fetched, as
+/// listed in `.git/shallow` (empty if the repo is complete)
+pub fn shallow_boundaries(
+	repo_path: &str,
+) -> Result<HashSet<CommitId>> {
+	scope_time!("shallow_boundaries");
+
+	let repo = repo(repo_path)?;
+
+	let content =
+		match fs::read_to_string(repo.path().join("shallow")) {
+			Ok(content) => content,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+				return Ok(HashSet::new())
+			}
+			Err(e) => return Err(e.into()),
+		};
+
+	Ok(content
+		.lines()
+		.filter_map(|line| Oid::from_str(line.trim()).ok())
+		.map(CommitId::new)
+		.collect())
+}
+
+/// moves `HEAD` back to `id`, the `ORIG_HEAD` the user confirmed.
+/// like `git reset ORIG_HEAD` this is a mixed reset: the index
+/// follows `HEAD`, the workdir stays untouched
+pub fn reset_to_orig_head(
+	repo_path: &str,
+	id: CommitId,
+) -> Result<()> {
+	scope_time!("reset_to_orig_head");
+
+	let repo = repo(repo_path)?;
+
+	let target = repo.find_commit(id.into())?;
+
+	set_orig_head(&repo)?;
+
+	repo.reset(target.as_object(), git2::ResetType::Mixed, None)?;
+
+	Ok(())
+}
+
 /// stage a removed file
 pub fn stage_addremoved(repo_path: &str, path: &Path) -> Result<()> {
 	scope_time!("stage_addremoved");
@@ -178,6 +279,21 @@ pub fn stage_addremoved(repo_path: &str, path: &Path) -> Result<()> {
 	Ok(())
 }
 
+/// escapes the glob characters of `path` so a pathspec matches
+/// exactly that file
+pub(crate) fn literal_pathspec(path: &str) -> String {
+	let mut res = String::with_capacity(path.len());
+
+	for c in path.chars() {
+		if matches!(c, '*' | '?' | '[' | '\\') {
+			res.push('\\');
+		}
+		res.push(c);
+	}
+
+	res
+}
+
 pub(crate) fn bytes2string(bytes: &[u8]) -> Result<String> {
 	Ok(String::from_utf8(bytes.to_vec())?)
 }
@@ -221,7 +337,9 @@ mod tests {
 	use super::*;
 	use crate::sync::{
 		commit,
+		config::ShowUntrackedFilesConfig,
 		diff::get_diff,
+		reset_stage,
 		status::{get_status, StatusType},
 		tests::{
 			debug_cmd_print, get_statuses, repo_init,
@@ -299,6 +417,72 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_staging_summarized_folder() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		fs::create_dir_all(&root.join("a/d"))?;
+		File::create(&root.join(Path::new("a/d/f1.txt")))?
+			.write_all(b"foo")?;
+		File::create(&root.join(Path::new("a/d/f2.txt")))?
+			.write_all(b"foo")?;
+		File::create(&root.join(Path::new("f3.txt")))?
+			.write_all(b"foo")?;
+
+		let status = |s: StatusType, config| {
+			get_status(repo_path, s, Some(config))
+				.unwrap()
+				.into_iter()
+				.map(|item| item.path)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			status(
+				StatusType::WorkingDir,
+				ShowUntrackedFilesConfig::All
+			)
+			.len(),
+			3
+		);
+		assert_eq!(
+			status(
+				StatusType::WorkingDir,
+				ShowUntrackedFilesConfig::Normal
+			),
+			vec![String::from("a/"), String::from("f3.txt")]
+		);
+
+		stage_add_file(repo_path, Path::new("a/")).unwrap();
+
+		assert_eq!(
+			status(
+				StatusType::Stage,
+				ShowUntrackedFilesConfig::Normal
+			)
+			.len(),
+			2
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_init_repo() {
+		let td = tempfile::TempDir::new().unwrap();
+		let repo_path = td.path().to_str().unwrap();
+
+		assert!(!is_repo(repo_path));
+
+		init_repo(repo_path).unwrap();
+
+		assert!(is_repo(repo_path));
+		assert!(!is_bare_repo(repo_path).unwrap());
+		assert_eq!(get_statuses(repo_path), (0, 0));
+	}
+
 	#[test]
 	fn test_undo_commit_empty_repo() {
 		let (_td, repo) = repo_init().unwrap();
@@ -334,6 +518,36 @@ mod tests {
 		assert_eq!(&*diff.hunks[0].lines[0].content, "@@ -1 +1 @@");
 	}
 
+	#[test]
+	fn test_orig_head() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert_eq!(orig_head(repo_path).unwrap(), None);
+
+		let c1 =
+			write_commit_file(&repo, "test.txt", "content1", "c1");
+		let c2 =
+			write_commit_file(&repo, "test.txt", "content2", "c2");
+
+		undo_last_commit(repo_path).unwrap();
+
+		assert_eq!(orig_head(repo_path).unwrap(), Some(c2));
+
+		reset_to_orig_head(repo_path, c2).unwrap();
+
+		assert_eq!(get_head_repo(&repo).unwrap(), c2);
+		assert_eq!(get_statuses(repo_path), (0, 0));
+		assert_eq!(orig_head(repo_path).unwrap(), Some(c1));
+
+		// undoing the reset leaves its changes unstaged
+		reset_to_orig_head(repo_path, c1).unwrap();
+
+		assert_eq!(get_head_repo(&repo).unwrap(), c1);
+		assert_eq!(get_statuses(repo_path), (1, 0));
+	}
+
 	#[test]
 	fn test_not_staging_untracked_folder() -> Result<()> {
 		let (_td, repo) = repo_init().unwrap();
@@ -425,6 +639,36 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_staging_folder_changes() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		fs::create_dir_all(&root.join("a/b"))?;
+		write_commit_file(&repo, "a/modified.txt", "foo", "c1");
+		write_commit_file(&repo, "a/b/deleted.txt", "foo", "c2");
+		write_commit_file(&repo, "other.txt", "foo", "c3");
+
+		File::create(&root.join("a/modified.txt"))?
+			.write_all(b"bar")?;
+		File::create(&root.join("a/b/new.txt"))?.write_all(b"new")?;
+		remove_file(&root.join("a/b/deleted.txt"))?;
+		File::create(&root.join("other.txt"))?.write_all(b"bar")?;
+
+		assert_eq!(get_statuses(repo_path), (4, 0));
+
+		stage_add_all(repo_path, "a")?;
+
+		assert_eq!(get_statuses(repo_path), (1, 3));
+
+		reset_stage(repo_path, "a")?;
+
+		assert_eq!(get_statuses(repo_path), (4, 0));
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_head_empty() -> Result<()> {
 		let (_td, repo) = repo_init_empty()?;
@@ -446,4 +690,28 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_shallow_boundaries() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		assert!(shallow_boundaries(repo_path)?.is_empty());
+
+		let head = get_head(repo_path)?;
+		fs::write(
+			repo.path().join("shallow"),
+			format!("{}\n", head.to_string()),
+		)?;
+
+		assert_eq!(
+			shallow_boundaries(repo_path)?
+				.into_iter()
+				.collect::<Vec<_>>(),
+			vec![head]
+		);
+
+		Ok(())
+	}
 }
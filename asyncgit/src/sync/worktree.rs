@@ -0,0 +1,208 @@
+//! git worktrees
+
+use super::utils::repo;
+use crate::error::{Error, Result};
+use git2::{BranchType, ErrorCode, Repository, WorktreeAddOptions};
+use scopetime::scope_time;
+use std::path::{Path, PathBuf};
+
+/// a working tree of the repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorktreeInfo {
+	/// name of a linked worktree, `None` for the main one
+	pub name: Option<String>,
+	///
+	pub path: PathBuf,
+	/// checked out branch, `None` if detached or unreadable
+	pub branch: Option<String>,
+	/// true for the worktree `repo_path` belongs to
+	pub is_current: bool,
+}
+
+fn head_branch(repo: &Repository) -> Option<String> {
+	let head = repo.head().ok()?;
+
+	if head.is_branch() {
+		head.shorthand().map(String::from)
+	} else {
+		None
+	}
+}
+
+/// git dir shared by all worktrees, a linked worktree points to it
+/// in its `commondir` file
+fn common_dir(repo: &Repository) -> Result<PathBuf> {
+	let git_dir = repo.path();
+
+	if !repo.is_worktree() {
+		return Ok(git_dir.to_path_buf());
+	}
+
+	let common = std::fs::read_to_string(git_dir.join("commondir"))?;
+
+	Ok(git_dir.join(common.trim_end()))
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+	match (a.canonicalize(), b.canonicalize()) {
+		(Ok(a), Ok(b)) => a == b,
+		_ => a == b,
+	}
+}
+
+/// lists the main worktree (unless bare) followed by all linked
+/// worktrees of the repository
+pub fn get_worktrees(repo_path: &str) -> Result<Vec<WorktreeInfo>> {
+	scope_time!("get_worktrees");
+
+	let repo = repo(repo_path)?;
+	let current = repo.workdir().map(Path::to_path_buf);
+	let is_current = |path: &Path| {
+		current.as_ref().map_or(false, |current| {
+			same_path(current.as_path(), path)
+		})
+	};
+
+	let mut res = Vec::new();
+
+	let main = Repository::open(common_dir(&repo)?)?;
+	if let Some(path) = main.workdir() {
+		res.push(WorktreeInfo {
+			name: None,
+			path: path.to_path_buf(),
+			branch: head_branch(&main),
+			is_current: is_current(path),
+		});
+	}
+
+	for name in repo.worktrees()?.iter().flatten() {
+		let worktree = repo.find_worktree(name)?;
+		let branch = Repository::open_from_worktree(&worktree)
+			.ok()
+			.and_then(|repo| head_branch(&repo));
+
+		res.push(WorktreeInfo {
+			name: Some(name.to_string()),
+			path: worktree.path().to_path_buf(),
+			branch,
+			is_current: is_current(worktree.path()),
+		});
+	}
+
+	Ok(res)
+}
+
+/// creates a worktree called `name` at `path` with `branch` checked
+/// out, `branch` gets created from HEAD if it does not exist yet
+pub fn add_worktree(
+	repo_path: &str,
+	name: &str,
+	path: &Path,
+	branch: &str,
+) -> Result<()> {
+	scope_time!("add_worktree");
+
+	if path.exists() {
+		return Err(Error::Generic(format!(
+			"path already exists: {}",
+			path.display()
+		)));
+	}
+
+	if let Some(wt) = get_worktrees(repo_path)?
+		.into_iter()
+		.find(|wt| wt.branch.as_deref() == Some(branch))
+	{
+		return Err(Error::Generic(format!(
+			"branch '{}' is already checked out at {}",
+			branch,
+			wt.path.display()
+		)));
+	}
+
+	let repo = repo(repo_path)?;
+
+	let branch = match repo.find_branch(branch, BranchType::Local) {
+		Ok(branch) => branch,
+		Err(e) if e.code() == ErrorCode::NotFound => {
+			let head = repo.head()?.peel_to_commit()?;
+			repo.branch(branch, &head, false)?
+		}
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut opts = WorktreeAddOptions::new();
+	opts.reference(Some(branch.get()));
+
+	repo.worktree(name, path, Some(&opts))?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{add_worktree, get_worktrees};
+	use crate::sync::tests::repo_init;
+	use tempfile::TempDir;
+
+	#[test]
+	fn test_get_worktrees() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let worktrees = get_worktrees(repo_path).unwrap();
+
+		assert_eq!(worktrees.len(), 1);
+		assert_eq!(worktrees[0].name, None);
+		assert_eq!(worktrees[0].branch.as_deref(), Some("master"));
+		assert!(worktrees[0].is_current);
+
+		let td = TempDir::new().unwrap();
+		let wt_path = td.path().join("wt");
+		repo.worktree("wt", &wt_path, None).unwrap();
+
+		let worktrees = get_worktrees(repo_path).unwrap();
+
+		assert_eq!(worktrees.len(), 2);
+		assert_eq!(worktrees[1].name.as_deref(), Some("wt"));
+		assert_eq!(worktrees[1].branch.as_deref(), Some("wt"));
+		assert!(!worktrees[1].is_current);
+
+		let worktrees =
+			get_worktrees(wt_path.as_os_str().to_str().unwrap())
+				.unwrap();
+
+		assert_eq!(worktrees.len(), 2);
+		assert!(!worktrees[0].is_current);
+		assert!(worktrees[1].is_current);
+	}
+
+	#[test]
+	fn test_add_worktree() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path = root.as_os_str().to_str().unwrap();
+
+		let td = TempDir::new().unwrap();
+		let wt_path = td.path().join("feature");
+
+		// checked out in the main worktree
+		assert!(add_worktree(
+			repo_path, "feature", &wt_path, "master"
+		)
+		.is_err());
+
+		add_worktree(repo_path, "feature", &wt_path, "feature")
+			.unwrap();
+
+		let worktrees = get_worktrees(repo_path).unwrap();
+
+		assert_eq!(worktrees.len(), 2);
+		assert_eq!(worktrees[1].branch.as_deref(), Some("feature"));
+
+		// path exists now
+		assert!(add_worktree(repo_path, "other", &wt_path, "other")
+			.is_err());
+	}
+}
@@ -2,22 +2,33 @@ use crate::{
 	accessors,
 	cmdbar::CommandBar,
 	components::{
-		event_pump, AppOption, BlameFileComponent,
-		BranchListComponent, CommandBlocking, CommandInfo,
-		CommitComponent, CompareCommitsComponent, Component,
-		ConfirmComponent, CreateBranchComponent, DrawableComponent,
-		ExternalEditorComponent, FileFindPopup, HelpComponent,
-		InspectCommitComponent, MsgComponent, OptionsPopupComponent,
-		PullComponent, PushComponent, PushTagsComponent,
-		RenameBranchComponent, RevisionFilesPopup, SharedOptions,
-		StashMsgComponent, TagCommitComponent, TagListComponent,
+		event_pump, AppOption, ApplyPatchComponent, BisectPopup,
+		BlameFileComponent, BranchListComponent,
+		BranchesContainingPopup, ChangeSetComponent, CoAuthorsPopup,
+		CommandBlocking, CommandInfo, CommandPalettePopup,
+		CommitComponent, CompareBranchesComponent,
+		CompareCommitsComponent, Component, ConfirmComponent,
+		CreateBranchComponent, CreateWorktreeComponent,
+		DiffSearchComponent, DrawableComponent, ExportPatchComponent,
+		ExternalEditorComponent, FileFindPopup, GotoLineComponent,
+		HelpComponent, InspectCommitComponent, LogFilterComponent,
+		MergeBranchPopup, MsgComponent, Options,
+		OptionsPopupComponent, PullComponent, PushComponent,
+		PushSummaryPopup, PushTagsComponent, RecentReposPopup,
+		RemoteEditComponent, RemotesComponent, RenameBranchComponent,
+		RevisionFilesPopup, SetUpstreamComponent, SharedOptions,
+		StashMsgComponent, StatusFilterComponent,
+		SubmodulesListComponent, TagCommitComponent,
+		TagListComponent, WorktreesComponent,
 	},
 	input::{Input, InputEvent, InputState},
 	keys::{KeyConfig, SharedKeyConfig},
 	queue::{Action, InternalEvent, NeedsUpdate, Queue},
-	setup_popups,
+	recent_repos, setup_popups,
+	statusbar::StatusBar,
 	strings::{self, order},
 	tabs::{FilesTab, Revlog, StashList, Stashing, Status},
+	try_or_popup,
 	ui::style::{SharedTheme, Theme},
 	AsyncAppNotification, AsyncNotification,
 };
@@ -27,6 +38,7 @@ use crossbeam_channel::Sender;
 use crossterm::event::{Event, KeyEvent};
 use std::{
 	cell::{Cell, RefCell},
+	env,
 	path::Path,
 	rc::Rc,
 };
@@ -41,10 +53,12 @@ use tui::{
 /// the main app type
 pub struct App {
 	do_quit: bool,
+	repo_switched: bool,
 	help: HelpComponent,
 	msg: MsgComponent,
 	reset: ConfirmComponent,
 	commit: CommitComponent,
+	co_authors_popup: CoAuthorsPopup,
 	blame_file_popup: BlameFileComponent,
 	stashmsg_popup: StashMsgComponent,
 	inspect_commit_popup: InspectCommitComponent,
@@ -52,16 +66,37 @@ pub struct App {
 	external_editor_popup: ExternalEditorComponent,
 	revision_files_popup: RevisionFilesPopup,
 	find_file_popup: FileFindPopup,
+	command_palette_popup: CommandPalettePopup,
 	push_popup: PushComponent,
+	push_summary_popup: PushSummaryPopup,
 	push_tags_popup: PushTagsComponent,
 	pull_popup: PullComponent,
 	tag_commit_popup: TagCommitComponent,
+	change_set_popup: ChangeSetComponent,
+	diff_search_popup: DiffSearchComponent,
+	goto_line_popup: GotoLineComponent,
+	export_patch_popup: ExportPatchComponent,
+	apply_patch_popup: ApplyPatchComponent,
+	status_filter_popup: StatusFilterComponent,
+	log_filter_popup: LogFilterComponent,
 	create_branch_popup: CreateBranchComponent,
 	rename_branch_popup: RenameBranchComponent,
+	set_upstream_popup: SetUpstreamComponent,
+	merge_branch_popup: MergeBranchPopup,
+	branches_containing_popup: BranchesContainingPopup,
 	select_branch_popup: BranchListComponent,
+	compare_branches_popup: CompareBranchesComponent,
+	bisect_popup: BisectPopup,
 	options_popup: OptionsPopupComponent,
 	tags_popup: TagListComponent,
+	worktrees_popup: WorktreesComponent,
+	remotes_popup: RemotesComponent,
+	remote_edit_popup: RemoteEditComponent,
+	recent_repos_popup: RecentReposPopup,
+	create_worktree_popup: CreateWorktreeComponent,
+	submodules_popup: SubmodulesListComponent,
 	cmdbar: RefCell<CommandBar>,
+	status_bar: StatusBar,
 	tab: usize,
 	revlog: Revlog,
 	status_tab: Status,
@@ -72,10 +107,12 @@ pub struct App {
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	input: Input,
+	/// `--read-only`, the keys of mutating commands are unbound
+	read_only: bool,
 
 	// "Flags"
 	requires_redraw: Cell<bool>,
-	file_to_open: Option<String>,
+	file_to_open: Option<(String, Option<u32>)>,
 }
 
 // public interface
@@ -88,11 +125,19 @@ impl App {
 		input: Input,
 		theme: Theme,
 		key_config: KeyConfig,
+		options: Options,
 	) -> Self {
 		let queue = Queue::new();
+
+		if let Err(e) = key_config.validate() {
+			log::error!("{}", e);
+			queue.push(InternalEvent::ShowErrorMsg(e.to_string()));
+		}
+
 		let theme = Rc::new(theme);
 		let key_config = Rc::new(key_config);
-		let options = SharedOptions::default();
+		let read_only = options.read_only;
+		let options: SharedOptions = Rc::new(RefCell::new(options));
 
 		Self {
 			input,
@@ -105,6 +150,12 @@ impl App {
 				queue.clone(),
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
+			),
+			co_authors_popup: CoAuthorsPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
 			),
 			blame_file_popup: BlameFileComponent::new(
 				&queue,
@@ -118,6 +169,7 @@ impl App {
 				sender_app,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			stashmsg_popup: StashMsgComponent::new(
 				queue.clone(),
@@ -129,12 +181,14 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			compare_commits_popup: CompareCommitsComponent::new(
 				&queue,
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			external_editor_popup: ExternalEditorComponent::new(
 				theme.clone(),
@@ -145,6 +199,12 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
+			),
+			push_summary_popup: PushSummaryPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
 			),
 			push_tags_popup: PushTagsComponent::new(
 				&queue,
@@ -157,12 +217,48 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			tag_commit_popup: TagCommitComponent::new(
 				queue.clone(),
 				theme.clone(),
 				key_config.clone(),
 			),
+			change_set_popup: ChangeSetComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			diff_search_popup: DiffSearchComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			goto_line_popup: GotoLineComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			export_patch_popup: ExportPatchComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			apply_patch_popup: ApplyPatchComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			status_filter_popup: StatusFilterComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			log_filter_popup: LogFilterComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
 			create_branch_popup: CreateBranchComponent::new(
 				queue.clone(),
 				theme.clone(),
@@ -173,8 +269,34 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			set_upstream_popup: SetUpstreamComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			branches_containing_popup: BranchesContainingPopup::new(
+				theme.clone(),
+				key_config.clone(),
+			),
+			merge_branch_popup: MergeBranchPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
 			select_branch_popup: BranchListComponent::new(
 				queue.clone(),
+				sender,
+				theme.clone(),
+				key_config.clone(),
+				options.clone(),
+			),
+			compare_branches_popup: CompareBranchesComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			bisect_popup: BisectPopup::new(
+				&queue,
 				theme.clone(),
 				key_config.clone(),
 			),
@@ -184,6 +306,37 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			worktrees_popup: WorktreesComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			remotes_popup: RemotesComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			remote_edit_popup: RemoteEditComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			recent_repos_popup: RecentReposPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			create_worktree_popup: CreateWorktreeComponent::new(
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			submodules_popup: SubmodulesListComponent::new(
+				&queue,
+				sender,
+				theme.clone(),
+				key_config.clone(),
+			),
 			options_popup: OptionsPopupComponent::new(
 				&queue,
 				theme.clone(),
@@ -195,7 +348,13 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			command_palette_popup: CommandPalettePopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
 			do_quit: false,
+			repo_switched: false,
 			cmdbar: RefCell::new(CommandBar::new(
 				theme.clone(),
 				key_config.clone(),
@@ -211,13 +370,14 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			status_tab: Status::new(
 				&queue,
 				sender,
 				theme.clone(),
 				key_config.clone(),
-				options,
+				options.clone(),
 			),
 			stashing_tab: Stashing::new(
 				sender,
@@ -229,23 +389,31 @@ impl App {
 				&queue,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			files_tab: FilesTab::new(
 				sender_app,
 				&queue,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
+			status_bar: StatusBar::new(theme.clone(), options),
 			queue,
 			theme,
 			key_config,
+			read_only,
 			requires_redraw: Cell::new(false),
 			file_to_open: None,
 		}
 	}
 
-	///
-	pub fn draw<B: Backend>(&self, f: &mut Frame<B>) -> Result<()> {
+	/// `spinner` is the char to draw if the status bar has the spinner
+	pub fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		spinner: char,
+	) -> Result<()> {
 		let fsize = f.size();
 
 		self.cmdbar.borrow_mut().refresh_width(fsize.width);
@@ -256,13 +424,17 @@ impl App {
 				[
 					Constraint::Length(2),
 					Constraint::Min(2),
+					Constraint::Length(
+						self.status_bar.is_visible().into(),
+					),
 					Constraint::Length(self.cmdbar.borrow().height()),
 				]
 				.as_ref(),
 			)
 			.split(fsize);
 
-		self.cmdbar.borrow().draw(f, chunks_main[2]);
+		self.status_bar.draw(f, chunks_main[2], spinner);
+		self.cmdbar.borrow().draw(f, chunks_main[3]);
 
 		self.draw_tabs(f, chunks_main[0]);
 
@@ -317,6 +489,24 @@ impl App {
 				} else if k == self.key_config.open_options {
 					self.options_popup.show()?;
 					NeedsUpdate::ALL
+				} else if k == self.key_config.open_worktrees {
+					self.worktrees_popup.open()?;
+					NeedsUpdate::ALL
+				} else if k == self.key_config.open_remotes {
+					self.remotes_popup.open()?;
+					NeedsUpdate::ALL
+				} else if k == self.key_config.open_recent_repos {
+					self.recent_repos_popup.open()?;
+					NeedsUpdate::ALL
+				} else if k == self.key_config.open_submodules {
+					self.submodules_popup.open()?;
+					NeedsUpdate::ALL
+				} else if k == self.key_config.open_command_palette {
+					self.command_palette_popup
+						.open(self.commands(false))?;
+					NeedsUpdate::COMMANDS
+				} else if k == self.key_config.refresh {
+					NeedsUpdate::ALL
 				} else {
 					NeedsUpdate::empty()
 				};
@@ -329,9 +519,10 @@ impl App {
 			self.external_editor_popup.hide();
 			if let InputState::Paused = polling_state {
 				let result = match self.file_to_open.take() {
-					Some(path) => {
+					Some((path, line)) => {
 						ExternalEditorComponent::open_file_in_editor(
 							Path::new(&path),
+							line,
 						)
 					}
 					None => self.commit.show_editor(),
@@ -363,6 +554,7 @@ impl App {
 		self.files_tab.update()?;
 		self.stashing_tab.update()?;
 		self.stashlist_tab.update()?;
+		self.status_bar.update();
 
 		self.update_commands();
 
@@ -392,6 +584,7 @@ impl App {
 		self.files_tab.update_async(ev);
 		self.revision_files_popup.update(ev);
 		self.tags_popup.update(ev);
+		self.submodules_popup.update(ev)?;
 
 		//TODO: better system for this
 		// can we simply process the queue here and everyone just uses the queue to schedule a cmd update?
@@ -405,6 +598,17 @@ impl App {
 		self.do_quit || self.input.is_aborted()
 	}
 
+	/// true once after the working directory moved to another
+	/// repo or worktree
+	pub fn take_repo_switched(&mut self) -> bool {
+		std::mem::take(&mut self.repo_switched)
+	}
+
+	/// the status bar takes the spinner over if it shows `{pending}`
+	pub fn spinner_position(&self) -> (u16, u16) {
+		self.status_bar.spinner_position().unwrap_or((0, 0))
+	}
+
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.status_tab.anything_pending()
@@ -416,10 +620,12 @@ impl App {
 			|| self.compare_commits_popup.any_work_pending()
 			|| self.input.is_state_changing()
 			|| self.push_popup.any_work_pending()
+			|| self.submodules_popup.any_work_pending()
 			|| self.push_tags_popup.any_work_pending()
 			|| self.pull_popup.any_work_pending()
 			|| self.revision_files_popup.any_work_pending()
 			|| self.tags_popup.any_work_pending()
+			|| self.select_branch_popup.any_work_pending()
 	}
 
 	///
@@ -439,23 +645,44 @@ impl App {
 		self,
 		[
 			find_file_popup,
+			command_palette_popup,
 			msg,
 			reset,
+			co_authors_popup,
 			commit,
 			blame_file_popup,
 			stashmsg_popup,
+			export_patch_popup,
+			apply_patch_popup,
+			status_filter_popup,
+			branches_containing_popup,
 			inspect_commit_popup,
 			compare_commits_popup,
+			push_summary_popup,
 			external_editor_popup,
 			push_popup,
 			push_tags_popup,
 			pull_popup,
 			tag_commit_popup,
+			change_set_popup,
+			diff_search_popup,
+			goto_line_popup,
+			log_filter_popup,
 			create_branch_popup,
 			rename_branch_popup,
+			set_upstream_popup,
+			merge_branch_popup,
 			select_branch_popup,
+			compare_branches_popup,
+			bisect_popup,
 			revision_files_popup,
 			tags_popup,
+			worktrees_popup,
+			remote_edit_popup,
+			remotes_popup,
+			recent_repos_popup,
+			create_worktree_popup,
+			submodules_popup,
 			options_popup,
 			help,
 			revlog,
@@ -470,19 +697,40 @@ impl App {
 		self,
 		[
 			commit,
+			co_authors_popup,
 			stashmsg_popup,
 			help,
 			inspect_commit_popup,
+			branches_containing_popup,
+			push_summary_popup,
 			compare_commits_popup,
 			blame_file_popup,
 			external_editor_popup,
 			tag_commit_popup,
+			change_set_popup,
+			diff_search_popup,
+			export_patch_popup,
+			apply_patch_popup,
+			status_filter_popup,
+			log_filter_popup,
 			select_branch_popup,
+			compare_branches_popup,
+			bisect_popup,
 			tags_popup,
+			worktrees_popup,
+			remotes_popup,
+			remote_edit_popup,
+			recent_repos_popup,
+			create_worktree_popup,
+			submodules_popup,
 			create_branch_popup,
 			rename_branch_popup,
+			set_upstream_popup,
+			merge_branch_popup,
 			revision_files_popup,
+			goto_line_popup,
 			find_file_popup,
+			command_palette_popup,
 			push_popup,
 			push_tags_popup,
 			pull_popup,
@@ -634,6 +882,14 @@ impl App {
 			}
 			InternalEvent::Update(u) => flags.insert(u),
 			InternalEvent::OpenCommit => self.commit.show()?,
+			InternalEvent::AmendCommit => self.commit.show_amend()?,
+			InternalEvent::RewordHead => {
+				try_or_popup!(
+					self,
+					"reword error:",
+					self.reword_head()
+				);
+			}
 			InternalEvent::PopupStashing(opts) => {
 				self.stashmsg_popup.options(opts);
 				self.stashmsg_popup.show()?;
@@ -641,24 +897,127 @@ impl App {
 			InternalEvent::TagCommit(id) => {
 				self.tag_commit_popup.open(id)?;
 			}
+			InternalEvent::OpenChangeSet(path, name, assigned) => {
+				self.change_set_popup.open(path, name, assigned)?;
+			}
+			InternalEvent::AssignChangeSet(path, name) => {
+				self.status_tab.assign_change_set(&path, &name);
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::OpenDiffSearch(query, case_sensitive) => {
+				self.diff_search_popup.open(query, case_sensitive)?;
+			}
+			InternalEvent::OpenGotoLine => {
+				self.goto_line_popup.open()?;
+			}
+			InternalEvent::GotoLine(line) => {
+				if self.revision_files_popup.is_visible() {
+					self.revision_files_popup.goto_line(line);
+				} else {
+					self.files_tab.goto_line(line);
+				}
+			}
+			InternalEvent::OpenLogFilter(kind, value) => {
+				self.log_filter_popup.open(kind, value)?;
+			}
+			InternalEvent::FilterLog(kind, value) => {
+				self.revlog.set_filter(kind, value)?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			InternalEvent::SearchDiff(query, case_sensitive) => {
+				if self.inspect_commit_popup.is_visible() {
+					self.inspect_commit_popup
+						.search_diff(query, case_sensitive);
+				} else if self.compare_commits_popup.is_visible() {
+					self.compare_commits_popup
+						.search_diff(query, case_sensitive);
+				} else {
+					self.status_tab
+						.search_diff(query, case_sensitive);
+				}
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::SelectDiffFile(forward) => {
+				self.status_tab.select_diff_file(forward);
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
 			InternalEvent::BlameFile(path) => {
 				self.blame_file_popup.open(&path)?;
 				flags
 					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
 			}
-			InternalEvent::CreateBranch => {
-				self.create_branch_popup.open()?;
+			InternalEvent::CreateBranch(start) => {
+				self.create_branch_popup.open(start)?;
 			}
 			InternalEvent::RenameBranch(branch_ref, cur_name) => {
 				self.rename_branch_popup
 					.open(branch_ref, cur_name)?;
 			}
+			InternalEvent::SetUpstream(branch, upstream) => {
+				self.set_upstream_popup.open(branch, upstream)?;
+			}
+			InternalEvent::MergeBranch(branch) => {
+				self.merge_branch_popup.open(branch)?;
+			}
 			InternalEvent::SelectBranch => {
 				self.select_branch_popup.open()?;
 			}
 			InternalEvent::Tags => {
 				self.tags_popup.open()?;
 			}
+			InternalEvent::OpenCreateWorktree(branch) => {
+				self.create_worktree_popup.open(branch)?;
+			}
+			InternalEvent::OpenRemotes => {
+				self.remotes_popup.open()?;
+			}
+			InternalEvent::OpenCoAuthors => {
+				self.co_authors_popup.open()?;
+			}
+			InternalEvent::AddCoAuthor(name, email) => {
+				self.commit.add_co_author(&name, &email);
+			}
+			InternalEvent::EditRemote(edit) => {
+				self.remote_edit_popup.open(edit)?;
+			}
+			InternalEvent::OpenExportPatch(source) => {
+				self.export_patch_popup.open(source)?;
+			}
+			InternalEvent::OpenApplyPatch => {
+				self.apply_patch_popup.open()?;
+			}
+			InternalEvent::OpenStatusFilter(filter) => {
+				self.status_filter_popup.open(filter)?;
+			}
+			InternalEvent::FilterStatus(filter) => {
+				self.status_tab.set_filter(filter)?;
+				flags.insert(
+					NeedsUpdate::DIFF | NeedsUpdate::COMMANDS,
+				);
+			}
+			InternalEvent::SwitchWorktree(path) => {
+				self.switch_repo(&path)?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::SwitchRepo(path) => {
+				self.switch_repo(&path)?;
+				if let Err(e) = recent_repos::add_current_repo() {
+					log::error!("recent repos: {}", e);
+				}
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::BranchesContaining(id) => {
+				self.branches_containing_popup.open(id)?;
+			}
+			InternalEvent::OpenCommitInBrowser(id) => {
+				try_or_popup!(
+					self,
+					"open in browser error:",
+					Self::open_commit_in_browser(id)
+				);
+			}
 			InternalEvent::TabSwitch => self.set_tab(0)?,
 			InternalEvent::InspectCommit(id, tags) => {
 				self.inspect_commit_popup.open(id, tags)?;
@@ -685,6 +1044,10 @@ impl App {
 				self.push_popup.push(branch, force, delete)?;
 				flags.insert(NeedsUpdate::ALL);
 			}
+			InternalEvent::OpenPushSummary(branch) => {
+				self.push_summary_popup.open(branch)?;
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
 			InternalEvent::Pull(branch) => {
 				if let Err(error) = self.pull_popup.fetch(branch) {
 					self.queue.push(InternalEvent::ShowErrorMsg(
@@ -712,7 +1075,8 @@ impl App {
 			}
 			InternalEvent::OptionSwitched(o) => {
 				match o {
-					AppOption::StatusShowUntracked => {
+					AppOption::StatusShowUntracked
+					| AppOption::StatusLargeFiles => {
 						self.status_tab.update()?;
 					}
 					AppOption::DiffContextLines
@@ -720,10 +1084,37 @@ impl App {
 					| AppOption::DiffInterhunkLines => {
 						self.status_tab.update_diff()?;
 					}
+					AppOption::StatusSplit
+					| AppOption::StatusStageOnTop
+					| AppOption::DiffTabWidth
+					| AppOption::DiffFileWrap
+					| AppOption::DiffMaxLines
+					| AppOption::CommitSignOff
+					| AppOption::CommitConfirmLargeFiles
+					| AppOption::CommitWrapBody
+					| AppOption::CommitSuggestAmend
+					| AppOption::FilesSyntaxHighlighting
+					| AppOption::FilesSyntaxMaxSize
+					| AppOption::LogRelativeTime
+					| AppOption::RemoteNotifyAfter
+					| AppOption::GeneralReadOnly => (),
+					AppOption::GeneralStatusBar => {
+						self.status_bar.update();
+					}
 				}
 
 				flags.insert(NeedsUpdate::ALL);
 			}
+			InternalEvent::CompareBranches(reference) => {
+				self.compare_branches_popup.open(&reference)?;
+			}
+			InternalEvent::RunKey(key) => {
+				self.event(InputEvent::Input(Event::Key(key)))?;
+			}
+			InternalEvent::OpenBisect(id) => {
+				self.bisect_popup.open(id)?;
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
 			InternalEvent::CompareCommits(id, other) => {
 				self.compare_commits_popup.open(id, other)?;
 				flags
@@ -813,19 +1204,153 @@ impl App {
 				self.queue
 					.push(InternalEvent::Push(branch, force, false));
 			}
+			Action::ForcePushWithLease(branch) => {
+				self.push_popup.push_with_lease(branch)?;
+				flags.insert(NeedsUpdate::ALL);
+			}
 			Action::PullMerge { rebase, .. } => {
 				self.pull_popup.try_conflict_free_merge(rebase);
 				flags.insert(NeedsUpdate::ALL);
 			}
-			Action::AbortMerge => {
-				self.status_tab.abort_merge();
+			Action::AbortPendingOp(state) => {
+				self.status_tab.abort_pending_op(state);
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::ResetToOrigHead(id) => {
+				self.status_tab.reset_to_orig_head(id);
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::RewordMergeCommit => {
+				self.commit.reword()?;
+			}
+			Action::CommitLargeFiles(_) => {
+				self.commit.show()?;
+			}
+			Action::IgnoreFolder(path) => {
+				if let Err(error) = sync::add_to_ignore(CWD, &path) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						error.to_string(),
+					));
+				}
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::ResetWorkdirAll(_) => {
+				if let Err(error) = sync::reset_workdir_all(CWD) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						error.to_string(),
+					));
+				}
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::ResetFiles(paths) => {
+				let failed = paths
+					.iter()
+					.filter_map(|path| {
+						sync::reset_workdir(CWD, path).err().map(
+							|error| format!("{}: {}", path, error),
+						)
+					})
+					.collect::<Vec<_>>();
+				if !failed.is_empty() {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						strings::reset_files_failed(&failed),
+					));
+				}
 				flags.insert(NeedsUpdate::ALL);
 			}
+			Action::DeleteRemote(name) => {
+				if let Err(error) = sync::remove_remote(CWD, &name) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						error.to_string(),
+					));
+				} else {
+					flags.insert(NeedsUpdate::ALL);
+					self.remotes_popup.update_remotes()?;
+				}
+			}
+			Action::RestoreFile(id, path) => {
+				if let Err(error) = sync::checkout_file(
+					CWD,
+					&id.to_string(),
+					&path,
+					false,
+				) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						error.to_string(),
+					));
+				}
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::CheckoutCommit(id) => {
+				if let Err(error) = sync::checkout_commit(CWD, id) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						error.to_string(),
+					));
+				}
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::CheckoutBranchStashed(branch_ref, _) => {
+				if let Err(error) =
+					sync::checkout_branch_stashed(CWD, &branch_ref)
+				{
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						error.to_string(),
+					));
+				} else {
+					self.select_branch_popup.hide();
+				}
+				// the changes may have been left in the stash
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::BRANCHES);
+			}
 		};
 
 		Ok(())
 	}
 
+	/// nothing that belongs to the previous repo may stick around
+	fn switch_repo(&mut self, path: &Path) -> Result<()> {
+		env::set_current_dir(path)?;
+		self.repo_switched = true;
+
+		if let Err(e) = self.options_popup.reload() {
+			self.queue.push(InternalEvent::ShowErrorMsg(format!(
+				"options loading error:\n{}",
+				e
+			)));
+		}
+
+		self.status_tab.reset_repo_state()?;
+		self.revlog.reset_repo_state()?;
+		self.commit.reset_repo_state();
+		self.status_bar.reset_repo_state();
+
+		Ok(())
+	}
+
+	/// asks first if `HEAD` is a merge commit
+	fn reword_head(&mut self) -> Result<()> {
+		if sync::is_head_merge_commit(CWD)? {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::RewordMergeCommit,
+			));
+		} else {
+			self.commit.reword()?;
+		}
+
+		Ok(())
+	}
+
+	fn open_commit_in_browser(id: sync::CommitId) -> Result<()> {
+		let url = sync::commit_web_url(CWD, id)?;
+
+		if !open::that(&url)?.success() {
+			bail!("could not open: {}", url);
+		}
+
+		Ok(())
+	}
+
 	fn commands(&self, force_all: bool) -> Vec<CommandInfo> {
 		let mut res = Vec::new();
 
@@ -873,6 +1398,58 @@ impl App {
 			)
 			.order(order::NAV),
 		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::open_command_palette(
+					&self.key_config,
+				),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV),
+		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::open_worktrees(&self.key_config),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV),
+		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::open_remotes(&self.key_config),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV),
+		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::open_recent_repos(
+					&self.key_config,
+				),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV),
+		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::refresh(&self.key_config),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV),
+		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::open_submodules(&self.key_config),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV),
+		);
 
 		res.push(
 			CommandInfo::new(
@@ -883,6 +1460,14 @@ impl App {
 			.order(100),
 		);
 
+		if self.read_only {
+			for cmd in &mut res {
+				if cmd.text.unbound {
+					cmd.enabled = false;
+				}
+			}
+		}
+
 		res
 	}
 
@@ -1,5 +1,6 @@
-use crate::bug_report;
+use crate::{bug_report, json_export::LOG_COUNT_DEFAULT};
 use anyhow::{anyhow, Result};
+use asyncgit::{sync::repo_dir, CWD};
 use clap::{
 	crate_authors, crate_description, crate_name, crate_version,
 	App as ClapApp, Arg,
@@ -13,6 +14,17 @@ use std::{
 
 pub struct CliArgs {
 	pub theme: PathBuf,
+	pub check_clean: bool,
+	/// path to explain the ignore state of (no ui)
+	pub check_ignore: Option<String>,
+	/// print the status as json (no ui)
+	pub status_json: bool,
+	/// number of commits to print as json (no ui)
+	pub log_json: Option<usize>,
+	/// use the built in vim key preset instead of `key_config.ron`
+	pub vim_keys: bool,
+	/// disable all commands changing the repo
+	pub read_only: bool,
 }
 
 pub fn process_cmdline() -> Result<CliArgs> {
@@ -39,6 +51,44 @@ pub fn process_cmdline() -> Result<CliArgs> {
 				.help("Generate a bug report")
 				.long("bugreport"),
 		)
+		.arg(
+			Arg::with_name("check-clean")
+				.help("Exit with 0 if working tree and index are clean (no ui)")
+				.long("check-clean"),
+		)
+		.arg(
+			Arg::with_name("check-ignore")
+				.help("Print the ignore rule matching PATH, exit with 0 if it is ignored (no ui)")
+				.long("check-ignore")
+				.value_name("PATH")
+				.takes_value(true),
+		)
+		.arg(
+			Arg::with_name("status-json")
+				.help("Print the staged and unstaged files as json (no ui)")
+				.long("status-json"),
+		)
+		.arg(
+			Arg::with_name("log-json")
+				.help("Print the last COUNT commits (default 100) as json (no ui)")
+				.long("log-json")
+				.value_name("COUNT")
+				.takes_value(true)
+				.min_values(0),
+		)
+		.arg(
+			Arg::with_name("keys")
+				.help("Use a built in key preset instead of key_config.ron")
+				.long("keys")
+				.value_name("PRESET")
+				.possible_values(&["default", "vim"])
+				.takes_value(true),
+		)
+		.arg(
+			Arg::with_name("read-only")
+				.help("Disable all commands changing the repo (stage, commit, push, ..)")
+				.long("read-only"),
+		)
 		.arg(
 			Arg::with_name("directory")
 				.help("Set the working directory")
@@ -60,15 +110,45 @@ pub fn process_cmdline() -> Result<CliArgs> {
 			arg_matches.value_of("directory").unwrap_or(".");
 		env::set_current_dir(directory)?;
 	}
+	let check_clean = arg_matches.is_present("check-clean");
+	let check_ignore =
+		arg_matches.value_of("check-ignore").map(String::from);
+	let status_json = arg_matches.is_present("status-json");
+	let log_json = if arg_matches.is_present("log-json") {
+		Some(arg_matches.value_of("log-json").map_or(
+			Ok(LOG_COUNT_DEFAULT),
+			|count| {
+				count.parse::<usize>().map_err(|_| {
+					anyhow!("invalid --log-json count: {}", count)
+				})
+			},
+		)?)
+	} else {
+		None
+	};
+	let vim_keys = arg_matches.value_of("keys") == Some("vim");
+	let read_only = arg_matches.is_present("read-only");
 	let arg_theme =
 		arg_matches.value_of("theme").unwrap_or("theme.ron");
 	if get_app_config_path()?.join(arg_theme).is_file() {
 		Ok(CliArgs {
 			theme: get_app_config_path()?.join(arg_theme),
+			check_clean,
+			check_ignore,
+			status_json,
+			log_json,
+			vim_keys,
+			read_only,
 		})
 	} else {
 		Ok(CliArgs {
 			theme: get_app_config_path()?.join("theme.ron"),
+			check_clean,
+			check_ignore,
+			status_json,
+			log_json,
+			vim_keys,
+			read_only,
 		})
 	}
 }
@@ -95,6 +175,14 @@ fn get_app_cache_path() -> Result<PathBuf> {
 	Ok(path)
 }
 
+/// `.git/gitui` of the current repo, if it exists, holding config
+/// files that override the global ones
+pub fn get_repo_config_path() -> Option<PathBuf> {
+	let path = repo_dir(CWD).ok()?.join("gitui");
+
+	path.is_dir().then(|| path)
+}
+
 pub fn get_app_config_path() -> Result<PathBuf> {
 	let mut path = if cfg!(target_os = "macos") {
 		dirs_next::home_dir().map(|h| h.join(".config"))
@@ -0,0 +1,178 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, utils::repo_work_dir},
+	CWD,
+};
+use crossterm::event::Event;
+use std::path::{Path, MAIN_SEPARATOR};
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct ApplyPatchComponent {
+	input: TextInputComponent,
+	to_index: bool,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for ApplyPatchComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for ApplyPatchComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::apply_patch_confirm(
+					&self.key_config,
+				),
+				!self.input.get_text().trim().is_empty(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::apply_patch_target(
+					&self.key_config,
+					self.to_index,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(e) = ev {
+				if e == self.key_config.apply_patch_target {
+					self.to_index = !self.to_index;
+					self.update_title();
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter
+					&& !self.input.get_text().trim().is_empty()
+				{
+					self.apply();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl ApplyPatchComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::apply_patch_popup_title(false),
+				&strings::apply_patch_popup_msg(&key_config),
+				true,
+			),
+			to_index: false,
+			key_config,
+		}
+	}
+
+	/// asks for the patch file, prefilled with the repo root
+	pub fn open(&mut self) -> Result<()> {
+		self.to_index = false;
+		self.update_title();
+		self.input.set_text(
+			repo_work_dir(CWD)
+				.map(|dir| {
+					format!(
+						"{}{}",
+						dir.trim_end_matches(MAIN_SEPARATOR),
+						MAIN_SEPARATOR
+					)
+				})
+				.unwrap_or_default(),
+		);
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_title(&mut self) {
+		self.input.set_title(strings::apply_patch_popup_title(
+			self.to_index,
+		));
+	}
+
+	fn apply(&mut self) {
+		let path = self.input.get_text().trim().to_string();
+
+		self.input.clear();
+		self.hide();
+
+		match sync::apply_patch(CWD, Path::new(&path), self.to_index)
+		{
+			Ok(_) => {
+				self.queue
+					.push(InternalEvent::Update(NeedsUpdate::ALL));
+			}
+			Err(e) => {
+				log::error!("apply patch: {}", e,);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("apply patch error:\n{}", e,),
+				));
+			}
+		}
+	}
+}
@@ -0,0 +1,268 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, BisectState, CommitId, CommitInfo},
+	CWD,
+};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Alignment, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+	Frame,
+};
+
+///
+pub struct BisectPopup {
+	/// commit the next mark applies to
+	commit: Option<CommitInfo>,
+	state: Option<BisectState>,
+	first_bad: Option<CommitInfo>,
+	visible: bool,
+	queue: Queue,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for BisectPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		_rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			let area = ui::centered_rect_absolute(60, 9, f.size());
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text())
+					.block(
+						Block::default()
+							.title(Span::styled(
+								strings::title_bisect(),
+								self.theme.title(true),
+							))
+							.borders(Borders::ALL)
+							.border_type(BorderType::Thick),
+					)
+					.alignment(Alignment::Left)
+					.wrap(Wrap { trim: true }),
+				area,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for BisectPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::bisect_mark(&self.key_config),
+				self.commit.is_some(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::bisect_reset(&self.key_config),
+				self.state.is_some(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(e) = ev {
+				if e == self.key_config.exit_popup {
+					self.hide();
+				} else if e == self.key_config.bisect_good {
+					try_or_popup!(
+						self,
+						"bisect error:",
+						self.mark(true)
+					);
+				} else if e == self.key_config.bisect_bad {
+					try_or_popup!(
+						self,
+						"bisect error:",
+						self.mark(false)
+					);
+				} else if e == self.key_config.bisect_reset
+					&& self.state.is_some()
+				{
+					try_or_popup!(
+						self,
+						"bisect reset error:",
+						self.reset()
+					);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl BisectPopup {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			commit: None,
+			state: None,
+			first_bad: None,
+			visible: false,
+			queue: queue.clone(),
+			theme,
+			key_config,
+		}
+	}
+
+	/// marks will apply to `commit` until bisect checks out another one
+	pub fn open(&mut self, commit: CommitId) -> Result<()> {
+		self.commit = Some(sync::get_commit_info(CWD, &commit)?);
+		self.update_state()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_state(&mut self) -> Result<()> {
+		self.state = sync::bisect_state(CWD)?;
+		self.first_bad = match self
+			.state
+			.as_ref()
+			.and_then(|state| state.first_bad)
+		{
+			Some(id) => Some(sync::get_commit_info(CWD, &id)?),
+			None => None,
+		};
+
+		Ok(())
+	}
+
+	/// starts bisecting on the first mark
+	fn mark(&mut self, good: bool) -> Result<()> {
+		if let Some(commit) = self.commit.as_ref().map(|info| info.id)
+		{
+			if self.state.is_none() {
+				sync::bisect_start(CWD)?;
+			}
+
+			let next = if good {
+				sync::bisect_good(CWD, commit)?
+			} else {
+				sync::bisect_bad(CWD, commit)?
+			};
+
+			if let Some(next) = next {
+				self.commit =
+					Some(sync::get_commit_info(CWD, &next)?);
+			}
+
+			self.update_state()?;
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		}
+
+		Ok(())
+	}
+
+	fn reset(&mut self) -> Result<()> {
+		sync::bisect_reset(CWD)?;
+
+		self.state = None;
+		self.first_bad = None;
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		self.hide();
+
+		Ok(())
+	}
+
+	fn get_text(&self) -> Vec<Spans> {
+		let commit_line = |label: &str, info: &CommitInfo| {
+			Spans::from(vec![
+				Span::raw(format!("{}: ", label)),
+				Span::styled(
+					info.id.get_short_string(),
+					self.theme.commit_hash(false),
+				),
+				Span::raw(format!(" {}", info.message)),
+			])
+		};
+
+		let mut txt = Vec::new();
+
+		if let Some(commit) = &self.commit {
+			txt.push(commit_line(strings::BISECT_COMMIT, commit));
+		}
+
+		match &self.state {
+			None => {
+				txt.push(Spans::from(strings::bisect_not_started(
+					&self.key_config,
+				)));
+			}
+			Some(state) => {
+				if let Some(first_bad) = &self.first_bad {
+					txt.push(commit_line(
+						strings::BISECT_FIRST_BAD,
+						first_bad,
+					));
+				} else {
+					txt.push(Spans::from(strings::bisect_range(
+						state.bad.as_ref(),
+						&state.good,
+					)));
+					txt.push(Spans::from(strings::bisect_remaining(
+						state.remaining,
+					)));
+				}
+			}
+		}
+
+		txt
+	}
+}
@@ -0,0 +1,280 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	strings,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, BranchContaining, CommitId},
+	CWD,
+};
+use crossterm::event::Event;
+use std::convert::TryInto;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+/// lists the branches that already contain a commit
+pub struct BranchesContainingPopup {
+	theme: SharedTheme,
+	commit: Option<CommitId>,
+	branches: Vec<BranchContaining>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for BranchesContainingPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(40, 40);
+			const MIN_SIZE: Size = Size::new(40, 12);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let name_width = if self.branches.is_empty() {
+				strings::branches_containing_none().len()
+			} else {
+				self.branches
+					.iter()
+					.fold(0, |acc, branch| acc.max(branch.name.len()))
+			};
+
+			let constraints = [
+				Constraint::Length(name_width.try_into()?),
+				// local/remote
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let title = self.commit.map_or_else(String::new, |id| {
+				strings::title_branches_containing(&id)
+			});
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							title,
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for BranchesContainingPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup
+					|| key == self.key_config.enter
+				{
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl BranchesContainingPopup {
+	pub fn new(
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			commit: None,
+			branches: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self, id: CommitId) -> Result<()> {
+		self.branches = sync::get_branches_containing(CWD, id)?;
+		self.commit = Some(id);
+		self.table_state.get_mut().select(Some(0));
+
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.branches.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		if self.branches.is_empty() {
+			return vec![Row::new(vec![Cell::from(
+				strings::branches_containing_none(),
+			)
+			.style(self.theme.text(false, false))])];
+		}
+
+		self.branches
+			.iter()
+			.map(|branch| {
+				Row::new(vec![
+					Cell::from(branch.name.clone()).style(
+						self.theme.branch(false, branch.is_head),
+					),
+					Cell::from(if branch.local {
+						"local"
+					} else {
+						"remote"
+					})
+					.style(self.theme.text(false, false)),
+				])
+			})
+			.collect()
+	}
+}
@@ -1,7 +1,7 @@
 use super::{
-	utils::scroll_vertical::VerticalScroll, visibility_blocking,
-	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState,
+	time_ago_string, utils::scroll_vertical::VerticalScroll,
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, SharedOptions,
 };
 use crate::{
 	components::ScrollType,
@@ -12,18 +12,27 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	branches_compare::AsyncBranchesCompareJob,
 	sync::{
 		self,
 		branch::{
 			checkout_remote_branch, BranchDetails, LocalBranch,
 			RemoteBranch,
 		},
-		checkout_branch, get_branches_info, BranchInfo, CommitId,
+		checkout_branch, get_branches_info, last_fetch_time,
+		BranchCompare, BranchInfo, CommitId,
 	},
 	AsyncGitNotification, CWD,
 };
+use crossbeam_channel::Sender;
 use crossterm::event::Event;
-use std::{cell::Cell, convert::TryInto};
+use std::{
+	cell::Cell,
+	collections::{HashMap, HashSet},
+	convert::TryInto,
+	time::SystemTime,
+};
 use tui::{
 	backend::Backend,
 	layout::{
@@ -39,7 +48,12 @@ use unicode_truncate::UnicodeTruncateStr;
 ///
 pub struct BranchListComponent {
 	branches: Vec<BranchInfo>,
+	/// ahead/behind of local branches, arrives after the branches
+	upstream_compare: HashMap<String, BranchCompare>,
+	async_compare: AsyncSingleJob<AsyncBranchesCompareJob>,
 	local: bool,
+	/// last fetch time by remote
+	last_fetch: HashMap<String, SystemTime>,
 	visible: bool,
 	selection: u16,
 	scroll: VerticalScroll,
@@ -47,6 +61,7 @@ pub struct BranchListComponent {
 	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl DrawableComponent for BranchListComponent {
@@ -138,6 +153,12 @@ impl Component for BranchListComponent {
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::compare_branches(&self.key_config),
+				self.valid_selection(),
+				true,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::toggle_branch_popup(
 					&self.key_config,
@@ -152,7 +173,8 @@ impl Component for BranchListComponent {
 					&self.key_config,
 				),
 				!self.selection_is_cur_branch()
-					&& self.valid_selection(),
+					&& self.valid_selection()
+					&& !self.options.borrow().read_only,
 				true,
 			));
 
@@ -195,6 +217,23 @@ impl Component for BranchListComponent {
 				true,
 				self.local,
 			));
+
+			out.push(CommandInfo::new(
+				strings::commands::set_upstream_popup(
+					&self.key_config,
+				),
+				self.valid_selection(),
+				self.local,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::open_create_worktree(
+					&self.key_config,
+				),
+				!self.selection_is_cur_branch()
+					&& self.valid_selection(),
+				self.local,
+			));
 		}
 		visibility_blocking(self)
 	}
@@ -228,7 +267,9 @@ impl Component for BranchListComponent {
 			} else if e == self.key_config.tab_toggle {
 				self.local = !self.local;
 				self.update_branches()?;
-			} else if e == self.key_config.enter {
+			} else if e == self.key_config.enter
+				&& !self.options.borrow().read_only
+			{
 				try_or_popup!(
 					self,
 					"switch branch error:",
@@ -236,11 +277,30 @@ impl Component for BranchListComponent {
 				);
 			} else if e == self.key_config.create_branch && self.local
 			{
-				self.queue.push(InternalEvent::CreateBranch);
+				self.queue.push(InternalEvent::CreateBranch(None));
+			} else if e == self.key_config.create_worktree
+				&& self.local
+				&& !self.selection_is_cur_branch()
+				&& self.valid_selection()
+			{
+				if let Some(branch) =
+					self.branches.get(usize::from(self.selection))
+				{
+					self.queue.push(
+						InternalEvent::OpenCreateWorktree(
+							branch.name.clone(),
+						),
+					);
+				}
 			} else if e == self.key_config.rename_branch
 				&& self.valid_selection()
 			{
 				self.rename_branch();
+			} else if e == self.key_config.set_upstream
+				&& self.local
+				&& self.valid_selection()
+			{
+				self.set_upstream();
 			} else if e == self.key_config.delete_branch
 				&& !self.selection_is_cur_branch()
 				&& self.valid_selection()
@@ -250,11 +310,7 @@ impl Component for BranchListComponent {
 				&& !self.selection_is_cur_branch()
 				&& self.valid_selection()
 			{
-				try_or_popup!(
-					self,
-					"merge branch error:",
-					self.merge_branch()
-				);
+				self.merge_branch();
 			} else if e == self.key_config.rebase_branch
 				&& !self.selection_is_cur_branch()
 				&& self.valid_selection()
@@ -280,6 +336,17 @@ impl Component for BranchListComponent {
 					self.queue
 						.push(InternalEvent::CompareCommits(b, None));
 				}
+			} else if e == self.key_config.compare_branches
+				&& self.valid_selection()
+			{
+				if let Some(branch) =
+					self.branches.get(usize::from(self.selection))
+				{
+					self.queue.push(InternalEvent::CompareBranches(
+						branch.reference.clone(),
+					));
+				}
+				self.hide();
 			}
 		}
 
@@ -304,18 +371,24 @@ impl Component for BranchListComponent {
 impl BranchListComponent {
 	pub fn new(
 		queue: Queue,
+		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			branches: Vec::new(),
+			upstream_compare: HashMap::new(),
+			async_compare: AsyncSingleJob::new(sender.clone()),
 			local: true,
+			last_fetch: HashMap::new(),
 			visible: false,
 			selection: 0,
 			scroll: VerticalScroll::new(),
 			queue,
 			theme,
 			key_config,
+			options,
 			current_height: Cell::new(0),
 		}
 	}
@@ -338,17 +411,57 @@ impl BranchListComponent {
 					.iter()
 					.position(|b| b.name.ends_with("/HEAD"))
 					.map(|idx| self.branches.remove(idx));
+			} else {
+				self.update_upstream_compare();
 			}
+			self.update_last_fetch();
 			self.set_selection(self.selection)?;
 		}
 		Ok(())
 	}
 
+	fn update_last_fetch(&mut self) {
+		let remotes = self
+			.branches
+			.iter()
+			.filter_map(BranchInfo::remote)
+			.collect::<HashSet<_>>();
+
+		self.last_fetch = remotes
+			.into_iter()
+			.filter_map(|remote| {
+				last_fetch_time(CWD, remote)
+					.ok()
+					.flatten()
+					.map(|time| (remote.to_string(), time))
+			})
+			.collect();
+	}
+
+	/// last fetch of the remote of the selected branch
+	fn selected_last_fetch(&self) -> Option<SystemTime> {
+		self.branches
+			.get(self.selection as usize)
+			.and_then(BranchInfo::remote)
+			.and_then(|remote| self.last_fetch.get(remote))
+			.copied()
+	}
+
 	///
 	pub fn update_git(
 		&mut self,
 		ev: AsyncGitNotification,
 	) -> Result<()> {
+		if let AsyncGitNotification::BranchesCompare = ev {
+			if let Some(Ok(upstream_compare)) = self
+				.async_compare
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				self.upstream_compare = upstream_compare;
+			}
+		}
+
 		if self.is_visible() {
 			if let AsyncGitNotification::Push = ev {
 				self.update_branches()?;
@@ -358,21 +471,65 @@ impl BranchListComponent {
 		Ok(())
 	}
 
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.async_compare.is_pending()
+	}
+
+	/// walking the history of every branch takes a while
+	fn update_upstream_compare(&mut self) {
+		let branches = self
+			.branches
+			.iter()
+			.filter(|branch| {
+				branch.local_details().map_or(false, |details| {
+					details.upstream.is_some()
+				})
+			})
+			.map(|branch| branch.name.clone())
+			.collect();
+
+		self.async_compare
+			.spawn(AsyncBranchesCompareJob::new(branches));
+	}
+
+	/// local branches show their upstream and how far apart they are
+	fn display_name(&self, branch: &BranchInfo) -> String {
+		let upstream = match branch
+			.local_details()
+			.and_then(|details| details.upstream.as_ref())
+		{
+			Some(upstream) => upstream,
+			None => return branch.name.clone(),
+		};
+
+		match self.upstream_compare.get(&branch.name) {
+			Some(compare) => format!(
+				"{} -> {} \u{2191}{} \u{2193}{}",
+				branch.name,
+				upstream.name,
+				compare.ahead,
+				compare.behind
+			),
+			// still being computed
+			None => format!("{} -> {}", branch.name, upstream.name),
+		}
+	}
+
 	fn valid_selection(&self) -> bool {
 		!self.branches.is_empty()
 	}
 
-	fn merge_branch(&mut self) -> Result<()> {
+	fn merge_branch(&mut self) {
 		if let Some(branch) =
 			self.branches.get(usize::from(self.selection))
 		{
-			sync::merge_branch(CWD, &branch.name)?;
+			self.queue.push(InternalEvent::MergeBranch(
+				branch.name.clone(),
+			));
 
 			self.hide();
-			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
 		}
-
-		Ok(())
 	}
 
 	fn rebase_branch(&mut self) -> Result<()> {
@@ -487,7 +644,7 @@ impl BranchListComponent {
 				commit_message += THREE_DOTS;
 			}
 
-			let mut branch_name = displaybranch.name.clone();
+			let mut branch_name = self.display_name(displaybranch);
 			if branch_name.len()
 				> branch_name_length.saturating_sub(THREE_DOTS_LENGTH)
 			{
@@ -513,9 +670,9 @@ impl BranchListComponent {
 				if is_head { HEAD_SYMBOL } else { EMPTY_SYMBOL };
 			let upstream_tracking_str = match displaybranch.details {
 				BranchDetails::Local(LocalBranch {
-					has_upstream,
+					upstream: Some(_),
 					..
-				}) if has_upstream => UPSTREAM_SYMBOL,
+				}) => UPSTREAM_SYMBOL,
 				BranchDetails::Remote(RemoteBranch {
 					has_tracking,
 					..
@@ -565,10 +722,21 @@ impl BranchListComponent {
 		}
 
 		if self.local {
-			checkout_branch(
-				asyncgit::CWD,
-				&self.branches[self.selection as usize].reference,
-			)?;
+			let reference =
+				&self.branches[self.selection as usize].reference;
+
+			match checkout_branch(CWD, reference) {
+				Err(asyncgit::Error::CheckoutConflicts(files)) => {
+					self.queue.push(InternalEvent::ConfirmAction(
+						Action::CheckoutBranchStashed(
+							reference.clone(),
+							files,
+						),
+					));
+					return Ok(());
+				}
+				res => res?,
+			}
 			self.hide();
 		} else {
 			checkout_remote_branch(
@@ -585,7 +753,17 @@ impl BranchListComponent {
 	}
 
 	fn draw_tabs<B: Backend>(&self, f: &mut Frame<B>, r: Rect) {
-		let tabs = [Span::raw("Local"), Span::raw("Remote")]
+		let remote = self.selected_last_fetch().map_or_else(
+			|| String::from("Remote"),
+			|time| {
+				format!(
+					"Remote ({})",
+					strings::last_fetch(&time_ago_string(time))
+				)
+			},
+		);
+
+		let tabs = [Span::raw("Local"), Span::raw(remote)]
 			.iter()
 			.cloned()
 			.map(Spans::from)
@@ -648,6 +826,17 @@ impl BranchListComponent {
 		));
 	}
 
+	fn set_upstream(&mut self) {
+		let branch = &self.branches[self.selection as usize];
+		self.queue.push(InternalEvent::SetUpstream(
+			branch.name.clone(),
+			branch
+				.local_details()
+				.and_then(|details| details.upstream.as_ref())
+				.map(|upstream| upstream.name.clone()),
+		));
+	}
+
 	fn delete_branch(&mut self) {
 		self.queue.push(InternalEvent::ConfirmAction(
 			Action::DeleteBranch(
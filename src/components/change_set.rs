@@ -0,0 +1,142 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct ChangeSetComponent {
+	input: TextInputComponent,
+	path: Option<String>,
+	assigned: bool,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for ChangeSetComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for ChangeSetComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::change_set_confirm_msg(
+					&self.key_config,
+				),
+				self.can_assign(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter && self.can_assign() {
+					self.assign();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl ChangeSetComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::change_set_popup_title(&key_config),
+				&strings::change_set_popup_msg(&key_config),
+				true,
+			),
+			path: None,
+			assigned: false,
+			key_config,
+		}
+	}
+
+	/// `assigned` tells whether `path` is part of a change set already
+	/// and can therefore be removed from it with an empty message
+	pub fn open(
+		&mut self,
+		path: String,
+		name: String,
+		assigned: bool,
+	) -> Result<()> {
+		self.path = Some(path);
+		self.assigned = assigned;
+		self.input.set_text(name);
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn can_assign(&self) -> bool {
+		self.assigned || !self.input.get_text().trim().is_empty()
+	}
+
+	fn assign(&mut self) {
+		if let Some(path) = self.path.take() {
+			self.queue.push(InternalEvent::AssignChangeSet(
+				path,
+				self.input.get_text().to_string(),
+			));
+		}
+
+		self.input.clear();
+		self.hide();
+	}
+}
@@ -1,7 +1,10 @@
 use super::{
 	filetree::FileTreeComponent,
-	utils::filetree::{FileTreeItem, FileTreeItemKind},
-	CommandBlocking, DrawableComponent,
+	utils::{
+		changesets::{ChangeSet, ChangeSets},
+		filetree::{FileTreeItem, FileTreeItemKind},
+	},
+	CommandBlocking, DrawableComponent, SharedOptions,
 };
 use crate::{
 	components::{CommandInfo, Component, EventState},
@@ -10,18 +13,28 @@ use crate::{
 	strings, try_or_popup,
 	ui::style::SharedTheme,
 };
-use anyhow::Result;
-use asyncgit::{sync, StatusItem, StatusItemType, CWD};
+use anyhow::{bail, Result};
+use asyncgit::{
+	sync::{
+		self, status::StatusType, ConflictSide, FileSizeHint,
+		HookResult, ShowUntrackedFilesConfig,
+	},
+	StatusItem, StatusItemType, CWD,
+};
 use crossterm::event::Event;
-use std::path::Path;
+use std::{collections::HashMap, path::Path};
 use tui::{backend::Backend, layout::Rect, Frame};
 
 ///
 pub struct ChangesComponent {
 	files: FileTreeComponent,
 	is_working_dir: bool,
+	change_sets: ChangeSets,
+	large_files: usize,
+	unresolved_conflicts: bool,
 	queue: Queue,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl ChangesComponent {
@@ -33,6 +46,7 @@ impl ChangesComponent {
 		queue: Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			files: FileTreeComponent::new(
@@ -43,17 +57,78 @@ impl ChangesComponent {
 				key_config.clone(),
 			),
 			is_working_dir,
+			change_sets: ChangeSets::default(),
+			large_files: 0,
+			unresolved_conflicts: false,
 			queue,
 			key_config,
+			options,
 		}
 	}
 
-	///
-	pub fn set_items(&mut self, list: &[StatusItem]) -> Result<()> {
+	/// `size_hints` are fetched along with the status
+	pub fn set_items(
+		&mut self,
+		list: &[StatusItem],
+		size_hints: HashMap<String, FileSizeHint>,
+	) -> Result<()> {
 		self.files.update(list)?;
+
+		if !self.change_sets.is_empty() {
+			self.change_sets.retain_staged(list);
+			self.files.set_markers(self.change_sets.markers());
+		}
+
+		self.large_files = size_hints
+			.values()
+			.filter(|hint| matches!(hint, FileSizeHint::Large(_)))
+			.count();
+		self.files.set_size_hints(size_hints);
+
+		if self.is_working_dir {
+			self.unresolved_conflicts = list.iter().any(|item| {
+				item.status == StatusItemType::Conflicted
+			});
+		}
+
 		Ok(())
 	}
 
+	/// see `FileTreeComponent::set_filter`
+	pub fn set_filter(
+		&mut self,
+		filter: Option<String>,
+	) -> Result<()> {
+		self.files.set_filter(filter)
+	}
+
+	/// see `FileTreeComponent::select_file`
+	pub fn select_file(&mut self, forward: bool, wrap: bool) -> bool {
+		self.files.select_file(forward, wrap)
+	}
+
+	/// true while the index still holds conflicted files
+	pub const fn has_unresolved_conflicts(&self) -> bool {
+		self.unresolved_conflicts
+	}
+
+	/// committing is blocked until all conflicts are resolved
+	pub fn set_unresolved_conflicts(&mut self, conflicts: bool) {
+		self.unresolved_conflicts = conflicts;
+	}
+
+	/// change sets belong to the repo they were made in
+	pub fn clear_change_sets(&mut self) {
+		self.change_sets.clear();
+		self.files.set_markers(self.change_sets.markers());
+	}
+
+	/// see `ChangeSets::assign`
+	pub fn assign_change_set(&mut self, path: &str, name: &str) {
+		self.change_sets.assign(path, name);
+		self.files.set_markers(self.change_sets.markers());
+	}
+
 	///
 	pub fn selection(&self) -> Option<FileTreeItem> {
 		self.files.selection()
@@ -76,7 +151,19 @@ impl ChangesComponent {
 	}
 
 	fn index_add_remove(&mut self) -> Result<bool> {
+		let marked = self.files.marked_items();
+		if !marked.is_empty() {
+			self.index_add_remove_items(&marked)?;
+			self.files.clear_marks();
+			return Ok(true);
+		}
+
 		if let Some(tree_item) = self.selection() {
+			if let Some(items) = self.filtered_folder(&tree_item) {
+				self.index_add_remove_items(&items)?;
+				return Ok(true);
+			}
+
 			if self.is_working_dir {
 				if let FileTreeItemKind::File(i) = tree_item.kind {
 					let path = Path::new(i.path.as_str());
@@ -112,6 +199,121 @@ impl ChangesComponent {
 		Ok(false)
 	}
 
+	/// the visible files of a selected folder while filtering,
+	/// folder operations must not touch the hidden ones
+	fn filtered_folder(
+		&self,
+		tree_item: &FileTreeItem,
+	) -> Option<Vec<StatusItem>> {
+		if matches!(tree_item.kind, FileTreeItemKind::Path(_)) {
+			self.files.filtered_items_in(&tree_item.info.full_path)
+		} else {
+			None
+		}
+	}
+
+	/// stages or unstages all `items` at once
+	fn index_add_remove_items(
+		&self,
+		items: &[StatusItem],
+	) -> Result<()> {
+		for item in items {
+			let path = Path::new(item.path.as_str());
+			if !self.is_working_dir {
+				sync::reset_stage(CWD, item.path.as_str())?;
+			} else if item.status == StatusItemType::Deleted {
+				sync::stage_addremoved(CWD, path)?;
+			} else {
+				sync::stage_add_file(CWD, path)?;
+			}
+		}
+
+		if self.is_working_dir && self.is_empty() {
+			self.queue.push(InternalEvent::StatusLastFileMoved);
+		}
+
+		Ok(())
+	}
+
+	fn open_change_set(&self) -> bool {
+		if let Some(file) = self.files.selection_file() {
+			let assigned = self.change_sets.name_of(&file.path);
+			let name = assigned
+				.or_else(|| self.change_sets.last_name())
+				.unwrap_or_default()
+				.to_string();
+
+			self.queue.push(InternalEvent::OpenChangeSet(
+				file.path,
+				name,
+				assigned.is_some(),
+			));
+
+			return true;
+		}
+
+		false
+	}
+
+	/// creates one commit per change set in the order they were
+	/// created running the commit hooks for each of them, sets are
+	/// only dropped once they got committed
+	fn commit_change_sets(&mut self) -> Result<()> {
+		let sign_off = self.options.borrow().commit_sign_off;
+
+		let mut committed = 0;
+		let result = self.change_sets.iter().try_for_each(|set| {
+			self.commit_change_set(set, sign_off)?;
+			committed += 1;
+			Ok(())
+		});
+
+		self.change_sets.remove_first(committed);
+		self.files.set_markers(self.change_sets.markers());
+
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		result
+	}
+
+	fn commit_change_set(
+		&self,
+		set: &ChangeSet,
+		sign_off: bool,
+	) -> Result<()> {
+		if set.name.trim().is_empty() {
+			bail!("change set without commit message");
+		}
+
+		if let HookResult::NotOk(e) = sync::hooks_pre_commit(CWD)? {
+			bail!("pre-commit hook error:\n{}", e);
+		}
+
+		let mut msg = if sign_off {
+			sync::sign_off_msg(CWD, &set.name)?
+		} else {
+			set.name.clone()
+		};
+
+		if let HookResult::NotOk(e) =
+			sync::hooks_commit_msg(CWD, &mut msg)?
+		{
+			bail!("commit-msg hook error:\n{}", e);
+		}
+
+		sync::commit_paths(CWD, &msg, &set.paths)?;
+
+		if let HookResult::NotOk(e) = sync::hooks_post_commit(CWD)? {
+			log::error!("post-commit hook error: {}", e);
+			self.queue.push(InternalEvent::ShowErrorMsg(format!(
+				"post-commit hook error:\n{}",
+				e
+			)));
+		}
+
+		Ok(())
+	}
+
 	fn index_add_all(&mut self) -> Result<()> {
 		sync::stage_add_all(CWD, "*")?;
 
@@ -121,15 +323,65 @@ impl ChangesComponent {
 	}
 
 	fn stage_remove_all(&mut self) -> Result<()> {
-		sync::reset_stage(CWD, "*")?;
+		sync::reset_stage_all(CWD)?;
 
 		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
 
 		Ok(())
 	}
 
+	/// lists all files the reset touches, untracked ones hidden by
+	/// `status.showUntrackedFiles` or the filter included
+	fn dispatch_reset_workdir_all(&self) -> Result<()> {
+		let files = sync::status::get_status(
+			CWD,
+			StatusType::WorkingDir,
+			Some(ShowUntrackedFilesConfig::All),
+		)?;
+
+		if !files.is_empty() {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::ResetWorkdirAll(
+					files.into_iter().map(|item| item.path).collect(),
+				),
+			));
+		}
+
+		Ok(())
+	}
+
 	fn dispatch_reset_workdir(&mut self) -> bool {
+		let marked = self.files.marked_items();
+		if !marked.is_empty() {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::ResetFiles(
+					marked
+						.into_iter()
+						.map(|item| item.path)
+						.collect(),
+				),
+			));
+			self.files.clear_marks();
+
+			return true;
+		}
+
 		if let Some(tree_item) = self.selection() {
+			if let Some(items) = self.filtered_folder(&tree_item) {
+				if !items.is_empty() {
+					self.queue.push(InternalEvent::ConfirmAction(
+						Action::ResetFiles(
+							items
+								.into_iter()
+								.map(|item| item.path)
+								.collect(),
+						),
+					));
+				}
+
+				return true;
+			}
+
 			let is_folder =
 				matches!(tree_item.kind, FileTreeItemKind::Path(_));
 			self.queue.push(InternalEvent::ConfirmAction(
@@ -144,8 +396,40 @@ impl ChangesComponent {
 		false
 	}
 
+	fn selected_conflict(&self) -> Option<String> {
+		self.files.selection_file().and_then(|item| {
+			(item.status == StatusItemType::Conflicted)
+				.then(|| item.path)
+		})
+	}
+
+	fn take_conflict_side(&mut self, side: ConflictSide) -> bool {
+		if let Some(path) = self.selected_conflict() {
+			try_or_popup!(
+				self,
+				"resolve conflict error:",
+				sync::checkout_conflict_side(CWD, &path, side)
+			);
+
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+			return true;
+		}
+
+		false
+	}
+
+	/// folders ask for confirmation as they may hide a lot
 	fn add_to_ignore(&mut self) -> bool {
 		if let Some(tree_item) = self.selection() {
+			if matches!(tree_item.kind, FileTreeItemKind::Path(_)) {
+				self.queue.push(InternalEvent::ConfirmAction(
+					Action::IgnoreFolder(tree_item.info.full_path),
+				));
+
+				return true;
+			}
+
 			if let Err(e) =
 				sync::add_to_ignore(CWD, &tree_item.info.full_path)
 			{
@@ -189,6 +473,12 @@ impl Component for ChangesComponent {
 
 		let some_selection = self.selection().is_some();
 
+		out.push(CommandInfo::new(
+			strings::commands::mark_file(&self.key_config),
+			self.files.selection_file().is_some(),
+			self.focused(),
+		));
+
 		if self.is_working_dir {
 			out.push(CommandInfo::new(
 				strings::commands::stage_all(&self.key_config),
@@ -197,7 +487,7 @@ impl Component for ChangesComponent {
 			));
 			out.push(CommandInfo::new(
 				strings::commands::stage_item(&self.key_config),
-				some_selection,
+				some_selection && !self.options.borrow().read_only,
 				self.focused(),
 			));
 			out.push(CommandInfo::new(
@@ -205,15 +495,34 @@ impl Component for ChangesComponent {
 				some_selection,
 				self.focused(),
 			));
+			out.push(CommandInfo::new(
+				strings::commands::reset_all(&self.key_config),
+				some_selection,
+				self.focused(),
+			));
 			out.push(CommandInfo::new(
 				strings::commands::ignore_item(&self.key_config),
 				some_selection,
 				self.focused(),
 			));
+			out.push(CommandInfo::new(
+				strings::commands::take_ours(&self.key_config),
+				true,
+				(self.focused()
+					&& self.selected_conflict().is_some())
+					|| force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::take_theirs(&self.key_config),
+				true,
+				(self.focused()
+					&& self.selected_conflict().is_some())
+					|| force_all,
+			));
 		} else {
 			out.push(CommandInfo::new(
 				strings::commands::unstage_item(&self.key_config),
-				some_selection,
+				some_selection && !self.options.borrow().read_only,
 				self.focused(),
 			));
 			out.push(CommandInfo::new(
@@ -224,11 +533,27 @@ impl Component for ChangesComponent {
 			out.push(
 				CommandInfo::new(
 					strings::commands::commit_open(&self.key_config),
-					!self.is_empty(),
+					!self.is_empty() && !self.unresolved_conflicts,
 					self.focused() || force_all,
 				)
 				.order(-1),
 			);
+			out.push(CommandInfo::new(
+				strings::commands::change_set_assign(
+					&self.key_config,
+				),
+				self.files.selection_file().is_some(),
+				self.focused() || force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::change_set_commit(
+					&self.key_config,
+					self.change_sets.len(),
+				),
+				true,
+				(self.focused() && !self.change_sets.is_empty())
+					|| force_all,
+			));
 		}
 
 		CommandBlocking::PassingOn
@@ -244,10 +569,28 @@ impl Component for ChangesComponent {
 				return if e == self.key_config.open_commit
 					&& !self.is_working_dir
 					&& !self.is_empty()
+					&& !self.unresolved_conflicts
 				{
-					self.queue.push(InternalEvent::OpenCommit);
+					if self.large_files > 0
+						&& self
+							.options
+							.borrow()
+							.commit_confirm_large_files
+					{
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::CommitLargeFiles(
+									self.large_files,
+								),
+							),
+						);
+					} else {
+						self.queue.push(InternalEvent::OpenCommit);
+					}
 					Ok(EventState::Consumed)
-				} else if e == self.key_config.enter {
+				} else if e == self.key_config.enter
+					&& !self.options.borrow().read_only
+				{
 					try_or_popup!(
 						self,
 						"staging error:",
@@ -258,6 +601,8 @@ impl Component for ChangesComponent {
 						NeedsUpdate::ALL,
 					));
 					Ok(EventState::Consumed)
+				} else if e == self.key_config.status_mark_file {
+					Ok(self.files.toggle_mark().into())
 				} else if e == self.key_config.status_stage_all
 					&& !self.is_empty()
 				{
@@ -268,20 +613,60 @@ impl Component for ChangesComponent {
 							self.index_add_all()
 						);
 					} else {
-						self.stage_remove_all()?;
+						try_or_popup!(
+							self,
+							"unstaging all error:",
+							self.stage_remove_all()
+						);
 					}
 					self.queue
 						.push(InternalEvent::StatusLastFileMoved);
 					Ok(EventState::Consumed)
+				} else if e == self.key_config.change_set_assign
+					&& !self.is_working_dir
+				{
+					Ok(self.open_change_set().into())
+				} else if e == self.key_config.change_set_commit
+					&& !self.is_working_dir
+					&& !self.change_sets.is_empty()
+				{
+					try_or_popup!(
+						self,
+						"commit change sets error:",
+						self.commit_change_sets()
+					);
+					Ok(EventState::Consumed)
 				} else if e == self.key_config.status_reset_item
 					&& self.is_working_dir
 				{
 					Ok(self.dispatch_reset_workdir().into())
+				} else if e == self.key_config.status_reset_all
+					&& self.is_working_dir
+					&& !self.is_empty()
+				{
+					try_or_popup!(
+						self,
+						"reset error:",
+						self.dispatch_reset_workdir_all()
+					);
+					Ok(EventState::Consumed)
 				} else if e == self.key_config.status_ignore_file
 					&& self.is_working_dir
 					&& !self.is_empty()
 				{
 					Ok(self.add_to_ignore().into())
+				} else if e == self.key_config.status_take_ours
+					&& self.is_working_dir
+				{
+					Ok(self
+						.take_conflict_side(ConflictSide::Ours)
+						.into())
+				} else if e == self.key_config.status_take_theirs
+					&& self.is_working_dir
+				{
+					Ok(self
+						.take_conflict_side(ConflictSide::Theirs)
+						.into())
 				} else {
 					Ok(EventState::NotConsumed)
 				};
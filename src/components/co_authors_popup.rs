@@ -0,0 +1,306 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, CommitSignature},
+	CWD,
+};
+use crossterm::event::Event;
+use std::convert::TryInto;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+/// how many commits are searched for authors
+const RECENT_COMMITS: usize = 500;
+
+/// lists collaborators to credit with a `Co-authored-by` trailer
+pub struct CoAuthorsPopup {
+	theme: SharedTheme,
+	queue: Queue,
+	/// picked before, they stay on top for the session
+	picked: Vec<CommitSignature>,
+	authors: Vec<CommitSignature>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for CoAuthorsPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(60, 50);
+			const MIN_SIZE: Size = Size::new(50, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let name_width = self
+				.authors
+				.iter()
+				.fold(0, |acc, author| acc.max(author.name.len()));
+
+			let constraints = [
+				Constraint::Length(name_width.try_into()?),
+				// email
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_co_authors(),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for CoAuthorsPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::select_co_author(&self.key_config),
+				self.selected_author().is_some(),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.enter {
+					self.pick_selected();
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl CoAuthorsPopup {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			picked: Vec::new(),
+			authors: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			key_config,
+		}
+	}
+
+	/// picked collaborators first, then the recent commit authors
+	pub fn open(&mut self) -> Result<()> {
+		let mut authors = self.picked.clone();
+		for author in sync::recent_authors(CWD, RECENT_COMMITS)? {
+			if !authors.iter().any(|a| a.email == author.email) {
+				authors.push(author);
+			}
+		}
+
+		self.authors = authors;
+		self.table_state.get_mut().select(Some(0));
+
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn pick_selected(&mut self) {
+		if let Some(author) = self.selected_author().cloned() {
+			self.picked.retain(|a| a.email != author.email);
+			self.picked.insert(0, author.clone());
+
+			self.queue.push(InternalEvent::AddCoAuthor(
+				author.name,
+				author.email,
+			));
+			self.hide();
+		}
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.authors.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		self.authors
+			.iter()
+			.map(|author| {
+				Row::new(vec![
+					Cell::from(author.name.clone())
+						.style(self.theme.commit_author(false)),
+					Cell::from(author.email.clone())
+						.style(self.theme.text(true, false)),
+				])
+			})
+			.collect()
+	}
+
+	fn selected_author(&self) -> Option<&CommitSignature> {
+		let table_state = self.table_state.take();
+
+		let author = table_state
+			.selected()
+			.and_then(|selected| self.authors.get(selected));
+
+		self.table_state.set(table_state);
+
+		author
+	}
+}
@@ -9,6 +9,8 @@ pub struct CommandText {
 	pub group: &'static str,
 	///
 	pub hide_help: bool,
+	/// its key is unbound, e.g. in `--read-only` mode
+	pub unbound: bool,
 }
 
 impl CommandText {
@@ -23,6 +25,7 @@ impl CommandText {
 			desc,
 			group,
 			hide_help: false,
+			unbound: false,
 		}
 	}
 	///
@@ -31,6 +34,12 @@ impl CommandText {
 		tmp.hide_help = true;
 		tmp
 	}
+	///
+	pub const fn unbound(self, unbound: bool) -> Self {
+		let mut tmp = self;
+		tmp.unbound = unbound;
+		tmp
+	}
 }
 
 ///
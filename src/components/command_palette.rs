@@ -0,0 +1,340 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, CommandText,
+	Component, DrawableComponent, EventState, ScrollType,
+	TextInputComponent,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use crossterm::event::{Event, KeyEvent};
+use fuzzy_matcher::FuzzyMatcher;
+use std::borrow::Cow;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::Span,
+	widgets::{Block, Borders, Clear},
+	Frame,
+};
+
+/// fuzzy searches the commands active when it was opened and
+/// runs the chosen one by sending its key
+pub struct CommandPalettePopup {
+	queue: Queue,
+	visible: bool,
+	find_text: TextInputComponent,
+	commands: Vec<(CommandText, KeyEvent)>,
+	/// indices into `commands` matching the query, best first
+	filtered: Vec<usize>,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl CommandPalettePopup {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		let mut find_text = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			"start typing..",
+			false,
+		);
+		find_text.embed();
+
+		Self {
+			queue: queue.clone(),
+			visible: false,
+			find_text,
+			commands: Vec::new(),
+			filtered: Vec::new(),
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	/// only enabled commands with a single key in their hint
+	/// can be run from here
+	pub fn open(&mut self, commands: Vec<CommandInfo>) -> Result<()> {
+		let bindings = self.key_config.bindings()?;
+
+		self.commands.clear();
+		for cmd in commands {
+			if !cmd.enabled
+				|| !cmd.available
+				|| self
+					.commands
+					.iter()
+					.any(|(text, _)| *text == cmd.text)
+			{
+				continue;
+			}
+
+			let key = hint(&cmd.text.name).and_then(|hint| {
+				bindings
+					.values()
+					.find(|key| {
+						self.key_config.get_hint(**key) == hint
+					})
+					.copied()
+			});
+
+			if let Some(key) = key {
+				self.commands.push((cmd.text, key));
+			}
+		}
+
+		self.find_text.set_text(String::new());
+		self.update_query();
+
+		self.show()?;
+		self.find_text.show()?;
+
+		Ok(())
+	}
+
+	fn update_query(&mut self) {
+		let query = self.find_text.get_text();
+
+		self.filtered = if query.is_empty() {
+			(0..self.commands.len()).collect()
+		} else {
+			let matcher =
+				fuzzy_matcher::skim::SkimMatcherV2::default();
+
+			let mut hits = self
+				.commands
+				.iter()
+				.enumerate()
+				.filter_map(|(idx, (text, _))| {
+					matcher
+						.fuzzy_match(
+							&format!("{} {}", text.name, text.desc),
+							query,
+						)
+						.map(|score| (score, idx))
+				})
+				.collect::<Vec<_>>();
+			hits.sort_by(|a, b| b.0.cmp(&a.0));
+
+			hits.into_iter().map(|(_, idx)| idx).collect()
+		};
+
+		self.selection = 0;
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.filtered.len().saturating_sub(1));
+	}
+
+	fn run_selected(&mut self) {
+		if let Some((_, key)) = self
+			.filtered
+			.get(self.selection)
+			.and_then(|idx| self.commands.get(*idx))
+		{
+			self.queue.push(InternalEvent::RunKey(*key));
+		}
+
+		self.hide();
+	}
+}
+
+/// the key hint at the end of a command name, `Push [p]` -> `p`
+fn hint(name: &str) -> Option<&str> {
+	let start = name.rfind('[')?;
+	name[start + 1..].strip_suffix(']')
+}
+
+impl DrawableComponent for CommandPalettePopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (60, 20);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::title_command_palette(),
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Length(1),
+						Constraint::Percentage(100),
+					]
+					.as_ref(),
+				)
+				.split(area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.find_text.draw(f, chunks[0])?;
+
+			let height = usize::from(chunks[1].height)
+				.saturating_sub(1)
+				.max(1);
+			let width = usize::from(chunks[1].width);
+
+			// keep the selection in view
+			let skip = self.selection.saturating_sub(height - 1);
+
+			let items = self
+				.filtered
+				.iter()
+				.enumerate()
+				.skip(skip)
+				.take(height)
+				.map(|(pos, idx)| {
+					let (text, _) = &self.commands[*idx];
+					let line =
+						format!("{} - {}", text.name, text.desc)
+							.chars()
+							.take(width)
+							.collect::<String>();
+
+					Span::styled(
+						Cow::from(line),
+						self.theme.text(pos == self.selection, false),
+					)
+				});
+
+			ui::draw_list_block(
+				f,
+				chunks[1],
+				Block::default()
+					.title(Span::styled(
+						strings::command_palette_hits(
+							self.filtered.len(),
+						),
+						self.theme.title(true),
+					))
+					.borders(Borders::TOP),
+				items,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for CommandPalettePopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::command_palette_run(
+					&self.key_config,
+				),
+				!self.filtered.is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.exit_popup {
+					self.hide();
+					return Ok(EventState::Consumed);
+				} else if *key == self.key_config.enter {
+					self.run_selected();
+					return Ok(EventState::Consumed);
+				} else if *key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if *key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			if self.find_text.event(event)?.is_consumed() {
+				self.update_query();
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::hint;
+
+	#[test]
+	fn test_hint() {
+		assert_eq!(hint("Push [p]"), Some("p"));
+		assert_eq!(hint("Fetch [^f] ..."), None);
+		assert_eq!(hint("Filter [/]"), Some("/"));
+		assert_eq!(hint("Status"), None);
+	}
+}
@@ -1,11 +1,12 @@
 use super::{
-	textinput::TextInputComponent, visibility_blocking,
+	branch_label, textinput::TextInputComponent, visibility_blocking,
 	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState, ExternalEditorComponent,
+	EventState, ExternalEditorComponent, InputType, SharedOptions,
 };
 use crate::{
 	keys::SharedKeyConfig,
 	queue::{InternalEvent, NeedsUpdate, Queue},
+	string_utils::wrap_commit_body,
 	strings, try_or_popup,
 	ui::style::SharedTheme,
 };
@@ -13,14 +14,15 @@ use anyhow::Result;
 use asyncgit::{
 	cached,
 	sync::{
-		self, get_config_string, CommitId, HookResult, RepoState,
+		self, status::StatusType, BranchCompare, CommitAuthor,
+		CommitId, CommitMessage, HookResult, RepoState,
 	},
 	CWD,
 };
 use crossterm::event::Event;
 use easy_cast::Cast;
 use std::{
-	fs::{read_to_string, File},
+	fs::File,
 	io::{Read, Write},
 };
 use tui::{
@@ -34,19 +36,33 @@ enum Mode {
 	Normal,
 	Amend(CommitId),
 	Merge(Vec<CommitId>),
+	Reword,
 }
 
 pub struct CommitComponent {
 	input: TextInputComponent,
+	author_input: TextInputComponent,
+	/// author of the following commits, kept for the session
+	author: Option<CommitAuthor>,
 	mode: Mode,
 	queue: Queue,
 	key_config: SharedKeyConfig,
 	git_branch_name: cached::BranchName,
 	commit_template: Option<String>,
+	/// the message got prefilled with the template or the merge
+	/// message, their comment lines are stripped before committing,
+	/// comments typed into the input are kept otherwise
+	prefilled_comments: bool,
+	/// amending keeps the author, otherwise it becomes the user
+	keep_author: bool,
+	/// the amended `HEAD` is on the upstream already
+	head_pushed: bool,
 	theme: SharedTheme,
+	options: SharedOptions,
 }
 
 const FIRST_LINE_LIMIT: usize = 50;
+const COMMIT_BODY_WIDTH: u16 = 72;
 
 impl CommitComponent {
 	///
@@ -54,6 +70,7 @@ impl CommitComponent {
 		queue: Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue,
@@ -66,10 +83,23 @@ impl CommitComponent {
 				&strings::commit_msg(&key_config),
 				true,
 			),
+			author_input: TextInputComponent::new(
+				theme.clone(),
+				key_config.clone(),
+				&strings::commit_author_popup_title(),
+				&strings::commit_author_popup_msg(),
+				true,
+			)
+			.with_input_type(InputType::Singleline),
+			author: None,
 			key_config,
 			git_branch_name: cached::BranchName::new(CWD),
 			commit_template: None,
+			prefilled_comments: false,
+			keep_author: true,
+			head_pushed: false,
 			theme,
+			options,
 		}
 	}
 
@@ -78,8 +108,17 @@ impl CommitComponent {
 		self.git_branch_name.lookup().ok();
 	}
 
+	/// a message typed for the repo switched away from is dropped
+	pub fn reset_repo_state(&mut self) {
+		self.mode = Mode::Normal;
+		self.input.clear();
+		self.commit_template = None;
+		self.prefilled_comments = false;
+		self.git_branch_name = cached::BranchName::new(CWD);
+	}
+
 	fn draw_branch_name<B: Backend>(&self, f: &mut Frame<B>) {
-		if let Some(name) = self.git_branch_name.last() {
+		if let Some(name) = branch_label(&self.git_branch_name) {
 			let w = Paragraph::new(format!("{{{}}}", name))
 				.alignment(Alignment::Right);
 
@@ -94,7 +133,47 @@ impl CommitComponent {
 		}
 	}
 
+	fn draw_hints<B: Backend>(&self, f: &mut Frame<B>) {
+		let mut hints = Vec::new();
+
+		if self.options.borrow().commit_sign_off {
+			hints.push(strings::commit_sign_off_hint());
+		}
+
+		if let Some(author) = &self.author {
+			hints.push(strings::commit_author_hint(
+				author.name.as_deref().unwrap_or_default(),
+				author.email.as_deref().unwrap_or_default(),
+			));
+		}
+
+		if !hints.is_empty() {
+			let msg = hints.join(" ");
+			let msg_length: u16 = msg.len().cast();
+			let w = Paragraph::new(msg)
+				.style(self.theme.text(false, false));
+
+			let rect = {
+				let mut rect = self.input.get_area();
+				rect.y += rect.height.saturating_sub(1);
+				rect.height = 1;
+				rect.x += 1;
+				rect.width =
+					msg_length.min(rect.width.saturating_sub(2));
+				rect
+			};
+
+			f.render_widget(w, rect);
+		}
+	}
+
 	fn draw_warnings<B: Backend>(&self, f: &mut Frame<B>) {
+		let mut warnings = Vec::new();
+
+		if matches!(self.mode, Mode::Amend(_)) && self.head_pushed {
+			warnings.push(strings::commit_amend_pushed_warning());
+		}
+
 		let first_line = self
 			.input
 			.get_text()
@@ -104,7 +183,12 @@ impl CommitComponent {
 			.unwrap_or_default();
 
 		if first_line > FIRST_LINE_LIMIT {
-			let msg = strings::commit_first_line_warning(first_line);
+			warnings
+				.push(strings::commit_first_line_warning(first_line));
+		}
+
+		if !warnings.is_empty() {
+			let msg = warnings.join(" ");
 			let msg_length: u16 = msg.len().cast();
 			let w =
 				Paragraph::new(msg).style(self.theme.text_danger());
@@ -127,6 +211,7 @@ impl CommitComponent {
 
 	pub fn show_editor(&mut self) -> Result<()> {
 		let file_path = sync::repo_dir(CWD)?.join("COMMIT_EDITMSG");
+		let comment_char = sync::comment_char(CWD)?;
 
 		{
 			let mut file = File::create(&file_path)?;
@@ -135,12 +220,17 @@ impl CommitComponent {
 				self.input.get_text()
 			))?;
 			file.write_all(
-				strings::commit_editor_msg(&self.key_config)
-					.as_bytes(),
+				strings::commit_editor_msg(
+					&self.key_config,
+					comment_char,
+				)
+				.as_bytes(),
 			)?;
 		}
 
-		ExternalEditorComponent::open_file_in_editor(&file_path)?;
+		ExternalEditorComponent::open_file_in_editor(
+			&file_path, None,
+		)?;
 
 		let mut message = String::new();
 
@@ -149,18 +239,7 @@ impl CommitComponent {
 		drop(file);
 		std::fs::remove_file(&file_path)?;
 
-		let message: String = message
-			.lines()
-			.flat_map(|l| {
-				if l.starts_with('#') {
-					vec![]
-				} else {
-					vec![l, "\n"]
-				}
-			})
-			.collect();
-
-		let message = message.trim().to_string();
+		let message = sync::strip_comments(&message, comment_char);
 
 		self.input.set_text(message);
 		self.input.show()?;
@@ -169,23 +248,43 @@ impl CommitComponent {
 	}
 
 	fn commit(&mut self) -> Result<()> {
-		let gpgsign = get_config_string(CWD, "commit.gpgsign")
-			.ok()
-			.flatten()
-			.and_then(|path| path.parse::<bool>().ok())
-			.unwrap_or_default();
-
-		if gpgsign {
+		if sync::repo_config(CWD)?.gpgsign {
 			anyhow::bail!("config commit.gpgsign=true detected.\ngpg signing not supported.\ndeactivate in your repo/gitconfig to be able to commit without signing.");
 		}
 
-		let msg = self.input.get_text().to_string();
+		let msg = if self.prefilled_comments {
+			sync::strip_comments(
+				self.input.get_text(),
+				sync::comment_char(CWD)?,
+			)
+		} else {
+			self.input.get_text().trim().to_string()
+		};
+
+		if msg.is_empty() {
+			anyhow::bail!(
+				"aborting commit due to empty commit message"
+			);
+		}
+
+		let msg = if self.options.borrow().commit_wrap_body {
+			wrap_commit_body(&msg, COMMIT_BODY_WIDTH.into())
+		} else {
+			msg
+		};
 		self.input.clear();
+		self.prefilled_comments = false;
 		self.commit_with_msg(msg)
 	}
 
 	fn commit_with_msg(&mut self, msg: String) -> Result<()> {
-		if let HookResult::NotOk(e) = sync::hooks_pre_commit(CWD)? {
+		// rewording leaves the content untouched, nothing to check
+		let pre_commit = if matches!(self.mode, Mode::Reword) {
+			HookResult::Ok
+		} else {
+			sync::hooks_pre_commit(CWD)?
+		};
+		if let HookResult::NotOk(e) = pre_commit {
 			log::error!("pre-commit hook error: {}", e);
 			self.queue.push(InternalEvent::ShowErrorMsg(format!(
 				"pre-commit hook error:\n{}",
@@ -193,7 +292,11 @@ impl CommitComponent {
 			)));
 			return Ok(());
 		}
-		let mut msg = msg;
+		let mut msg = if self.options.borrow().commit_sign_off {
+			sync::sign_off_msg(CWD, &msg)?
+		} else {
+			msg
+		};
 		if let HookResult::NotOk(e) =
 			sync::hooks_commit_msg(CWD, &mut msg)?
 		{
@@ -206,9 +309,20 @@ impl CommitComponent {
 		}
 
 		let res = match &self.mode {
-			Mode::Normal => sync::commit(CWD, &msg),
-			Mode::Amend(amend) => sync::amend(CWD, *amend, &msg),
+			Mode::Normal => self.author.as_ref().map_or_else(
+				|| sync::commit(CWD, &msg),
+				|author| sync::commit_with_author(CWD, &msg, author),
+			),
+			Mode::Amend(amend) => sync::amend(
+				CWD,
+				*amend,
+				&msg,
+				(!self.keep_author)
+					.then(|| self.author.clone().unwrap_or_default())
+					.as_ref(),
+			),
 			Mode::Merge(ids) => sync::merge_commit(CWD, &msg, ids),
+			Mode::Reword => sync::reword_head(CWD, &msg),
 		};
 
 		if let Err(e) = res {
@@ -254,22 +368,156 @@ impl CommitComponent {
 			!= self.commit_template.as_ref().map(|s| s.trim())
 	}
 
+	/// how the current branch compares to its upstream if it has one
+	fn upstream_compare(&mut self) -> Option<BranchCompare> {
+		self.git_branch_name.lookup().ok().and_then(|branch| {
+			sync::branch_compare_upstream(CWD, &branch).ok()
+		})
+	}
+
+	/// `HEAD` is not pushed yet and the staged files were all
+	/// changed by it, so they likely belong to it
+	fn continues_head(&mut self) -> Result<bool> {
+		let unpushed = self
+			.upstream_compare()
+			.map_or(false, |compare| compare.ahead > 0);
+		if !unpushed {
+			return Ok(false);
+		}
+
+		let head_files =
+			sync::get_commit_files(CWD, sync::get_head(CWD)?, None)?;
+		let staged =
+			sync::status::get_status(CWD, StatusType::Stage, None)?;
+
+		Ok(!staged.is_empty()
+			&& staged.iter().all(|item| {
+				head_files.iter().any(|file| file.path == item.path)
+			}))
+	}
+
 	fn amend(&mut self) -> Result<()> {
 		if self.can_amend() {
 			let id = sync::get_head(CWD)?;
 			self.mode = Mode::Amend(id);
+			self.keep_author = true;
+			self.head_pushed = self
+				.upstream_compare()
+				.map_or(false, |compare| compare.ahead == 0);
 
 			let details = sync::get_commit_details(CWD, id)?;
+			let staged_files = sync::status::get_status(
+				CWD,
+				StatusType::Stage,
+				None,
+			)?
+			.len();
 
-			self.input.set_title(strings::commit_title_amend());
+			self.input
+				.set_title(strings::commit_title_amend(staged_files));
 
 			if let Some(msg) = details.message {
 				self.input.set_text(msg.combine());
 			}
+			self.prefilled_comments = false;
 		}
 
 		Ok(())
 	}
+
+	/// back from amending to a new commit with an empty message
+	fn new_commit(&mut self) {
+		if matches!(self.mode, Mode::Amend(_)) {
+			self.mode = Mode::Normal;
+			self.input.clear();
+
+			if let Some(s) = &self.commit_template {
+				self.input.set_text(s.clone());
+			}
+			self.prefilled_comments = self.commit_template.is_some();
+
+			self.input.set_title(strings::commit_title());
+		}
+	}
+
+	/// opens the popup directly in amend mode
+	pub fn show_amend(&mut self) -> Result<()> {
+		self.show()?;
+		self.amend()
+	}
+
+	/// prefilled with the current author
+	fn open_author_input(&mut self) -> Result<()> {
+		let text = self.author.as_ref().map_or_else(
+			|| {
+				sync::user_signature(CWD).map(|user| {
+					format!("{} <{}>", user.name, user.email)
+				})
+			},
+			|author| {
+				Ok(format!(
+					"{} <{}>",
+					author.name.as_deref().unwrap_or_default(),
+					author.email.as_deref().unwrap_or_default()
+				))
+			},
+		)?;
+
+		self.author_input.set_text(text);
+		self.author_input.show()
+	}
+
+	/// missing parts of the author are taken from the user
+	fn confirm_author(&mut self) -> Result<()> {
+		self.author = match parse_author(self.author_input.get_text())
+		{
+			Some(author) => {
+				let user = sync::user_signature(CWD)?;
+				Some(CommitAuthor {
+					name: author.name.or(Some(user.name)),
+					email: author.email.or(Some(user.email)),
+					time: author.time,
+				})
+			}
+			None => None,
+		};
+		self.author_input.hide();
+
+		Ok(())
+	}
+
+	/// credits the collaborator with a trailer in the message
+	pub fn add_co_author(&mut self, name: &str, email: &str) {
+		let msg =
+			sync::co_author_msg(self.input.get_text(), name, email);
+		self.input.set_text(msg);
+	}
+
+	fn update_wrap_width(&mut self) {
+		let wrap = self.options.borrow().commit_wrap_body;
+		self.input.set_wrap_width(wrap.then(|| COMMIT_BODY_WIDTH));
+	}
+
+	/// opens the input prefilled with the message of `HEAD`
+	pub fn reword(&mut self) -> Result<()> {
+		let id = sync::get_head(CWD)?;
+		let details = sync::get_commit_details(CWD, id)?;
+
+		self.mode = Mode::Reword;
+		self.prefilled_comments = false;
+
+		self.input.set_title(strings::commit_title_reword());
+		self.input.set_text(
+			details
+				.message
+				.map(CommitMessage::combine)
+				.unwrap_or_default(),
+		);
+		self.update_wrap_width();
+		self.input.show()?;
+
+		Ok(())
+	}
 }
 
 impl DrawableComponent for CommitComponent {
@@ -281,7 +529,9 @@ impl DrawableComponent for CommitComponent {
 		if self.is_visible() {
 			self.input.draw(f, rect)?;
 			self.draw_branch_name(f);
+			self.draw_hints(f);
 			self.draw_warnings(f);
+			self.author_input.draw(f, rect)?;
 		}
 
 		Ok(())
@@ -294,6 +544,20 @@ impl Component for CommitComponent {
 		out: &mut Vec<CommandInfo>,
 		force_all: bool,
 	) -> CommandBlocking {
+		if self.author_input.is_visible() {
+			self.author_input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_author_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+
+			return visibility_blocking(self);
+		}
+
 		self.input.commands(out, force_all);
 
 		if self.is_visible() || force_all {
@@ -309,6 +573,42 @@ impl Component for CommitComponent {
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::commit_keep_author(
+					&self.key_config,
+					self.keep_author,
+				),
+				matches!(self.mode, Mode::Amend(_)),
+				matches!(self.mode, Mode::Amend(_)),
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_new(&self.key_config),
+				matches!(self.mode, Mode::Amend(_)),
+				matches!(self.mode, Mode::Amend(_)),
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_sign_off(
+					&self.key_config,
+					self.options.borrow().commit_sign_off,
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_author(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_co_author(&self.key_config),
+				true,
+				true,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::commit_open_editor(
 					&self.key_config,
@@ -323,6 +623,24 @@ impl Component for CommitComponent {
 
 	fn event(&mut self, ev: Event) -> Result<EventState> {
 		if self.is_visible() {
+			if self.author_input.is_visible() {
+				if self.author_input.event(ev)?.is_consumed() {
+					return Ok(EventState::Consumed);
+				}
+
+				if let Event::Key(e) = ev {
+					if e == self.key_config.enter {
+						try_or_popup!(
+							self,
+							"author error:",
+							self.confirm_author()
+						);
+					}
+				}
+
+				return Ok(EventState::Consumed);
+			}
+
 			if self.input.event(ev)?.is_consumed() {
 				return Ok(EventState::Consumed);
 			}
@@ -338,6 +656,23 @@ impl Component for CommitComponent {
 					&& self.can_amend()
 				{
 					self.amend()?;
+				} else if e == self.key_config.commit_amend
+					&& matches!(self.mode, Mode::Amend(_))
+				{
+					self.keep_author = !self.keep_author;
+				} else if e == self.key_config.commit_new {
+					self.new_commit();
+				} else if e == self.key_config.commit_sign_off {
+					let old = self.options.borrow().commit_sign_off;
+					self.options.borrow_mut().commit_sign_off = !old;
+				} else if e == self.key_config.commit_author {
+					try_or_popup!(
+						self,
+						"author error:",
+						self.open_author_input()
+					);
+				} else if e == self.key_config.commit_co_author {
+					self.queue.push(InternalEvent::OpenCoAuthors);
 				} else if e == self.key_config.open_commit_editor {
 					self.queue.push(
 						InternalEvent::OpenExternalEditor(None),
@@ -358,6 +693,7 @@ impl Component for CommitComponent {
 	}
 
 	fn hide(&mut self) {
+		self.author_input.hide();
 		self.input.hide();
 	}
 
@@ -365,6 +701,7 @@ impl Component for CommitComponent {
 		//only clear text if it was not a normal commit dlg before, so to preserve old commit msg that was edited
 		if !matches!(self.mode, Mode::Normal) {
 			self.input.clear();
+			self.prefilled_comments = false;
 		}
 
 		self.mode = Mode::Normal;
@@ -373,17 +710,16 @@ impl Component for CommitComponent {
 			let ids = sync::mergehead_ids(CWD)?;
 			self.input.set_title(strings::commit_title_merge());
 			self.input.set_text(sync::merge_msg(CWD)?);
+			self.prefilled_comments = true;
 			Mode::Merge(ids)
 		} else {
 			self.commit_template =
-				get_config_string(CWD, "commit.template")
-					.ok()
-					.flatten()
-					.and_then(|path| read_to_string(path).ok());
+				sync::commit_template(CWD).ok().flatten();
 
 			if self.is_empty() {
 				if let Some(s) = &self.commit_template {
 					self.input.set_text(s.clone());
+					self.prefilled_comments = true;
 				}
 			}
 
@@ -391,8 +727,78 @@ impl Component for CommitComponent {
 			Mode::Normal
 		};
 
+		let suggest_amend =
+			self.options.borrow().commit_suggest_amend;
+		if matches!(self.mode, Mode::Normal)
+			&& suggest_amend
+			&& self.continues_head()?
+		{
+			self.amend()?;
+		}
+
+		self.update_wrap_width();
 		self.input.show()?;
 
 		Ok(())
 	}
 }
+
+/// `name <email>`, either part may be left out
+fn parse_author(text: &str) -> Option<CommitAuthor> {
+	let text = text.trim();
+
+	if text.is_empty() {
+		return None;
+	}
+
+	let (name, email) = match (text.find('<'), text.strip_suffix('>'))
+	{
+		(Some(start), Some(rest)) => {
+			(text[..start].trim(), rest[start + 1..].trim())
+		}
+		_ => (text, ""),
+	};
+
+	let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+
+	Some(CommitAuthor {
+		name: non_empty(name),
+		email: non_empty(email),
+		time: None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::parse_author;
+	use asyncgit::sync::CommitAuthor;
+
+	#[test]
+	fn test_parse_author() {
+		assert_eq!(parse_author("  "), None);
+		assert_eq!(
+			parse_author("foo bar <foo@bar.com>"),
+			Some(CommitAuthor {
+				name: Some(String::from("foo bar")),
+				email: Some(String::from("foo@bar.com")),
+				time: None,
+			})
+		);
+		assert_eq!(
+			parse_author("<foo@bar.com>"),
+			Some(CommitAuthor {
+				name: None,
+				email: Some(String::from("foo@bar.com")),
+				time: None,
+			})
+		);
+		assert_eq!(
+			parse_author("foo"),
+			Some(CommitAuthor {
+				name: Some(String::from("foo")),
+				email: None,
+				time: None,
+			})
+		);
+	}
+}
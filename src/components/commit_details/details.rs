@@ -2,7 +2,10 @@ use crate::{
 	components::{
 		commit_details::style::style_detail,
 		dialog_paragraph,
-		utils::{scroll_vertical::VerticalScroll, time_to_string},
+		utils::{
+			scroll_vertical::VerticalScroll, time_relative_string,
+			time_to_string,
+		},
 		CommandBlocking, CommandInfo, Component, DrawableComponent,
 		EventState, ScrollType,
 	},
@@ -15,6 +18,7 @@ use asyncgit::{
 	sync::{self, CommitDetails, CommitId, CommitMessage},
 	CWD,
 };
+use chrono::Local;
 use crossterm::event::Event;
 use std::clone::Clone;
 use std::{borrow::Cow, cell::Cell};
@@ -32,6 +36,8 @@ use super::style::Detail;
 pub struct DetailsComponent {
 	data: Option<CommitDetails>,
 	tags: Vec<String>,
+	/// index of the highlighted parent
+	parent_selection: usize,
 	theme: SharedTheme,
 	focused: bool,
 	current_width: Cell<u16>,
@@ -53,6 +59,7 @@ impl DetailsComponent {
 		Self {
 			data: None,
 			tags: Vec::new(),
+			parent_selection: 0,
 			theme,
 			focused,
 			scroll_to_bottom_next_draw: Cell::new(false),
@@ -69,6 +76,12 @@ impl DetailsComponent {
 	) {
 		self.tags.clear();
 
+		let same_commit = id.map(|id| id.to_string())
+			== self.data.as_ref().map(|data| data.hash.clone());
+		if !same_commit {
+			self.parent_selection = 0;
+		}
+
 		self.data =
 			id.and_then(|id| sync::get_commit_details(CWD, id).ok());
 
@@ -79,6 +92,35 @@ impl DetailsComponent {
 		}
 	}
 
+	///
+	pub fn parent_count(&self) -> usize {
+		self.data.as_ref().map_or(0, |data| data.parents.len())
+	}
+
+	///
+	pub const fn parent_index(&self) -> usize {
+		self.parent_selection
+	}
+
+	///
+	pub fn selected_parent(&self) -> Option<CommitId> {
+		self.data.as_ref().and_then(|data| {
+			data.parents.get(self.parent_selection).copied()
+		})
+	}
+
+	/// cycles through the parents of a merge commit
+	pub fn select_next_parent(&mut self) -> bool {
+		let count = self.parent_count();
+		if count > 1 {
+			self.parent_selection =
+				(self.parent_selection + 1) % count;
+			return true;
+		}
+
+		false
+	}
+
 	fn wrap_commit_details(
 		message: &CommitMessage,
 		width: usize,
@@ -152,6 +194,22 @@ impl DetailsComponent {
 	#[allow(unstable_name_collisions, clippy::too_many_lines)]
 	fn get_text_info(&self) -> Vec<Spans> {
 		self.data.as_ref().map_or_else(Vec::new, |data| {
+			let now = Local::now().timestamp();
+			let time = |secs: i64| {
+				strings::commit::details_time(
+					&time_to_string(secs, false),
+					&time_relative_string(secs, now, false),
+				)
+			};
+
+			// author and committer times differ after a rebase or
+			// amend, then both get labeled
+			let author_date = if data.committer.is_some() {
+				Detail::AuthorDate
+			} else {
+				Detail::Date
+			};
+
 			let mut res = vec![
 				Spans::from(vec![
 					style_detail(&self.theme, &Detail::Author),
@@ -164,12 +222,9 @@ impl DetailsComponent {
 					),
 				]),
 				Spans::from(vec![
-					style_detail(&self.theme, &Detail::Date),
+					style_detail(&self.theme, &author_date),
 					Span::styled(
-						Cow::from(time_to_string(
-							data.author.time,
-							false,
-						)),
+						Cow::from(time(data.author.time)),
 						self.theme.text(true, false),
 					),
 				]),
@@ -188,12 +243,12 @@ impl DetailsComponent {
 						),
 					]),
 					Spans::from(vec![
-						style_detail(&self.theme, &Detail::Date),
+						style_detail(
+							&self.theme,
+							&Detail::CommitDate,
+						),
 						Span::styled(
-							Cow::from(time_to_string(
-								committer.time,
-								false,
-							)),
+							Cow::from(time(committer.time)),
 							self.theme.text(true, false),
 						),
 					]),
@@ -211,6 +266,32 @@ impl DetailsComponent {
 				),
 			]));
 
+			if !data.parents.is_empty() {
+				let is_merge = data.parents.len() > 1;
+				let mut parents = vec![Span::styled(
+					Cow::from(strings::commit::details_parents()),
+					self.theme.text(false, false),
+				)];
+
+				// the highlighted one is what `commit_goto_parent`
+				// jumps to
+				parents.extend(itertools::Itertools::intersperse(
+					data.parents.iter().enumerate().map(|(i, id)| {
+						Span::styled(
+							Cow::from(id.get_short_string()),
+							self.theme.text(
+								true,
+								is_merge
+									&& i == self.parent_selection,
+							),
+						)
+					}),
+					Span::raw(Cow::from(" ")),
+				));
+
+				res.push(Spans::from(parents));
+			}
+
 			if !self.tags.is_empty() {
 				res.push(Spans::from(style_detail(
 					&self.theme,
@@ -259,7 +340,7 @@ impl DrawableComponent for DetailsComponent {
 		let chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				[Constraint::Length(8), Constraint::Min(10)].as_ref(),
+				[Constraint::Length(9), Constraint::Min(10)].as_ref(),
 			)
 			.split(rect);
 
@@ -12,8 +12,10 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
-	sync::CommitTags, AsyncCommitFiles, AsyncGitNotification,
-	CommitFilesParams,
+	asyncjob::AsyncSingleJob,
+	diff_stats::AsyncCommitDiffStatsJob,
+	sync::{CommitId, CommitTags, DiffStats},
+	AsyncCommitFiles, AsyncGitNotification, CommitFilesParams,
 };
 use compare_details::CompareDetailsComponent;
 use crossbeam_channel::Sender;
@@ -22,6 +24,7 @@ use details::DetailsComponent;
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Direction, Layout, Rect},
+	widgets::Paragraph,
 	Frame,
 };
 
@@ -31,7 +34,12 @@ pub struct CommitDetailsComponent {
 	compare_details: CompareDetailsComponent,
 	file_tree: FileTreeComponent,
 	git_commit_files: AsyncCommitFiles,
+	stats: Option<(CommitFilesParams, DiffStats)>,
+	/// params of the last stats job spawned
+	stats_requested: Option<CommitFilesParams>,
+	git_stats: AsyncSingleJob<AsyncCommitDiffStatsJob>,
 	visible: bool,
+	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 }
 
@@ -60,11 +68,15 @@ impl CommitDetailsComponent {
 				"",
 				false,
 				Some(queue.clone()),
-				theme,
+				theme.clone(),
 				key_config.clone(),
 			),
+			stats: None,
+			stats_requested: None,
+			git_stats: AsyncSingleJob::new(sender.clone()),
 			visible: false,
 			commit: None,
+			theme,
 			key_config,
 		}
 	}
@@ -92,14 +104,20 @@ impl CommitDetailsComponent {
 
 		self.commit = params;
 
-		if let Some(id) = params {
+		if let Some(mut id) = params {
 			if let Some(other) = id.other {
 				self.compare_details
 					.set_commits(Some((id.id, other)));
 			} else {
 				self.single_details.set_commit(Some(id.id), tags);
+
+				// the files stay diffed against the picked parent
+				id.parent = self.single_details.parent_index();
+				self.commit = Some(id);
 			}
 
+			self.update_stats(id);
+
 			if let Some((fetched_id, res)) =
 				self.git_commit_files.current()?
 			{
@@ -120,9 +138,31 @@ impl CommitDetailsComponent {
 		Ok(())
 	}
 
+	fn update_stats(&mut self, params: CommitFilesParams) {
+		if self.stats_requested != Some(params) {
+			self.stats_requested = Some(params);
+			self.git_stats
+				.spawn(AsyncCommitDiffStatsJob::new(params));
+		}
+	}
+
+	/// takes the stats of a finished job
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		if ev == AsyncGitNotification::CommitDiffStats {
+			if let Some((params, Ok(stats))) = self
+				.git_stats
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				self.stats = Some((params, stats));
+			}
+		}
+	}
+
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.git_commit_files.is_pending()
+			|| self.git_stats.is_pending()
 	}
 
 	///
@@ -130,6 +170,49 @@ impl CommitDetailsComponent {
 		&self.file_tree
 	}
 
+	/// parents of the single commit shown, none when comparing
+	pub fn parent_count(&self) -> usize {
+		if self.is_compare() {
+			0
+		} else {
+			self.single_details.parent_count()
+		}
+	}
+
+	/// see `DetailsComponent::selected_parent`
+	pub fn selected_parent(&self) -> Option<CommitId> {
+		if self.is_compare() {
+			None
+		} else {
+			self.single_details.selected_parent()
+		}
+	}
+
+	/// see `DetailsComponent::parent_index`
+	pub fn parent_index(&self) -> usize {
+		self.commit.map_or(0, |params| params.parent)
+	}
+
+	/// the files and stats then compare to the newly picked parent
+	pub fn select_next_parent(&mut self) -> Result<bool> {
+		if self.is_compare()
+			|| !self.single_details.select_next_parent()
+		{
+			return Ok(false);
+		}
+
+		if let Some(mut params) = self.commit {
+			params.parent = self.single_details.parent_index();
+			self.commit = Some(params);
+
+			self.update_stats(params);
+			self.file_tree.clear()?;
+			self.git_commit_files.fetch(params)?;
+		}
+
+		Ok(true)
+	}
+
 	fn details_focused(&self) -> bool {
 		self.single_details.focused()
 			|| self.compare_details.focused()
@@ -182,7 +265,30 @@ impl DrawableComponent for CommitDetailsComponent {
 		} else {
 			self.single_details.draw(f, chunks[0])?;
 		}
-		self.file_tree.draw(f, chunks[1])?;
+
+		let stats = self
+			.stats
+			.filter(|(params, _)| Some(*params) == self.commit)
+			.map(|(_, stats)| stats);
+
+		if let Some(stats) = stats {
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[Constraint::Length(1), Constraint::Min(0)]
+						.as_ref(),
+				)
+				.split(chunks[1]);
+
+			f.render_widget(
+				Paragraph::new(strings::diff_stats(&stats))
+					.style(self.theme.text(true, false)),
+				chunks[0],
+			);
+			self.file_tree.draw(f, chunks[1])?;
+		} else {
+			self.file_tree.draw(f, chunks[1])?;
+		}
 
 		Ok(())
 	}
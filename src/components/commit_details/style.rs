@@ -5,6 +5,8 @@ use tui::text::Span;
 pub enum Detail {
 	Author,
 	Date,
+	AuthorDate,
+	CommitDate,
 	Commiter,
 	Sha,
 	Message,
@@ -23,6 +25,14 @@ pub fn style_detail<'a>(
 			Cow::from(strings::commit::details_date()),
 			theme.text(false, false),
 		),
+		Detail::AuthorDate => Span::styled(
+			Cow::from(strings::commit::details_author_date()),
+			theme.text(false, false),
+		),
+		Detail::CommitDate => Span::styled(
+			Cow::from(strings::commit::details_commit_date()),
+			theme.text(false, false),
+		),
 		Detail::Commiter => Span::styled(
 			Cow::from(strings::commit::details_committer()),
 			theme.text(false, false),
@@ -3,6 +3,7 @@ use crate::{
 	components::{
 		utils::string_width_align, CommandBlocking, CommandInfo,
 		Component, DrawableComponent, EventState, ScrollType,
+		SharedOptions,
 	},
 	keys::SharedKeyConfig,
 	strings::{self, symbol},
@@ -10,11 +11,16 @@ use crate::{
 	ui::{calc_scroll_top, draw_scrollbar},
 };
 use anyhow::Result;
-use asyncgit::sync::{CommitId, Tags};
+use asyncgit::sync::{CommitId, SignatureStatus, Tags};
 use chrono::{DateTime, Local};
 use crossterm::event::Event;
 use std::{
-	borrow::Cow, cell::Cell, cmp, convert::TryFrom, time::Instant,
+	borrow::Cow,
+	cell::Cell,
+	cmp,
+	collections::{HashMap, HashSet},
+	convert::TryFrom,
+	time::Instant,
 };
 use tui::{
 	backend::Backend,
@@ -24,7 +30,7 @@ use tui::{
 	Frame,
 };
 
-const ELEMENTS_PER_LINE: usize = 9;
+const ELEMENTS_PER_LINE: usize = 11;
 
 ///
 pub struct CommitList {
@@ -36,10 +42,14 @@ pub struct CommitList {
 	marked: Vec<CommitId>,
 	scroll_state: (Instant, f32),
 	tags: Option<Tags>,
+	signatures: HashMap<CommitId, SignatureStatus>,
+	/// commits of a shallow clone whose history was cut off
+	shallow_boundaries: HashSet<CommitId>,
 	current_size: Cell<(u16, u16)>,
 	scroll_top: Cell<usize>,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl CommitList {
@@ -48,6 +58,7 @@ impl CommitList {
 		title: &str,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			items: ItemBatch::default(),
@@ -57,10 +68,13 @@ impl CommitList {
 			count_total: 0,
 			scroll_state: (Instant::now(), 0_f32),
 			tags: None,
+			signatures: HashMap::new(),
+			shallow_boundaries: HashSet::new(),
 			current_size: Cell::new((0, 0)),
 			scroll_top: Cell::new(0),
 			theme,
 			key_config,
+			options,
 			title: title.into(),
 		}
 	}
@@ -85,6 +99,11 @@ impl CommitList {
 		self.current_size.get()
 	}
 
+	///
+	pub fn set_title(&mut self, title: &str) {
+		self.title = title.into();
+	}
+
 	///
 	pub fn set_count_total(&mut self, total: usize) {
 		self.count_total = total;
@@ -113,6 +132,33 @@ impl CommitList {
 		self.tags = Some(tags);
 	}
 
+	///
+	pub fn set_shallow_boundaries(
+		&mut self,
+		boundaries: HashSet<CommitId>,
+	) {
+		self.shallow_boundaries = boundaries;
+	}
+
+	/// caches verified signatures by `CommitId`
+	pub fn set_signatures(
+		&mut self,
+		signatures: Vec<(CommitId, SignatureStatus)>,
+	) {
+		self.signatures.extend(signatures);
+	}
+
+	/// ids of the currently visible commits that are not verified yet
+	pub fn visible_unverified(&self) -> Vec<CommitId> {
+		self.items
+			.iter()
+			.skip(self.scroll_top.get())
+			.take(self.current_size.get().1.into())
+			.map(|e| e.id)
+			.filter(|id| !self.signatures.contains_key(id))
+			.collect()
+	}
+
 	///
 	pub fn selected_entry(&self) -> Option<&LogEntry> {
 		self.items.iter().nth(
@@ -137,13 +183,20 @@ impl CommitList {
 		&self.marked
 	}
 
-	pub fn copy_entry_hash(&self) -> Result<()> {
-		if let Some(e) = self.items.iter().nth(
-			self.selection.saturating_sub(self.items.index_offset()),
-		) {
-			crate::clipboard::copy_string(&e.hash_short)?;
-		}
-		Ok(())
+	///
+	pub fn clear_marked(&mut self) {
+		self.marked.clear();
+	}
+
+	/// hash of the selected entry, full 40 chars or short
+	pub fn selected_entry_hash(&self, full: bool) -> Option<String> {
+		self.selected_entry().map(|e| {
+			if full {
+				e.id.to_string()
+			} else {
+				e.hash_short.to_string()
+			}
+		})
 	}
 
 	fn move_selection(&mut self, scroll: ScrollType) -> Result<bool> {
@@ -226,17 +279,23 @@ impl CommitList {
 		}
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	fn get_entry_to_add<'a>(
 		e: &'a LogEntry,
 		selected: bool,
 		tags: Option<String>,
+		signature: Option<SignatureStatus>,
 		theme: &Theme,
 		width: usize,
 		now: DateTime<Local>,
+		relative_time: bool,
 		marked: Option<bool>,
+		shallow_boundary: bool,
 	) -> Spans<'a> {
 		let mut txt: Vec<Span> = Vec::with_capacity(
-			ELEMENTS_PER_LINE + if marked.is_some() { 2 } else { 0 },
+			ELEMENTS_PER_LINE
+				+ if marked.is_some() { 2 } else { 0 }
+				+ usize::from(shallow_boundary),
 		);
 
 		let splitter_txt = Cow::from(symbol::EMPTY_SPACE);
@@ -264,16 +323,33 @@ impl CommitList {
 
 		txt.push(splitter.clone());
 
+		// signature badge
+		txt.push(Span::styled(
+			Cow::from(match signature {
+				Some(SignatureStatus::Good) => symbol::CHECKMARK,
+				Some(SignatureStatus::Bad) => symbol::CROSSMARK,
+				Some(SignatureStatus::Unverifiable) => {
+					symbol::QUESTION_MARK
+				}
+				Some(SignatureStatus::None) | None => {
+					symbol::EMPTY_SPACE
+				}
+			}),
+			theme.commit_signature(signature, selected),
+		));
+
+		txt.push(splitter.clone());
+
 		// commit timestamp
 		txt.push(Span::styled(
-			Cow::from(e.time_to_string(now)),
+			Cow::from(e.time_to_string(now, relative_time)),
 			theme.commit_time(selected),
 		));
 
 		txt.push(splitter.clone());
 
 		let author_width =
-			(width.saturating_sub(19) / 3).max(3).min(20);
+			(width.saturating_sub(21) / 3).max(3).min(20);
 		let author = string_width_align(&e.author, author_width);
 
 		// commit author
@@ -295,6 +371,13 @@ impl CommitList {
 
 		txt.push(splitter);
 
+		if shallow_boundary {
+			txt.push(Span::styled(
+				Cow::from(strings::log_shallow_boundary()),
+				theme.tags(selected),
+			));
+		}
+
 		// commit msg
 		txt.push(Span::styled(
 			Cow::from(&*e.msg),
@@ -309,7 +392,9 @@ impl CommitList {
 
 		let mut txt: Vec<Spans> = Vec::with_capacity(height);
 
+		// drawn again on every clock tick to keep the age current
 		let now = Local::now();
+		let relative_time = self.options.borrow().log_relative_time;
 
 		let any_marked = !self.marked.is_empty();
 
@@ -336,10 +421,13 @@ impl CommitList {
 				e,
 				idx + self.scroll_top.get() == selection,
 				tags,
+				self.signatures.get(&e.id).copied(),
 				&self.theme,
 				width,
 				now,
+				relative_time,
 				marked,
+				self.shallow_boundaries.contains(&e.id),
 			));
 		}
 
@@ -442,6 +530,10 @@ impl Component for CommitList {
 			} else if k == self.key_config.log_mark_commit {
 				self.mark();
 				true
+			} else if k == self.key_config.log_toggle_relative_time {
+				let old = self.options.borrow().log_relative_time;
+				self.options.borrow_mut().log_relative_time = !old;
+				return Ok(EventState::Consumed);
 			} else {
 				false
 			};
@@ -469,6 +561,14 @@ impl Component for CommitList {
 			true,
 			true,
 		));
+		out.push(CommandInfo::new(
+			strings::commands::commit_list_relative_time(
+				&self.key_config,
+				self.options.borrow().log_relative_time,
+			),
+			true,
+			true,
+		));
 		CommandBlocking::PassingOn
 	}
 }
@@ -498,4 +598,14 @@ mod tests {
 			"Jon Grythe Stødle  "
 		);
 	}
+
+	#[test]
+	fn test_string_width_align_wide_chars() {
+		assert_eq!(string_width_align("山田太郎", 8), "山田太郎");
+		assert_eq!(string_width_align("山田太郎", 9), "山田太郎 ");
+		// a wide char never gets cut in half
+		assert_eq!(string_width_align("山田太郎", 7), "山田.. ");
+		assert_eq!(string_width_align("👍👍👍", 5), "👍.. ");
+		assert_eq!(string_width_align("a👍b", 4), "a👍b");
+	}
 }
@@ -0,0 +1,505 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings, try_or_popup,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{
+		branch_compare_commits, get_branches_info, get_commits_info,
+		BranchInfo, CommitInfo,
+	},
+	CWD,
+};
+use crossterm::event::Event;
+use std::cell::Cell;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
+	widgets::{
+		Block, BorderType, Borders, Clear, Paragraph, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+/// max number of commits listed per side
+const COMMITS_LIMIT: usize = 100;
+const MESSAGE_LENGTH_LIMIT: usize = 100;
+
+///
+pub struct CompareBranchesComponent {
+	theme: SharedTheme,
+	queue: Queue,
+	/// local followed by remote branches
+	branches: Vec<BranchInfo>,
+	/// indices into `branches` of both sides
+	left: Option<usize>,
+	right: Option<usize>,
+	/// commits only on the left and only on the right side
+	commits: (Vec<CommitInfo>, Vec<CommitInfo>),
+	visible: bool,
+	table_state: Cell<TableState>,
+	current_height: Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for CompareBranchesComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 70);
+			const MIN_SIZE: Size = Size::new(60, 24);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			f.render_widget(Clear, area);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Percentage(50),
+						Constraint::Percentage(50),
+					]
+					.as_ref(),
+				)
+				.split(area);
+
+			self.draw_branches(f, chunks[0]);
+
+			let sides = Layout::default()
+				.direction(Direction::Horizontal)
+				.constraints(
+					[
+						Constraint::Percentage(50),
+						Constraint::Percentage(50),
+					]
+					.as_ref(),
+				)
+				.split(chunks[1]);
+
+			self.draw_commits(
+				f,
+				sides[0],
+				self.left,
+				&self.commits.0,
+			);
+			self.draw_commits(
+				f,
+				sides[1],
+				self.right,
+				&self.commits.1,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for CompareBranchesComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::compare_branches_sides(
+					&self.key_config,
+				),
+				!self.branches.is_empty(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::compare_branches_diff(
+					&self.key_config,
+				),
+				self.can_diff(),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.move_left {
+					self.left = self.selection();
+					try_or_popup!(
+						self,
+						"compare branches error:",
+						self.update_commits()
+					);
+				} else if key == self.key_config.move_right {
+					self.right = self.selection();
+					try_or_popup!(
+						self,
+						"compare branches error:",
+						self.update_commits()
+					);
+				} else if key == self.key_config.enter
+					&& self.can_diff()
+				{
+					if let (Some(left), Some(right)) =
+						(self.left_branch(), self.right_branch())
+					{
+						self.queue.push(
+							InternalEvent::CompareCommits(
+								left.top_commit,
+								Some(right.top_commit),
+							),
+						);
+						self.hide();
+					}
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl CompareBranchesComponent {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			branches: Vec::new(),
+			left: None,
+			right: None,
+			commits: (Vec::new(), Vec::new()),
+			visible: false,
+			table_state: Cell::new(TableState::default()),
+			current_height: Cell::new(0),
+			key_config,
+		}
+	}
+
+	/// compares the current branch with the branch `reference`
+	/// points to
+	pub fn open(&mut self, reference: &str) -> Result<()> {
+		self.branches = get_branches_info(CWD, true)?;
+		self.branches.extend(get_branches_info(CWD, false)?);
+
+		self.left = self.branches.iter().position(is_head);
+		self.right = self
+			.branches
+			.iter()
+			.position(|b| b.reference == reference);
+
+		self.table_state.get_mut().select(Some(
+			self.right.or(self.left).unwrap_or_default(),
+		));
+
+		self.update_commits()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_commits(&mut self) -> Result<()> {
+		self.commits = match (self.left_branch(), self.right_branch())
+		{
+			(Some(left), Some(right)) => {
+				let (left, right) = branch_compare_commits(
+					CWD,
+					left.top_commit,
+					right.top_commit,
+					COMMITS_LIMIT,
+				)?;
+
+				(
+					get_commits_info(
+						CWD,
+						&left,
+						MESSAGE_LENGTH_LIMIT,
+					)?,
+					get_commits_info(
+						CWD,
+						&right,
+						MESSAGE_LENGTH_LIMIT,
+					)?,
+				)
+			}
+			_ => (Vec::new(), Vec::new()),
+		};
+
+		Ok(())
+	}
+
+	fn left_branch(&self) -> Option<&BranchInfo> {
+		self.left.and_then(|idx| self.branches.get(idx))
+	}
+
+	fn right_branch(&self) -> Option<&BranchInfo> {
+		self.right.and_then(|idx| self.branches.get(idx))
+	}
+
+	/// both sides are set and point to different commits
+	fn can_diff(&self) -> bool {
+		match (self.left_branch(), self.right_branch()) {
+			(Some(left), Some(right)) => {
+				left.top_commit != right.top_commit
+			}
+			_ => false,
+		}
+	}
+
+	fn selection(&self) -> Option<usize> {
+		let table_state = self.table_state.take();
+		let selection = table_state
+			.selected()
+			.filter(|idx| *idx < self.branches.len());
+		self.table_state.set(table_state);
+
+		selection
+	}
+
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.branches.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	fn draw_branches<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) {
+		const LEFT_SYMBOL: &str = "<";
+		const RIGHT_SYMBOL: &str = ">";
+		const EMPTY_SYMBOL: &str = " ";
+
+		let constraints = [
+			// symbols of the sides the branch is on
+			Constraint::Length(2),
+			Constraint::Percentage(40),
+			Constraint::Percentage(60),
+		];
+
+		let rows = self
+			.branches
+			.iter()
+			.enumerate()
+			.map(|(idx, branch)| {
+				let side = |side: Option<usize>, symbol| {
+					if side == Some(idx) {
+						symbol
+					} else {
+						EMPTY_SYMBOL
+					}
+				};
+
+				Row::new(vec![
+					Span::styled(
+						format!(
+							"{}{}",
+							side(self.left, LEFT_SYMBOL),
+							side(self.right, RIGHT_SYMBOL)
+						),
+						self.theme.commit_author(false),
+					),
+					Span::styled(
+						branch.name.clone(),
+						self.theme.branch(false, is_head(branch)),
+					),
+					Span::styled(
+						branch.top_commit_message.clone(),
+						self.theme.text(true, false),
+					),
+				])
+			})
+			.collect::<Vec<_>>();
+
+		let number_of_rows = rows.len();
+
+		let table = Table::new(rows)
+			.widths(&constraints)
+			.column_spacing(1)
+			.highlight_style(self.theme.text(true, true))
+			.block(
+				Block::default()
+					.borders(Borders::ALL)
+					.title(Span::styled(
+						strings::title_compare_branches(),
+						self.theme.title(true),
+					))
+					.border_style(self.theme.block(true))
+					.border_type(BorderType::Thick),
+			);
+
+		let mut table_state = self.table_state.take();
+
+		f.render_stateful_widget(table, area, &mut table_state);
+
+		let area = area.inner(&Margin {
+			vertical: 1,
+			horizontal: 0,
+		});
+
+		ui::draw_scrollbar(
+			f,
+			area,
+			&self.theme,
+			number_of_rows,
+			table_state.selected().unwrap_or(0),
+		);
+
+		self.table_state.set(table_state);
+		self.current_height.set(area.height.into());
+	}
+
+	fn draw_commits<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+		side: Option<usize>,
+		commits: &[CommitInfo],
+	) {
+		let title = side
+			.and_then(|idx| self.branches.get(idx))
+			.map_or_else(String::new, |branch| {
+				strings::compare_branches_only_in(
+					&branch.name,
+					commits.len(),
+					COMMITS_LIMIT,
+				)
+			});
+
+		let lines = commits
+			.iter()
+			.map(|commit| {
+				Spans::from(vec![
+					Span::styled(
+						commit.id.get_short_string(),
+						self.theme.commit_hash(false),
+					),
+					Span::raw(" "),
+					Span::styled(
+						commit.message.clone(),
+						self.theme.text(true, false),
+					),
+				])
+			})
+			.collect::<Vec<_>>();
+
+		f.render_widget(
+			Paragraph::new(lines).block(
+				Block::default()
+					.borders(Borders::ALL)
+					.title(Span::styled(
+						title,
+						self.theme.title(false),
+					))
+					.border_style(self.theme.block(false)),
+			),
+			area,
+		);
+	}
+}
+
+fn is_head(branch: &BranchInfo) -> bool {
+	branch
+		.local_details()
+		.map_or(false, |details| details.is_head)
+}
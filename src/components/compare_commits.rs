@@ -1,7 +1,7 @@
 use super::{
 	command_pump, event_pump, visibility_blocking, CommandBlocking,
 	CommandInfo, CommitDetailsComponent, Component, DiffComponent,
-	DrawableComponent, EventState,
+	DrawableComponent, EventState, SharedOptions,
 };
 use crate::{
 	accessors, keys::SharedKeyConfig, queue::Queue, strings,
@@ -160,6 +160,7 @@ impl CompareCommitsComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			details: CommitDetailsComponent::new(
@@ -172,6 +173,7 @@ impl CompareCommitsComponent {
 				queue.clone(),
 				theme,
 				key_config.clone(),
+				options,
 				true,
 			),
 			commit_ids: None,
@@ -213,12 +215,23 @@ impl CompareCommitsComponent {
 				self.update()?;
 			} else if let AsyncGitNotification::Diff = ev {
 				self.update_diff()?;
+			} else if let AsyncGitNotification::CommitDiffStats = ev {
+				self.details.update_git(ev);
 			}
 		}
 
 		Ok(())
 	}
 
+	/// see `DiffComponent::search`
+	pub fn search_diff(
+		&mut self,
+		query: String,
+		case_sensitive: bool,
+	) {
+		self.diff.search(query, case_sensitive);
+	}
+
 	/// called when any tree component changed selection
 	pub fn update_diff(&mut self) -> Result<()> {
 		if self.is_visible() {
@@ -240,8 +253,13 @@ impl CompareCommitsComponent {
 						}
 					}
 
-					self.git_diff.request(diff_params)?;
-					self.diff.clear(true);
+					if let Some(diff) =
+						self.git_diff.request(diff_params)?
+					{
+						self.diff.update(f.path, false, diff);
+					} else {
+						self.diff.clear(true);
+					}
 					return Ok(());
 				}
 			}
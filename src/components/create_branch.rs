@@ -10,7 +10,10 @@ use crate::{
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
-use asyncgit::{sync, CWD};
+use asyncgit::{
+	sync::{self, CommitId},
+	CWD,
+};
 use crossterm::event::Event;
 use easy_cast::Cast;
 use tui::{
@@ -19,6 +22,8 @@ use tui::{
 
 pub struct CreateBranchComponent {
 	input: TextInputComponent,
+	/// commit to branch off, `HEAD` if `None`
+	start: Option<CommitId>,
 	queue: Queue,
 	key_config: SharedKeyConfig,
 	theme: SharedTheme,
@@ -108,13 +113,19 @@ impl CreateBranchComponent {
 				&strings::create_branch_popup_msg(&key_config),
 				true,
 			),
+			start: None,
 			theme,
 			key_config,
 		}
 	}
 
-	///
-	pub fn open(&mut self) -> Result<()> {
+	/// branching off `HEAD` checks out the new branch
+	pub fn open(&mut self, start: Option<CommitId>) -> Result<()> {
+		self.input.set_title(start.map_or_else(
+			|| strings::create_branch_popup_title(&self.key_config),
+			|id| strings::create_branch_at_popup_title(&id),
+		));
+		self.start = start;
 		self.show()?;
 
 		Ok(())
@@ -122,7 +133,11 @@ impl CreateBranchComponent {
 
 	///
 	pub fn create_branch(&mut self) {
-		let res = sync::create_branch(CWD, self.input.get_text());
+		let name = self.input.get_text();
+		let res = self.start.map_or_else(
+			|| sync::create_branch(CWD, name),
+			|id| sync::create_branch_at(CWD, name, id),
+		);
 
 		self.input.clear();
 		self.hide();
@@ -0,0 +1,200 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, utils::repo_work_dir},
+	CWD,
+};
+use crossterm::event::Event;
+use std::path::{Path, PathBuf};
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// the input currently asked for
+#[derive(Copy, Clone, PartialEq)]
+enum Step {
+	Branch,
+	Path,
+}
+
+pub struct CreateWorktreeComponent {
+	input: TextInputComponent,
+	step: Step,
+	branch: String,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for CreateWorktreeComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for CreateWorktreeComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::create_worktree_confirm_msg(
+					&self.key_config,
+					self.step == Step::Path,
+				),
+				!self.input.get_text().trim().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter
+					&& !self.input.get_text().trim().is_empty()
+				{
+					match self.step {
+						Step::Branch => self.ask_for_path(),
+						Step::Path => self.create_worktree(),
+					}
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl CreateWorktreeComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::create_worktree_branch_title(&key_config),
+				&strings::create_worktree_branch_msg(&key_config),
+				true,
+			),
+			step: Step::Branch,
+			branch: String::new(),
+			key_config,
+		}
+	}
+
+	/// asks for the branch to check out, prefilled with `branch`
+	pub fn open(&mut self, branch: String) -> Result<()> {
+		self.step = Step::Branch;
+		self.input.set_title(strings::create_worktree_branch_title(
+			&self.key_config,
+		));
+		self.input.set_default_msg(
+			strings::create_worktree_branch_msg(&self.key_config),
+		);
+		self.input.set_text(branch);
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn ask_for_path(&mut self) {
+		self.branch = self.input.get_text().trim().to_string();
+		self.step = Step::Path;
+
+		self.input.set_title(strings::create_worktree_path_title(
+			&self.key_config,
+		));
+		self.input.set_default_msg(
+			strings::create_worktree_path_msg(&self.key_config),
+		);
+		self.input.set_text(
+			Self::default_path(&self.branch)
+				.map(|p| p.to_string_lossy().to_string())
+				.unwrap_or_default(),
+		);
+	}
+
+	/// sibling of the current workdir named after repo and branch
+	fn default_path(branch: &str) -> Option<PathBuf> {
+		let work_dir = repo_work_dir(CWD).ok()?;
+		let work_dir = Path::new(&work_dir);
+		let repo_name = work_dir.file_name()?.to_string_lossy();
+
+		Some(work_dir.parent()?.join(format!(
+			"{}-{}",
+			repo_name,
+			branch.replace('/', "-")
+		)))
+	}
+
+	fn create_worktree(&mut self) {
+		let path = PathBuf::from(self.input.get_text().trim());
+		let name = path
+			.file_name()
+			.map(|name| name.to_string_lossy().to_string())
+			.unwrap_or_else(|| self.branch.replace('/', "-"));
+
+		let res = sync::add_worktree(CWD, &name, &path, &self.branch);
+
+		self.input.clear();
+		self.hide();
+
+		match res {
+			Ok(_) => {
+				self.queue.push(InternalEvent::Update(
+					NeedsUpdate::BRANCHES,
+				));
+			}
+			Err(e) => {
+				log::error!("create worktree: {}", e,);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("create worktree error:\n{}", e,),
+				));
+			}
+		}
+	}
+}
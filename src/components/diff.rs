@@ -1,19 +1,19 @@
 use super::{
 	utils::scroll_vertical::VerticalScroll, CommandBlocking,
-	Direction, DrawableComponent, ScrollType,
+	Direction, DrawableComponent, ScrollType, SharedOptions,
 };
 use crate::{
 	components::{CommandInfo, Component, EventState},
 	keys::SharedKeyConfig,
 	queue::{Action, InternalEvent, NeedsUpdate, Queue, ResetItem},
-	string_utils::tabs_to_spaces,
+	string_utils::{find_all, skip_columns, tabs_to_spaces},
 	strings, try_or_popup,
 	ui::style::SharedTheme,
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use asyncgit::{
 	hash,
-	sync::{self, diff::DiffLinePosition},
+	sync::{self, diff::DiffLinePosition, CommitId, PatchSource},
 	DiffLine, DiffLineType, FileDiff, CWD,
 };
 use bytesize::ByteSize;
@@ -27,6 +27,23 @@ use tui::{
 	widgets::{Block, Borders, Paragraph},
 	Frame,
 };
+use unicode_width::UnicodeWidthStr;
+
+/// columns to shift the diff content per horizontal scroll step
+const HORIZONTAL_SCROLL_STEP: usize = 4;
+
+struct Search {
+	query: String,
+	case_sensitive: bool,
+	/// indices of the lines containing `query`
+	matches: Vec<usize>,
+}
+
+impl Search {
+	fn position(&self, line: usize) -> Option<usize> {
+		self.matches.iter().position(|m| *m == line)
+	}
+}
 
 #[derive(Default)]
 struct Current {
@@ -108,10 +125,17 @@ pub struct DiffComponent {
 	focused: bool,
 	current: Current,
 	scroll: VerticalScroll,
+	horizontal_scroll: usize,
+	max_horizontal_scroll: Cell<usize>,
+	search: Option<Search>,
+	/// diffs above `diff_max_lines` stay collapsed until loaded
+	large_diff_loaded: bool,
 	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 	is_immutable: bool,
+	commit: Option<CommitId>,
 }
 
 impl DiffComponent {
@@ -120,6 +144,7 @@ impl DiffComponent {
 		queue: Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 		is_immutable: bool,
 	) -> Self {
 		Self {
@@ -132,11 +157,26 @@ impl DiffComponent {
 			current_size: Cell::new((0, 0)),
 			selection: Selection::Single(0),
 			scroll: VerticalScroll::new(),
+			horizontal_scroll: 0,
+			max_horizontal_scroll: Cell::new(0),
+			search: None,
+			large_diff_loaded: false,
 			theme,
 			key_config,
+			options,
 			is_immutable,
+			commit: None,
 		}
 	}
+	/// rendering huge diffs blocks the ui, they are only shown
+	/// once requested
+	fn is_large_diff_hidden(&self) -> bool {
+		let max_lines = self.options.borrow().diff_max_lines;
+
+		!self.large_diff_loaded
+			&& max_lines > 0
+			&& self.lines_count() > max_lines
+	}
 	///
 	fn can_scroll(&self) -> bool {
 		self.diff
@@ -148,14 +188,22 @@ impl DiffComponent {
 	pub fn current(&self) -> (String, bool) {
 		(self.current.path.clone(), self.current.is_stage)
 	}
+	/// commit the shown diff belongs to, exporting a patch then
+	/// writes the whole commit
+	pub fn set_commit(&mut self, commit: Option<CommitId>) {
+		self.commit = commit;
+	}
 	///
 	pub fn clear(&mut self, pending: bool) {
 		self.current = Current::default();
 		self.diff = None;
 		self.scroll.reset();
+		self.horizontal_scroll = 0;
 		self.selection = Selection::Single(0);
 		self.selected_hunk = None;
+		self.large_diff_loaded = false;
 		self.pending = pending;
+		self.update_search_matches();
 	}
 	///
 	pub fn update(
@@ -180,7 +228,9 @@ impl DiffComponent {
 			self.diff = Some(diff);
 
 			if reset_selection {
+				self.large_diff_loaded = false;
 				self.scroll.reset();
+				self.horizontal_scroll = 0;
 				self.selection = Selection::Single(0);
 				self.update_selection(0);
 			} else {
@@ -190,9 +240,97 @@ impl DiffComponent {
 				};
 				self.update_selection(old_selection);
 			}
+
+			self.update_search_matches();
 		}
 	}
 
+	/// highlights all lines containing `query` and selects the
+	/// first match from the current selection on,
+	/// an empty `query` ends the search
+	pub fn search(&mut self, query: String, case_sensitive: bool) {
+		if query.is_empty() {
+			self.search = None;
+			return;
+		}
+
+		self.search = Some(Search {
+			query,
+			case_sensitive,
+			matches: Vec::new(),
+		});
+		self.update_search_matches();
+
+		let start = self.selection.get_start();
+		let first_match = self.search.as_ref().and_then(|search| {
+			search
+				.matches
+				.iter()
+				.find(|m| **m >= start)
+				.or_else(|| search.matches.first())
+				.copied()
+		});
+
+		if let Some(line) = first_match {
+			self.update_selection(line);
+		}
+	}
+
+	fn update_search_matches(&mut self) {
+		if let Some(search) = self.search.as_mut() {
+			search.matches =
+				self.diff.as_ref().map_or_else(Vec::new, |diff| {
+					diff.hunks
+						.iter()
+						.flat_map(|hunk| hunk.lines.iter())
+						.enumerate()
+						.filter(|(_, line)| {
+							!find_all(
+								&line.content,
+								&search.query,
+								search.case_sensitive,
+							)
+							.is_empty()
+						})
+						.map(|(idx, _)| idx)
+						.collect()
+				});
+		}
+	}
+
+	fn move_to_match(&mut self, forward: bool) {
+		let current = self.selection.get_start();
+
+		let next = self.search.as_ref().and_then(|search| {
+			if forward {
+				search
+					.matches
+					.iter()
+					.find(|m| **m > current)
+					.or_else(|| search.matches.first())
+					.copied()
+			} else {
+				search
+					.matches
+					.iter()
+					.rev()
+					.find(|m| **m < current)
+					.or_else(|| search.matches.last())
+					.copied()
+			}
+		});
+
+		if let Some(line) = next {
+			self.update_selection(line);
+		}
+	}
+
+	fn has_search_matches(&self) -> bool {
+		self.search
+			.as_ref()
+			.map_or(false, |search| !search.matches.is_empty())
+	}
+
 	fn move_selection(&mut self, move_type: ScrollType) {
 		if let Some(diff) = &self.diff {
 			let max = diff.lines.saturating_sub(1) as usize;
@@ -272,6 +410,78 @@ impl DiffComponent {
 		}
 	}
 
+	/// copies the forge link to the selected line as of the shown
+	/// commit, or `HEAD` for changes in the working dir
+	fn copy_permalink(&self) {
+		try_or_popup!(
+			self,
+			"copy link error:",
+			self.permalink().and_then(|url| {
+				crate::clipboard::copy_string(&url)
+			})
+		);
+	}
+
+	fn permalink(&self) -> Result<String> {
+		let (id, line) = self
+			.permalink_target()?
+			.ok_or_else(|| anyhow!("no committed line selected"))?;
+
+		Ok(sync::line_web_url(CWD, id, &self.current.path, line)?)
+	}
+
+	/// commit and line in it a permalink of the selection points to,
+	/// lines not in that commit resolve to the next one that is
+	fn permalink_target(&self) -> Result<Option<(CommitId, u32)>> {
+		let (id, in_commit) = if let Some(commit) = self.commit {
+			(commit, true)
+		} else if self.is_immutable() {
+			return Ok(None);
+		} else {
+			(sync::get_head(CWD)?, false)
+		};
+
+		Ok(self.diff.as_ref().and_then(|diff| {
+			diff.hunks
+				.iter()
+				.flat_map(|hunk| hunk.lines.iter())
+				.skip(self.selection.get_top())
+				.find_map(|line| {
+					if in_commit {
+						line.position.new_lineno
+					} else {
+						line.position.old_lineno
+					}
+				})
+				.map(|line| (id, line))
+		}))
+	}
+
+	/// copies the selected hunk or the whole diff as patch text
+	fn copy_patch(&self) {
+		if let Some(diff) = &self.diff {
+			let hunk = self
+				.selected_hunk
+				.and_then(|hunk| diff.hunks.get(hunk));
+
+			let text = hunk.map_or_else(
+				|| {
+					diff.hunks.iter().fold(
+						diff.patch_header.clone(),
+						|text, hunk| text + &hunk.patch,
+					)
+				},
+				|hunk| hunk.patch.clone(),
+			);
+
+			try_or_popup!(
+				self,
+				"copy to clipboard error:",
+				crate::clipboard::copy_string(&text)
+			);
+		}
+	}
+
 	fn find_selected_hunk(
 		diff: &FileDiff,
 		line_selected: usize,
@@ -303,8 +513,13 @@ impl DiffComponent {
 				let delta_byte_size =
 					ByteSize::b(diff.size_delta.abs() as u64);
 				let sign = if is_positive { "+" } else { "-" };
+				let label = if diff.is_binary {
+					strings::diff_binary_file()
+				} else {
+					String::from("size: ")
+				};
 				res.extend(vec![Spans::from(vec![
-					Span::raw(Cow::from("size: ")),
+					Span::raw(Cow::from(label)),
 					Span::styled(
 						Cow::from(format!(
 							"{}",
@@ -337,12 +552,21 @@ impl DiffComponent {
 					),
 					Span::raw(Cow::from(")")),
 				])]);
+			} else if self.is_large_diff_hidden() {
+				res.push(Spans::from(vec![Span::styled(
+					Cow::from(strings::diff_large_hidden(
+						diff.lines,
+						&self.key_config,
+					)),
+					self.theme.text(false, false),
+				)]));
 			} else {
 				let min = self.scroll.get_top();
 				let max = min + height as usize;
 
+				let tab_width = self.options.borrow().tab_width;
+				let mut visible_lines = Vec::new();
 				let mut line_cursor = 0_usize;
-				let mut lines_added = 0_usize;
 
 				for (i, hunk) in diff.hunks.iter().enumerate() {
 					let hunk_selected = self.focused()
@@ -350,7 +574,7 @@ impl DiffComponent {
 							.selected_hunk
 							.map_or(false, |s| s == i);
 
-					if lines_added >= height as usize {
+					if visible_lines.len() >= height as usize {
 						break;
 					}
 
@@ -361,44 +585,107 @@ impl DiffComponent {
 					if Self::hunk_visible(
 						hunk_min, hunk_max, min, max,
 					) {
-						for (i, line) in hunk.lines.iter().enumerate()
+						// only walk the lines in view, hunks of
+						// huge diffs can be long
+						let skip = min.saturating_sub(hunk_min);
+						let take =
+							(max + 1).saturating_sub(hunk_min + skip);
+
+						for (i, line) in hunk
+							.lines
+							.iter()
+							.enumerate()
+							.skip(skip)
+							.take(take)
 						{
-							if line_cursor >= min
-								&& line_cursor <= max
-							{
-								res.push(Self::get_line_to_add(
-									width,
-									line,
-									self.focused()
-										&& self
-											.selection
-											.contains(line_cursor),
-									hunk_selected,
-									i == hunk_len as usize - 1,
-									&self.theme,
-								));
-								lines_added += 1;
-							}
-
-							line_cursor += 1;
+							visible_lines.push((
+								line,
+								tabs_to_spaces(
+									line.content.to_string(),
+									tab_width,
+								),
+								self.focused()
+									&& self
+										.selection
+										.contains(hunk_min + i),
+								hunk_selected,
+								i == hunk_len as usize - 1,
+							));
 						}
-					} else {
-						line_cursor += hunk_len;
 					}
+
+					line_cursor += hunk_len;
 				}
+
+				let longest_line = visible_lines
+					.iter()
+					.map(|(_, content, ..)| content.width())
+					.max()
+					.unwrap_or_default();
+				let horizontal_scroll =
+					self.clamp_horizontal_scroll(longest_line, width);
+
+				res.extend(visible_lines.iter().map(
+					|(
+						line,
+						content,
+						selected,
+						hunk_selected,
+						end,
+					)| {
+						Self::get_line_to_add(
+							width,
+							line,
+							skip_columns(content, horizontal_scroll),
+							*selected,
+							*hunk_selected,
+							*end,
+							self.search.as_ref(),
+							&self.theme,
+						)
+					},
+				));
 			}
 		}
 		res
 	}
 
-	fn get_line_to_add<'a>(
+	/// limits horizontal scrolling to the longest line in view,
+	/// `width` includes the borders and the hunk marker column
+	fn clamp_horizontal_scroll(
+		&self,
+		longest_line: usize,
+		width: u16,
+	) -> usize {
+		let max_scroll = longest_line
+			.saturating_sub(usize::from(width.saturating_sub(3)));
+		self.max_horizontal_scroll.set(max_scroll);
+
+		cmp::min(self.horizontal_scroll, max_scroll)
+	}
+
+	fn scroll_horizontal(&mut self, right: bool) {
+		let max_scroll = self.max_horizontal_scroll.get();
+		let current = cmp::min(self.horizontal_scroll, max_scroll);
+
+		self.horizontal_scroll = if right {
+			cmp::min(current + HORIZONTAL_SCROLL_STEP, max_scroll)
+		} else {
+			current.saturating_sub(HORIZONTAL_SCROLL_STEP)
+		};
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn get_line_to_add(
 		width: u16,
-		line: &'a DiffLine,
+		line: &DiffLine,
+		content: &str,
 		selected: bool,
 		selected_hunk: bool,
 		end_of_hunk: bool,
+		search: Option<&Search>,
 		theme: &SharedTheme,
-	) -> Spans<'a> {
+	) -> Spans<'static> {
 		let style = theme.diff_hunk_marker(selected_hunk);
 
 		let left_side_of_line = if end_of_hunk {
@@ -418,19 +705,36 @@ impl DiffComponent {
 
 		let filled = if selected {
 			// selected line
-			format!("{:w$}\n", line.content, w = width as usize)
+			format!("{:w$}\n", content, w = width as usize)
 		} else {
 			// weird eof missing eol line
-			format!("{}\n", line.content)
+			format!("{}\n", content)
 		};
 
-		Spans::from(vec![
-			left_side_of_line,
-			Span::styled(
-				Cow::from(tabs_to_spaces(filled)),
-				theme.diff_line(line.line_type, selected),
-			),
-		])
+		let line_style = theme.diff_line(line.line_type, selected);
+		let matches = search.map_or_else(Vec::new, |search| {
+			find_all(content, &search.query, search.case_sensitive)
+		});
+
+		let mut spans = vec![left_side_of_line];
+		let mut last_end = 0;
+		for m in matches {
+			spans.push(Span::styled(
+				Cow::from(filled[last_end..m.start].to_string()),
+				line_style,
+			));
+			spans.push(Span::styled(
+				Cow::from(filled[m.start..m.end].to_string()),
+				theme.diff_search_match(selected),
+			));
+			last_end = m.end;
+		}
+		spans.push(Span::styled(
+			Cow::from(filled[last_end..].to_string()),
+			line_style,
+		));
+
+		Spans::from(spans)
 	}
 
 	const fn hunk_visible(
@@ -536,6 +840,18 @@ impl DiffComponent {
 		}
 	}
 
+	/// line in the working file of the selection, lines that got
+	/// removed resolve to the next line still in the file
+	pub fn selected_file_line(&self) -> Option<u32> {
+		self.diff.as_ref().and_then(|diff| {
+			diff.hunks
+				.iter()
+				.flat_map(|hunk| hunk.lines.iter())
+				.skip(self.selection.get_top())
+				.find_map(|line| line.position.new_lineno)
+		})
+	}
+
 	fn selected_lines(&self) -> Vec<DiffLinePosition> {
 		self.diff
 			.as_ref()
@@ -583,6 +899,27 @@ impl DiffComponent {
 	const fn is_stage(&self) -> bool {
 		self.current.is_stage
 	}
+
+	/// conflict diffs cannot be (un)staged or reset line by line
+	fn is_immutable(&self) -> bool {
+		self.is_immutable
+			|| self.diff.as_ref().map_or(false, |diff| diff.conflict)
+	}
+
+	fn patch_source(&self) -> Option<PatchSource> {
+		self.diff.as_ref()?;
+
+		if let Some(commit) = self.commit {
+			Some(PatchSource::Commit(commit))
+		} else if self.is_immutable() {
+			None
+		} else {
+			Some(PatchSource::File(
+				self.current.path.clone(),
+				self.current.is_stage,
+			))
+		}
+	}
 }
 
 impl DrawableComponent for DiffComponent {
@@ -604,12 +941,28 @@ impl DrawableComponent for DiffComponent {
 			usize::from(current_height),
 		);
 
-		let title = format!(
+		let mut title = format!(
 			"{}{}",
 			strings::title_diff(&self.key_config),
 			self.current.path
 		);
 
+		if let Some((old, new)) =
+			self.diff.as_ref().and_then(|diff| diff.mode_change)
+		{
+			title.push(' ');
+			title.push_str(&strings::diff_mode_change(old, new));
+		}
+
+		if let Some(search) = &self.search {
+			title.push(' ');
+			title.push_str(&strings::diff_search_status(
+				&search.query,
+				search.position(self.selection.get_start()),
+				search.matches.len(),
+			));
+		}
+
 		let txt = if self.pending {
 			vec![Spans::from(vec![Span::styled(
 				Cow::from(strings::loading_text(&self.key_config)),
@@ -646,6 +999,16 @@ impl Component for DiffComponent {
 		out: &mut Vec<CommandInfo>,
 		_force_all: bool,
 	) -> CommandBlocking {
+		if self.is_large_diff_hidden() {
+			out.push(CommandInfo::new(
+				strings::commands::diff_load_large(&self.key_config),
+				true,
+				self.focused,
+			));
+
+			return CommandBlocking::PassingOn;
+		}
+
 		out.push(CommandInfo::new(
 			strings::commands::scroll(&self.key_config),
 			self.can_scroll(),
@@ -661,15 +1024,16 @@ impl Component for DiffComponent {
 			.hidden(),
 		);
 
-		if !self.is_immutable {
+		if !self.is_immutable() {
+			let read_only = self.options.borrow().read_only;
 			out.push(CommandInfo::new(
 				strings::commands::diff_hunk_remove(&self.key_config),
-				self.selected_hunk.is_some(),
+				self.selected_hunk.is_some() && !read_only,
 				self.focused && self.is_stage(),
 			));
 			out.push(CommandInfo::new(
 				strings::commands::diff_hunk_add(&self.key_config),
-				self.selected_hunk.is_some(),
+				self.selected_hunk.is_some() && !read_only,
 				self.focused && !self.is_stage(),
 			));
 			out.push(CommandInfo::new(
@@ -699,14 +1063,57 @@ impl Component for DiffComponent {
 				true,
 				self.focused && self.is_stage(),
 			));
+			out.push(CommandInfo::new(
+				strings::commands::diff_next_file(&self.key_config),
+				true,
+				self.focused,
+			));
 		}
 
+		out.push(CommandInfo::new(
+			strings::commands::diff_scroll_horizontal(
+				&self.key_config,
+			),
+			self.max_horizontal_scroll.get() > 0
+				|| self.horizontal_scroll > 0,
+			self.focused,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::diff_search(&self.key_config),
+			self.diff.is_some(),
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_search_next(&self.key_config),
+			self.has_search_matches(),
+			self.focused && self.search.is_some(),
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::export_patch(&self.key_config),
+			self.patch_source().is_some(),
+			self.focused,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::copy(&self.key_config),
 			true,
 			self.focused,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::diff_copy_patch(&self.key_config),
+			self.diff.is_some(),
+			self.focused,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::diff_copy_permalink(&self.key_config),
+			self.commit.is_some() || !self.is_immutable(),
+			self.focused,
+		));
+
 		CommandBlocking::PassingOn
 	}
 
@@ -714,6 +1121,15 @@ impl Component for DiffComponent {
 	fn event(&mut self, ev: Event) -> Result<EventState> {
 		if self.focused {
 			if let Event::Key(e) = ev {
+				if self.is_large_diff_hidden() {
+					return if e == self.key_config.enter {
+						self.large_diff_loaded = true;
+						Ok(EventState::Consumed)
+					} else {
+						Ok(EventState::NotConsumed)
+					};
+				}
+
 				return if e == self.key_config.move_down {
 					self.move_selection(ScrollType::Down);
 					Ok(EventState::Consumed)
@@ -739,7 +1155,8 @@ impl Component for DiffComponent {
 					self.move_selection(ScrollType::PageDown);
 					Ok(EventState::Consumed)
 				} else if e == self.key_config.enter
-					&& !self.is_immutable
+					&& !self.is_immutable()
+					&& !self.options.borrow().read_only
 				{
 					try_or_popup!(
 						self,
@@ -749,7 +1166,7 @@ impl Component for DiffComponent {
 
 					Ok(EventState::Consumed)
 				} else if e == self.key_config.status_reset_item
-					&& !self.is_immutable
+					&& !self.is_immutable()
 					&& !self.is_stage()
 				{
 					if let Some(diff) = &self.diff {
@@ -761,12 +1178,12 @@ impl Component for DiffComponent {
 					}
 					Ok(EventState::Consumed)
 				} else if e == self.key_config.diff_stage_lines
-					&& !self.is_immutable
+					&& !self.is_immutable()
 				{
 					self.stage_lines();
 					Ok(EventState::Consumed)
 				} else if e == self.key_config.diff_reset_lines
-					&& !self.is_immutable
+					&& !self.is_immutable()
 					&& !self.is_stage()
 				{
 					if let Some(diff) = &self.diff {
@@ -779,6 +1196,63 @@ impl Component for DiffComponent {
 				} else if e == self.key_config.copy {
 					self.copy_selection();
 					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_copy_patch {
+					self.copy_patch();
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_copy_permalink {
+					self.copy_permalink();
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_scroll_right {
+					self.scroll_horizontal(true);
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_scroll_left {
+					self.scroll_horizontal(false);
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_search {
+					let (query, case_sensitive) =
+						self.search.as_ref().map_or_else(
+							|| (String::new(), false),
+							|search| {
+								(
+									search.query.clone(),
+									search.case_sensitive,
+								)
+							},
+						);
+					self.queue.push(InternalEvent::OpenDiffSearch(
+						query,
+						case_sensitive,
+					));
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_search_next
+					&& self.search.is_some()
+				{
+					self.move_to_match(true);
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_search_prev
+					&& self.search.is_some()
+				{
+					self.move_to_match(false);
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_next_file
+					&& !self.is_immutable()
+				{
+					self.queue
+						.push(InternalEvent::SelectDiffFile(true));
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.diff_prev_file
+					&& !self.is_immutable()
+				{
+					self.queue
+						.push(InternalEvent::SelectDiffFile(false));
+					Ok(EventState::Consumed)
+				} else if e == self.key_config.export_patch {
+					if let Some(source) = self.patch_source() {
+						self.queue.push(
+							InternalEvent::OpenExportPatch(source),
+						);
+					}
+					Ok(EventState::Consumed)
 				} else {
 					Ok(EventState::NotConsumed)
 				};
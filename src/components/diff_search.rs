@@ -0,0 +1,154 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct DiffSearchComponent {
+	input: TextInputComponent,
+	case_sensitive: bool,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for DiffSearchComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for DiffSearchComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::diff_search_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::diff_search_case(
+					&self.key_config,
+					self.case_sensitive,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(e) = ev {
+				if e == self.key_config.diff_search_case {
+					self.case_sensitive = !self.case_sensitive;
+					self.update_title();
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter {
+					self.search();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl DiffSearchComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::diff_search_popup_title(false),
+				&strings::diff_search_popup_msg(&key_config),
+				true,
+			),
+			case_sensitive: false,
+			key_config,
+		}
+	}
+
+	/// opens the search input prefilled with the active search
+	pub fn open(
+		&mut self,
+		query: String,
+		case_sensitive: bool,
+	) -> Result<()> {
+		self.case_sensitive = case_sensitive;
+		self.update_title();
+		self.input.set_text(query);
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_title(&mut self) {
+		self.input.set_title(strings::diff_search_popup_title(
+			self.case_sensitive,
+		));
+	}
+
+	fn search(&mut self) {
+		self.queue.push(InternalEvent::SearchDiff(
+			self.input.get_text().to_string(),
+			self.case_sensitive,
+		));
+
+		self.input.clear();
+		self.hide();
+	}
+}
@@ -0,0 +1,159 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, utils::repo_work_dir, PatchSource},
+	CWD,
+};
+use crossterm::event::Event;
+use std::path::{Path, PathBuf};
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct ExportPatchComponent {
+	input: TextInputComponent,
+	source: Option<PatchSource>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for ExportPatchComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for ExportPatchComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::export_patch_confirm(
+					&self.key_config,
+				),
+				!self.input.get_text().trim().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter
+					&& !self.input.get_text().trim().is_empty()
+				{
+					self.export();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl ExportPatchComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::export_patch_popup_title(&key_config),
+				&strings::export_patch_popup_msg(&key_config),
+				true,
+			),
+			source: None,
+			key_config,
+		}
+	}
+
+	/// asks where to write the patch of `source`
+	pub fn open(&mut self, source: PatchSource) -> Result<()> {
+		self.input.set_text(
+			Self::default_path(&source)
+				.map(|p| p.to_string_lossy().to_string())
+				.unwrap_or_default(),
+		);
+		self.source = Some(source);
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// file in the repo root named after the file or commit
+	fn default_path(source: &PatchSource) -> Option<PathBuf> {
+		let work_dir = repo_work_dir(CWD).ok()?;
+
+		let name = match source {
+			PatchSource::File(path, _) => Path::new(path)
+				.file_name()?
+				.to_string_lossy()
+				.to_string(),
+			PatchSource::Commit(id) => id.get_short_string(),
+		};
+
+		Some(Path::new(&work_dir).join(format!("{}.patch", name)))
+	}
+
+	fn export(&mut self) {
+		let path = PathBuf::from(self.input.get_text().trim());
+
+		self.input.clear();
+		self.hide();
+
+		if let Some(source) = self.source.take() {
+			if let Err(e) = sync::export_patch(CWD, &source, &path) {
+				log::error!("export patch: {}", e,);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("export patch error:\n{}", e,),
+				));
+			}
+		}
+	}
+}
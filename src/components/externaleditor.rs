@@ -9,7 +9,7 @@ use crate::{
 };
 use anyhow::{anyhow, bail, Result};
 use asyncgit::{
-	sync::{get_config_string, utils::repo_work_dir},
+	sync::{repo_config, utils::repo_work_dir},
 	CWD,
 };
 use crossterm::{
@@ -18,8 +18,12 @@ use crossterm::{
 	ExecutableCommand,
 };
 use scopeguard::defer;
-use std::ffi::OsStr;
-use std::{env, io, path::Path, process::Command};
+use std::ffi::{OsStr, OsString};
+use std::{
+	env, io,
+	path::{Path, PathBuf},
+	process::Command,
+};
 use tui::{
 	backend::Backend,
 	layout::Rect,
@@ -48,8 +52,12 @@ impl ExternalEditorComponent {
 		}
 	}
 
-	/// opens file at given `path` in an available editor
-	pub fn open_file_in_editor(path: &Path) -> Result<()> {
+	/// opens file at given `path` in an available editor,
+	/// editors known to support it are told to jump to `line`
+	pub fn open_file_in_editor(
+		path: &Path,
+		line: Option<u32>,
+	) -> Result<()> {
 		let work_dir = repo_work_dir(CWD)?;
 
 		let path = if path.is_relative() {
@@ -71,42 +79,26 @@ impl ExternalEditorComponent {
 
 		let editor = env::var(environment_options[0])
 			.ok()
-			.or_else(|| get_config_string(CWD, "core.editor").ok()?)
+			.or_else(|| repo_config(CWD).ok()?.editor)
 			.or_else(|| env::var(environment_options[1]).ok())
 			.or_else(|| env::var(environment_options[2]).ok())
 			.unwrap_or_else(|| String::from("vi"));
 
-		// TODO: proper handling arguments containing whitespaces
-		// This does not do the right thing if the input is `editor --something "with spaces"`
+		let mut words = split_shell_words(&editor).into_iter();
 
-		// deal with "editor name with spaces" p1 p2 p3
-		// and with "editor_no_spaces" p1 p2 p3
-		// does not address spaces in pn
-		let mut echars = editor.chars().peekable();
-
-		let first_char = *echars.peek().ok_or_else(|| {
+		let command = words.next().ok_or_else(|| {
 			anyhow!(
-				"editor env variable found empty: {}",
-				environment_options.join(" or ")
+				"editor found empty: {}, core.editor, {} or {}",
+				environment_options[0],
+				environment_options[1],
+				environment_options[2]
 			)
 		})?;
-		let command: String = if first_char == '\"' {
-			echars
-				.by_ref()
-				.skip(1)
-				.take_while(|c| *c != '\"')
-				.collect()
-		} else {
-			echars.by_ref().take_while(|c| *c != ' ').collect()
-		};
-
-		let remainder_str = echars.collect::<String>();
-		let remainder = remainder_str.split_whitespace();
 
-		let mut args: Vec<&OsStr> =
-			remainder.map(|s| OsStr::new(s)).collect();
+		let mut args: Vec<OsString> =
+			words.map(OsString::from).collect();
 
-		args.push(path.as_os_str());
+		args.extend(file_args(&command, path, line));
 
 		Command::new(command.clone())
 			.current_dir(work_dir)
@@ -118,6 +110,95 @@ impl ExternalEditorComponent {
 	}
 }
 
+/// splits `s` into words like a posix shell would: whitespace
+/// separates words unless quoted with `'` or `"` or escaped with `\`.
+/// a `\` not followed by a quote, `\` or whitespace is kept as is
+/// to not break windows paths
+fn split_shell_words(s: &str) -> Vec<String> {
+	let mut words = Vec::new();
+	let mut word: Option<String> = None;
+	let mut quote: Option<char> = None;
+	let mut chars = s.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		match (quote, c) {
+			(Some(q), c) if c == q => quote = None,
+			(Some('"'), '\\') | (None, '\\') => {
+				let escaped = chars.peek().copied().filter(|next| {
+					matches!(next, '\\' | '"')
+						|| (quote.is_none()
+							&& (*next == '\''
+								|| next.is_whitespace()))
+				});
+
+				if escaped.is_some() {
+					chars.next();
+				}
+
+				word.get_or_insert_with(String::new)
+					.push(escaped.unwrap_or(c));
+			}
+			(Some(_), c) => {
+				word.get_or_insert_with(String::new).push(c);
+			}
+			(None, '\'') | (None, '"') => {
+				quote = Some(c);
+				word.get_or_insert_with(String::new);
+			}
+			(None, c) if c.is_whitespace() => {
+				words.extend(word.take());
+			}
+			(None, c) => word.get_or_insert_with(String::new).push(c),
+		}
+	}
+
+	words.extend(word);
+
+	words
+}
+
+/// arguments opening `path` at `line` for editors known to
+/// support it, unknown editors just open the file
+fn file_args(
+	command: &str,
+	path: PathBuf,
+	line: Option<u32>,
+) -> Vec<OsString> {
+	let editor = Path::new(command)
+		.file_stem()
+		.and_then(OsStr::to_str)
+		.unwrap_or_default()
+		.to_lowercase();
+
+	match line {
+		Some(line)
+			if [
+				"vi",
+				"vim",
+				"nvim",
+				"gvim",
+				"nano",
+				"emacs",
+				"emacsclient",
+				"kak",
+			]
+			.contains(&editor.as_str()) =>
+		{
+			vec![format!("+{}", line).into(), path.into_os_string()]
+		}
+		Some(line)
+			if ["code", "code-insiders", "codium"]
+				.contains(&editor.as_str()) =>
+		{
+			let mut target = path.into_os_string();
+			target.push(format!(":{}", line));
+
+			vec!["--goto".into(), target]
+		}
+		_ => vec![path.into_os_string()],
+	}
+}
+
 impl DrawableComponent for ExternalEditorComponent {
 	fn draw<B: Backend>(
 		&self,
@@ -188,3 +269,62 @@ impl Component for ExternalEditorComponent {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{file_args, split_shell_words};
+	use std::{ffi::OsString, path::PathBuf};
+
+	#[test]
+	fn test_split_shell_words() {
+		assert_eq!(split_shell_words("vim"), vec!["vim"]);
+		assert_eq!(
+			split_shell_words("  code --wait "),
+			vec!["code", "--wait"]
+		);
+		assert_eq!(
+			split_shell_words("\"code --wait\""),
+			vec!["code --wait"]
+		);
+		assert_eq!(
+			split_shell_words(
+				"'/Applications/Sublime Text/subl' -n -w"
+			),
+			vec!["/Applications/Sublime Text/subl", "-n", "-w"]
+		);
+		assert_eq!(
+			split_shell_words("my\\ editor \"a \\\"b\\\"\" ''"),
+			vec!["my editor", "a \"b\"", ""]
+		);
+		assert_eq!(
+			split_shell_words("C:\\Windows\\notepad.exe"),
+			vec!["C:\\Windows\\notepad.exe"]
+		);
+		assert!(split_shell_words("   ").is_empty());
+	}
+
+	#[test]
+	fn test_file_args() {
+		let path = || PathBuf::from("src/main.rs");
+		let args = |args: &[&str]| {
+			args.iter().map(OsString::from).collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			file_args("/usr/bin/nvim", path(), Some(12)),
+			args(&["+12", "src/main.rs"])
+		);
+		assert_eq!(
+			file_args("code", path(), Some(12)),
+			args(&["--goto", "src/main.rs:12"])
+		);
+		assert_eq!(
+			file_args("vim", path(), None),
+			args(&["src/main.rs"])
+		);
+		assert_eq!(
+			file_args("unknown-editor", path(), Some(12)),
+			args(&["src/main.rs"])
+		);
+	}
+}
@@ -1,5 +1,6 @@
 use super::{
 	utils::{
+		self,
 		filetree::{FileTreeItem, FileTreeItemKind},
 		statustree::{MoveSelection, StatusTree},
 	},
@@ -9,15 +10,27 @@ use crate::{
 	components::{CommandInfo, Component, EventState},
 	keys::SharedKeyConfig,
 	queue::{InternalEvent, NeedsUpdate, Queue},
-	strings::{self, order},
+	strings::{self, order, symbol},
 	ui,
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
-use asyncgit::{hash, StatusItem, StatusItemType};
+use asyncgit::{
+	hash, sync::FileSizeHint, StatusItem, StatusItemType,
+};
 use crossterm::event::Event;
-use std::{borrow::Cow, cell::Cell, convert::From, path::Path};
-use tui::{backend::Backend, layout::Rect, text::Span, Frame};
+use fuzzy_matcher::FuzzyMatcher;
+use std::{
+	borrow::Cow,
+	cell::Cell,
+	collections::{HashMap, HashSet},
+	convert::From,
+	path::Path,
+};
+use tui::{
+	backend::Backend, layout::Rect, style::Modifier, text::Span,
+	Frame,
+};
 
 //TODO: rename so that its clear this only works for Statuses
 //TODO: use new `filetreelist` crate
@@ -26,10 +39,17 @@ use tui::{backend::Backend, layout::Rect, text::Span, Frame};
 pub struct FileTreeComponent {
 	title: String,
 	tree: StatusTree,
+	/// unfiltered items the tree is built from
+	items: Vec<StatusItem>,
+	filter: Option<String>,
 	pending: bool,
 	current_hash: u64,
 	focused: bool,
 	show_selection: bool,
+	markers: HashMap<String, char>,
+	/// paths of the files picked to act on together
+	marked: HashSet<String>,
+	size_hints: HashMap<String, FileSizeHint>,
 	queue: Option<Queue>,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
@@ -48,9 +68,14 @@ impl FileTreeComponent {
 		Self {
 			title: title.to_string(),
 			tree: StatusTree::default(),
+			items: Vec::new(),
+			filter: None,
 			current_hash: 0,
 			focused: focus,
 			show_selection: focus,
+			markers: HashMap::new(),
+			marked: HashSet::new(),
+			size_hints: HashMap::new(),
 			queue,
 			theme,
 			key_config,
@@ -64,13 +89,80 @@ impl FileTreeComponent {
 		self.pending = false;
 		let new_hash = hash(list);
 		if self.current_hash != new_hash {
-			self.tree.update(list)?;
+			self.items = list.to_vec();
+			self.marked.retain(|path| {
+				list.iter().any(|item| &item.path == path)
+			});
+			self.update_tree()?;
 			self.current_hash = new_hash;
 		}
 
 		Ok(())
 	}
 
+	/// only shows files whose path fuzzy matches `filter`
+	pub fn set_filter(
+		&mut self,
+		filter: Option<String>,
+	) -> Result<()> {
+		if self.filter != filter {
+			self.filter = filter;
+			self.update_tree()?;
+		}
+
+		Ok(())
+	}
+
+	fn update_tree(&mut self) -> Result<()> {
+		if let Some(filter) = &self.filter {
+			let items = self.filtered_items(filter);
+			self.tree.update(&items)
+		} else {
+			self.tree.update(&self.items)
+		}
+	}
+
+	fn filtered_items(&self, filter: &str) -> Vec<StatusItem> {
+		let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+
+		self.items
+			.iter()
+			.filter(|item| {
+				matcher.fuzzy_match(&item.path, filter).is_some()
+			})
+			.cloned()
+			.collect()
+	}
+
+	/// the files below `folder` passing the filter,
+	/// `None` without a filter
+	pub fn filtered_items_in(
+		&self,
+		folder: &str,
+	) -> Option<Vec<StatusItem>> {
+		self.filter.as_ref().map(|filter| {
+			self.filtered_items(filter)
+				.into_iter()
+				.filter(|item| {
+					Path::new(&item.path).starts_with(folder)
+				})
+				.collect()
+		})
+	}
+
+	fn title(&self) -> Cow<str> {
+		self.filter.as_ref().map_or_else(
+			|| Cow::from(self.title.as_str()),
+			|filter| {
+				Cow::from(format!(
+					"{}{}",
+					self.title,
+					strings::status_filter_note(filter)
+				))
+			},
+		)
+	}
+
 	///
 	pub fn selection(&self) -> Option<FileTreeItem> {
 		self.tree.selected_item()
@@ -87,6 +179,33 @@ impl FileTreeComponent {
 		})
 	}
 
+	/// marks the selected file or unmarks it if it was already
+	pub fn toggle_mark(&mut self) -> bool {
+		if let Some(file) = self.selection_file() {
+			if !self.marked.remove(&file.path) {
+				self.marked.insert(file.path);
+			}
+
+			return true;
+		}
+
+		false
+	}
+
+	/// the marked files in the order of the list
+	pub fn marked_items(&self) -> Vec<StatusItem> {
+		self.items
+			.iter()
+			.filter(|item| self.marked.contains(&item.path))
+			.cloned()
+			.collect()
+	}
+
+	///
+	pub fn clear_marks(&mut self) {
+		self.marked.clear();
+	}
+
 	///
 	pub fn show_selection(&mut self, show: bool) {
 		self.show_selection = show;
@@ -107,10 +226,24 @@ impl FileTreeComponent {
 		self.title = title;
 	}
 
+	/// extra marker char to show next to the status of a file
+	pub fn set_markers(&mut self, markers: HashMap<String, char>) {
+		self.markers = markers;
+	}
+
+	/// large files and lfs pointers get flagged after their name
+	pub fn set_size_hints(
+		&mut self,
+		size_hints: HashMap<String, FileSizeHint>,
+	) {
+		self.size_hints = size_hints;
+	}
+
 	///
 	pub fn clear(&mut self) -> Result<()> {
 		self.current_hash = 0;
 		self.pending = true;
+		self.items.clear();
 		self.tree.update(&[])
 	}
 
@@ -124,6 +257,20 @@ impl FileTreeComponent {
 		})
 	}
 
+	/// moves the selection to the next (or previous) file,
+	/// see `StatusTree::select_file`
+	pub fn select_file(&mut self, forward: bool, wrap: bool) -> bool {
+		let changed = self.tree.select_file(forward, wrap);
+
+		if changed {
+			if let Some(ref queue) = self.queue {
+				queue.push(InternalEvent::Update(NeedsUpdate::DIFF));
+			}
+		}
+
+		changed
+	}
+
 	fn move_selection(&mut self, dir: MoveSelection) -> bool {
 		let changed = self.tree.move_selection(dir);
 
@@ -136,6 +283,26 @@ impl FileTreeComponent {
 		changed
 	}
 
+	fn copy_path(&self, absolute: bool) -> Result<EventState> {
+		match (&self.queue, self.selection()) {
+			(Some(queue), Some(item)) => {
+				if let Err(e) = utils::copy_path(
+					queue,
+					&item.info.full_path,
+					absolute,
+				) {
+					queue.push(InternalEvent::ShowErrorMsg(format!(
+						"copy path error:\n{}",
+						e
+					)));
+				}
+
+				Ok(EventState::Consumed)
+			}
+			_ => Ok(EventState::NotConsumed),
+		}
+	}
+
 	const fn item_status_char(item_type: StatusItemType) -> char {
 		match item_type {
 			StatusItemType::Modified => 'M',
@@ -147,11 +314,56 @@ impl FileTreeComponent {
 		}
 	}
 
+	fn file_marker(&self, item: &FileTreeItemKind) -> Option<char> {
+		if self.markers.is_empty() {
+			return None;
+		}
+
+		Some(match item {
+			FileTreeItemKind::File(status_item) => self
+				.markers
+				.get(&status_item.path)
+				.copied()
+				.unwrap_or(' '),
+			FileTreeItemKind::Path(_) => ' ',
+		})
+	}
+
+	/// `None` while nothing is marked to not waste the column
+	fn file_marked(&self, item: &FileTreeItemKind) -> Option<bool> {
+		if self.marked.is_empty() {
+			return None;
+		}
+
+		Some(match item {
+			FileTreeItemKind::File(status_item) => {
+				self.marked.contains(&status_item.path)
+			}
+			FileTreeItemKind::Path(_) => false,
+		})
+	}
+
+	fn file_size_hint(
+		&self,
+		item: &FileTreeItemKind,
+	) -> Option<FileSizeHint> {
+		match item {
+			FileTreeItemKind::File(status_item) => {
+				self.size_hints.get(&status_item.path).copied()
+			}
+			FileTreeItemKind::Path(_) => None,
+		}
+	}
+
+	#[allow(clippy::too_many_arguments)]
 	fn item_to_text<'b>(
 		string: &str,
 		indent: usize,
 		visible: bool,
 		file_item_kind: &FileTreeItemKind,
+		marker: Option<char>,
+		marked: Option<bool>,
+		size_hint: Option<FileSizeHint>,
 		width: u16,
 		selected: bool,
 		theme: &'b SharedTheme,
@@ -166,6 +378,13 @@ impl FileTreeComponent {
 			return None;
 		}
 
+		let marker = marker.map_or_else(String::new, String::from);
+		let marker = match marked {
+			Some(true) => format!("{}{}", marker, symbol::CHECKMARK),
+			Some(false) => format!("{} ", marker),
+			None => marker,
+		};
+
 		match file_item_kind {
 			FileTreeItemKind::File(status_item) => {
 				let status_char =
@@ -174,23 +393,49 @@ impl FileTreeComponent {
 					.file_name()
 					.and_then(std::ffi::OsStr::to_str)
 					.expect("invalid path.");
+				let file = size_hint.map_or_else(
+					|| file.to_string(),
+					|hint| {
+						format!(
+							"{} {}",
+							file,
+							strings::file_size_hint(hint)
+						)
+					},
+				);
+				let file = match status_item.mode_change {
+					Some((old, new)) => format!(
+						"{} {}",
+						file,
+						strings::file_mode_change(old, new)
+					),
+					None => file,
+				};
 
 				let txt = if selected {
 					format!(
-						"{} {}{:w$}",
+						"{}{} {}{:w$}",
 						status_char,
+						marker,
 						indent_str,
 						file,
 						w = width as usize
 					)
 				} else {
-					format!("{} {}{}", status_char, indent_str, file)
+					format!(
+						"{}{} {}{}",
+						status_char, marker, indent_str, file
+					)
 				};
 
-				Some(Span::styled(
-					Cow::from(txt),
-					theme.item(status_item.status, selected),
-				))
+				let style = theme.item(status_item.status, selected);
+				let style = if marked == Some(true) {
+					style.add_modifier(Modifier::BOLD)
+				} else {
+					style
+				};
+
+				Some(Span::styled(Cow::from(txt), style))
 			}
 
 			FileTreeItemKind::Path(path_collapsed) => {
@@ -199,7 +444,8 @@ impl FileTreeComponent {
 
 				let txt = if selected {
 					format!(
-						"  {}{}{:w$}",
+						"  {}{}{}{:w$}",
+						marker,
 						indent_str,
 						collapse_char,
 						string,
@@ -207,8 +453,8 @@ impl FileTreeComponent {
 					)
 				} else {
 					format!(
-						"  {}{}{}",
-						indent_str, collapse_char, string,
+						"  {}{}{}{}",
+						marker, indent_str, collapse_char, string,
 					)
 				};
 
@@ -323,7 +569,7 @@ impl DrawableComponent for FileTreeComponent {
 			ui::draw_list(
 				f,
 				r,
-				self.title.as_str(),
+				&self.title(),
 				items.into_iter(),
 				self.focused,
 				&self.theme,
@@ -357,6 +603,9 @@ impl DrawableComponent for FileTreeComponent {
 						draw_text_info.indent as usize,
 						draw_text_info.visible,
 						draw_text_info.item_kind,
+						self.file_marker(draw_text_info.item_kind),
+						self.file_marked(draw_text_info.item_kind),
+						self.file_size_hint(draw_text_info.item_kind),
 						r.width,
 						self.show_selection && select == index,
 						&self.theme,
@@ -366,7 +615,7 @@ impl DrawableComponent for FileTreeComponent {
 			ui::draw_list(
 				f,
 				r,
-				self.title.as_str(),
+				&self.title(),
 				items,
 				self.focused,
 				&self.theme,
@@ -391,6 +640,16 @@ impl Component for FileTreeComponent {
 			)
 			.order(order::NAV),
 		);
+		out.push(
+			CommandInfo::new(
+				strings::commands::tree_collapse_expand_all(
+					&self.key_config,
+				),
+				!self.is_empty(),
+				self.focused || force_all,
+			)
+			.order(order::NAV),
+		);
 		out.push(
 			CommandInfo::new(
 				strings::commands::blame_file(&self.key_config),
@@ -399,6 +658,14 @@ impl Component for FileTreeComponent {
 			)
 			.order(order::RARE_ACTION),
 		);
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_path(&self.key_config),
+				self.selection().is_some(),
+				self.focused || force_all,
+			)
+			.order(order::RARE_ACTION),
+		);
 
 		CommandBlocking::PassingOn
 	}
@@ -417,6 +684,12 @@ impl Component for FileTreeComponent {
 						}
 						_ => Ok(EventState::NotConsumed),
 					}
+				} else if e == self.key_config.copy
+					|| e == self.key_config.copy_absolute_path
+				{
+					self.copy_path(
+						e == self.key_config.copy_absolute_path,
+					)
 				} else if e == self.key_config.move_down {
 					Ok(self
 						.move_selection(MoveSelection::Down)
@@ -441,6 +714,10 @@ impl Component for FileTreeComponent {
 					Ok(self
 						.move_selection(MoveSelection::Right)
 						.into())
+				} else if e == self.key_config.tree_collapse_all {
+					Ok(self.tree.collapse_all().into())
+				} else if e == self.key_config.tree_expand_all {
+					Ok(self.tree.expand_all().into())
 				} else {
 					Ok(EventState::NotConsumed)
 				};
@@ -470,6 +747,7 @@ mod tests {
 			.map(|a| StatusItem {
 				path: String::from(*a),
 				status: StatusItemType::Modified,
+				mode_change: None,
 			})
 			.collect::<Vec<_>>()
 	}
@@ -555,4 +833,85 @@ mod tests {
 
 		assert_eq!(ftc.scroll_top.get(), 0); // should still be at top
 	}
+
+	#[test]
+	fn test_filter() {
+		let items = string_vec_to_status(&[
+			"a/b/main.rs", //
+			"a/c/lib.rs",  //
+			"d/main.rs",   //
+		]);
+
+		let mut ftc = FileTreeComponent::new(
+			"title",
+			true,
+			None,
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+		);
+		ftc.update(&items)
+			.expect("Updating FileTreeComponent failed");
+		assert_eq!(ftc.file_count(), 3);
+
+		assert_eq!(ftc.filtered_items_in("a"), None);
+
+		ftc.set_filter(Some(String::from("main")))
+			.expect("Filtering FileTreeComponent failed");
+		assert_eq!(ftc.file_count(), 2);
+
+		// folder operations only see the visible files
+		let visible = ftc.filtered_items_in("a").unwrap_or_default();
+		assert_eq!(visible.len(), 1);
+		assert_eq!(visible[0].path, "a/b/main.rs");
+
+		// keeps the filter when the status changes
+		ftc.update(&items[..2])
+			.expect("Updating FileTreeComponent failed");
+		assert_eq!(ftc.file_count(), 1);
+
+		ftc.set_filter(None)
+			.expect("Filtering FileTreeComponent failed");
+		assert_eq!(ftc.file_count(), 2);
+	}
+
+	#[test]
+	fn test_marks() {
+		let items = string_vec_to_status(&[
+			"a.rs", //
+			"b.rs", //
+			"c.rs", //
+		]);
+
+		let mut ftc = FileTreeComponent::new(
+			"title",
+			true,
+			None,
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+		);
+		ftc.update(&items)
+			.expect("Updating FileTreeComponent failed");
+
+		assert!(ftc.toggle_mark());
+		ftc.move_selection(MoveSelection::Down);
+		ftc.move_selection(MoveSelection::Down);
+		assert!(ftc.toggle_mark());
+		assert_eq!(
+			ftc.marked_items(),
+			vec![items[0].clone(), items[2].clone()]
+		);
+
+		// toggles back
+		ftc.toggle_mark();
+		assert_eq!(ftc.marked_items(), vec![items[0].clone()]);
+
+		// files gone from the status lose their mark
+		ftc.toggle_mark();
+		ftc.update(&items[1..])
+			.expect("Updating FileTreeComponent failed");
+		assert_eq!(ftc.marked_items(), vec![items[2].clone()]);
+
+		ftc.clear_marks();
+		assert!(ftc.marked_items().is_empty());
+	}
 }
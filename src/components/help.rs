@@ -1,8 +1,11 @@
 use super::{
 	visibility_blocking, CommandBlocking, CommandInfo, Component,
-	DrawableComponent, EventState,
+	DrawableComponent, EventState, TextInputComponent,
+};
+use crate::{
+	keys::SharedKeyConfig, string_utils::find_all, strings, ui,
+	version::Version,
 };
-use crate::{keys::SharedKeyConfig, strings, ui, version::Version};
 use anyhow::Result;
 use asyncgit::hash;
 use crossterm::event::Event;
@@ -23,6 +26,8 @@ pub struct HelpComponent {
 	cmds: Vec<CommandInfo>,
 	visible: bool,
 	selection: u16,
+	/// filters `cmds` by name or description
+	search: TextInputComponent,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 }
@@ -51,21 +56,30 @@ impl DrawableComponent for HelpComponent {
 				area,
 			);
 
+			let search_height =
+				if self.search.is_visible() { 1 } else { 0 };
+
 			let chunks = Layout::default()
 				.vertical_margin(1)
 				.horizontal_margin(1)
 				.direction(Direction::Vertical)
 				.constraints(
-					[Constraint::Min(1), Constraint::Length(1)]
-						.as_ref(),
+					[
+						Constraint::Length(search_height),
+						Constraint::Min(1),
+						Constraint::Length(1),
+					]
+					.as_ref(),
 				)
 				.split(area);
 
+			self.search.draw(f, chunks[0])?;
+
 			f.render_widget(
 				Paragraph::new(self.get_text())
 					.scroll((scroll, 0))
 					.alignment(Alignment::Left),
-				chunks[0],
+				chunks[1],
 			);
 
 			f.render_widget(
@@ -74,7 +88,7 @@ impl DrawableComponent for HelpComponent {
 					Style::default(),
 				)]))
 				.alignment(Alignment::Right),
-				chunks[1],
+				chunks[2],
 			);
 		}
 
@@ -100,6 +114,12 @@ impl Component for HelpComponent {
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::help_search(&self.key_config),
+				true,
+				true,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::close_popup(&self.key_config),
 				true,
@@ -124,13 +144,24 @@ impl Component for HelpComponent {
 	fn event(&mut self, ev: Event) -> Result<EventState> {
 		if self.visible {
 			if let Event::Key(e) = ev {
-				if e == self.key_config.exit_popup {
+				if self.search.is_visible() {
+					if e == self.key_config.exit_popup {
+						self.clear_search();
+					} else if self.search.event(ev)?.is_consumed() {
+						self.selection = 0;
+					} else if e == self.key_config.move_down {
+						self.move_selection(true);
+					} else if e == self.key_config.move_up {
+						self.move_selection(false);
+					}
+				} else if e == self.key_config.exit_popup {
 					self.hide();
+				} else if e == self.key_config.help_search {
+					self.search.show()?;
 				} else if e == self.key_config.move_down {
 					self.move_selection(true);
 				} else if e == self.key_config.move_up {
 					self.move_selection(false);
-				} else {
 				}
 			}
 
@@ -153,6 +184,7 @@ impl Component for HelpComponent {
 
 	fn hide(&mut self) {
 		self.visible = false;
+		self.clear_search();
 	}
 
 	fn show(&mut self) -> Result<()> {
@@ -163,14 +195,24 @@ impl Component for HelpComponent {
 }
 
 impl HelpComponent {
-	pub const fn new(
+	pub fn new(
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
 	) -> Self {
+		let mut search = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			"search commands..",
+			false,
+		);
+		search.embed();
+
 		Self {
 			cmds: vec![],
 			visible: false,
 			selection: 0,
+			search,
 			theme,
 			key_config,
 		}
@@ -186,6 +228,59 @@ impl HelpComponent {
 		self.cmds.sort_by_key(|e| hash(&e.text.group));
 	}
 
+	fn clear_search(&mut self) {
+		self.search.clear();
+		self.search.hide();
+		self.selection = 0;
+	}
+
+	/// commands whose name or description contain the search
+	fn filtered_cmds(&self) -> Vec<&CommandInfo> {
+		let query = self.search.get_text();
+
+		self.cmds
+			.iter()
+			.filter(|cmd| {
+				let matches = |text: &str| {
+					!find_all(text, query, false).is_empty()
+				};
+
+				query.is_empty()
+					|| matches(&cmd.text.name)
+					|| matches(cmd.text.desc)
+			})
+			.collect()
+	}
+
+	/// `text` split up to highlight the search matches
+	fn highlighted<'a>(
+		&self,
+		text: String,
+		selected: bool,
+	) -> Vec<Span<'a>> {
+		let style = self.theme.text(true, selected);
+		let mut spans = Vec::new();
+		let mut last_end = 0;
+
+		for m in find_all(&text, self.search.get_text(), false) {
+			spans.push(Span::styled(
+				Cow::from(text[last_end..m.start].to_string()),
+				style,
+			));
+			spans.push(Span::styled(
+				Cow::from(text[m.start..m.end].to_string()),
+				self.theme.diff_search_match(selected),
+			));
+			last_end = m.end;
+		}
+		spans.push(Span::styled(
+			Cow::from(text[last_end..].to_string()),
+			style,
+		));
+
+		spans
+	}
+
 	fn move_selection(&mut self, inc: bool) {
 		let mut new_selection = self.selection;
 
@@ -196,9 +291,9 @@ impl HelpComponent {
 		};
 		new_selection = cmp::max(new_selection, 0);
 
-		if let Ok(max) =
-			u16::try_from(self.cmds.len().saturating_sub(1))
-		{
+		if let Ok(max) = u16::try_from(
+			self.filtered_cmds().len().saturating_sub(1),
+		) {
 			self.selection = cmp::min(new_selection, max);
 		}
 	}
@@ -208,8 +303,10 @@ impl HelpComponent {
 
 		let mut processed = 0_u16;
 
-		for (key, group) in
-			&self.cmds.iter().group_by(|e| e.text.group)
+		for (key, group) in &self
+			.filtered_cmds()
+			.into_iter()
+			.group_by(|e| e.text.group)
 		{
 			txt.push(Spans::from(Span::styled(
 				Cow::from(key.to_string()),
@@ -221,22 +318,19 @@ impl HelpComponent {
 
 				processed += 1;
 
-				txt.push(Spans::from(Span::styled(
-					Cow::from(if is_selected {
+				txt.push(Spans::from(self.highlighted(
+					if is_selected {
 						format!(">{}", command_info.text.name)
 					} else {
 						format!(" {}", command_info.text.name)
-					}),
-					self.theme.text(true, is_selected),
+					},
+					is_selected,
 				)));
 
 				if is_selected {
-					txt.push(Spans::from(Span::styled(
-						Cow::from(format!(
-							"  {}\n",
-							command_info.text.desc
-						)),
-						self.theme.text(true, is_selected),
+					txt.push(Spans::from(self.highlighted(
+						format!("  {}\n", command_info.text.desc),
+						is_selected,
 					)));
 				}
 			}
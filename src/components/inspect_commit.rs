@@ -1,7 +1,7 @@
 use super::{
 	command_pump, event_pump, visibility_blocking, CommandBlocking,
 	CommandInfo, CommitDetailsComponent, Component, DiffComponent,
-	DrawableComponent, EventState,
+	DrawableComponent, EventState, SharedOptions,
 };
 use crate::{
 	accessors,
@@ -111,6 +111,36 @@ impl Component for InspectCommitComponent {
 				true,
 				true,
 			));
+
+			out.push(CommandInfo::new(
+				strings::commands::open_in_browser(&self.key_config),
+				self.commit_id.is_some(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::branches_containing(
+					&self.key_config,
+				),
+				self.commit_id.is_some(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_goto_parent(
+					&self.key_config,
+				),
+				self.details.parent_count() > 0,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_next_parent(
+					&self.key_config,
+				),
+				true,
+				self.details.parent_count() > 1 || force_all,
+			));
 		}
 
 		visibility_blocking(self)
@@ -144,6 +174,36 @@ impl Component for InspectCommitComponent {
 						));
 						self.hide();
 					}
+				} else if e == self.key_config.open_in_browser {
+					if let Some(commit) = self.commit_id {
+						self.queue.push(
+							InternalEvent::OpenCommitInBrowser(
+								commit,
+							),
+						);
+					}
+				} else if e == self.key_config.log_branches_containing
+				{
+					if let Some(commit) = self.commit_id {
+						self.queue.push(
+							InternalEvent::BranchesContaining(commit),
+						);
+					}
+				} else if e == self.key_config.commit_goto_parent {
+					if let Some(parent) =
+						self.details.selected_parent()
+					{
+						self.queue.push(
+							InternalEvent::SelectCommitInRevlog(
+								parent,
+							),
+						);
+						self.open(parent, None)?;
+					}
+				} else if e == self.key_config.commit_next_parent
+					&& self.details.select_next_parent()?
+				{
+					self.update_diff()?;
 				} else if e == self.key_config.focus_left {
 					self.hide();
 				}
@@ -180,6 +240,7 @@ impl InspectCommitComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
@@ -193,6 +254,7 @@ impl InspectCommitComponent {
 				queue.clone(),
 				theme,
 				key_config.clone(),
+				options,
 				true,
 			),
 			commit_id: None,
@@ -231,21 +293,37 @@ impl InspectCommitComponent {
 				self.update()?;
 			} else if let AsyncGitNotification::Diff = ev {
 				self.update_diff()?;
+			} else if let AsyncGitNotification::CommitDiffStats = ev {
+				self.details.update_git(ev);
 			}
 		}
 
 		Ok(())
 	}
 
+	/// see `DiffComponent::search`
+	pub fn search_diff(
+		&mut self,
+		query: String,
+		case_sensitive: bool,
+	) {
+		self.diff.search(query, case_sensitive);
+	}
+
 	/// called when any tree component changed selection
 	pub fn update_diff(&mut self) -> Result<()> {
 		if self.is_visible() {
+			self.diff.set_commit(self.commit_id);
+
 			if let Some(id) = self.commit_id {
 				if let Some(f) = self.details.files().selection_file()
 				{
 					let diff_params = DiffParams {
 						path: f.path.clone(),
-						diff_type: DiffType::Commit(id),
+						diff_type: DiffType::Commit(
+							id,
+							self.details.parent_index(),
+						),
 						options: DiffOptions::default(),
 					};
 
@@ -258,8 +336,13 @@ impl InspectCommitComponent {
 						}
 					}
 
-					self.git_diff.request(diff_params)?;
-					self.diff.clear(true);
+					if let Some(diff) =
+						self.git_diff.request(diff_params)?
+					{
+						self.diff.update(f.path, false, diff);
+					} else {
+						self.diff.clear(true);
+					}
 					return Ok(());
 				}
 			}
@@ -0,0 +1,163 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// what the log gets filtered by
+#[derive(Copy, Clone, PartialEq)]
+pub enum LogFilterKind {
+	Author,
+	Path,
+	Date,
+}
+
+pub struct LogFilterComponent {
+	input: TextInputComponent,
+	kind: LogFilterKind,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for LogFilterComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for LogFilterComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::log_filter_confirm_msg(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter {
+					self.filter();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl LogFilterComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::log_filter_author_popup_title(&key_config),
+				&strings::log_filter_author_popup_msg(&key_config),
+				true,
+			),
+			kind: LogFilterKind::Author,
+			key_config,
+		}
+	}
+
+	/// opens the input prefilled with the active filter of `kind`
+	pub fn open(
+		&mut self,
+		kind: LogFilterKind,
+		value: String,
+	) -> Result<()> {
+		self.kind = kind;
+		let (title, msg) = match kind {
+			LogFilterKind::Author => (
+				strings::log_filter_author_popup_title(
+					&self.key_config,
+				),
+				strings::log_filter_author_popup_msg(
+					&self.key_config,
+				),
+			),
+			LogFilterKind::Path => (
+				strings::log_filter_path_popup_title(
+					&self.key_config,
+				),
+				strings::log_filter_path_popup_msg(&self.key_config),
+			),
+			LogFilterKind::Date => (
+				strings::log_filter_date_popup_title(
+					&self.key_config,
+				),
+				strings::log_filter_date_popup_msg(&self.key_config),
+			),
+		};
+		self.input.set_title(title);
+		self.input.set_default_msg(msg);
+		self.input.set_text(value);
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn filter(&mut self) {
+		self.queue.push(InternalEvent::FilterLog(
+			self.kind,
+			self.input.get_text().trim().to_string(),
+		));
+
+		self.input.clear();
+		self.hide();
+	}
+}
@@ -0,0 +1,212 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, MergeMode},
+	CWD,
+};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+
+const MODES: [MergeMode; 3] = [
+	MergeMode::FastForwardOnly,
+	MergeMode::NoFastForward,
+	MergeMode::Squash,
+];
+
+/// asks how to merge a branch into `HEAD`
+pub struct MergeBranchPopup {
+	theme: SharedTheme,
+	queue: Queue,
+	branch: String,
+	visible: bool,
+	selection: usize,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for MergeBranchPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const SIZE: (u16, u16) = (60, 5);
+
+			let area =
+				ui::centered_rect_absolute(SIZE.0, SIZE.1, f.size());
+			let area = area.intersection(rect);
+
+			let rows = MODES.iter().map(|mode| {
+				Row::new(vec![
+					Cell::from(strings::merge_mode_name(*mode))
+						.style(self.theme.text(true, false)),
+					Cell::from(strings::merge_mode_desc(*mode))
+						.style(self.theme.text(false, false)),
+				])
+			});
+
+			let table = Table::new(rows)
+				.widths(&[
+					Constraint::Length(12),
+					Constraint::Percentage(100),
+				])
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_merge_branch(&self.branch),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = TableState::default();
+			table_state.select(Some(self.selection));
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for MergeBranchPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::merge_branch_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.selection = self.selection.saturating_sub(1);
+				} else if key == self.key_config.move_down {
+					self.selection =
+						(self.selection + 1).min(MODES.len() - 1);
+				} else if key == self.key_config.enter {
+					self.merge();
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl MergeBranchPopup {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			branch: String::new(),
+			visible: false,
+			selection: 0,
+			key_config,
+		}
+	}
+
+	/// keeps the mode picked last time selected
+	pub fn open(&mut self, branch: String) -> Result<()> {
+		self.branch = branch;
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// a squash opens the commit popup to write the message for the
+	/// staged changes, conflicts are left to the pending merge flow
+	fn merge(&mut self) {
+		let mode = MODES[self.selection];
+
+		match sync::merge_branch_with(CWD, &self.branch, mode) {
+			Ok(_) => {
+				self.hide();
+				self.queue
+					.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+				if mode == MergeMode::Squash {
+					self.queue.push(InternalEvent::OpenCommit);
+				}
+			}
+			Err(e) => {
+				log::error!("merge branch: {}", e);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("merge branch error:\n{}", e),
+				));
+			}
+		}
+	}
+}
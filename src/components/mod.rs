@@ -1,67 +1,112 @@
+mod apply_patch;
+mod bisect;
 mod blame_file;
+mod branches_containing_popup;
 mod branchlist;
+mod change_set;
 mod changes;
+mod co_authors_popup;
 mod command;
+mod command_palette;
 mod commit;
 mod commit_details;
 mod commitlist;
+mod compare_branches;
 mod compare_commits;
 mod create_branch;
+mod create_worktree;
 mod cred;
 mod diff;
+mod diff_search;
+mod export_patch;
 mod externaleditor;
 mod file_find_popup;
 mod filetree;
+mod goto_line;
 mod help;
 mod inspect_commit;
+mod log_filter;
+mod merge_branch_popup;
 mod msg;
 mod options_popup;
 mod pull;
 mod push;
+mod push_summary;
 mod push_tags;
+mod recent_repos_popup;
+mod remote_edit;
+mod remotes;
 mod rename_branch;
 mod reset;
 mod revision_files;
 mod revision_files_popup;
+mod set_upstream;
 mod stashmsg;
+mod status_filter;
+mod submodules;
 mod syntax_text;
 mod tag_commit;
 mod taglist;
 mod textinput;
 mod utils;
+mod worktrees;
 
 pub use self::filetree::FileTreeComponent;
+pub use apply_patch::ApplyPatchComponent;
+pub use bisect::BisectPopup;
 pub use blame_file::BlameFileComponent;
+pub use branches_containing_popup::BranchesContainingPopup;
 pub use branchlist::BranchListComponent;
+pub use change_set::ChangeSetComponent;
 pub use changes::ChangesComponent;
+pub use co_authors_popup::CoAuthorsPopup;
 pub use command::{CommandInfo, CommandText};
+pub use command_palette::CommandPalettePopup;
 pub use commit::CommitComponent;
 pub use commit_details::CommitDetailsComponent;
 pub use commitlist::CommitList;
+pub use compare_branches::CompareBranchesComponent;
 pub use compare_commits::CompareCommitsComponent;
 pub use create_branch::CreateBranchComponent;
+pub use create_worktree::CreateWorktreeComponent;
 pub use diff::DiffComponent;
+pub use diff_search::DiffSearchComponent;
+pub use export_patch::ExportPatchComponent;
 pub use externaleditor::ExternalEditorComponent;
 pub use file_find_popup::FileFindPopup;
+pub use goto_line::GotoLineComponent;
 pub use help::HelpComponent;
 pub use inspect_commit::InspectCommitComponent;
+pub use log_filter::{LogFilterComponent, LogFilterKind};
+pub use merge_branch_popup::MergeBranchPopup;
 pub use msg::MsgComponent;
 pub use options_popup::{
-	AppOption, OptionsPopupComponent, SharedOptions,
+	AppOption, Options, OptionsPopupComponent, SharedOptions,
 };
 pub use pull::PullComponent;
 pub use push::PushComponent;
+pub use push_summary::PushSummaryPopup;
 pub use push_tags::PushTagsComponent;
+pub use recent_repos_popup::RecentReposPopup;
+pub use remote_edit::RemoteEditComponent;
+pub use remotes::RemotesComponent;
 pub use rename_branch::RenameBranchComponent;
 pub use reset::ConfirmComponent;
 pub use revision_files::RevisionFilesComponent;
 pub use revision_files_popup::RevisionFilesPopup;
+pub use set_upstream::SetUpstreamComponent;
 pub use stashmsg::StashMsgComponent;
+pub use status_filter::StatusFilterComponent;
+pub use submodules::SubmodulesListComponent;
 pub use syntax_text::SyntaxTextComponent;
 pub use tag_commit::TagCommitComponent;
 pub use taglist::TagListComponent;
 pub use textinput::{InputType, TextInputComponent};
-pub use utils::filetree::FileTreeItemKind;
+pub use utils::{
+	branch_label, checkout_commit, filetree::FileTreeItemKind,
+	parse_date_range, time_ago_string,
+};
+pub use worktrees::WorktreesComponent;
 
 use crate::ui::style::Theme;
 use anyhow::Result;
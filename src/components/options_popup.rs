@@ -1,10 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, fs, path::Path, rc::Rc};
 
 use super::{
 	visibility_blocking, CommandBlocking, CommandInfo, Component,
 	DrawableComponent, EventState,
 };
 use crate::{
+	args::{get_app_config_path, get_repo_config_path},
 	components::utils::string_width_align,
 	keys::SharedKeyConfig,
 	queue::{InternalEvent, Queue},
@@ -14,6 +15,7 @@ use crate::{
 use anyhow::Result;
 use asyncgit::sync::{diff::DiffOptions, ShowUntrackedFilesConfig};
 use crossterm::event::Event;
+use serde::Deserialize;
 use tui::{
 	backend::Backend,
 	layout::{Alignment, Rect},
@@ -26,21 +28,305 @@ use tui::{
 #[derive(Clone, Copy, PartialEq)]
 pub enum AppOption {
 	StatusShowUntracked,
+	StatusSplit,
+	StatusStageOnTop,
+	StatusLargeFiles,
 	DiffIgnoreWhitespaces,
 	DiffContextLines,
 	DiffInterhunkLines,
+	DiffTabWidth,
+	DiffFileWrap,
+	DiffMaxLines,
+	CommitSignOff,
+	CommitConfirmLargeFiles,
+	CommitWrapBody,
+	CommitSuggestAmend,
+	FilesSyntaxHighlighting,
+	FilesSyntaxMaxSize,
+	LogRelativeTime,
+	RemoteNotifyAfter,
+	GeneralStatusBar,
+	GeneralReadOnly,
 }
 
-#[derive(Default, Copy, Clone)]
+/// the options in the order the popup lists them
+const OPTIONS: &[AppOption] = &[
+	AppOption::StatusShowUntracked,
+	AppOption::StatusSplit,
+	AppOption::StatusStageOnTop,
+	AppOption::StatusLargeFiles,
+	AppOption::DiffIgnoreWhitespaces,
+	AppOption::DiffContextLines,
+	AppOption::DiffInterhunkLines,
+	AppOption::DiffTabWidth,
+	AppOption::DiffFileWrap,
+	AppOption::DiffMaxLines,
+	AppOption::CommitSignOff,
+	AppOption::CommitConfirmLargeFiles,
+	AppOption::CommitWrapBody,
+	AppOption::CommitSuggestAmend,
+	AppOption::FilesSyntaxHighlighting,
+	AppOption::FilesSyntaxMaxSize,
+	AppOption::LogRelativeTime,
+	AppOption::RemoteNotifyAfter,
+	AppOption::GeneralStatusBar,
+	AppOption::GeneralReadOnly,
+];
+
+/// percentage of the width the file lists take in the status tab
+const STATUS_SPLIT_DEFAULT: u16 = 50;
+const STATUS_SPLIT_MIN: u16 = 20;
+const STATUS_SPLIT_MAX: u16 = 80;
+const STATUS_SPLIT_STEP: u16 = 5;
+
+#[derive(Copy, Clone)]
+pub struct StatusLayout {
+	pub split: u16,
+	pub stage_on_top: bool,
+}
+
+impl Default for StatusLayout {
+	fn default() -> Self {
+		Self {
+			split: STATUS_SPLIT_DEFAULT,
+			stage_on_top: false,
+		}
+	}
+}
+
+impl StatusLayout {
+	/// width percentage of the file lists (clamped to sane bounds)
+	pub fn split(&self) -> u16 {
+		self.split.clamp(STATUS_SPLIT_MIN, STATUS_SPLIT_MAX)
+	}
+}
+
+/// size in MB above which files get flagged in the status tab
+const LARGE_FILE_MB_DEFAULT: u64 = 10;
+
+/// number of columns a tab expands to in diffs and file views
+const TAB_WIDTH_DEFAULT: usize = 2;
+const TAB_WIDTH_MIN: usize = 1;
+const TAB_WIDTH_MAX: usize = 8;
+
+/// lines above which a diff is only rendered on demand
+const DIFF_MAX_LINES_DEFAULT: usize = 10_000;
+const DIFF_MAX_LINES_STEP: usize = 5_000;
+
+/// size in KB above which files are shown without syntax highlighting
+const SYNTAX_MAX_KB_DEFAULT: u64 = 512;
+const SYNTAX_MAX_KB_MIN: u64 = 128;
+const SYNTAX_MAX_KB_STEP: u64 = 128;
+
+/// seconds a push or fetch has to take to send a desktop notification
+const NOTIFY_AFTER_SECS_DEFAULT: u64 = 0;
+const NOTIFY_AFTER_SECS_STEP: u64 = 10;
+
+/// see `StatusBar` for the supported placeholders
+const STATUS_BAR_DEFAULT: &str = "{pending} {branch} {state}";
+
+#[derive(Clone)]
 pub struct Options {
 	pub status_show_untracked: Option<ShowUntrackedFilesConfig>,
+	pub status_layout: StatusLayout,
 	pub diff: DiffOptions,
+	pub tab_width: usize,
+	/// jumping past the last changed file from the diff starts over
+	pub diff_file_wrap: bool,
+	/// `0` always renders the whole diff
+	pub diff_max_lines: usize,
+	pub commit_sign_off: bool,
+	/// `0` disables flagging large files
+	pub status_large_file_mb: u64,
+	pub commit_confirm_large_files: bool,
+	/// reflow the commit body at `COMMIT_BODY_WIDTH` columns
+	pub commit_wrap_body: bool,
+	/// open the commit popup amending an unpushed `HEAD` if the
+	/// staged files were all changed by it
+	pub commit_suggest_amend: bool,
+	pub syntax_highlighting: bool,
+	pub syntax_max_kb: u64,
+	/// show commit times in lists as age (`3d ago`)
+	pub log_relative_time: bool,
+	/// format of the status bar, empty hides it
+	pub status_bar: String,
+	/// `0` disables desktop notifications
+	pub notify_after_secs: u64,
+	/// `--read-only`, nothing may change the repo
+	pub read_only: bool,
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Self {
+			status_show_untracked: None,
+			status_layout: StatusLayout::default(),
+			diff: DiffOptions::default(),
+			tab_width: TAB_WIDTH_DEFAULT,
+			diff_file_wrap: false,
+			diff_max_lines: DIFF_MAX_LINES_DEFAULT,
+			commit_sign_off: false,
+			status_large_file_mb: LARGE_FILE_MB_DEFAULT,
+			commit_confirm_large_files: true,
+			commit_wrap_body: false,
+			commit_suggest_amend: false,
+			syntax_highlighting: true,
+			syntax_max_kb: SYNTAX_MAX_KB_DEFAULT,
+			log_relative_time: false,
+			status_bar: STATUS_BAR_DEFAULT.to_string(),
+			notify_after_secs: NOTIFY_AFTER_SECS_DEFAULT,
+			read_only: false,
+		}
+	}
+}
+
+impl Options {
+	/// defaults overridden by the global and then the repo local
+	/// `options.ron`
+	pub fn init() -> Result<Self> {
+		let mut options = Self::default();
+
+		let dirs =
+			[get_app_config_path().ok(), get_repo_config_path()];
+		for file in
+			dirs.iter().flatten().map(|dir| dir.join(OPTIONS_FILE))
+		{
+			if file.exists() {
+				OptionsFile::read(&file)?.apply(&mut options);
+			}
+		}
+
+		Ok(options)
+	}
+
+	/// size in bytes above which a file counts as large
+	pub const fn large_file_threshold(&self) -> u64 {
+		self.status_large_file_mb.saturating_mul(1024 * 1024)
+	}
+
+	/// size in bytes above which files skip syntax highlighting
+	pub const fn syntax_max_size(&self) -> u64 {
+		self.syntax_max_kb.saturating_mul(1024)
+	}
+}
+
+const OPTIONS_FILE: &str = "options.ron";
+
+#[derive(Copy, Clone, Deserialize)]
+enum UntrackedFiles {
+	No,
+	Normal,
+	All,
+}
+
+impl From<UntrackedFiles> for ShowUntrackedFilesConfig {
+	fn from(untracked: UntrackedFiles) -> Self {
+		match untracked {
+			UntrackedFiles::No => Self::No,
+			UntrackedFiles::Normal => Self::Normal,
+			UntrackedFiles::All => Self::All,
+		}
+	}
+}
+
+/// options set in an `options.ron`, the unset ones keep their value
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OptionsFile {
+	status_show_untracked: Option<UntrackedFiles>,
+	status_split: Option<u16>,
+	status_stage_on_top: Option<bool>,
+	status_large_file_mb: Option<u64>,
+	diff_ignore_whitespace: Option<bool>,
+	diff_context: Option<u32>,
+	diff_interhunk_lines: Option<u32>,
+	tab_width: Option<usize>,
+	diff_file_wrap: Option<bool>,
+	diff_max_lines: Option<usize>,
+	commit_sign_off: Option<bool>,
+	commit_confirm_large_files: Option<bool>,
+	commit_wrap_body: Option<bool>,
+	commit_suggest_amend: Option<bool>,
+	syntax_highlighting: Option<bool>,
+	syntax_max_kb: Option<u64>,
+	log_relative_time: Option<bool>,
+	status_bar: Option<String>,
+	notify_after_secs: Option<u64>,
+}
+
+impl OptionsFile {
+	fn read(file: &Path) -> Result<Self> {
+		Ok(ron::de::from_bytes(&fs::read(file)?)?)
+	}
+
+	fn apply(self, options: &mut Options) {
+		if let Some(untracked) = self.status_show_untracked {
+			options.status_show_untracked = Some(untracked.into());
+		}
+		if let Some(split) = self.status_split {
+			options.status_layout.split = split;
+		}
+		if let Some(stage_on_top) = self.status_stage_on_top {
+			options.status_layout.stage_on_top = stage_on_top;
+		}
+		if let Some(mb) = self.status_large_file_mb {
+			options.status_large_file_mb = mb;
+		}
+		if let Some(ignore) = self.diff_ignore_whitespace {
+			options.diff.ignore_whitespace = ignore;
+		}
+		if let Some(context) = self.diff_context {
+			options.diff.context = context;
+		}
+		if let Some(lines) = self.diff_interhunk_lines {
+			options.diff.interhunk_lines = lines;
+		}
+		if let Some(width) = self.tab_width {
+			options.tab_width =
+				width.clamp(TAB_WIDTH_MIN, TAB_WIDTH_MAX);
+		}
+		if let Some(wrap) = self.diff_file_wrap {
+			options.diff_file_wrap = wrap;
+		}
+		if let Some(lines) = self.diff_max_lines {
+			options.diff_max_lines = lines;
+		}
+		if let Some(sign_off) = self.commit_sign_off {
+			options.commit_sign_off = sign_off;
+		}
+		if let Some(confirm) = self.commit_confirm_large_files {
+			options.commit_confirm_large_files = confirm;
+		}
+		if let Some(wrap) = self.commit_wrap_body {
+			options.commit_wrap_body = wrap;
+		}
+		if let Some(suggest) = self.commit_suggest_amend {
+			options.commit_suggest_amend = suggest;
+		}
+		if let Some(highlighting) = self.syntax_highlighting {
+			options.syntax_highlighting = highlighting;
+		}
+		if let Some(kb) = self.syntax_max_kb {
+			options.syntax_max_kb = kb.max(SYNTAX_MAX_KB_MIN);
+		}
+		if let Some(relative) = self.log_relative_time {
+			options.log_relative_time = relative;
+		}
+		if let Some(format) = self.status_bar {
+			options.status_bar = format;
+		}
+		if let Some(secs) = self.notify_after_secs {
+			options.notify_after_secs = secs;
+		}
+	}
 }
 
 pub type SharedOptions = Rc<RefCell<Options>>;
 
 pub struct OptionsPopupComponent {
 	selection: AppOption,
+	/// status bar format to restore after hiding it
+	status_bar_format: String,
 	queue: Queue,
 	visible: bool,
 	key_config: SharedKeyConfig,
@@ -58,6 +344,7 @@ impl OptionsPopupComponent {
 	) -> Self {
 		Self {
 			selection: AppOption::StatusShowUntracked,
+			status_bar_format: STATUS_BAR_DEFAULT.to_string(),
 			queue: queue.clone(),
 			visible: false,
 			key_config,
@@ -66,10 +353,27 @@ impl OptionsPopupComponent {
 		}
 	}
 
+	/// loads the options of the repo switched to,
+	/// `--read-only` is kept
+	pub fn reload(&mut self) -> Result<()> {
+		let mut options = Options::init()?;
+
+		let mut current = self.options.borrow_mut();
+		options.read_only = current.read_only;
+		*current = options;
+
+		Ok(())
+	}
+
 	fn get_text(&self, width: u16) -> Vec<Spans> {
 		let mut txt: Vec<Spans> = Vec::with_capacity(10);
 
 		self.add_status(&mut txt, width);
+		self.add_commit(&mut txt, width);
+		self.add_files(&mut txt, width);
+		self.add_log(&mut txt, width);
+		self.add_remote(&mut txt, width);
+		self.add_general(&mut txt, width);
 
 		txt
 	}
@@ -89,6 +393,35 @@ impl OptionsPopupComponent {
 			},
 			self.is_select(AppOption::StatusShowUntracked),
 		);
+		self.add_entry(
+			txt,
+			width,
+			"Split (%)",
+			&self.options.borrow().status_layout.split().to_string(),
+			self.is_select(AppOption::StatusSplit),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Stage on top",
+			&self
+				.options
+				.borrow()
+				.status_layout
+				.stage_on_top
+				.to_string(),
+			self.is_select(AppOption::StatusStageOnTop),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Large file (MB)",
+			&match self.options.borrow().status_large_file_mb {
+				0 => String::from("Off"),
+				mb => mb.to_string(),
+			},
+			self.is_select(AppOption::StatusLargeFiles),
+		);
 		Self::add_header(txt, "");
 
 		Self::add_header(txt, "Diff");
@@ -113,6 +446,140 @@ impl OptionsPopupComponent {
 			&self.options.borrow().diff.interhunk_lines.to_string(),
 			self.is_select(AppOption::DiffInterhunkLines),
 		);
+		self.add_entry(
+			txt,
+			width,
+			"Tab width",
+			&self.options.borrow().tab_width.to_string(),
+			self.is_select(AppOption::DiffTabWidth),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Wrap file jumps",
+			&self.options.borrow().diff_file_wrap.to_string(),
+			self.is_select(AppOption::DiffFileWrap),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Large diff (lines)",
+			&match self.options.borrow().diff_max_lines {
+				0 => String::from("Off"),
+				lines => lines.to_string(),
+			},
+			self.is_select(AppOption::DiffMaxLines),
+		);
+	}
+
+	fn add_commit(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Commit");
+		self.add_entry(
+			txt,
+			width,
+			"Sign-off",
+			&self.options.borrow().commit_sign_off.to_string(),
+			self.is_select(AppOption::CommitSignOff),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Confirm large files",
+			&self
+				.options
+				.borrow()
+				.commit_confirm_large_files
+				.to_string(),
+			self.is_select(AppOption::CommitConfirmLargeFiles),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Wrap body (72)",
+			&self.options.borrow().commit_wrap_body.to_string(),
+			self.is_select(AppOption::CommitWrapBody),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Amend unpushed",
+			&self.options.borrow().commit_suggest_amend.to_string(),
+			self.is_select(AppOption::CommitSuggestAmend),
+		);
+	}
+
+	fn add_files(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Files");
+		self.add_entry(
+			txt,
+			width,
+			"Syntax highlighting",
+			&self.options.borrow().syntax_highlighting.to_string(),
+			self.is_select(AppOption::FilesSyntaxHighlighting),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Highlight limit (KB)",
+			&self.options.borrow().syntax_max_kb.to_string(),
+			self.is_select(AppOption::FilesSyntaxMaxSize),
+		);
+	}
+
+	fn add_log(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Log");
+		self.add_entry(
+			txt,
+			width,
+			"Relative time",
+			&self.options.borrow().log_relative_time.to_string(),
+			self.is_select(AppOption::LogRelativeTime),
+		);
+	}
+
+	fn add_remote(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Remote");
+		self.add_entry(
+			txt,
+			width,
+			"Notify after (s)",
+			&match self.options.borrow().notify_after_secs {
+				0 => String::from("Off"),
+				secs => secs.to_string(),
+			},
+			self.is_select(AppOption::RemoteNotifyAfter),
+		);
+	}
+
+	fn add_general(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "General");
+		self.add_entry(
+			txt,
+			width,
+			"Status bar",
+			&match self.options.borrow().status_bar.as_str() {
+				"" => String::from("Off"),
+				format => format.to_string(),
+			},
+			self.is_select(AppOption::GeneralStatusBar),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Read-only (--read-only)",
+			&self.options.borrow().read_only.to_string(),
+			self.is_select(AppOption::GeneralReadOnly),
+		);
 	}
 
 	fn is_select(&self, kind: AppOption) -> bool {
@@ -149,36 +616,29 @@ impl OptionsPopupComponent {
 	}
 
 	fn move_selection(&mut self, up: bool) {
-		if up {
-			self.selection = match self.selection {
-				AppOption::StatusShowUntracked => {
-					AppOption::DiffInterhunkLines
-				}
-				AppOption::DiffIgnoreWhitespaces => {
-					AppOption::StatusShowUntracked
-				}
-				AppOption::DiffContextLines => {
-					AppOption::DiffIgnoreWhitespaces
-				}
-				AppOption::DiffInterhunkLines => {
-					AppOption::DiffContextLines
-				}
-			};
+		let idx = OPTIONS
+			.iter()
+			.position(|option| *option == self.selection)
+			.unwrap_or_default();
+
+		let idx = if up {
+			idx.checked_sub(1).unwrap_or(OPTIONS.len() - 1)
 		} else {
-			self.selection = match self.selection {
-				AppOption::StatusShowUntracked => {
-					AppOption::DiffIgnoreWhitespaces
-				}
-				AppOption::DiffIgnoreWhitespaces => {
-					AppOption::DiffContextLines
-				}
-				AppOption::DiffContextLines => {
-					AppOption::DiffInterhunkLines
-				}
-				AppOption::DiffInterhunkLines => {
-					AppOption::StatusShowUntracked
-				}
-			};
+			(idx + 1) % OPTIONS.len()
+		};
+
+		self.selection = OPTIONS[idx];
+	}
+
+	/// hiding keeps the format to show it again
+	fn toggle_status_bar(&mut self) {
+		let mut options = self.options.borrow_mut();
+
+		if options.status_bar.is_empty() {
+			options.status_bar = self.status_bar_format.clone();
+		} else {
+			self.status_bar_format =
+				std::mem::take(&mut options.status_bar);
 		}
 	}
 
@@ -205,6 +665,30 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().status_show_untracked =
 						untracked;
 				}
+				AppOption::StatusSplit => {
+					let old =
+						self.options.borrow().status_layout.split();
+					self.options.borrow_mut().status_layout.split =
+						(old + STATUS_SPLIT_STEP)
+							.min(STATUS_SPLIT_MAX);
+				}
+				AppOption::StatusStageOnTop => {
+					let old = self
+						.options
+						.borrow()
+						.status_layout
+						.stage_on_top;
+					self.options
+						.borrow_mut()
+						.status_layout
+						.stage_on_top = !old;
+				}
+				AppOption::StatusLargeFiles => {
+					let old =
+						self.options.borrow().status_large_file_mb;
+					self.options.borrow_mut().status_large_file_mb =
+						old.saturating_add(1);
+				}
 				AppOption::DiffIgnoreWhitespaces => {
 					let old =
 						self.options.borrow().diff.ignore_whitespace;
@@ -224,6 +708,69 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().diff.interhunk_lines =
 						old.saturating_add(1);
 				}
+				AppOption::DiffTabWidth => {
+					let old = self.options.borrow().tab_width;
+					self.options.borrow_mut().tab_width =
+						(old + 1).min(TAB_WIDTH_MAX);
+				}
+				AppOption::DiffFileWrap => {
+					let old = self.options.borrow().diff_file_wrap;
+					self.options.borrow_mut().diff_file_wrap = !old;
+				}
+				AppOption::DiffMaxLines => {
+					let old = self.options.borrow().diff_max_lines;
+					self.options.borrow_mut().diff_max_lines =
+						old.saturating_add(DIFF_MAX_LINES_STEP);
+				}
+				AppOption::CommitSignOff => {
+					let old = self.options.borrow().commit_sign_off;
+					self.options.borrow_mut().commit_sign_off = !old;
+				}
+				AppOption::CommitConfirmLargeFiles => {
+					let old = self
+						.options
+						.borrow()
+						.commit_confirm_large_files;
+					self.options
+						.borrow_mut()
+						.commit_confirm_large_files = !old;
+				}
+				AppOption::CommitWrapBody => {
+					let old = self.options.borrow().commit_wrap_body;
+					self.options.borrow_mut().commit_wrap_body = !old;
+				}
+				AppOption::CommitSuggestAmend => {
+					let old =
+						self.options.borrow().commit_suggest_amend;
+					self.options.borrow_mut().commit_suggest_amend =
+						!old;
+				}
+				AppOption::FilesSyntaxHighlighting => {
+					let old =
+						self.options.borrow().syntax_highlighting;
+					self.options.borrow_mut().syntax_highlighting =
+						!old;
+				}
+				AppOption::FilesSyntaxMaxSize => {
+					let old = self.options.borrow().syntax_max_kb;
+					self.options.borrow_mut().syntax_max_kb =
+						old.saturating_add(SYNTAX_MAX_KB_STEP);
+				}
+				AppOption::LogRelativeTime => {
+					let old = self.options.borrow().log_relative_time;
+					self.options.borrow_mut().log_relative_time =
+						!old;
+				}
+				AppOption::RemoteNotifyAfter => {
+					let old = self.options.borrow().notify_after_secs;
+					self.options.borrow_mut().notify_after_secs =
+						old.saturating_add(NOTIFY_AFTER_SECS_STEP);
+				}
+				AppOption::GeneralStatusBar => {
+					self.toggle_status_bar()
+				}
+				// set by `--read-only` only, keys got unbound at start
+				AppOption::GeneralReadOnly => (),
 			};
 		} else {
 			match self.selection {
@@ -247,6 +794,30 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().status_show_untracked =
 						untracked;
 				}
+				AppOption::StatusSplit => {
+					let old =
+						self.options.borrow().status_layout.split();
+					self.options.borrow_mut().status_layout.split =
+						old.saturating_sub(STATUS_SPLIT_STEP)
+							.max(STATUS_SPLIT_MIN);
+				}
+				AppOption::StatusStageOnTop => {
+					let old = self
+						.options
+						.borrow()
+						.status_layout
+						.stage_on_top;
+					self.options
+						.borrow_mut()
+						.status_layout
+						.stage_on_top = !old;
+				}
+				AppOption::StatusLargeFiles => {
+					let old =
+						self.options.borrow().status_large_file_mb;
+					self.options.borrow_mut().status_large_file_mb =
+						old.saturating_sub(1);
+				}
 				AppOption::DiffIgnoreWhitespaces => {
 					let old =
 						self.options.borrow().diff.ignore_whitespace;
@@ -266,6 +837,69 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().diff.interhunk_lines =
 						old.saturating_sub(1);
 				}
+				AppOption::DiffTabWidth => {
+					let old = self.options.borrow().tab_width;
+					self.options.borrow_mut().tab_width =
+						old.saturating_sub(1).max(TAB_WIDTH_MIN);
+				}
+				AppOption::DiffFileWrap => {
+					let old = self.options.borrow().diff_file_wrap;
+					self.options.borrow_mut().diff_file_wrap = !old;
+				}
+				AppOption::DiffMaxLines => {
+					let old = self.options.borrow().diff_max_lines;
+					self.options.borrow_mut().diff_max_lines =
+						old.saturating_sub(DIFF_MAX_LINES_STEP);
+				}
+				AppOption::CommitSignOff => {
+					let old = self.options.borrow().commit_sign_off;
+					self.options.borrow_mut().commit_sign_off = !old;
+				}
+				AppOption::CommitConfirmLargeFiles => {
+					let old = self
+						.options
+						.borrow()
+						.commit_confirm_large_files;
+					self.options
+						.borrow_mut()
+						.commit_confirm_large_files = !old;
+				}
+				AppOption::CommitWrapBody => {
+					let old = self.options.borrow().commit_wrap_body;
+					self.options.borrow_mut().commit_wrap_body = !old;
+				}
+				AppOption::CommitSuggestAmend => {
+					let old =
+						self.options.borrow().commit_suggest_amend;
+					self.options.borrow_mut().commit_suggest_amend =
+						!old;
+				}
+				AppOption::FilesSyntaxHighlighting => {
+					let old =
+						self.options.borrow().syntax_highlighting;
+					self.options.borrow_mut().syntax_highlighting =
+						!old;
+				}
+				AppOption::FilesSyntaxMaxSize => {
+					let old = self.options.borrow().syntax_max_kb;
+					self.options.borrow_mut().syntax_max_kb = old
+						.saturating_sub(SYNTAX_MAX_KB_STEP)
+						.max(SYNTAX_MAX_KB_MIN);
+				}
+				AppOption::LogRelativeTime => {
+					let old = self.options.borrow().log_relative_time;
+					self.options.borrow_mut().log_relative_time =
+						!old;
+				}
+				AppOption::RemoteNotifyAfter => {
+					let old = self.options.borrow().notify_after_secs;
+					self.options.borrow_mut().notify_after_secs =
+						old.saturating_sub(NOTIFY_AFTER_SECS_STEP);
+				}
+				AppOption::GeneralStatusBar => {
+					self.toggle_status_bar()
+				}
+				AppOption::GeneralReadOnly => (),
 			};
 		}
 
@@ -281,7 +915,7 @@ impl DrawableComponent for OptionsPopupComponent {
 		area: Rect,
 	) -> Result<()> {
 		if self.is_visible() {
-			const SIZE: (u16, u16) = (50, 10);
+			const SIZE: (u16, u16) = (50, 35);
 			let area =
 				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
 
@@ -377,3 +1011,76 @@ impl Component for OptionsPopupComponent {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		AppOption, Options, OptionsFile, OptionsPopupComponent,
+		OPTIONS,
+	};
+	use crate::queue::Queue;
+	use asyncgit::sync::ShowUntrackedFilesConfig;
+	use std::{cell::RefCell, rc::Rc};
+
+	#[test]
+	fn test_options_file() {
+		let file: OptionsFile = ron::de::from_str(
+			"(status_show_untracked: Some(No), diff_context: Some(5), tab_width: Some(20))",
+		)
+		.unwrap();
+
+		let mut options = Options::default();
+		file.apply(&mut options);
+
+		assert!(
+			options.status_show_untracked
+				== Some(ShowUntrackedFilesConfig::No)
+		);
+		assert_eq!(options.diff.context, 5);
+		assert_eq!(options.tab_width, 8);
+		assert_eq!(
+			options.diff.interhunk_lines,
+			Options::default().diff.interhunk_lines
+		);
+	}
+
+	#[test]
+	fn test_move_selection() {
+		let mut popup = OptionsPopupComponent::new(
+			&Queue::new(),
+			Default::default(),
+			Default::default(),
+			Rc::new(RefCell::new(Options::default())),
+		);
+
+		popup.move_selection(true);
+		assert!(popup.selection == AppOption::GeneralReadOnly);
+
+		popup.move_selection(false);
+		assert!(popup.selection == AppOption::StatusShowUntracked);
+
+		for _ in 1..OPTIONS.len() {
+			popup.move_selection(false);
+		}
+		assert!(popup.selection == AppOption::GeneralReadOnly);
+	}
+
+	#[test]
+	fn test_toggle_status_bar() {
+		let options = Rc::new(RefCell::new(Options::default()));
+		options.borrow_mut().status_bar = String::from("{branch}");
+
+		let mut popup = OptionsPopupComponent::new(
+			&Queue::new(),
+			Default::default(),
+			Default::default(),
+			options.clone(),
+		);
+
+		popup.toggle_status_bar();
+		assert!(options.borrow().status_bar.is_empty());
+
+		popup.toggle_status_bar();
+		assert_eq!(options.borrow().status_bar, "{branch}");
+	}
+}
@@ -1,10 +1,12 @@
 use super::PushComponent;
 use crate::{
 	components::{
-		cred::CredComponent, visibility_blocking, CommandBlocking,
-		CommandInfo, Component, DrawableComponent, EventState,
+		cred::CredComponent, time_ago_string, visibility_blocking,
+		CommandBlocking, CommandInfo, Component, DrawableComponent,
+		EventState, SharedOptions,
 	},
 	keys::SharedKeyConfig,
+	notification,
 	queue::{Action, InternalEvent, Queue},
 	strings, try_or_popup,
 	ui::{self, style::SharedTheme},
@@ -17,13 +19,14 @@ use asyncgit::{
 			extract_username_password, need_username_password,
 			BasicAuthCredential,
 		},
-		get_default_remote,
+		get_default_remote, last_fetch_time,
 	},
 	AsyncFetch, AsyncGitNotification, FetchRequest, RemoteProgress,
 	CWD,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
+use std::time::{Instant, SystemTime};
 use tui::{
 	backend::Backend,
 	layout::Rect,
@@ -38,10 +41,14 @@ pub struct PullComponent {
 	git_fetch: AsyncFetch,
 	progress: Option<RemoteProgress>,
 	pending: bool,
+	/// when the fetch was requested, to notify about slow ones
+	started: Option<Instant>,
 	branch: String,
+	last_fetch: Option<SystemTime>,
 	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 	input_cred: CredComponent,
 }
 
@@ -52,12 +59,15 @@ impl PullComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
 			pending: false,
+			started: None,
 			visible: false,
 			branch: String::new(),
+			last_fetch: None,
 			git_fetch: AsyncFetch::new(sender),
 			progress: None,
 			input_cred: CredComponent::new(
@@ -66,6 +76,7 @@ impl PullComponent {
 			),
 			theme,
 			key_config,
+			options,
 		}
 	}
 
@@ -93,10 +104,13 @@ impl PullComponent {
 		&mut self,
 		cred: Option<BasicAuthCredential>,
 	) -> Result<()> {
+		let remote = get_default_remote(CWD)?;
 		self.pending = true;
+		self.started = Some(Instant::now());
 		self.progress = None;
+		self.last_fetch = last_fetch_time(CWD, &remote)?;
 		self.git_fetch.request(FetchRequest {
-			remote: get_default_remote(CWD)?,
+			remote,
 			branch: self.branch.clone(),
 			basic_credential: cred,
 		})?;
@@ -113,13 +127,25 @@ impl PullComponent {
 	pub fn update_git(&mut self, ev: AsyncGitNotification) {
 		if self.is_visible() {
 			if let AsyncGitNotification::Fetch = ev {
-				if let Err(error) = self.update() {
+				let res = self.update();
+
+				if let Err(error) = &res {
 					self.pending = false;
 					self.hide();
 					self.queue.push(InternalEvent::ShowErrorMsg(
 						format!("fetch failed:\n{}", error),
 					));
 				}
+
+				if !self.pending {
+					notification::notify_remote_op(
+						self.started.take(),
+						self.options.borrow().notify_after_secs,
+						"fetch",
+						&self.branch,
+						res.err().map(|e| e.to_string()).as_deref(),
+					);
+				}
 			}
 		}
 	}
@@ -201,17 +227,33 @@ impl DrawableComponent for PullComponent {
 		if self.visible {
 			let (state, progress) =
 				PushComponent::get_progress(&self.progress);
+			let label = if self.progress.is_some() {
+				format!("{} {}%", state, progress)
+			} else {
+				state
+			};
+
+			let title = self.last_fetch.map_or_else(
+				|| String::from(strings::PULL_POPUP_MSG),
+				|time| {
+					format!(
+						"{} ({})",
+						strings::PULL_POPUP_MSG,
+						strings::last_fetch(&time_ago_string(time))
+					)
+				},
+			);
 
 			let area = ui::centered_rect_absolute(30, 3, f.size());
 
 			f.render_widget(Clear, area);
 			f.render_widget(
 				Gauge::default()
-					.label(state.as_str())
+					.label(label.as_str())
 					.block(
 						Block::default()
 							.title(Span::styled(
-								strings::PULL_POPUP_MSG,
+								title,
 								self.theme.title(true),
 							))
 							.borders(Borders::ALL)
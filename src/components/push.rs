@@ -2,8 +2,10 @@ use crate::{
 	components::{
 		cred::CredComponent, visibility_blocking, CommandBlocking,
 		CommandInfo, Component, DrawableComponent, EventState,
+		SharedOptions,
 	},
 	keys::SharedKeyConfig,
+	notification,
 	queue::{InternalEvent, Queue},
 	strings,
 	ui::{self, style::SharedTheme},
@@ -22,6 +24,7 @@ use asyncgit::{
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
+use std::time::Instant;
 use tui::{
 	backend::Backend,
 	layout::Rect,
@@ -35,13 +38,16 @@ use tui::{
 enum PushComponentModifier {
 	None,
 	Force,
+	ForceWithLease,
 	Delete,
 	ForceDelete,
 }
 
 impl PushComponentModifier {
 	pub(crate) fn force(&self) -> bool {
-		self == &Self::Force || self == &Self::ForceDelete
+		self == &Self::Force
+			|| self == &Self::ForceWithLease
+			|| self == &Self::ForceDelete
 	}
 	pub(crate) fn delete(&self) -> bool {
 		self == &Self::Delete || self == &Self::ForceDelete
@@ -55,10 +61,13 @@ pub struct PushComponent {
 	git_push: AsyncPush,
 	progress: Option<RemoteProgress>,
 	pending: bool,
+	/// when the push was requested, to notify about slow ones
+	started: Option<Instant>,
 	branch: String,
 	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 	input_cred: CredComponent,
 }
 
@@ -69,11 +78,13 @@ impl PushComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
 			modifier: PushComponentModifier::None,
 			pending: false,
+			started: None,
 			visible: false,
 			branch: String::new(),
 			git_push: AsyncPush::new(sender),
@@ -84,6 +95,7 @@ impl PushComponent {
 			),
 			theme,
 			key_config,
+			options,
 		}
 	}
 
@@ -94,16 +106,34 @@ impl PushComponent {
 		force: bool,
 		delete: bool,
 	) -> Result<()> {
-		self.branch = branch;
-		self.modifier = match (force, delete) {
+		let modifier = match (force, delete) {
 			(true, true) => PushComponentModifier::ForceDelete,
 			(false, true) => PushComponentModifier::Delete,
 			(true, false) => PushComponentModifier::Force,
 			(false, false) => PushComponentModifier::None,
 		};
 
+		self.push_with(branch, modifier)
+	}
+
+	/// force push unless someone pushed to `branch` since our last
+	/// fetch
+	pub fn push_with_lease(&mut self, branch: String) -> Result<()> {
+		self.push_with(branch, PushComponentModifier::ForceWithLease)
+	}
+
+	fn push_with(
+		&mut self,
+		branch: String,
+		modifier: PushComponentModifier,
+	) -> Result<()> {
+		self.branch = branch;
+		self.modifier = modifier;
+
 		self.show()?;
 
+		let force = self.modifier.force();
+
 		if need_username_password()? {
 			let cred =
 				extract_username_password().unwrap_or_else(|_| {
@@ -142,11 +172,14 @@ impl PushComponent {
 		};
 
 		self.pending = true;
+		self.started = Some(Instant::now());
 		self.progress = None;
 		self.git_push.request(PushRequest {
 			remote,
 			branch: self.branch.clone(),
 			force,
+			lease: self.modifier
+				== PushComponentModifier::ForceWithLease,
 			delete: self.modifier.delete(),
 			basic_credential: cred,
 		})?;
@@ -173,7 +206,17 @@ impl PushComponent {
 		self.progress = self.git_push.progress()?;
 
 		if !self.pending {
-			if let Some(err) = self.git_push.last_result()? {
+			let err = self.git_push.last_result()?;
+
+			notification::notify_remote_op(
+				self.started.take(),
+				self.options.borrow().notify_after_secs,
+				"push",
+				&self.branch,
+				err.as_deref(),
+			);
+
+			if let Some(err) = err {
 				self.queue.push(InternalEvent::ShowErrorMsg(
 					format!("push failed:\n{}", err),
 				));
@@ -245,7 +288,11 @@ impl DrawableComponent for PushComponent {
 					.block(
 						Block::default()
 							.title(Span::styled(
-								if self.modifier.force() {
+								if self.modifier
+									== PushComponentModifier::ForceWithLease
+								{
+									strings::FORCE_PUSH_LEASE_POPUP_MSG
+								} else if self.modifier.force() {
 									strings::FORCE_PUSH_POPUP_MSG
 								} else {
 									strings::PUSH_POPUP_MSG
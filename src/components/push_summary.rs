@@ -0,0 +1,327 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{self, CommitId, CommitInfo},
+	CWD,
+};
+use crossterm::event::Event;
+use std::{cell::Cell, convert::TryInto};
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell as TableCell, Clear, Row,
+		Table, TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+/// max number of commits listed
+const COMMITS_LIMIT: usize = 100;
+const MESSAGE_LENGTH_LIMIT: usize = 100;
+
+/// lists the commits a push sends before doing it
+pub struct PushSummaryPopup {
+	theme: SharedTheme,
+	queue: Queue,
+	branch: String,
+	/// tips of the branch and its upstream
+	tips: Option<(CommitId, CommitId)>,
+	/// only on the branch, newest first
+	commits: Vec<CommitInfo>,
+	visible: bool,
+	table_state: Cell<TableState>,
+	current_height: Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for PushSummaryPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(60, 50);
+			const MIN_SIZE: Size = Size::new(50, 12);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let author_width = self
+				.commits
+				.iter()
+				.fold(0, |acc, commit| acc.max(commit.author.len()))
+				.min(20);
+
+			let constraints = [
+				Constraint::Length(7),
+				Constraint::Length(author_width.try_into()?),
+				// message
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_push_summary(
+								&self.branch,
+								self.commits.len(),
+								COMMITS_LIMIT,
+							),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for PushSummaryPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::push_summary_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::push_summary_diff(
+					&self.key_config,
+				),
+				self.tips.is_some(),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.enter
+					|| key == self.key_config.push
+				{
+					self.queue.push(InternalEvent::Push(
+						self.branch.clone(),
+						false,
+						false,
+					));
+					self.hide();
+				} else if key == self.key_config.focus_right {
+					if let Some((tip, upstream)) = self.tips {
+						self.queue.push(
+							InternalEvent::CompareCommits(
+								tip,
+								Some(upstream),
+							),
+						);
+					}
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl PushSummaryPopup {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			branch: String::new(),
+			tips: None,
+			commits: Vec::new(),
+			visible: false,
+			table_state: Cell::new(TableState::default()),
+			current_height: Cell::new(0),
+			key_config,
+		}
+	}
+
+	/// lists the commits of `branch` its upstream does not have yet
+	pub fn open(&mut self, branch: String) -> Result<()> {
+		let (tip, upstream) =
+			sync::branch_upstream_tips(CWD, &branch)?;
+		let (ahead, _) = sync::branch_compare_commits(
+			CWD,
+			tip,
+			upstream,
+			COMMITS_LIMIT,
+		)?;
+
+		self.commits = sync::get_commits_info(
+			CWD,
+			&ahead,
+			MESSAGE_LENGTH_LIMIT,
+		)?;
+		self.tips = Some((tip, upstream));
+		self.branch = branch;
+		self.table_state.get_mut().select(Some(0));
+
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.commits.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		self.commits
+			.iter()
+			.map(|commit| {
+				Row::new(vec![
+					TableCell::from(commit.id.get_short_string())
+						.style(self.theme.commit_hash(false)),
+					TableCell::from(commit.author.clone())
+						.style(self.theme.commit_author(false)),
+					TableCell::from(commit.message.clone())
+						.style(self.theme.text(true, false)),
+				])
+			})
+			.collect()
+	}
+}
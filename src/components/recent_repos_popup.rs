@@ -0,0 +1,352 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	recent_repos::{current_repo, RecentRepos},
+	strings,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::sync::is_repo;
+use crossterm::event::Event;
+use std::path::PathBuf;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+struct RecentRepo {
+	path: PathBuf,
+	is_current: bool,
+	/// path no longer points to a repo
+	is_stale: bool,
+}
+
+///
+pub struct RecentReposPopup {
+	theme: SharedTheme,
+	queue: Queue,
+	recent: RecentRepos,
+	repos: Vec<RecentRepo>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for RecentReposPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 50);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let constraints = [
+				// symbol if repo is the current one
+				Constraint::Length(1),
+				// path
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_recent_repos(),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RecentReposPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::select_recent_repo(
+					&self.key_config,
+				),
+				self.selected_repo().map_or(false, |repo| {
+					!repo.is_current && !repo.is_stale
+				}),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::remove_recent_repo(
+					&self.key_config,
+				),
+				self.selected_repo()
+					.map_or(false, |repo| !repo.is_current),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.remove_recent_repo {
+					self.remove_selected()?;
+				} else if key == self.key_config.enter {
+					if let Some(repo) =
+						self.selected_repo().filter(|repo| {
+							!repo.is_current && !repo.is_stale
+						}) {
+						self.queue.push(InternalEvent::SwitchRepo(
+							repo.path.clone(),
+						));
+						self.hide();
+					}
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl RecentReposPopup {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			recent: RecentRepos::default(),
+			repos: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.recent = RecentRepos::load();
+		self.update_repos();
+		self.table_state.get_mut().select(Some(0));
+
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_repos(&mut self) {
+		let current = current_repo().ok();
+
+		self.repos = self
+			.recent
+			.paths()
+			.iter()
+			.map(|path| RecentRepo {
+				path: path.clone(),
+				is_current: Some(path) == current.as_ref(),
+				is_stale: !path.to_str().map_or(false, is_repo),
+			})
+			.collect();
+	}
+
+	fn remove_selected(&mut self) -> Result<()> {
+		if let Some(path) = self
+			.selected_repo()
+			.filter(|repo| !repo.is_current)
+			.map(|repo| repo.path.clone())
+		{
+			self.recent.remove(&path);
+			self.recent.save()?;
+			self.update_repos();
+
+			let table_state = self.table_state.get_mut();
+			let selection = table_state
+				.selected()
+				.unwrap_or(0)
+				.min(self.repos.len().saturating_sub(1));
+			table_state.select(Some(selection));
+		}
+
+		Ok(())
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.repos.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		self.repos.iter().map(|repo| self.get_row(repo)).collect()
+	}
+
+	///
+	fn get_row(&self, repo: &RecentRepo) -> Row {
+		const CURRENT_SYMBOL: &str = "*";
+		const EMPTY_SYMBOL: &str = " ";
+
+		let cells: Vec<Cell> = vec![
+			Cell::from(if repo.is_current {
+				CURRENT_SYMBOL
+			} else {
+				EMPTY_SYMBOL
+			})
+			.style(self.theme.commit_author(false)),
+			Cell::from(repo.path.to_string_lossy().to_string())
+				.style(self.theme.text(!repo.is_stale, false)),
+		];
+
+		Row::new(cells)
+	}
+
+	fn selected_repo(&self) -> Option<&RecentRepo> {
+		let table_state = self.table_state.take();
+
+		let repo = table_state
+			.selected()
+			.and_then(|selected| self.repos.get(selected));
+
+		self.table_state.set(table_state);
+
+		repo
+	}
+}
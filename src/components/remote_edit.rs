@@ -0,0 +1,187 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue, RemoteEdit},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{sync, CWD};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// asks for the name or url of a remote depending on `RemoteEdit`
+pub struct RemoteEditComponent {
+	input: TextInputComponent,
+	edit: Option<RemoteEdit>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for RemoteEditComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for RemoteEditComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_edit_confirm_msg(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter {
+					self.confirm();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl RemoteEditComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				"",
+				"",
+				true,
+			),
+			edit: None,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self, edit: RemoteEdit) -> Result<()> {
+		let (title, msg, text) = match &edit {
+			RemoteEdit::Add => (
+				strings::remote_add_popup_title(),
+				strings::remote_add_popup_msg(),
+				String::new(),
+			),
+			RemoteEdit::AddUrl(_) => (
+				strings::remote_add_popup_title(),
+				strings::remote_url_popup_msg(),
+				String::new(),
+			),
+			RemoteEdit::Rename(name) => (
+				strings::remote_rename_popup_title(),
+				strings::remote_rename_popup_msg(),
+				name.clone(),
+			),
+			RemoteEdit::SetUrl(_, url) => (
+				strings::remote_url_popup_title(),
+				strings::remote_url_popup_msg(),
+				url.clone(),
+			),
+		};
+
+		self.input.set_title(title);
+		self.input.set_default_msg(msg);
+		self.input.set_text(text);
+		self.edit = Some(edit);
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// adding asks for the name first and then for the url
+	fn confirm(&mut self) {
+		let text = self.input.get_text().trim().to_string();
+
+		if text.is_empty() {
+			return;
+		}
+
+		let res = match &self.edit {
+			Some(RemoteEdit::Add) => {
+				self.queue.push(InternalEvent::EditRemote(
+					RemoteEdit::AddUrl(text),
+				));
+				return;
+			}
+			Some(RemoteEdit::AddUrl(name)) => {
+				sync::add_remote(CWD, name, &text)
+			}
+			Some(RemoteEdit::Rename(name)) => {
+				sync::rename_remote(CWD, name, &text)
+			}
+			Some(RemoteEdit::SetUrl(name, _)) => {
+				sync::set_remote_url(CWD, name, &text)
+			}
+			None => return,
+		};
+
+		match res {
+			Ok(_) => {
+				self.queue
+					.push(InternalEvent::Update(NeedsUpdate::ALL));
+				self.hide();
+				self.queue.push(InternalEvent::OpenRemotes);
+			}
+			Err(e) => {
+				log::error!("edit remote: {}", e);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("edit remote error:\n{}", e),
+				));
+			}
+		}
+	}
+}
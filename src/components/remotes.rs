@@ -0,0 +1,337 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{Action, InternalEvent, Queue, RemoteEdit},
+	strings,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{get_remotes_info, RemoteInfo},
+	CWD,
+};
+use crossterm::event::Event;
+use std::convert::TryInto;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+///
+pub struct RemotesComponent {
+	theme: SharedTheme,
+	queue: Queue,
+	remotes: Vec<RemoteInfo>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for RemotesComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 50);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let name_width = self
+				.remotes
+				.iter()
+				.fold(0, |acc, remote| acc.max(remote.name.len()));
+
+			let constraints = [
+				Constraint::Length(name_width.try_into()?),
+				// url
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_remotes(),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RemotesComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_add(&self.key_config),
+				true,
+				true,
+			));
+
+			let selected = self.selected_remote().is_some();
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_rename(&self.key_config),
+				selected,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_set_url(&self.key_config),
+				selected,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_remove(&self.key_config),
+				selected,
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.remote_add {
+					self.queue.push(InternalEvent::EditRemote(
+						RemoteEdit::Add,
+					));
+				} else if let Some(remote) =
+					self.selected_remote().cloned()
+				{
+					if key == self.key_config.remote_rename {
+						self.queue.push(InternalEvent::EditRemote(
+							RemoteEdit::Rename(remote.name),
+						));
+					} else if key == self.key_config.remote_set_url {
+						self.queue.push(InternalEvent::EditRemote(
+							RemoteEdit::SetUrl(
+								remote.name,
+								remote.url.unwrap_or_default(),
+							),
+						));
+					} else if key == self.key_config.remote_remove {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::DeleteRemote(remote.name),
+							),
+						);
+					}
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl RemotesComponent {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			remotes: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.update_remotes()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// keeps the selection in bounds after a remote was removed
+	pub fn update_remotes(&mut self) -> Result<()> {
+		self.remotes = get_remotes_info(CWD)?;
+
+		let table_state = self.table_state.get_mut();
+		let selection = table_state
+			.selected()
+			.unwrap_or_default()
+			.min(self.remotes.len().saturating_sub(1));
+		table_state.select(Some(selection));
+
+		Ok(())
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.remotes.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		self.remotes
+			.iter()
+			.map(|remote| {
+				Row::new(vec![
+					Cell::from(remote.name.clone())
+						.style(self.theme.branch(false, true)),
+					Cell::from(
+						remote.url.clone().unwrap_or_default(),
+					)
+					.style(self.theme.text(true, false)),
+				])
+			})
+			.collect()
+	}
+
+	fn selected_remote(&self) -> Option<&RemoteInfo> {
+		let table_state = self.table_state.take();
+
+		let remote = table_state
+			.selected()
+			.and_then(|selected| self.remotes.get(selected));
+
+		self.table_state.set(table_state);
+
+		remote
+	}
+}
@@ -193,13 +193,62 @@ impl ConfirmComponent {
                         branch.rsplit('/').next().expect("There was no / in the head reference which is impossible in git"),
                     ),
                 ),
+                Action::ForcePushWithLease(branch) => (
+                    strings::confirm_title_force_push_lease(
+                        &self.key_config,
+                    ),
+                    strings::confirm_msg_force_push_lease(
+                        &self.key_config,
+                        branch.rsplit('/').next().expect("There was no / in the head reference which is impossible in git"),
+                    ),
+                ),
                 Action::PullMerge{incoming,rebase} => (
                     strings::confirm_title_merge(&self.key_config,*rebase),
                     strings::confirm_msg_merge(&self.key_config,*incoming,*rebase),
                 ),
-                Action::AbortMerge => (
-                    strings::confirm_title_abortmerge(),
-                    strings::confirm_msg_abortmerge(),
+                Action::AbortPendingOp(state) => (
+                    strings::confirm_title_abort_pending_op(*state),
+                    strings::confirm_msg_abort_pending_op(),
+                ),
+                Action::ResetToOrigHead(id) => (
+                    strings::confirm_title_reset_orig_head(),
+                    strings::confirm_msg_reset_orig_head(id),
+                ),
+                Action::RewordMergeCommit => (
+                    strings::confirm_title_reword_merge(),
+                    strings::confirm_msg_reword_merge(),
+                ),
+                Action::CommitLargeFiles(count) => (
+                    strings::confirm_title_commit_large_files(),
+                    strings::confirm_msg_commit_large_files(*count),
+                ),
+                Action::IgnoreFolder(path) => (
+                    strings::confirm_title_ignore_folder(),
+                    strings::confirm_msg_ignore_folder(path),
+                ),
+                Action::ResetWorkdirAll(files) => (
+                    strings::confirm_title_reset_all(),
+                    strings::confirm_msg_reset_all(files),
+                ),
+                Action::ResetFiles(paths) => (
+                    strings::confirm_title_reset(),
+                    strings::confirm_msg_reset_files(paths.len()),
+                ),
+                Action::DeleteRemote(name) => (
+                    strings::confirm_title_delete_remote(),
+                    strings::confirm_msg_delete_remote(name),
+                ),
+                Action::RestoreFile(id, path) => (
+                    strings::confirm_title_restore_file(),
+                    strings::confirm_msg_restore_file(path, id),
+                ),
+                Action::CheckoutCommit(id) => (
+                    strings::confirm_title_checkout_commit(),
+                    strings::confirm_msg_checkout_commit(id),
+                ),
+                Action::CheckoutBranchStashed(branch_ref, files) => (
+                    strings::confirm_title_checkout_branch(),
+                    strings::confirm_msg_checkout_branch(branch_ref, files),
                 ),
             };
 		}
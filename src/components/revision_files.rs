@@ -1,12 +1,13 @@
 use super::{
-	utils::scroll_vertical::VerticalScroll, CommandBlocking,
-	CommandInfo, Component, DrawableComponent, EventState,
-	SyntaxTextComponent,
+	utils::{self, scroll_vertical::VerticalScroll},
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState, SharedOptions, SyntaxTextComponent,
 };
 use crate::{
 	keys::SharedKeyConfig,
-	queue::{InternalEvent, Queue},
+	queue::{Action, InternalEvent, Queue},
 	strings::{self, order, symbol},
+	try_or_popup,
 	ui::{self, common_nav, style::SharedTheme},
 	AsyncAppNotification, AsyncNotification,
 };
@@ -56,15 +57,18 @@ impl RevisionFilesComponent {
 		sender: &Sender<AsyncAppNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
 			tree: FileTree::default(),
 			scroll: VerticalScroll::new(),
 			current_file: SyntaxTextComponent::new(
+				queue,
 				sender,
 				key_config.clone(),
 				theme.clone(),
+				options,
 			),
 			theme,
 			files: Vec::new(),
@@ -141,11 +145,50 @@ impl RevisionFilesComponent {
 		})
 	}
 
+	fn restore_file(&self) -> bool {
+		match (self.revision, self.tree.selected_file()) {
+			(Some(id), Some(file)) => {
+				self.queue.push(InternalEvent::ConfirmAction(
+					Action::RestoreFile(
+						id,
+						file.full_path_str()
+							.strip_prefix("./")
+							.unwrap_or_default()
+							.to_string(),
+					),
+				));
+				true
+			}
+			_ => false,
+		}
+	}
+
+	fn copy_path(&self, absolute: bool) -> bool {
+		self.tree.selected_item().map_or(false, |item| {
+			let path = item.full_path_str();
+			try_or_popup!(
+				self,
+				"copy path error:",
+				utils::copy_path(
+					&self.queue,
+					path.strip_prefix("./").unwrap_or(path),
+					absolute,
+				)
+			);
+			true
+		})
+	}
+
 	fn open_finder(&self) {
 		self.queue
 			.push(InternalEvent::OpenFileFinder(self.files.clone()));
 	}
 
+	/// see `SyntaxTextComponent::goto_line`
+	pub fn goto_line(&self, line: usize) {
+		self.current_file.goto_line(line);
+	}
+
 	pub fn find_file(&mut self, file: &Option<PathBuf>) {
 		if let Some(file) = file {
 			self.tree.collapse_but_root();
@@ -271,6 +314,22 @@ impl Component for RevisionFilesComponent {
 				)
 				.order(order::NAV),
 			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::copy_path(&self.key_config),
+					self.tree.selected_item().is_some(),
+					true,
+				)
+				.order(order::RARE_ACTION),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::restore_file(&self.key_config),
+					self.tree.selected_file().is_some(),
+					true,
+				)
+				.order(order::RARE_ACTION),
+			);
 			tree_nav_cmds(&self.tree, &self.key_config, out);
 		} else {
 			self.current_file.commands(out, force_all);
@@ -295,6 +354,21 @@ impl Component for RevisionFilesComponent {
 					self.hide();
 					return Ok(EventState::Consumed);
 				}
+			} else if is_tree_focused
+				&& (key == self.key_config.copy
+					|| key == self.key_config.copy_absolute_path)
+			{
+				if self.copy_path(
+					key == self.key_config.copy_absolute_path,
+				) {
+					return Ok(EventState::Consumed);
+				}
+			} else if is_tree_focused
+				&& key == self.key_config.restore_file
+			{
+				if self.restore_file() {
+					return Ok(EventState::Consumed);
+				}
 			} else if key == self.key_config.move_right {
 				if is_tree_focused {
 					self.focus = Focus::File;
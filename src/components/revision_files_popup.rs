@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use super::{
 	revision_files::RevisionFilesComponent, visibility_blocking,
 	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState,
+	EventState, SharedOptions,
 };
 use crate::{
 	keys::SharedKeyConfig,
@@ -31,6 +31,7 @@ impl RevisionFilesPopup {
 		sender: &Sender<AsyncAppNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			files: RevisionFilesComponent::new(
@@ -38,6 +39,7 @@ impl RevisionFilesPopup {
 				sender,
 				theme,
 				key_config.clone(),
+				options,
 			),
 			visible: false,
 			key_config,
@@ -65,6 +67,11 @@ impl RevisionFilesPopup {
 	pub fn file_finder_update(&mut self, file: &Option<PathBuf>) {
 		self.files.find_file(file);
 	}
+
+	///
+	pub fn goto_line(&self, line: usize) {
+		self.files.goto_line(line);
+	}
 }
 
 impl DrawableComponent for RevisionFilesPopup {
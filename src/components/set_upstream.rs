@@ -0,0 +1,152 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{sync, CWD};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct SetUpstreamComponent {
+	input: TextInputComponent,
+	branch: Option<String>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for SetUpstreamComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for SetUpstreamComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::set_upstream_confirm_msg(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.enter {
+					self.set_upstream();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl SetUpstreamComponent {
+	///
+	pub fn new(
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::set_upstream_popup_title(&key_config),
+				&strings::set_upstream_popup_msg(&key_config),
+				true,
+			),
+			branch: None,
+			key_config,
+		}
+	}
+
+	/// `upstream` prefills the input, defaults to `origin/<branch>`
+	pub fn open(
+		&mut self,
+		branch: String,
+		upstream: Option<String>,
+	) -> Result<()> {
+		self.input.set_text(
+			upstream.unwrap_or_else(|| format!("origin/{}", branch)),
+		);
+		self.branch = Some(branch);
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// an empty input unsets the upstream
+	fn set_upstream(&mut self) {
+		if let Some(branch) = &self.branch {
+			let upstream = self.input.get_text().trim();
+			let upstream = if upstream.is_empty() {
+				None
+			} else {
+				Some(upstream)
+			};
+
+			match sync::set_upstream(CWD, branch, upstream) {
+				Ok(_) => {
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+					self.hide();
+					self.queue.push(InternalEvent::SelectBranch);
+				}
+				Err(e) => {
+					log::error!("set upstream: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("set upstream error:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
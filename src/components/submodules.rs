@@ -0,0 +1,380 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::{self, Size},
+	AsyncNotification,
+};
+use anyhow::Result;
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	submodule_update::AsyncSubmoduleUpdateJob,
+	sync::{get_submodules, SubmoduleInfo},
+	AsyncGitNotification, CWD,
+};
+use crossbeam_channel::Sender;
+use crossterm::event::Event;
+use std::convert::TryInto;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+///
+pub struct SubmodulesListComponent {
+	theme: SharedTheme,
+	queue: Queue,
+	submodules: Vec<SubmoduleInfo>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	async_update: AsyncSingleJob<AsyncSubmoduleUpdateJob>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for SubmodulesListComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 50);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let name_width = self
+				.submodules
+				.iter()
+				.fold(0, |acc, sub| acc.max(sub.name.len()));
+
+			let constraints = [
+				// symbol if submodule is not initialized
+				Constraint::Length(1),
+				Constraint::Length(name_width.try_into()?),
+				// commit id
+				Constraint::Length(7),
+				// url
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_submodules(
+								self.any_work_pending(),
+							),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for SubmodulesListComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::submodule_update(&self.key_config),
+				self.selected_submodule().is_some()
+					&& !self.any_work_pending(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::submodule_update_all(
+					&self.key_config,
+				),
+				!self.submodules.is_empty()
+					&& !self.any_work_pending(),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.submodule_update
+					&& !self.any_work_pending()
+				{
+					if let Some(name) = self
+						.selected_submodule()
+						.map(|s| s.name.clone())
+					{
+						self.update_submodules(vec![name]);
+					}
+				} else if key == self.key_config.submodule_update_all
+					&& !self.any_work_pending()
+				{
+					let names = self
+						.submodules
+						.iter()
+						.map(|s| s.name.clone())
+						.collect();
+					self.update_submodules(names);
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl SubmodulesListComponent {
+	pub fn new(
+		queue: &Queue,
+		sender: &Sender<AsyncGitNotification>,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			submodules: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			async_update: AsyncSingleJob::new(sender.clone()),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.table_state.get_mut().select(Some(0));
+		self.update_submodules_list()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn update(&mut self, ev: AsyncNotification) -> Result<()> {
+		if matches!(
+			ev,
+			AsyncNotification::Git(
+				AsyncGitNotification::SubmoduleUpdate
+			)
+		) {
+			if let Some(job) = self.async_update.take_last() {
+				if let Some(Err(e)) = job.result() {
+					log::error!("submodule update: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("submodule update error:\n{}", e),
+					));
+				}
+			}
+
+			self.update_submodules_list()?;
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		}
+
+		Ok(())
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.async_update.is_pending()
+	}
+
+	fn update_submodules_list(&mut self) -> Result<()> {
+		self.submodules = get_submodules(CWD)?;
+
+		Ok(())
+	}
+
+	/// inits submodules where needed, then checks out their
+	/// recorded commit
+	fn update_submodules(&mut self, names: Vec<String>) {
+		self.async_update
+			.spawn(AsyncSubmoduleUpdateJob::new(names, true));
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.submodules.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		self.submodules
+			.iter()
+			.map(|sub| self.get_row(sub))
+			.collect()
+	}
+
+	///
+	fn get_row(&self, sub: &SubmoduleInfo) -> Row {
+		const UNINITIALIZED_SYMBOL: &str = "-";
+		const EMPTY_SYMBOL: &str = " ";
+
+		let cells: Vec<Cell> = vec![
+			Cell::from(if sub.initialized {
+				EMPTY_SYMBOL
+			} else {
+				UNINITIALIZED_SYMBOL
+			})
+			.style(self.theme.commit_author(false)),
+			Cell::from(sub.name.clone())
+				.style(self.theme.text(true, false)),
+			Cell::from(
+				sub.id
+					.map(|id| id.get_short_string())
+					.unwrap_or_default(),
+			)
+			.style(self.theme.commit_hash(false)),
+			Cell::from(sub.url.clone().unwrap_or_default())
+				.style(self.theme.text(true, false)),
+		];
+
+		Row::new(cells)
+	}
+
+	fn selected_submodule(&self) -> Option<&SubmoduleInfo> {
+		let table_state = self.table_state.take();
+
+		let sub = table_state
+			.selected()
+			.and_then(|selected| self.submodules.get(selected));
+
+		self.table_state.set(table_state);
+
+		sub
+	}
+}
@@ -1,28 +1,30 @@
 use super::{
 	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState,
+	EventState, SharedOptions,
 };
 use crate::{
 	keys::SharedKeyConfig,
-	string_utils::tabs_to_spaces,
+	queue::{InternalEvent, Queue},
 	strings,
 	ui::{
-		self, common_nav, style::SharedTheme, AsyncSyntaxJob,
-		ParagraphState, ScrollPos, StatefulParagraph,
+		self, common_nav, style::SharedTheme, AsyncFileContentJob,
+		AsyncSyntaxJob, ParagraphState, ScrollPos, StatefulParagraph,
 	},
 	AsyncAppNotification, AsyncNotification, SyntaxHighlightProgress,
 };
 use anyhow::Result;
 use asyncgit::{
-	asyncjob::AsyncSingleJob,
-	sync::{self, TreeFile},
-	ProgressPercent, CWD,
+	asyncjob::AsyncSingleJob, sync::TreeFile, ProgressPercent,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
 use filetreelist::MoveSelection;
 use itertools::Either;
-use std::{cell::Cell, convert::From, path::Path};
+use std::{
+	cell::Cell,
+	convert::{From, TryFrom},
+	path::Path,
+};
 use tui::{
 	backend::Backend,
 	layout::Rect,
@@ -33,39 +35,54 @@ use tui::{
 
 pub struct SyntaxTextComponent {
 	current_file: Option<(String, Either<ui::SyntaxText, String>)>,
+	current_tab_width: usize,
+	/// highlighting enabled and size limit the file was loaded with
+	current_highlighting: (bool, u64),
+	/// file exceeded the size limit and is shown as plain text
+	highlight_skipped: bool,
+	async_content: AsyncSingleJob<AsyncFileContentJob>,
 	async_highlighting: AsyncSingleJob<AsyncSyntaxJob>,
 	syntax_progress: Option<ProgressPercent>,
 	key_config: SharedKeyConfig,
 	paragraph_state: Cell<ParagraphState>,
 	focused: bool,
+	queue: Queue,
 	theme: SharedTheme,
+	options: SharedOptions,
 }
 
 impl SyntaxTextComponent {
 	///
 	pub fn new(
+		queue: &Queue,
 		sender: &Sender<AsyncAppNotification>,
 		key_config: SharedKeyConfig,
 		theme: SharedTheme,
+		options: SharedOptions,
 	) -> Self {
 		Self {
+			async_content: AsyncSingleJob::new(sender.clone()),
 			async_highlighting: AsyncSingleJob::new(sender.clone()),
 			syntax_progress: None,
 			current_file: None,
+			current_tab_width: 0,
+			current_highlighting: (false, 0),
+			highlight_skipped: false,
 			paragraph_state: Cell::new(ParagraphState::default()),
 			focused: false,
+			queue: queue.clone(),
 			key_config,
 			theme,
+			options,
 		}
 	}
 
 	///
 	pub fn update(&mut self, ev: AsyncNotification) {
-		if let AsyncNotification::App(
-			AsyncAppNotification::SyntaxHighlighting(progress),
-		) = ev
-		{
-			match progress {
+		match ev {
+			AsyncNotification::App(
+				AsyncAppNotification::SyntaxHighlighting(progress),
+			) => match progress {
 				SyntaxHighlightProgress::Progress => {
 					self.syntax_progress =
 						self.async_highlighting.progress();
@@ -86,13 +103,59 @@ impl SyntaxTextComponent {
 						}
 					}
 				}
+			},
+			AsyncNotification::App(
+				AsyncAppNotification::FileContent,
+			) => self.content_loaded(),
+			AsyncNotification::Git(_) => (),
+		}
+	}
+
+	fn content_loaded(&mut self) {
+		if let Some(job) = self.async_content.take_last() {
+			if let Some((path, content)) = self.current_file.as_mut()
+			{
+				if job.path() == path.as_str() {
+					match job.result() {
+						Some(Ok(text)) => {
+							let (highlighting, max_size) =
+								self.current_highlighting;
+							let too_large =
+								text.len() as u64 > max_size;
+
+							// never start on huge files, the plain
+							// text is all we show for those
+							if highlighting && !too_large {
+								self.syntax_progress =
+									Some(ProgressPercent::empty());
+								self.async_highlighting.spawn(
+									AsyncSyntaxJob::new(
+										text.clone(),
+										path.clone(),
+									),
+								);
+							}
+							self.highlight_skipped =
+								highlighting && too_large;
+							*content = Either::Right(text);
+						}
+						Some(Err(e)) => {
+							*content = Either::Right(format!(
+								"error loading file: {}",
+								e
+							));
+						}
+						None => (),
+					}
+				}
 			}
 		}
 	}
 
 	///
 	pub fn any_work_pending(&self) -> bool {
-		self.async_highlighting.is_pending()
+		self.async_content.is_pending()
+			|| self.async_highlighting.is_pending()
 	}
 
 	///
@@ -102,42 +165,58 @@ impl SyntaxTextComponent {
 
 	///
 	pub fn load_file(&mut self, path: String, item: &TreeFile) {
+		let (tab_width, highlighting) = {
+			let options = self.options.borrow();
+			(
+				options.tab_width,
+				(
+					options.syntax_highlighting,
+					options.syntax_max_size(),
+				),
+			)
+		};
 		let already_loaded = self
 			.current_file
 			.as_ref()
 			.map(|(current_file, _)| current_file == &path)
-			.unwrap_or_default();
+			.unwrap_or_default()
+			&& self.current_tab_width == tab_width
+			&& self.current_highlighting == highlighting;
 
 		if !already_loaded {
-			//TODO: fetch file content async aswell
-			match sync::tree_file_content(CWD, item) {
-				Ok(content) => {
-					let content = tabs_to_spaces(content);
-					self.syntax_progress =
-						Some(ProgressPercent::empty());
-					self.async_highlighting.spawn(
-						AsyncSyntaxJob::new(
-							content.clone(),
-							path.clone(),
-						),
-					);
-
-					self.current_file =
-						Some((path, Either::Right(content)));
-				}
-				Err(e) => {
-					self.current_file = Some((
-						path,
-						Either::Right(format!(
-							"error loading file: {}",
-							e
-						)),
-					));
-				}
-			}
+			self.current_tab_width = tab_width;
+			self.current_highlighting = highlighting;
+			self.highlight_skipped = false;
+
+			// drop loads of files selected before that did not start yet
+			self.async_content.cancel();
+			self.async_highlighting.cancel();
+			self.syntax_progress = None;
+
+			self.async_content.spawn(AsyncFileContentJob::new(
+				path.clone(),
+				item.clone(),
+				tab_width,
+			));
+
+			self.current_file = Some((
+				path,
+				Either::Right(strings::loading_text(
+					&self.key_config,
+				)),
+			));
 		}
 	}
 
+	/// scrolls `line` (1-based) to the top, as far as the end of the
+	/// file allows. wrapped lines above it count as lines too
+	pub fn goto_line(&self, line: usize) -> bool {
+		let pos =
+			u16::try_from(line.saturating_sub(1)).unwrap_or(u16::MAX);
+
+		self.set_scroll(pos)
+	}
+
 	fn scroll(&self, nav: MoveSelection) -> bool {
 		let state = self.paragraph_state.get();
 
@@ -207,6 +286,9 @@ impl DrawableComponent for SyntaxTextComponent {
 				.unwrap_or_default(),
 			self.syntax_progress
 				.map(|p| format!(" ({}%)", p.progress))
+				.or_else(|| self
+					.highlight_skipped
+					.then(strings::syntax_highlighting_skipped))
 				.unwrap_or_default()
 		);
 
@@ -258,6 +340,19 @@ impl Component for SyntaxTextComponent {
 				)
 				.order(strings::order::NAV),
 			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::scroll_page(&self.key_config),
+					true,
+					true,
+				)
+				.order(strings::order::NAV),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::goto_line(&self.key_config),
+				self.current_file.is_some(),
+				true,
+			));
 		}
 		CommandBlocking::PassingOn
 	}
@@ -267,6 +362,13 @@ impl Component for SyntaxTextComponent {
 		event: crossterm::event::Event,
 	) -> Result<EventState> {
 		if let Event::Key(key) = event {
+			if key == self.key_config.goto_line
+				&& self.current_file.is_some()
+			{
+				self.queue.push(InternalEvent::OpenGotoLine);
+				return Ok(EventState::Consumed);
+			}
+
 			if let Some(nav) = common_nav(key, &self.key_config) {
 				return Ok(self
 					.scroll(nav)
@@ -1,12 +1,12 @@
 use super::{
-	utils, visibility_blocking, CommandBlocking, CommandInfo,
-	Component, DrawableComponent, EventState,
+	checkout_commit, utils, visibility_blocking, CommandBlocking,
+	CommandInfo, Component, DrawableComponent, EventState,
 };
 use crate::{
 	components::ScrollType,
 	keys::SharedKeyConfig,
 	queue::{Action, InternalEvent, Queue},
-	strings,
+	strings, try_or_popup,
 	ui::{self, Size},
 	AsyncNotification,
 };
@@ -18,7 +18,9 @@ use asyncgit::{
 		extract_username_password, need_username_password,
 		BasicAuthCredential,
 	},
-	sync::{get_tags_with_metadata, TagWithMetadata},
+	sync::{
+		get_tags_with_metadata, tag_version_cmp, TagWithMetadata,
+	},
 	AsyncGitNotification, CWD,
 };
 use crossbeam_channel::Sender;
@@ -41,6 +43,8 @@ pub struct TagListComponent {
 	theme: SharedTheme,
 	queue: Queue,
 	tags: Option<Vec<TagWithMetadata>>,
+	/// newest version first instead of newest commit first
+	sort_by_version: bool,
 	visible: bool,
 	table_state: std::cell::Cell<TableState>,
 	current_height: std::cell::Cell<usize>,
@@ -85,6 +89,8 @@ impl DrawableComponent for TagListComponent {
 				// author width
 				Constraint::Length(19),
 				// commit id
+				Constraint::Length(7),
+				// tag or commit message
 				Constraint::Percentage(100),
 			];
 
@@ -99,7 +105,7 @@ impl DrawableComponent for TagListComponent {
 					Block::default()
 						.borders(Borders::ALL)
 						.title(Span::styled(
-							strings::title_tags(),
+							strings::title_tags(self.sort_by_version),
 							self.theme.title(true),
 						))
 						.border_style(self.theme.block(true))
@@ -165,6 +171,16 @@ impl Component for TagListComponent {
 				self.valid_selection(),
 				true,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::checkout_tag(&self.key_config),
+				self.valid_selection(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::toggle_tag_sort(&self.key_config),
+				true,
+				true,
+			));
 			out.push(CommandInfo::new(
 				strings::commands::push_tags(&self.key_config),
 				true,
@@ -221,6 +237,20 @@ impl Component for TagListComponent {
 							Ok(EventState::Consumed)
 						},
 					);
+				} else if key == self.key_config.log_checkout_commit {
+					if let Some(id) =
+						self.selected_tag().map(|tag| tag.commit_id)
+					{
+						try_or_popup!(
+							self,
+							"checkout error:",
+							checkout_commit(&self.queue, id)
+						);
+						self.hide();
+					}
+				} else if key == self.key_config.toggle_tag_sort {
+					self.sort_by_version = !self.sort_by_version;
+					self.sort_tags();
 				} else if key == self.key_config.push {
 					self.queue.push(InternalEvent::PushTags);
 				}
@@ -258,6 +288,7 @@ impl TagListComponent {
 			theme,
 			queue: queue.clone(),
 			tags: None,
+			sort_by_version: false,
 			visible: false,
 			table_state: std::cell::Cell::new(TableState::default()),
 			current_height: std::cell::Cell::new(0),
@@ -323,10 +354,23 @@ impl TagListComponent {
 		let tags = get_tags_with_metadata(CWD)?;
 
 		self.tags = Some(tags);
+		self.sort_tags();
 
 		Ok(())
 	}
 
+	fn sort_tags(&mut self) {
+		if let Some(tags) = self.tags.as_mut() {
+			if self.sort_by_version {
+				tags.sort_by(|a, b| {
+					tag_version_cmp(&b.name, &a.name)
+				});
+			} else {
+				tags.sort_by(|a, b| b.time.cmp(&a.time));
+			}
+		}
+	}
+
 	pub fn update_missing_remote_tags(&mut self) {
 		self.async_remote_tags.spawn(AsyncRemoteTagsJob::new(
 			self.basic_credential.clone(),
@@ -402,8 +446,19 @@ impl TagListComponent {
 				.style(self.theme.commit_time(false)),
 			Cell::from(tag.author.clone())
 				.style(self.theme.commit_author(false)),
-			Cell::from(tag.message.clone())
-				.style(self.theme.text(true, false)),
+			Cell::from(tag.commit_id.get_short_string())
+				.style(self.theme.commit_hash(false)),
+			// first line of an annotated tag's message
+			Cell::from(
+				tag.annotation
+					.as_deref()
+					.and_then(|msg| msg.lines().next())
+					.map_or_else(
+						|| tag.message.clone(),
+						String::from,
+					),
+			)
+			.style(self.theme.text(true, false)),
 		];
 
 		Row::new(cells)
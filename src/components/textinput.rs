@@ -42,6 +42,7 @@ pub struct TextInputComponent {
 	input_type: InputType,
 	current_area: Cell<Rect>,
 	embed: bool,
+	wrap_width: Option<u16>,
 }
 
 impl TextInputComponent {
@@ -65,6 +66,7 @@ impl TextInputComponent {
 			input_type: InputType::Multiline,
 			current_area: Cell::new(Rect::default()),
 			embed: false,
+			wrap_width: None,
 		}
 	}
 
@@ -97,6 +99,12 @@ impl TextInputComponent {
 		self.embed = true;
 	}
 
+	/// limits the multiline popup to `width` columns of text so
+	/// lines soft-wrap there
+	pub fn set_wrap_width(&mut self, width: Option<u16>) {
+		self.wrap_width = width;
+	}
+
 	/// Move the cursor right one char.
 	fn incr_cursor(&mut self) {
 		if let Some(pos) = self.next_char_position() {
@@ -147,6 +155,11 @@ impl TextInputComponent {
 		self.title = t;
 	}
 
+	/// Set the `default_msg` shown while the input is empty.
+	pub fn set_default_msg(&mut self, msg: String) {
+		self.default_msg = msg;
+	}
+
 	fn get_draw_text(&self) -> Text {
 		let style = self.theme.text(true, false);
 
@@ -293,11 +306,20 @@ impl DrawableComponent for TextInputComponent {
 					InputType::Multiline => {
 						let area =
 							ui::centered_rect(60, 20, f.size());
-						ui::rect_inside(
+						let mut area = ui::rect_inside(
 							Size::new(10, 3),
 							f.size().into(),
 							area,
-						)
+						);
+						if let Some(width) = self.wrap_width {
+							// account for the borders
+							let width = width.saturating_add(2);
+							if area.width > width {
+								area.x += (area.width - width) / 2;
+								area.width = width;
+							}
+						}
+						area
 					}
 					_ => ui::centered_rect_absolute(32, 3, f.size()),
 				}
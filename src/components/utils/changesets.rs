@@ -0,0 +1,184 @@
+use asyncgit::StatusItem;
+use std::{collections::HashMap, convert::TryFrom};
+
+/// single named group of staged files,
+/// the name is used as the commit message
+pub struct ChangeSet {
+	pub name: String,
+	pub paths: Vec<String>,
+}
+
+/// staged files grouped into change sets that get committed
+/// one after another
+#[derive(Default)]
+pub struct ChangeSets {
+	sets: Vec<ChangeSet>,
+}
+
+impl ChangeSets {
+	///
+	pub fn is_empty(&self) -> bool {
+		self.sets.is_empty()
+	}
+
+	///
+	pub fn len(&self) -> usize {
+		self.sets.len()
+	}
+
+	///
+	pub fn clear(&mut self) {
+		self.sets.clear();
+	}
+
+	/// change sets in the order they were created
+	pub fn iter(&self) -> std::slice::Iter<'_, ChangeSet> {
+		self.sets.iter()
+	}
+
+	/// name of the change set `path` is part of
+	pub fn name_of(&self, path: &str) -> Option<&str> {
+		self.sets
+			.iter()
+			.find(|set| set.paths.iter().any(|p| p == path))
+			.map(|set| set.name.as_str())
+	}
+
+	/// name of the most recently created change set
+	pub fn last_name(&self) -> Option<&str> {
+		self.sets.last().map(|set| set.name.as_str())
+	}
+
+	/// moves `path` into the change set called `name` (creating it if
+	/// needed), an empty `name` removes `path` from all change sets
+	pub fn assign(&mut self, path: &str, name: &str) {
+		self.remove_path(path);
+
+		let name = name.trim();
+		if name.is_empty() {
+			return;
+		}
+
+		if let Some(set) =
+			self.sets.iter_mut().find(|set| set.name == name)
+		{
+			set.paths.push(path.to_string());
+		} else {
+			self.sets.push(ChangeSet {
+				name: name.to_string(),
+				paths: vec![path.to_string()],
+			});
+		}
+	}
+
+	/// drops the first `count` change sets (the ones that got
+	/// committed already)
+	pub fn remove_first(&mut self, count: usize) {
+		self.sets.drain(..count.min(self.sets.len()));
+	}
+
+	/// drops all paths that are no longer staged
+	pub fn retain_staged(&mut self, staged: &[StatusItem]) {
+		for set in &mut self.sets {
+			set.paths.retain(|path| {
+				staged.iter().any(|i| &i.path == path)
+			});
+		}
+		self.sets.retain(|set| !set.paths.is_empty());
+	}
+
+	/// marker char per path: `1`-`9` for the first nine sets, `+` after
+	pub fn markers(&self) -> HashMap<String, char> {
+		self.sets
+			.iter()
+			.enumerate()
+			.flat_map(|(idx, set)| {
+				let marker = std::char::from_digit(
+					u32::try_from(idx + 1).unwrap_or(u32::MAX),
+					10,
+				)
+				.unwrap_or('+');
+				set.paths.iter().map(move |p| (p.clone(), marker))
+			})
+			.collect()
+	}
+
+	fn remove_path(&mut self, path: &str) {
+		for set in &mut self.sets {
+			set.paths.retain(|p| p != path);
+		}
+		self.sets.retain(|set| !set.paths.is_empty());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use asyncgit::StatusItemType;
+
+	fn staged(paths: &[&str]) -> Vec<StatusItem> {
+		paths
+			.iter()
+			.map(|p| StatusItem {
+				path: String::from(*p),
+				status: StatusItemType::Modified,
+				mode_change: None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_assign() {
+		let mut sets = ChangeSets::default();
+
+		sets.assign("a", "first");
+		sets.assign("b", "second");
+		sets.assign("c", "first");
+
+		assert_eq!(sets.len(), 2);
+		assert_eq!(sets.name_of("c"), Some("first"));
+		assert_eq!(sets.last_name(), Some("second"));
+
+		sets.assign("b", "first");
+
+		assert_eq!(sets.len(), 1);
+		assert_eq!(sets.markers().get("b"), Some(&'1'));
+
+		sets.assign("a", " ");
+
+		assert_eq!(sets.name_of("a"), None);
+	}
+
+	#[test]
+	fn test_retain_staged() {
+		let mut sets = ChangeSets::default();
+
+		sets.assign("a", "first");
+		sets.assign("b", "second");
+
+		sets.retain_staged(&staged(&["a", "c"]));
+
+		assert_eq!(sets.len(), 1);
+		assert_eq!(sets.name_of("a"), Some("first"));
+		assert_eq!(sets.name_of("b"), None);
+	}
+
+	#[test]
+	fn test_remove_first() {
+		let mut sets = ChangeSets::default();
+
+		sets.assign("a", "first");
+		sets.assign("b", "second");
+		sets.assign("c", "third");
+
+		sets.remove_first(1);
+
+		assert_eq!(sets.len(), 2);
+		assert_eq!(sets.name_of("a"), None);
+		assert_eq!(sets.markers().get("b"), Some(&'1'));
+
+		sets.remove_first(5);
+
+		assert!(sets.is_empty());
+	}
+}
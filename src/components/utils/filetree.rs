@@ -63,10 +63,13 @@ impl FileTreeItem {
 			item_path.ancestors().count().saturating_sub(2),
 		)?;
 
+		// keep the `/` of untracked folders summarized into one item
+		let suffix = if item.path.ends_with('/') { "/" } else { "" };
+
 		let name = item_path
 			.file_name()
 			.map(OsStr::to_string_lossy)
-			.map(|x| x.to_string());
+			.map(|x| format!("{}{}", x, suffix));
 
 		match name {
 			Some(path) => Ok(Self {
@@ -269,6 +272,7 @@ mod tests {
 			.map(|a| StatusItem {
 				path: String::from(*a),
 				status: StatusItemType::Modified,
+				mode_change: None,
 			})
 			.collect::<Vec<_>>()
 	}
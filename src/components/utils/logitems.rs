@@ -1,8 +1,13 @@
 use asyncgit::sync::{CommitId, CommitInfo};
 use chrono::{DateTime, Duration, Local, NaiveDateTime, Utc};
-use std::slice::Iter;
+use std::{
+	collections::{HashMap, HashSet},
+	slice::Iter,
+};
 
-use crate::components::utils::emojifi_string;
+use crate::components::utils::{
+	emojifi_string, time_relative_string,
+};
 
 static SLICE_OFFSET_RELOAD_THRESHOLD: usize = 100;
 
@@ -43,7 +48,23 @@ impl From<CommitInfo> for LogEntry {
 }
 
 impl LogEntry {
-	pub fn time_to_string(&self, now: DateTime<Local>) -> String {
+	/// `relative` always shows the age like `3d ago`
+	pub fn time_to_string(
+		&self,
+		now: DateTime<Local>,
+		relative: bool,
+	) -> String {
+		if relative {
+			return format!(
+				"{: <10}",
+				time_relative_string(
+					self.time.timestamp(),
+					now.timestamp(),
+					true
+				)
+			);
+		}
+
 		let delta = now - self.time;
 		if delta < Duration::minutes(30) {
 			let delta_str = if delta < Duration::minutes(1) {
@@ -65,6 +86,8 @@ impl LogEntry {
 pub struct ItemBatch {
 	index_offset: usize,
 	items: Vec<LogEntry>,
+	/// messages of `items` are cut to this width
+	msg_width: usize,
 }
 
 impl ItemBatch {
@@ -82,6 +105,15 @@ impl ItemBatch {
 		self.items.iter()
 	}
 
+	/// loaded messages are cut to the width they were loaded for,
+	/// a different one needs all of them loaded again
+	pub fn set_msg_width(&mut self, width: usize) {
+		if width != self.msg_width {
+			self.msg_width = width;
+			self.items.clear();
+		}
+	}
+
 	/// clear curent list of items
 	pub fn clear(&mut self) {
 		self.items.clear();
@@ -98,6 +130,48 @@ impl ItemBatch {
 		self.index_offset = start_index;
 	}
 
+	/// keeps the loaded entries that are part of `ids`, `commits`
+	/// has to contain the others (see `missing`)
+	pub fn update_items(
+		&mut self,
+		start_index: usize,
+		ids: &[CommitId],
+		commits: Vec<CommitInfo>,
+	) {
+		let mut loaded = self
+			.items
+			.drain(..)
+			.map(|e| (e.id, e))
+			.collect::<HashMap<_, _>>();
+		let mut fetched = commits
+			.into_iter()
+			.map(|c| (c.id, c))
+			.collect::<HashMap<_, _>>();
+
+		self.items.extend(ids.iter().filter_map(|id| {
+			loaded
+				.remove(id)
+				.or_else(|| fetched.remove(id).map(LogEntry::from))
+		}));
+		self.index_offset = start_index;
+	}
+
+	/// the entries of `ids` that are not loaded yet
+	pub fn missing(&self, ids: &[CommitId]) -> Vec<CommitId> {
+		let loaded =
+			self.items.iter().map(|e| e.id).collect::<HashSet<_>>();
+
+		ids.iter()
+			.filter(|id| !loaded.contains(id))
+			.copied()
+			.collect()
+	}
+
+	/// `true` if the entry at `idx` is loaded
+	pub fn contains(&self, idx: usize) -> bool {
+		idx >= self.index_offset && idx < self.last_idx()
+	}
+
 	/// returns `true` if we should fetch updated list of items
 	pub fn needs_data(&self, idx: usize, idx_max: usize) -> bool {
 		let want_min =
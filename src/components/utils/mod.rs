@@ -1,8 +1,24 @@
-use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use crate::{
+	queue::{Action, InternalEvent, NeedsUpdate, Queue},
+	strings,
+};
+use anyhow::Result;
+use asyncgit::{
+	cached,
+	sync::{
+		self, status::StatusType, utils::repo_work_dir, CommitId,
+	},
+	CWD,
+};
+use chrono::{
+	DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc,
+};
 use lazy_static::lazy_static;
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path, time::SystemTime};
+use unicode_truncate::UnicodeTruncateStr;
 use unicode_width::UnicodeWidthStr;
 
+pub mod changesets;
 pub mod filetree;
 pub mod logitems;
 pub mod scroll_vertical;
@@ -23,6 +39,65 @@ macro_rules! try_or_popup {
 	};
 }
 
+/// the branch `HEAD` is on or `(detached @ <sha>)`
+pub fn branch_label(
+	branch_name: &cached::BranchName,
+) -> Option<String> {
+	branch_name.last().or_else(|| {
+		branch_name
+			.detached()
+			.map(|id| strings::detached_head(&id.get_short_string()))
+	})
+}
+
+/// checks out `id` detached, asks first if there are uncommitted
+/// changes
+pub fn checkout_commit(queue: &Queue, id: CommitId) -> Result<()> {
+	let changes = sync::status::get_status(
+		CWD,
+		StatusType::Both,
+		Some(sync::ShowUntrackedFilesConfig::No),
+	)?;
+
+	if changes.is_empty() {
+		sync::checkout_commit(CWD, id)?;
+		queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+	} else {
+		queue.push(InternalEvent::ConfirmAction(
+			Action::CheckoutCommit(id),
+		));
+	}
+
+	Ok(())
+}
+
+/// copies the repo relative `path` (or its absolute version)
+/// to the clipboard, shows it in a popup if that fails
+pub fn copy_path(
+	queue: &Queue,
+	path: &str,
+	absolute: bool,
+) -> Result<()> {
+	let path = if absolute {
+		Path::new(&repo_work_dir(CWD)?)
+			.join(path)
+			.to_string_lossy()
+			.to_string()
+	} else {
+		path.to_string()
+	};
+
+	if let Err(e) = crate::clipboard::copy_string(&path) {
+		// keep the path reachable without a clipboard
+		log::error!("copy to clipboard error: {} ({})", e, path);
+		queue.push(InternalEvent::ShowErrorMsg(
+			strings::copy_path_failed(&path),
+		));
+	}
+
+	Ok(())
+}
+
 /// helper func to convert unix time since epoch to formated time string in local timezone
 pub fn time_to_string(secs: i64, short: bool) -> String {
 	let time = DateTime::<Local>::from(DateTime::<Utc>::from_utc(
@@ -37,21 +112,128 @@ pub fn time_to_string(secs: i64, short: bool) -> String {
 	.to_string()
 }
 
+/// relative age of the unix time `secs` at `now` like `3 days ago`,
+/// `short` gives the compact `3d ago` that fits the log column
+pub fn time_relative_string(
+	secs: i64,
+	now: i64,
+	short: bool,
+) -> String {
+	const UNITS: [(i64, &str, &str); 6] = [
+		(60 * 60 * 24 * 365, "y", "year"),
+		(60 * 60 * 24 * 30, "mo", "month"),
+		(60 * 60 * 24 * 7, "w", "week"),
+		(60 * 60 * 24, "d", "day"),
+		(60 * 60, "h", "hour"),
+		(60, "m", "minute"),
+	];
+
+	let delta = now.saturating_sub(secs).max(0);
+
+	UNITS
+		.iter()
+		.find(|(unit_secs, _, _)| delta >= *unit_secs)
+		.map_or_else(
+			|| {
+				if short {
+					String::from("<1m ago")
+				} else {
+					String::from("just now")
+				}
+			},
+			|(unit_secs, short_unit, unit)| {
+				let count = delta / unit_secs;
+				if short {
+					format!("{}{} ago", count, short_unit)
+				} else {
+					format!(
+						"{} {}{} ago",
+						count,
+						unit,
+						if count == 1 { "" } else { "s" }
+					)
+				}
+			},
+		)
+}
+
+/// parses a date range like `2023-01-01..2023-06-30` into unix
+/// times (local timezone) of the start of the first and the end of
+/// the last day, either side may be left out to keep it open and a
+/// single date selects just that day
+pub fn parse_date_range(
+	input: &str,
+) -> Option<(Option<i64>, Option<i64>)> {
+	fn parse_day(day: &str) -> Option<NaiveDate> {
+		NaiveDate::parse_from_str(day.trim(), "%Y-%m-%d").ok()
+	}
+
+	fn local_timestamp(time: NaiveDateTime) -> Option<i64> {
+		Local
+			.from_local_datetime(&time)
+			.earliest()
+			.map(|time| time.timestamp())
+	}
+
+	let (since, until) =
+		input.find("..").map_or((input, input), |idx| {
+			(&input[..idx], &input[idx + 2..])
+		});
+
+	let since = match since.trim() {
+		"" => None,
+		day => {
+			Some(local_timestamp(parse_day(day)?.and_hms(0, 0, 0))?)
+		}
+	};
+	let until = match until.trim() {
+		"" => None,
+		day => Some(local_timestamp(
+			parse_day(day)?.and_hms(23, 59, 59),
+		)?),
+	};
+
+	Some((since, until))
+}
+
+/// helper func to describe how long ago `time` was (e.g. `2h`)
+pub fn time_ago_string(time: SystemTime) -> String {
+	let secs = SystemTime::now()
+		.duration_since(time)
+		.map(|d| d.as_secs())
+		.unwrap_or_default();
+
+	if secs < 60 {
+		String::from("<1m")
+	} else if secs < 60 * 60 {
+		format!("{}m", secs / 60)
+	} else if secs < 60 * 60 * 24 {
+		format!("{}h", secs / (60 * 60))
+	} else {
+		format!("{}d", secs / (60 * 60 * 24))
+	}
+}
+
+/// pads or truncates `s` to exactly `width` display columns,
+/// wide chars (CJK, emoji) are never cut in half
 #[inline]
 pub fn string_width_align(s: &str, width: usize) -> String {
 	static POSTFIX: &str = "..";
 
 	let len = UnicodeWidthStr::width(s);
-	let width_wo_postfix = width.saturating_sub(POSTFIX.len());
 
-	if (len >= width_wo_postfix && len <= width)
-		|| (len <= width_wo_postfix)
-	{
-		format!("{:w$}", s, w = width)
+	if len <= width {
+		format!("{}{}", s, " ".repeat(width - len))
 	} else {
-		let mut s = s.to_string();
-		s.truncate(find_truncate_point(&s, width_wo_postfix));
-		format!("{}{}", s, POSTFIX)
+		let (s, len) =
+			s.unicode_truncate(width.saturating_sub(POSTFIX.len()));
+		let len = len + POSTFIX.len();
+		format!(
+			"{}{}{}",
+			s,
+			POSTFIX,
+			" ".repeat(width.saturating_sub(len))
+		)
 	}
 }
 
@@ -70,7 +252,67 @@ pub fn emojifi_string(s: &mut String) {
 	}
 }
 
-#[inline]
-fn find_truncate_point(s: &str, chars: usize) -> usize {
-	s.chars().take(chars).map(char::len_utf8).sum()
+#[cfg(test)]
+mod tests {
+	use super::{parse_date_range, time_relative_string};
+
+	#[test]
+	fn test_parse_date_range() {
+		let (since, until) =
+			parse_date_range("2023-01-01..2023-01-02").unwrap();
+		assert_eq!(
+			until.unwrap() - since.unwrap(),
+			2 * 24 * 60 * 60 - 1
+		);
+
+		let (since, until) =
+			parse_date_range(" 2023-01-01 ").unwrap();
+		assert_eq!(until.unwrap() - since.unwrap(), 24 * 60 * 60 - 1);
+
+		assert!(matches!(
+			parse_date_range("2023-01-01.."),
+			Some((Some(_), None))
+		));
+		assert!(matches!(
+			parse_date_range("..2023-01-01"),
+			Some((None, Some(_)))
+		));
+
+		assert_eq!(parse_date_range("2023-13-01"), None);
+		assert_eq!(parse_date_range("yesterday..today"), None);
+	}
+	#[test]
+	fn test_time_relative_string() {
+		let now = 1_000_000_000;
+
+		assert_eq!(
+			time_relative_string(now - 5, now, false),
+			"just now"
+		);
+		assert_eq!(
+			time_relative_string(now - 5, now, true),
+			"<1m ago"
+		);
+		assert_eq!(
+			time_relative_string(now - 60, now, false),
+			"1 minute ago"
+		);
+		assert_eq!(
+			time_relative_string(now - 3 * 24 * 60 * 60, now, false),
+			"3 days ago"
+		);
+		assert_eq!(
+			time_relative_string(now - 3 * 24 * 60 * 60, now, true),
+			"3d ago"
+		);
+		assert_eq!(
+			time_relative_string(now - 400 * 24 * 60 * 60, now, true),
+			"1y ago"
+		);
+		// clock skew, commit from the future
+		assert_eq!(
+			time_relative_string(now + 60, now, false),
+			"just now"
+		);
+	}
 }
@@ -154,6 +154,114 @@ impl StatusTree {
 		})
 	}
 
+	/// collapses every folder but the ones containing the selection,
+	/// returns `true` if any folder changed
+	pub fn collapse_all(&mut self) -> bool {
+		let selected = self
+			.selected_item()
+			.map(|item| item.info.full_path)
+			.unwrap_or_default();
+
+		self.set_all_collapsed(|path| {
+			!selected.starts_with(&format!("{}/", path))
+		})
+	}
+
+	/// expands every folder, returns `true` if any folder changed
+	pub fn expand_all(&mut self) -> bool {
+		self.set_all_collapsed(|_| false)
+	}
+
+	/// selects the next (or previous) file, skipping folders and
+	/// expanding the ones containing it. `wrap` continues at the
+	/// other end of the tree. returns `true` if the selection changed
+	pub fn select_file(&mut self, forward: bool, wrap: bool) -> bool {
+		let selection = self.selection.unwrap_or(0);
+
+		let files = self
+			.tree
+			.items()
+			.iter()
+			.enumerate()
+			.filter(|(_, item)| {
+				matches!(item.kind, FileTreeItemKind::File(_))
+			})
+			.map(|(idx, _)| idx)
+			.collect::<Vec<_>>();
+
+		let target = if forward {
+			files
+				.iter()
+				.find(|idx| **idx > selection)
+				.or_else(|| files.first().filter(|_| wrap))
+		} else {
+			files
+				.iter()
+				.rev()
+				.find(|idx| **idx < selection)
+				.or_else(|| files.last().filter(|_| wrap))
+		}
+		.copied();
+
+		match target {
+			Some(target) if Some(target) != self.selection => {
+				let path = self.tree[target].info.full_path.clone();
+
+				for i in 0..target {
+					let is_parent = path.starts_with(&format!(
+						"{}/",
+						self.tree[i].info.full_path
+					));
+
+					if let FileTreeItemKind::Path(PathCollapsed(
+						ref mut collapsed,
+					)) = self.tree[i].kind
+					{
+						if is_parent {
+							*collapsed = false;
+						}
+					}
+				}
+
+				self.update_visibility(None, 0, true);
+				self.selection = Some(target);
+
+				true
+			}
+			_ => false,
+		}
+	}
+
+	fn set_all_collapsed(
+		&mut self,
+		collapse: impl Fn(&str) -> bool,
+	) -> bool {
+		let mut changed = false;
+
+		for i in 0..self.tree.len() {
+			let should_collapse =
+				collapse(&self.tree[i].info.full_path);
+
+			if let FileTreeItemKind::Path(PathCollapsed(
+				ref mut collapsed,
+			)) = self.tree[i].kind
+			{
+				changed |= *collapsed != should_collapse;
+				*collapsed = should_collapse;
+			}
+		}
+
+		if changed {
+			self.update_visibility(None, 0, true);
+
+			if let Some(idx) = self.selection {
+				self.selection = Some(self.find_visible_idx(idx));
+			}
+		}
+
+		changed
+	}
+
 	///
 	pub fn selected_item(&self) -> Option<FileTreeItem> {
 		self.selection.map(|i| self.tree[i].clone())
@@ -440,6 +548,7 @@ mod tests {
 			.map(|a| StatusItem {
 				path: String::from(*a),
 				status: StatusItemType::Modified,
+				mode_change: None,
 			})
 			.collect::<Vec<_>>()
 	}
@@ -740,6 +849,115 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_collapse_expand_all() {
+		let items = string_vec_to_status(&[
+			"a/b/c", //
+			"a/d",   //
+			"e/f",   //
+		]);
+
+		//0 a/
+		//1   b/
+		//2     c
+		//3   d
+		//4 e/
+		//5   f
+
+		let mut res = StatusTree::default();
+		res.update(&items).unwrap();
+		res.selection = Some(2);
+
+		assert!(res.collapse_all());
+
+		assert_eq!(
+			get_visibles(&res),
+			vec![
+				true,  //
+				true,  //
+				true,  //
+				true,  //
+				true,  //
+				false, //
+			]
+		);
+		assert_eq!(res.selection, Some(2));
+		assert!(!res.collapse_all());
+
+		res.selection = Some(0);
+
+		assert!(res.collapse_all());
+
+		assert_eq!(
+			get_visibles(&res),
+			vec![
+				true,  //
+				false, //
+				false, //
+				false, //
+				true,  //
+				false, //
+			]
+		);
+
+		assert!(res.expand_all());
+
+		assert_eq!(get_visibles(&res), vec![true; 6]);
+		assert!(!res.expand_all());
+	}
+
+	#[test]
+	fn test_select_file() {
+		let items = string_vec_to_status(&[
+			"a/b/c", //
+			"a/d",   //
+			"e/f",   //
+		]);
+
+		//0 a/
+		//1   b/
+		//2     c
+		//3   d
+		//4 e/
+		//5   f
+
+		let mut res = StatusTree::default();
+		res.update(&items).unwrap();
+		res.selection = Some(0);
+		res.collapse_all();
+
+		assert!(res.select_file(true, false));
+		assert_eq!(res.selection, Some(2));
+		assert_eq!(
+			get_visibles(&res),
+			vec![
+				true,  //
+				true,  //
+				true,  //
+				true,  //
+				true,  //
+				false, //
+			]
+		);
+
+		assert!(res.select_file(true, false));
+		assert_eq!(res.selection, Some(3));
+		assert!(res.select_file(true, false));
+		assert_eq!(res.selection, Some(5));
+		assert_eq!(get_visibles(&res), vec![true; 6]);
+		assert!(!res.select_file(true, false));
+		assert_eq!(res.selection, Some(5));
+
+		assert!(res.select_file(true, true));
+		assert_eq!(res.selection, Some(2));
+
+		assert!(!res.select_file(false, false));
+		assert!(res.select_file(false, true));
+		assert_eq!(res.selection, Some(5));
+		assert!(res.select_file(false, true));
+		assert_eq!(res.selection, Some(3));
+	}
+
 	#[test]
 	fn test_selection_skips_collapsed() {
 		let items = string_vec_to_status(&[
@@ -0,0 +1,336 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	components::ScrollType,
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{get_worktrees, WorktreeInfo},
+	CWD,
+};
+use crossterm::event::Event;
+use std::convert::TryInto;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+///
+pub struct WorktreesComponent {
+	theme: SharedTheme,
+	queue: Queue,
+	worktrees: Vec<WorktreeInfo>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for WorktreesComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 50);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.size(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(rect);
+
+			let name_width = self
+				.worktrees
+				.iter()
+				.fold(0, |acc, wt| acc.max(Self::name(wt).len()));
+			let branch_width =
+				self.worktrees.iter().fold(0, |acc, wt| {
+					acc.max(wt.branch.as_ref().map_or(0, String::len))
+				});
+
+			let constraints = [
+				// symbol if worktree is the current one
+				Constraint::Length(1),
+				Constraint::Length(name_width.try_into()?),
+				Constraint::Length(branch_width.try_into()?),
+				// path
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows)
+				.widths(&constraints)
+				.column_spacing(1)
+				.highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_worktrees(),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(&Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for WorktreesComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::open_create_worktree(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::select_worktree(&self.key_config),
+				self.selected_worktree()
+					.map_or(false, |wt| !wt.is_current),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key == self.key_config.exit_popup {
+					self.hide();
+				} else if key == self.key_config.move_up {
+					self.move_selection(ScrollType::Up);
+				} else if key == self.key_config.move_down {
+					self.move_selection(ScrollType::Down);
+				} else if key == self.key_config.shift_up
+					|| key == self.key_config.home
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key == self.key_config.shift_down
+					|| key == self.key_config.end
+				{
+					self.move_selection(ScrollType::End);
+				} else if key == self.key_config.page_down {
+					self.move_selection(ScrollType::PageDown);
+				} else if key == self.key_config.page_up {
+					self.move_selection(ScrollType::PageUp);
+				} else if key == self.key_config.create_worktree {
+					self.queue.push(
+						InternalEvent::OpenCreateWorktree(
+							String::new(),
+						),
+					);
+					self.hide();
+				} else if key == self.key_config.enter {
+					if let Some(wt) = self
+						.selected_worktree()
+						.filter(|wt| !wt.is_current)
+					{
+						self.queue.push(
+							InternalEvent::SwitchWorktree(
+								wt.path.clone(),
+							),
+						);
+						self.hide();
+					}
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl WorktreesComponent {
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			theme,
+			queue: queue.clone(),
+			worktrees: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.worktrees = get_worktrees(CWD)?;
+
+		let current = self
+			.worktrees
+			.iter()
+			.position(|wt| wt.is_current)
+			.unwrap_or_default();
+		self.table_state.get_mut().select(Some(current));
+
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn name(wt: &WorktreeInfo) -> &str {
+		wt.name.as_deref().unwrap_or(strings::WORKTREE_MAIN)
+	}
+
+	///
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.worktrees.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row> {
+		self.worktrees.iter().map(|wt| self.get_row(wt)).collect()
+	}
+
+	///
+	fn get_row(&self, wt: &WorktreeInfo) -> Row {
+		const CURRENT_SYMBOL: &str = "*";
+		const EMPTY_SYMBOL: &str = " ";
+
+		let cells: Vec<Cell> = vec![
+			Cell::from(if wt.is_current {
+				CURRENT_SYMBOL
+			} else {
+				EMPTY_SYMBOL
+			})
+			.style(self.theme.commit_author(false)),
+			Cell::from(Self::name(wt).to_string())
+				.style(self.theme.text(true, false)),
+			Cell::from(wt.branch.clone().unwrap_or_default())
+				.style(self.theme.branch(false, true)),
+			Cell::from(wt.path.to_string_lossy().to_string())
+				.style(self.theme.text(true, false)),
+		];
+
+		Row::new(cells)
+	}
+
+	fn selected_worktree(&self) -> Option<&WorktreeInfo> {
+		let table_state = self.table_state.take();
+
+		let wt = table_state
+			.selected()
+			.and_then(|selected| self.worktrees.get(selected));
+
+		self.table_state.set(table_state);
+
+		wt
+	}
+}
@@ -0,0 +1,117 @@
+//! headless `--status-json` and `--log-json` output for scripting
+
+use anyhow::Result;
+use asyncgit::{
+	sync::{
+		self,
+		status::{get_status, StatusType},
+		CommitInfo,
+	},
+	StatusItem, StatusItemType,
+};
+use std::fmt::Write;
+
+/// commits printed by `--log-json` without a count
+pub const LOG_COUNT_DEFAULT: usize = 100;
+
+/// quoted and escaped json string
+fn json_string(s: &str) -> String {
+	let mut res = String::with_capacity(s.len() + 2);
+	res.push('"');
+
+	for c in s.chars() {
+		match c {
+			'"' => res.push_str("\\\""),
+			'\\' => res.push_str("\\\\"),
+			'\n' => res.push_str("\\n"),
+			'\r' => res.push_str("\\r"),
+			'\t' => res.push_str("\\t"),
+			c if c < ' ' => {
+				let _ = write!(res, "\\u{:04x}", u32::from(c));
+			}
+			c => res.push(c),
+		}
+	}
+
+	res.push('"');
+	res
+}
+
+const fn status_name(status: StatusItemType) -> &'static str {
+	match status {
+		StatusItemType::New => "new",
+		StatusItemType::Modified => "modified",
+		StatusItemType::Deleted => "deleted",
+		StatusItemType::Renamed => "renamed",
+		StatusItemType::Typechange => "typechange",
+		StatusItemType::Conflicted => "conflicted",
+	}
+}
+
+fn status_items_json(items: &[StatusItem]) -> String {
+	let items: Vec<String> = items
+		.iter()
+		.map(|item| {
+			format!(
+				"{{\"path\":{},\"status\":{}}}",
+				json_string(&item.path),
+				json_string(status_name(item.status))
+			)
+		})
+		.collect();
+
+	format!("[{}]", items.join(","))
+}
+
+/// `{"staged":[..],"unstaged":[..]}` with the `path` and `status`
+/// of each changed file
+pub fn status_json(repo_path: &str) -> Result<String> {
+	let stage = get_status(repo_path, StatusType::Stage, None)?;
+	let workdir =
+		get_status(repo_path, StatusType::WorkingDir, None)?;
+
+	Ok(format!(
+		"{{\"staged\":{},\"unstaged\":{}}}",
+		status_items_json(&stage),
+		status_items_json(&workdir)
+	))
+}
+
+fn commit_json(commit: &CommitInfo) -> String {
+	format!(
+		"{{\"id\":{},\"author\":{},\"time\":{},\"message\":{}}}",
+		json_string(&commit.id.to_string()),
+		json_string(&commit.author),
+		commit.time,
+		json_string(&commit.message)
+	)
+}
+
+/// the last `count` commits from `HEAD` (newest first) with their
+/// `id`, `author`, unix `time` and the first line of the `message`
+pub fn log_json(repo_path: &str, count: usize) -> Result<String> {
+	let ids = sync::get_log_ids(repo_path, count)?;
+	let commits =
+		sync::get_commits_info(repo_path, &ids, usize::MAX)?;
+
+	let commits: Vec<String> =
+		commits.iter().map(commit_json).collect();
+
+	Ok(format!("[{}]", commits.join(",")))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::json_string;
+
+	#[test]
+	fn test_json_string() {
+		assert_eq!(json_string("foo"), "\"foo\"");
+		assert_eq!(
+			json_string("say \"hi\"\n\\o/"),
+			"\"say \\\"hi\\\"\\n\\\\o/\""
+		);
+		assert_eq!(json_string("\u{1b}[0m"), "\"\\u001b[0m\"");
+		assert_eq!(json_string("äö"), "\"äö\"");
+	}
+}
@@ -7,18 +7,374 @@ use ron::{
 	self,
 	ser::{to_string_pretty, PrettyConfig},
 };
-use serde::{Deserialize, Serialize};
+use serde::{
+	de::{self, MapAccess, Visitor},
+	Deserialize, Deserializer, Serialize,
+};
 use std::{
+	collections::HashMap,
+	fmt,
 	fs::{self, File},
 	io::{Read, Write},
 	path::PathBuf,
 	rc::Rc,
 };
 
-use crate::{args::get_app_config_path, strings::symbol};
+use crate::{
+	args::{get_app_config_path, get_repo_config_path},
+	strings::symbol,
+};
 
 pub type SharedKeyConfig = Rc<KeyConfig>;
 
+/// handled by the app in every tab
+const GLOBAL_KEYS: &[&str] = &[
+	"tab_status",
+	"tab_log",
+	"tab_files",
+	"tab_stashing",
+	"tab_stashes",
+	"tab_toggle",
+	"tab_toggle_reverse",
+	"cmd_bar_toggle",
+	"open_help",
+	"open_options",
+	"open_worktrees",
+	"open_remotes",
+	"open_recent_repos",
+	"open_command_palette",
+	"open_submodules",
+	"refresh",
+	"exit",
+	"quit",
+];
+
+/// keys active at the same time (next to `GLOBAL_KEYS`),
+/// popups are left out as they get the keys first
+const KEY_CONTEXTS: &[(&str, &[&str])] = &[
+	(
+		"status files",
+		&[
+			"abort_merge",
+			"apply_patch",
+			"commit_amend",
+			"edit_file",
+			"focus_left",
+			"focus_right",
+			"force_push",
+			"force_push_lease",
+			"pull",
+			"push",
+			"reset_orig_head",
+			"reword_head",
+			"select_branch",
+			"status_filter",
+			"toggle_workarea",
+			"undo_commit",
+			"blame",
+			"copy",
+			"copy_absolute_path",
+			"move_left",
+			"move_right",
+			"move_up",
+			"move_down",
+			"home",
+			"end",
+			"shift_up",
+			"shift_down",
+			"tree_collapse_all",
+			"tree_expand_all",
+			"change_set_assign",
+			"change_set_commit",
+			"enter",
+			"open_commit",
+			"status_ignore_file",
+			"status_reset_item",
+			"status_reset_all",
+			"status_stage_all",
+			"status_take_ours",
+			"status_take_theirs",
+			"status_mark_file",
+		],
+	),
+	(
+		"status diff",
+		&[
+			"abort_merge",
+			"edit_file",
+			"focus_left",
+			"copy",
+			"diff_copy_patch",
+			"diff_copy_permalink",
+			"diff_next_file",
+			"diff_prev_file",
+			"diff_reset_lines",
+			"diff_scroll_left",
+			"diff_scroll_right",
+			"diff_search",
+			"diff_search_next",
+			"diff_search_prev",
+			"diff_stage_lines",
+			"enter",
+			"export_patch",
+			"move_up",
+			"move_down",
+			"page_up",
+			"page_down",
+			"home",
+			"end",
+			"shift_up",
+			"shift_down",
+			"status_reset_item",
+		],
+	),
+	(
+		"log",
+		&[
+			"bisect",
+			"compare_commits",
+			"log_checkout_commit",
+			"log_create_branch",
+			"log_branches_containing",
+			"log_toggle_relative_time",
+			"copy",
+			"copy_full_hash",
+			"enter",
+			"focus_right",
+			"log_filter_author",
+			"log_filter_date",
+			"log_filter_path",
+			"log_tag_commit",
+			"open_file_tree",
+			"open_in_browser",
+			"push",
+			"reword_head",
+			"select_branch",
+			"tags",
+			"log_mark_commit",
+			"log_cherry_pick",
+			"commit_goto_parent",
+			"commit_next_parent",
+			"move_up",
+			"move_down",
+			"page_up",
+			"page_down",
+			"home",
+			"end",
+			"shift_up",
+			"shift_down",
+		],
+	),
+	(
+		"files",
+		&[
+			"blame",
+			"goto_line",
+			"copy",
+			"copy_absolute_path",
+			"file_find",
+			"restore_file",
+			"move_left",
+			"move_right",
+			"move_up",
+			"move_down",
+			"page_up",
+			"page_down",
+			"home",
+			"end",
+			"shift_up",
+			"shift_down",
+			"tree_collapse_recursive",
+			"tree_expand_recursive",
+		],
+	),
+	(
+		"stashing",
+		&[
+			"stashing_save",
+			"stashing_toggle_index",
+			"stashing_toggle_untracked",
+			"blame",
+			"copy",
+			"copy_absolute_path",
+			"move_left",
+			"move_right",
+			"move_up",
+			"move_down",
+			"home",
+			"end",
+			"shift_up",
+			"shift_down",
+			"tree_collapse_all",
+			"tree_expand_all",
+		],
+	),
+	(
+		"stashes",
+		&[
+			"enter",
+			"stash_apply",
+			"stash_drop",
+			"stash_open",
+			"log_mark_commit",
+			"log_toggle_relative_time",
+			"move_up",
+			"move_down",
+			"page_up",
+			"page_down",
+			"home",
+			"end",
+			"shift_up",
+			"shift_down",
+		],
+	),
+];
+
+/// actions changing the repo (or its remotes), unbound in read-only
+/// mode
+const MUTATING_KEYS: &[&str] = &[
+	"open_commit",
+	"open_commit_editor",
+	"submodule_update",
+	"submodule_update_all",
+	"edit_file",
+	"status_stage_all",
+	"status_reset_item",
+	"status_reset_all",
+	"status_ignore_file",
+	"status_take_ours",
+	"status_take_theirs",
+	"diff_stage_lines",
+	"diff_reset_lines",
+	"apply_patch",
+	"stashing_save",
+	"stash_apply",
+	"stash_drop",
+	"log_tag_commit",
+	"log_checkout_commit",
+	"log_create_branch",
+	"log_cherry_pick",
+	"commit_amend",
+	"create_branch",
+	"create_worktree",
+	"rename_branch",
+	"set_upstream",
+	"remote_add",
+	"remote_rename",
+	"remote_set_url",
+	"remote_remove",
+	"delete_branch",
+	"merge_branch",
+	"rebase_branch",
+	"bisect",
+	"bisect_good",
+	"bisect_bad",
+	"bisect_reset",
+	"delete_tag",
+	"push",
+	"force_push",
+	"force_push_lease",
+	"pull",
+	"restore_file",
+	"abort_merge",
+	"undo_commit",
+	"reset_orig_head",
+	"reword_head",
+	"change_set_commit",
+];
+
+/// bound to actions that are switched off, no terminal sends it
+const UNBOUND: KeyEvent = KeyEvent {
+	code: KeyCode::Null,
+	modifiers: KeyModifiers::empty(),
+};
+
+/// sharing a key on purpose: the tree uses it to fold
+/// and passes it on to switch focus once there is nothing to fold
+const KEY_FALL_THROUGHS: &[(&str, &str)] =
+	&[("move_left", "focus_left"), ("move_right", "focus_right")];
+
+/// subset of the bindings in `KeyConfig` syntax, used to layer a
+/// repo local config on top of the global one
+struct KeyOverrides(HashMap<String, KeyEvent>);
+
+/// ron hands out field names only as identifiers, not as strings
+struct KeyName(String);
+
+impl<'de> Deserialize<'de> for KeyName {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct NameVisitor;
+
+		impl<'de> Visitor<'de> for NameVisitor {
+			type Value = KeyName;
+
+			fn expecting(
+				&self,
+				f: &mut fmt::Formatter,
+			) -> fmt::Result {
+				f.write_str("an action name")
+			}
+
+			fn visit_str<E>(
+				self,
+				name: &str,
+			) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(KeyName(name.to_string()))
+			}
+		}
+
+		deserializer.deserialize_identifier(NameVisitor)
+	}
+}
+
+impl<'de> Deserialize<'de> for KeyOverrides {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct OverridesVisitor;
+
+		impl<'de> Visitor<'de> for OverridesVisitor {
+			type Value = KeyOverrides;
+
+			fn expecting(
+				&self,
+				f: &mut fmt::Formatter,
+			) -> fmt::Result {
+				f.write_str("key bindings")
+			}
+
+			fn visit_map<A>(
+				self,
+				mut map: A,
+			) -> Result<Self::Value, A::Error>
+			where
+				A: MapAccess<'de>,
+			{
+				let mut keys = HashMap::new();
+				while let Some((KeyName(name), key)) =
+					map.next_entry()?
+				{
+					keys.insert(name, key);
+				}
+				Ok(KeyOverrides(keys))
+			}
+		}
+
+		deserializer.deserialize_struct(
+			"KeyConfig",
+			&[],
+			OverridesVisitor,
+		)
+	}
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct KeyConfig {
 	pub tab_status: KeyEvent,
@@ -39,9 +395,21 @@ pub struct KeyConfig {
 	pub open_commit: KeyEvent,
 	pub open_commit_editor: KeyEvent,
 	pub open_help: KeyEvent,
+	pub help_search: KeyEvent,
 	pub open_options: KeyEvent,
+	pub open_worktrees: KeyEvent,
+	pub open_remotes: KeyEvent,
+	pub open_recent_repos: KeyEvent,
+	pub open_command_palette: KeyEvent,
+	pub refresh: KeyEvent,
+	pub open_submodules: KeyEvent,
+	pub submodule_update: KeyEvent,
+	pub submodule_update_all: KeyEvent,
 	pub move_left: KeyEvent,
 	pub move_right: KeyEvent,
+	pub tree_collapse_all: KeyEvent,
+	pub tree_expand_all: KeyEvent,
+	pub status_filter: KeyEvent,
 	pub tree_collapse_recursive: KeyEvent,
 	pub tree_expand_recursive: KeyEvent,
 	pub home: KeyEvent,
@@ -57,9 +425,26 @@ pub struct KeyConfig {
 	pub edit_file: KeyEvent,
 	pub status_stage_all: KeyEvent,
 	pub status_reset_item: KeyEvent,
+	pub status_reset_all: KeyEvent,
 	pub status_ignore_file: KeyEvent,
+	pub status_take_ours: KeyEvent,
+	pub status_take_theirs: KeyEvent,
+	pub status_mark_file: KeyEvent,
 	pub diff_stage_lines: KeyEvent,
 	pub diff_reset_lines: KeyEvent,
+	pub diff_scroll_left: KeyEvent,
+	pub diff_scroll_right: KeyEvent,
+	pub diff_search: KeyEvent,
+	pub diff_search_next: KeyEvent,
+	pub diff_search_prev: KeyEvent,
+	pub diff_search_case: KeyEvent,
+	pub diff_next_file: KeyEvent,
+	pub diff_prev_file: KeyEvent,
+	pub diff_copy_patch: KeyEvent,
+	pub diff_copy_permalink: KeyEvent,
+	pub export_patch: KeyEvent,
+	pub apply_patch: KeyEvent,
+	pub apply_patch_target: KeyEvent,
 	pub stashing_save: KeyEvent,
 	pub stashing_toggle_untracked: KeyEvent,
 	pub stashing_toggle_index: KeyEvent,
@@ -69,25 +454,62 @@ pub struct KeyConfig {
 	pub cmd_bar_toggle: KeyEvent,
 	pub log_tag_commit: KeyEvent,
 	pub log_mark_commit: KeyEvent,
+	pub log_cherry_pick: KeyEvent,
+	pub commit_goto_parent: KeyEvent,
+	pub commit_next_parent: KeyEvent,
+	pub goto_line: KeyEvent,
+	pub log_filter_author: KeyEvent,
+	pub log_filter_path: KeyEvent,
+	pub log_filter_date: KeyEvent,
+	pub log_checkout_commit: KeyEvent,
+	pub log_create_branch: KeyEvent,
+	pub log_branches_containing: KeyEvent,
+	pub log_toggle_relative_time: KeyEvent,
 	pub commit_amend: KeyEvent,
+	pub commit_sign_off: KeyEvent,
+	pub commit_author: KeyEvent,
+	pub commit_co_author: KeyEvent,
+	pub commit_new: KeyEvent,
 	pub copy: KeyEvent,
+	pub copy_full_hash: KeyEvent,
+	pub copy_absolute_path: KeyEvent,
+	pub open_in_browser: KeyEvent,
 	pub create_branch: KeyEvent,
+	pub create_worktree: KeyEvent,
+	pub remove_recent_repo: KeyEvent,
 	pub rename_branch: KeyEvent,
+	pub set_upstream: KeyEvent,
+	pub remote_add: KeyEvent,
+	pub remote_rename: KeyEvent,
+	pub remote_set_url: KeyEvent,
+	pub remote_remove: KeyEvent,
 	pub select_branch: KeyEvent,
 	pub delete_branch: KeyEvent,
 	pub merge_branch: KeyEvent,
 	pub rebase_branch: KeyEvent,
 	pub compare_commits: KeyEvent,
+	pub compare_branches: KeyEvent,
+	pub bisect: KeyEvent,
+	pub bisect_good: KeyEvent,
+	pub bisect_bad: KeyEvent,
+	pub bisect_reset: KeyEvent,
 	pub tags: KeyEvent,
 	pub delete_tag: KeyEvent,
 	pub select_tag: KeyEvent,
+	pub toggle_tag_sort: KeyEvent,
 	pub push: KeyEvent,
 	pub open_file_tree: KeyEvent,
 	pub file_find: KeyEvent,
+	pub restore_file: KeyEvent,
 	pub force_push: KeyEvent,
+	pub force_push_lease: KeyEvent,
 	pub pull: KeyEvent,
 	pub abort_merge: KeyEvent,
 	pub undo_commit: KeyEvent,
+	pub reset_orig_head: KeyEvent,
+	pub reword_head: KeyEvent,
+	pub change_set_assign: KeyEvent,
+	pub change_set_commit: KeyEvent,
 }
 
 #[rustfmt::skip]
@@ -112,9 +534,21 @@ impl Default for KeyConfig {
 			open_commit: KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::empty()},
 			open_commit_editor: KeyEvent { code: KeyCode::Char('e'), modifiers:KeyModifiers::CONTROL},
 			open_help: KeyEvent { code: KeyCode::Char('h'), modifiers: KeyModifiers::empty()},
+			help_search: KeyEvent { code: KeyCode::Char('/'), modifiers: KeyModifiers::empty()},
 			open_options: KeyEvent { code: KeyCode::Char('o'), modifiers: KeyModifiers::empty()},
+			open_worktrees: KeyEvent { code: KeyCode::Char('w'), modifiers: KeyModifiers::CONTROL},
+			open_remotes: KeyEvent { code: KeyCode::Char('u'), modifiers: KeyModifiers::CONTROL},
+			open_recent_repos: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::CONTROL},
+			open_command_palette: KeyEvent { code: KeyCode::Char('k'), modifiers: KeyModifiers::CONTROL},
+			refresh: KeyEvent { code: KeyCode::F(5), modifiers: KeyModifiers::empty()},
+			open_submodules: KeyEvent { code: KeyCode::Char('S'), modifiers: KeyModifiers::SHIFT},
+			submodule_update: KeyEvent { code: KeyCode::Char('u'), modifiers: KeyModifiers::empty()},
+			submodule_update_all: KeyEvent { code: KeyCode::Char('U'), modifiers: KeyModifiers::SHIFT},
 			move_left: KeyEvent { code: KeyCode::Left, modifiers: KeyModifiers::empty()},
 			move_right: KeyEvent { code: KeyCode::Right, modifiers: KeyModifiers::empty()},
+			tree_collapse_all: KeyEvent { code: KeyCode::Char('['), modifiers: KeyModifiers::empty()},
+			tree_expand_all: KeyEvent { code: KeyCode::Char(']'), modifiers: KeyModifiers::empty()},
+			status_filter: KeyEvent { code: KeyCode::Char('/'), modifiers: KeyModifiers::empty()},
 			tree_collapse_recursive: KeyEvent { code: KeyCode::Left, modifiers: KeyModifiers::SHIFT},
 			tree_expand_recursive: KeyEvent { code: KeyCode::Right, modifiers: KeyModifiers::SHIFT},
 			home: KeyEvent { code: KeyCode::Home, modifiers: KeyModifiers::empty()},
@@ -130,9 +564,26 @@ impl Default for KeyConfig {
 			edit_file: KeyEvent { code: KeyCode::Char('e'), modifiers: KeyModifiers::empty()},
 			status_stage_all: KeyEvent { code: KeyCode::Char('a'), modifiers: KeyModifiers::empty()},
 			status_reset_item: KeyEvent { code: KeyCode::Char('D'), modifiers: KeyModifiers::SHIFT},
+			status_reset_all: KeyEvent { code: KeyCode::Char('d'), modifiers: KeyModifiers::CONTROL},
 			diff_reset_lines: KeyEvent { code: KeyCode::Char('d'), modifiers: KeyModifiers::empty()},
 			status_ignore_file: KeyEvent { code: KeyCode::Char('i'), modifiers: KeyModifiers::empty()},
+			status_take_ours: KeyEvent { code: KeyCode::Char('o'), modifiers: KeyModifiers::CONTROL},
+			status_take_theirs: KeyEvent { code: KeyCode::Char('t'), modifiers: KeyModifiers::CONTROL},
+			status_mark_file: KeyEvent { code: KeyCode::Char(' '), modifiers: KeyModifiers::empty()},
 			diff_stage_lines: KeyEvent { code: KeyCode::Char('s'), modifiers: KeyModifiers::empty()},
+			diff_scroll_left: KeyEvent { code: KeyCode::Left, modifiers: KeyModifiers::SHIFT},
+			diff_scroll_right: KeyEvent { code: KeyCode::Right, modifiers: KeyModifiers::SHIFT},
+			diff_search: KeyEvent { code: KeyCode::Char('/'), modifiers: KeyModifiers::empty()},
+			diff_search_next: KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::empty()},
+			diff_search_prev: KeyEvent { code: KeyCode::Char('N'), modifiers: KeyModifiers::SHIFT},
+			diff_search_case: KeyEvent { code: KeyCode::Char('t'), modifiers: KeyModifiers::CONTROL},
+			diff_next_file: KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL},
+			diff_prev_file: KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL},
+			diff_copy_patch: KeyEvent { code: KeyCode::Char('Y'), modifiers: KeyModifiers::SHIFT},
+			diff_copy_permalink: KeyEvent { code: KeyCode::Char('y'), modifiers: KeyModifiers::CONTROL},
+			export_patch: KeyEvent { code: KeyCode::Char('x'), modifiers: KeyModifiers::empty()},
+			apply_patch: KeyEvent { code: KeyCode::Char('X'), modifiers: KeyModifiers::SHIFT},
+			apply_patch_target: KeyEvent { code: KeyCode::Char('l'), modifiers: KeyModifiers::CONTROL},
 			stashing_save: KeyEvent { code: KeyCode::Char('s'), modifiers: KeyModifiers::empty()},
 			stashing_toggle_untracked: KeyEvent { code: KeyCode::Char('u'), modifiers: KeyModifiers::empty()},
 			stashing_toggle_index: KeyEvent { code: KeyCode::Char('i'), modifiers: KeyModifiers::empty()},
@@ -142,25 +593,99 @@ impl Default for KeyConfig {
 			cmd_bar_toggle: KeyEvent { code: KeyCode::Char('.'), modifiers: KeyModifiers::empty()},
 			log_tag_commit: KeyEvent { code: KeyCode::Char('t'), modifiers: KeyModifiers::empty()},
 			log_mark_commit: KeyEvent { code: KeyCode::Char(' '), modifiers: KeyModifiers::empty()},
+			log_cherry_pick: KeyEvent { code: KeyCode::Char('A'), modifiers: KeyModifiers::SHIFT},
+			commit_goto_parent: KeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::empty()},
+			commit_next_parent: KeyEvent { code: KeyCode::Char('G'), modifiers: KeyModifiers::SHIFT},
+			goto_line: KeyEvent { code: KeyCode::Char(':'), modifiers: KeyModifiers::empty()},
+			log_filter_author: KeyEvent { code: KeyCode::Char('a'), modifiers: KeyModifiers::empty()},
+			log_filter_path: KeyEvent { code: KeyCode::Char('P'), modifiers: KeyModifiers::SHIFT},
+			log_filter_date: KeyEvent { code: KeyCode::Char('D'), modifiers: KeyModifiers::SHIFT},
+			log_checkout_commit: KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::empty()},
+			log_create_branch: KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::empty()},
+			log_branches_containing: KeyEvent { code: KeyCode::Char('i'), modifiers: KeyModifiers::empty()},
+			log_toggle_relative_time: KeyEvent { code: KeyCode::Char('R'), modifiers: KeyModifiers::SHIFT},
 			commit_amend: KeyEvent { code: KeyCode::Char('a'), modifiers: KeyModifiers::CONTROL},
+			commit_sign_off: KeyEvent { code: KeyCode::Char('s'), modifiers: KeyModifiers::CONTROL},
+			commit_author: KeyEvent { code: KeyCode::Char('u'), modifiers: KeyModifiers::CONTROL},
+			commit_co_author: KeyEvent { code: KeyCode::Char('o'), modifiers: KeyModifiers::CONTROL},
+			commit_new: KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL},
 			copy: KeyEvent { code: KeyCode::Char('y'), modifiers: KeyModifiers::empty()},
+			copy_full_hash: KeyEvent { code: KeyCode::Char('Y'), modifiers: KeyModifiers::SHIFT},
+			copy_absolute_path: KeyEvent { code: KeyCode::Char('Y'), modifiers: KeyModifiers::SHIFT},
+			open_in_browser: KeyEvent { code: KeyCode::Char('W'), modifiers: KeyModifiers::SHIFT},
 			create_branch: KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::empty()},
+			create_worktree: KeyEvent { code: KeyCode::Char('w'), modifiers: KeyModifiers::empty()},
+			remove_recent_repo: KeyEvent { code: KeyCode::Char('D'), modifiers: KeyModifiers::SHIFT},
 			rename_branch: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::empty()},
+			set_upstream: KeyEvent { code: KeyCode::Char('u'), modifiers: KeyModifiers::empty()},
+			remote_add: KeyEvent { code: KeyCode::Char('a'), modifiers: KeyModifiers::empty()},
+			remote_rename: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::empty()},
+			remote_set_url: KeyEvent { code: KeyCode::Char('e'), modifiers: KeyModifiers::empty()},
+			remote_remove: KeyEvent { code: KeyCode::Char('D'), modifiers: KeyModifiers::SHIFT},
 			select_branch: KeyEvent { code: KeyCode::Char('b'), modifiers: KeyModifiers::empty()},
 			delete_branch: KeyEvent { code: KeyCode::Char('D'), modifiers: KeyModifiers::SHIFT},
 			merge_branch: KeyEvent { code: KeyCode::Char('m'), modifiers: KeyModifiers::empty()},
 			rebase_branch: KeyEvent { code: KeyCode::Char('R'), modifiers: KeyModifiers::SHIFT},
 			compare_commits: KeyEvent { code: KeyCode::Char('C'), modifiers: KeyModifiers::SHIFT},
+			compare_branches: KeyEvent { code: KeyCode::Char('v'), modifiers: KeyModifiers::empty()},
+			bisect: KeyEvent { code: KeyCode::Char('b'), modifiers: KeyModifiers::CONTROL},
+			bisect_good: KeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::empty()},
+			bisect_bad: KeyEvent { code: KeyCode::Char('b'), modifiers: KeyModifiers::empty()},
+			bisect_reset: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::empty()},
 			tags: KeyEvent { code: KeyCode::Char('T'), modifiers: KeyModifiers::SHIFT},
 			delete_tag: KeyEvent { code: KeyCode::Char('D'), modifiers: KeyModifiers::SHIFT},
 			select_tag: KeyEvent { code: KeyCode::Enter, modifiers: KeyModifiers::empty()},
+			toggle_tag_sort: KeyEvent { code: KeyCode::Char('s'), modifiers: KeyModifiers::empty()},
 			push: KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::empty()},
 			force_push: KeyEvent { code: KeyCode::Char('P'), modifiers: KeyModifiers::SHIFT},
+			force_push_lease: KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL},
 			undo_commit: KeyEvent { code: KeyCode::Char('U'), modifiers: KeyModifiers::SHIFT},
+			reset_orig_head: KeyEvent { code: KeyCode::Char('O'), modifiers: KeyModifiers::SHIFT},
+			reword_head: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::empty()},
+			change_set_assign: KeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::empty()},
+			change_set_commit: KeyEvent { code: KeyCode::Char('G'), modifiers: KeyModifiers::SHIFT},
 			pull: KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::empty()},
 			abort_merge: KeyEvent { code: KeyCode::Char('M'), modifiers: KeyModifiers::SHIFT},
 			open_file_tree: KeyEvent { code: KeyCode::Char('F'), modifiers: KeyModifiers::SHIFT},
 			file_find: KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::empty()},
+			restore_file: KeyEvent { code: KeyCode::Char('R'), modifiers: KeyModifiers::SHIFT},
+		}
+	}
+}
+
+#[rustfmt::skip]
+impl KeyConfig {
+	/// same bindings as `vim_style_key_config.ron`,
+	/// all keys not listed here are the defaults
+	pub fn vim() -> Self {
+		Self {
+			focus_right: KeyEvent { code: KeyCode::Char('l'), modifiers: KeyModifiers::empty()},
+			focus_left: KeyEvent { code: KeyCode::Char('h'), modifiers: KeyModifiers::empty()},
+			focus_above: KeyEvent { code: KeyCode::Char('k'), modifiers: KeyModifiers::empty()},
+			focus_below: KeyEvent { code: KeyCode::Char('j'), modifiers: KeyModifiers::empty()},
+			// `h` moves left
+			open_help: KeyEvent { code: KeyCode::F(1), modifiers: KeyModifiers::empty()},
+			move_left: KeyEvent { code: KeyCode::Char('h'), modifiers: KeyModifiers::empty()},
+			move_right: KeyEvent { code: KeyCode::Char('l'), modifiers: KeyModifiers::empty()},
+			move_up: KeyEvent { code: KeyCode::Char('k'), modifiers: KeyModifiers::empty()},
+			move_down: KeyEvent { code: KeyCode::Char('j'), modifiers: KeyModifiers::empty()},
+			page_up: KeyEvent { code: KeyCode::Char('b'), modifiers: KeyModifiers::CONTROL},
+			page_down: KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::CONTROL},
+			shift_up: KeyEvent { code: KeyCode::Char('K'), modifiers: KeyModifiers::SHIFT},
+			shift_down: KeyEvent { code: KeyCode::Char('J'), modifiers: KeyModifiers::SHIFT},
+			edit_file: KeyEvent { code: KeyCode::Char('I'), modifiers: KeyModifiers::SHIFT},
+			status_reset_item: KeyEvent { code: KeyCode::Char('U'), modifiers: KeyModifiers::SHIFT},
+			diff_reset_lines: KeyEvent { code: KeyCode::Char('u'), modifiers: KeyModifiers::empty()},
+			diff_scroll_left: KeyEvent { code: KeyCode::Char('H'), modifiers: KeyModifiers::SHIFT},
+			diff_scroll_right: KeyEvent { code: KeyCode::Char('L'), modifiers: KeyModifiers::SHIFT},
+			stashing_save: KeyEvent { code: KeyCode::Char('w'), modifiers: KeyModifiers::empty()},
+			stashing_toggle_index: KeyEvent { code: KeyCode::Char('m'), modifiers: KeyModifiers::empty()},
+			stash_open: KeyEvent { code: KeyCode::Char('l'), modifiers: KeyModifiers::empty()},
+			// `U` resets items
+			undo_commit: KeyEvent { code: KeyCode::Char('z'), modifiers: KeyModifiers::CONTROL},
+			// `^b` pages up
+			bisect: KeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::CONTROL},
+			..Self::default()
 		}
 	}
 }
@@ -185,6 +710,103 @@ impl KeyConfig {
 		Ok(ron::de::from_bytes(&buffer)?)
 	}
 
+	/// `key_config.ron` in the repo local config dir
+	pub fn get_repo_config_file() -> Option<PathBuf> {
+		get_repo_config_path()
+			.map(|path| path.join("key_config.ron"))
+			.filter(|file| file.exists())
+	}
+
+	/// replaces the bindings set in `config_file`, all others stay
+	pub fn with_overrides(
+		&self,
+		config_file: PathBuf,
+	) -> Result<Self> {
+		let mut f = File::open(config_file)?;
+		let mut buffer = Vec::new();
+		f.read_to_end(&mut buffer)?;
+
+		self.merge(ron::de::from_bytes(&buffer)?)
+	}
+
+	/// unbinds all actions changing the repo, their hint is empty
+	/// (`Push []`)
+	pub fn read_only(&self) -> Result<Self> {
+		self.merge(KeyOverrides(
+			MUTATING_KEYS
+				.iter()
+				.map(|name| ((*name).to_string(), UNBOUND))
+				.collect(),
+		))
+	}
+
+	fn merge(&self, overrides: KeyOverrides) -> Result<Self> {
+		let mut keys = self.bindings()?;
+		keys.extend(overrides.0);
+
+		let fields = keys
+			.iter()
+			.map(|(name, key)| -> Result<String> {
+				Ok(format!("{}:{}", name, ron::to_string(key)?))
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(ron::de::from_str(&format!("({})", fields.join(",")))?)
+	}
+
+	/// all bindings by action name
+	pub fn bindings(&self) -> Result<HashMap<String, KeyEvent>> {
+		Ok(ron::de::from_str::<KeyOverrides>(&ron::to_string(self)?)?
+			.0)
+	}
+
+	/// errors listing all actions sharing a key while being
+	/// active at the same time
+	pub fn validate(&self) -> Result<()> {
+		let keys = self.bindings()?;
+
+		let mut conflicts = Vec::new();
+		for (context, actions) in KEY_CONTEXTS {
+			let actions = GLOBAL_KEYS
+				.iter()
+				.chain(actions.iter())
+				.collect::<Vec<_>>();
+
+			for (idx, a) in actions.iter().enumerate() {
+				for b in &actions[idx + 1..] {
+					let fall_through =
+						KEY_FALL_THROUGHS.iter().any(|(x, y)| {
+							(x == *a && y == *b)
+								|| (x == *b && y == *a)
+						});
+
+					if let Some(key) = keys.get(**a).filter(|key| {
+						!fall_through
+							&& **key != UNBOUND && keys.get(**b)
+							== Some(key)
+					}) {
+						conflicts.push(format!(
+							"{}: `{}` and `{}` both use [{}]",
+							context,
+							a,
+							b,
+							self.get_hint(*key)
+						));
+					}
+				}
+			}
+		}
+
+		if conflicts.is_empty() {
+			Ok(())
+		} else {
+			Err(anyhow::anyhow!(
+				"conflicting key bindings:\n{}",
+				conflicts.join("\n")
+			))
+		}
+	}
+
 	pub fn init(file: PathBuf) -> Result<Self> {
 		if file.exists() {
 			match Self::read_file(file.clone()) {
@@ -233,6 +855,11 @@ impl KeyConfig {
 		}
 	}
 
+	/// `ev` was unbound by `read_only`
+	pub fn is_unbound(ev: KeyEvent) -> bool {
+		ev == UNBOUND
+	}
+
 	pub fn get_hint(&self, ev: KeyEvent) -> String {
 		match ev.code {
 			KeyCode::Down
@@ -305,6 +932,35 @@ mod tests {
 		assert_eq!(h, "^c");
 	}
 
+	#[test]
+	fn test_overrides() {
+		let overrides = ron::de::from_str(
+			"(push: (code: Char('P'), modifiers: (bits: 1,),),)",
+		)
+		.unwrap();
+		let config = KeyConfig::default().merge(overrides).unwrap();
+
+		assert_eq!(
+			config.push,
+			KeyEvent {
+				code: KeyCode::Char('P'),
+				modifiers: KeyModifiers::SHIFT,
+			}
+		);
+		assert_eq!(config.pull, KeyConfig::default().pull);
+	}
+
+	#[test]
+	fn test_read_only() {
+		let config = KeyConfig::default().read_only().unwrap();
+
+		assert_eq!(config.get_hint(config.push), "");
+		assert!(KeyConfig::is_unbound(config.push));
+		assert!(!KeyConfig::is_unbound(config.copy));
+		assert_eq!(config.copy, KeyConfig::default().copy);
+		assert!(config.validate().is_ok());
+	}
+
 	#[test]
 	fn test_load_vim_style_example() {
 		assert_eq!(
@@ -313,4 +969,33 @@ mod tests {
 			true
 		);
 	}
+
+	#[test]
+	fn test_validate() {
+		assert!(KeyConfig::default().validate().is_ok());
+		assert!(KeyConfig::vim().validate().is_ok());
+
+		let overrides = ron::de::from_str(
+			"(pull: (code: Char('p'), modifiers: (bits: 0,),),)",
+		)
+		.unwrap();
+		let config = KeyConfig::default().merge(overrides).unwrap();
+		let err = config.validate().unwrap_err().to_string();
+
+		assert!(err.contains("`pull` and `push`"));
+		// `move_left` folds before `focus_left` switches focus
+		assert!(!err.contains("focus_left"));
+	}
+
+	#[test]
+	fn test_vim_preset_matches_example() {
+		let example =
+			KeyConfig::read_file("vim_style_key_config.ron".into())
+				.unwrap();
+
+		assert_eq!(
+			ron::to_string(&KeyConfig::vim()).unwrap(),
+			ron::to_string(&example).unwrap()
+		);
+	}
 }
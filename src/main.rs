@@ -25,20 +25,31 @@ mod clipboard;
 mod cmdbar;
 mod components;
 mod input;
+mod json_export;
 mod keys;
+mod notification;
 mod notify_mutex;
 mod profiler;
 mod queue;
+mod recent_repos;
 mod spinner;
+mod statusbar;
 mod string_utils;
 mod strings;
 mod tabs;
 mod ui;
 mod version;
+mod watcher;
 
-use crate::{app::App, args::process_cmdline};
+use crate::{app::App, args::process_cmdline, components::Options};
 use anyhow::{bail, Result};
-use asyncgit::AsyncGitNotification;
+use asyncgit::{
+	sync::{
+		self,
+		status::{get_status, StatusType},
+	},
+	AsyncGitNotification,
+};
 use backtrace::Backtrace;
 use crossbeam_channel::{tick, unbounded, Receiver, Select};
 use crossterm::{
@@ -55,6 +66,7 @@ use scopeguard::defer;
 use scopetime::scope_time;
 use spinner::Spinner;
 use std::{
+	env,
 	io::{self, Write},
 	panic, process,
 	time::{Duration, Instant},
@@ -64,15 +76,20 @@ use tui::{
 	Terminal,
 };
 use ui::style::Theme;
+use watcher::RepoWatcher;
 
 static TICK_INTERVAL: Duration = Duration::from_secs(5);
 static SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+/// redraws to keep relative times (`3m ago`) current
+static CLOCK_INTERVAL: Duration = Duration::from_secs(30);
 
 ///
 #[derive(Clone, Copy)]
 pub enum QueueEvent {
 	Tick,
+	Notify,
 	SpinnerUpdate,
+	ClockTick,
 	AsyncEvent(AsyncNotification),
 	InputEvent(InputEvent),
 }
@@ -87,6 +104,8 @@ pub enum SyntaxHighlightProgress {
 pub enum AsyncAppNotification {
 	///
 	SyntaxHighlighting(SyntaxHighlightProgress),
+	///
+	FileContent,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -104,14 +123,75 @@ fn main() -> Result<()> {
 
 	asyncgit::register_tracing_logging();
 
+	let headless = cliargs.check_clean
+		|| cliargs.check_ignore.is_some()
+		|| cliargs.status_json
+		|| cliargs.log_json.is_some();
+
+	// creating a repo is a change, `--read-only` must not offer it
+	if !headless
+		&& !cliargs.read_only
+		&& !sync::is_repo(asyncgit::CWD)
+		&& ask_init_repo()?
+	{
+		sync::init_repo(asyncgit::CWD)?;
+	}
+
 	if !valid_path()? {
 		eprintln!("invalid path\nplease run gitui inside of a non-bare git repository");
+		if headless {
+			process::exit(2);
+		}
 		return Ok(());
 	}
 
-	let key_config = KeyConfig::init(KeyConfig::get_config_file()?)
-		.map_err(|e| eprintln!("KeyConfig loading error: {}", e))
+	if cliargs.check_clean {
+		return check_clean();
+	}
+
+	if let Some(path) = &cliargs.check_ignore {
+		return check_ignore(path);
+	}
+
+	if cliargs.status_json {
+		println!("{}", json_export::status_json(asyncgit::CWD)?);
+		return Ok(());
+	}
+
+	if let Some(count) = cliargs.log_json {
+		println!("{}", json_export::log_json(asyncgit::CWD, count)?);
+		return Ok(());
+	}
+
+	if let Err(e) = recent_repos::add_current_repo() {
+		log::error!("recent repos: {}", e);
+	}
+
+	let key_config = if cliargs.vim_keys {
+		KeyConfig::vim()
+	} else {
+		KeyConfig::init(KeyConfig::get_config_file()?)
+			.map_err(|e| eprintln!("KeyConfig loading error: {}", e))
+			.unwrap_or_default()
+	};
+	let key_config = match KeyConfig::get_repo_config_file() {
+		Some(file) => key_config
+			.with_overrides(file)
+			.map_err(|e| {
+				eprintln!("repo KeyConfig loading error: {}", e)
+			})
+			.unwrap_or(key_config),
+		None => key_config,
+	};
+	let key_config = if cliargs.read_only {
+		key_config.read_only()?
+	} else {
+		key_config
+	};
+	let mut options = Options::init()
+		.map_err(|e| eprintln!("Options loading error: {}", e))
 		.unwrap_or_default();
+	options.read_only = cliargs.read_only;
 	let theme = Theme::init(cliargs.theme)
 		.map_err(|e| eprintln!("Theme loading error: {}", e))
 		.unwrap_or_default();
@@ -131,11 +211,12 @@ fn main() -> Result<()> {
 	let input = Input::new();
 
 	let rx_input = input.receiver();
-	let ticker = tick(TICK_INTERVAL);
+	let mut watcher = RepoWatcher::new(asyncgit::CWD, TICK_INTERVAL);
 	let spinner_ticker = tick(SPINNER_INTERVAL);
+	let clock_ticker = tick(CLOCK_INTERVAL);
 
 	let mut app =
-		App::new(&tx_git, &tx_app, input, theme, key_config);
+		App::new(&tx_git, &tx_app, input, theme, key_config, options);
 
 	let mut spinner = Spinner::default();
 	let mut first_update = true;
@@ -149,15 +230,18 @@ fn main() -> Result<()> {
 				&rx_input,
 				&rx_git,
 				&rx_app,
-				&ticker,
+				watcher.ticker(),
+				watcher.receiver(),
 				&spinner_ticker,
+				&clock_ticker,
 			)?
 		};
 
 		{
 			if let QueueEvent::SpinnerUpdate = event {
 				spinner.update();
-				spinner.draw(&mut terminal)?;
+				spinner
+					.draw(&mut terminal, app.spinner_position())?;
 				continue;
 			}
 
@@ -172,7 +256,9 @@ fn main() -> Result<()> {
 					}
 					app.event(ev)?;
 				}
-				QueueEvent::Tick => app.update()?,
+				QueueEvent::Tick | QueueEvent::Notify => {
+					app.update()?
+				}
 				QueueEvent::AsyncEvent(ev) => {
 					if !matches!(
 						ev,
@@ -183,17 +269,26 @@ fn main() -> Result<()> {
 						app.update_async(ev)?;
 					}
 				}
+				// nothing changed but the time, just redraw
+				QueueEvent::ClockTick => (),
 				QueueEvent::SpinnerUpdate => unreachable!(),
 			}
 
-			draw(&mut terminal, &app)?;
-
 			spinner.set_state(app.any_work_pending());
-			spinner.draw(&mut terminal)?;
+
+			draw(&mut terminal, &app, spinner.current())?;
+
+			spinner.draw(&mut terminal, app.spinner_position())?;
 
 			if app.is_quit() {
 				break;
 			}
+
+			if app.take_repo_switched() {
+				// watch the new workdir instead
+				watcher =
+					RepoWatcher::new(asyncgit::CWD, TICK_INTERVAL);
+			}
 		}
 	}
 
@@ -224,13 +319,14 @@ fn shutdown_terminal() {
 fn draw<B: Backend>(
 	terminal: &mut Terminal<B>,
 	app: &App,
+	spinner: char,
 ) -> io::Result<()> {
 	if app.requires_redraw() {
 		terminal.resize(terminal.size()?)?;
 	}
 
 	terminal.draw(|mut f| {
-		if let Err(e) = app.draw(&mut f) {
+		if let Err(e) = app.draw(&mut f, spinner) {
 			log::error!("failed to draw: {:?}", e);
 		}
 	})?;
@@ -243,12 +339,66 @@ fn valid_path() -> Result<bool> {
 		&& !asyncgit::sync::is_bare_repo(asyncgit::CWD)?)
 }
 
+/// asks on the plain terminal before the ui starts
+fn ask_init_repo() -> Result<bool> {
+	print!(
+		"no git repository found in '{}'\ninitialize one? [y/N] ",
+		env::current_dir()?.display()
+	);
+	io::stdout().flush()?;
+
+	let mut answer = String::new();
+	io::stdin().read_line(&mut answer)?;
+
+	Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// headless `--check-clean`: exits non-zero if workdir or stage contain changes
+fn check_clean() -> Result<()> {
+	let workdir =
+		get_status(asyncgit::CWD, StatusType::WorkingDir, None)?;
+	let stage = get_status(asyncgit::CWD, StatusType::Stage, None)?;
+
+	if workdir.is_empty() && stage.is_empty() {
+		return Ok(());
+	}
+
+	println!("{} unstaged, {} staged", workdir.len(), stage.len());
+
+	process::exit(1);
+}
+
+/// headless `--check-ignore`: prints the deciding rule like
+/// `git check-ignore -v`, exits non-zero if `path` is not ignored
+fn check_ignore(path: &str) -> Result<()> {
+	let check = sync::check_ignore(asyncgit::CWD, path)?;
+
+	match &check.rule {
+		Some(rule) => println!(
+			"{}:{}:{}\t{}",
+			rule.file.display(),
+			rule.line,
+			rule.pattern,
+			path
+		),
+		None => println!("::\t{}", path),
+	}
+
+	if !check.ignored {
+		process::exit(1);
+	}
+
+	Ok(())
+}
+
 fn select_event(
 	rx_input: &Receiver<InputEvent>,
 	rx_git: &Receiver<AsyncGitNotification>,
 	rx_app: &Receiver<AsyncAppNotification>,
 	rx_ticker: &Receiver<Instant>,
+	rx_watcher: &Receiver<()>,
 	rx_spinner: &Receiver<Instant>,
+	rx_clock: &Receiver<Instant>,
 ) -> Result<QueueEvent> {
 	let mut sel = Select::new();
 
@@ -256,7 +406,9 @@ fn select_event(
 	sel.recv(rx_git);
 	sel.recv(rx_app);
 	sel.recv(rx_ticker);
+	sel.recv(rx_watcher);
 	sel.recv(rx_spinner);
+	sel.recv(rx_clock);
 
 	let oper = sel.select();
 	let index = oper.index();
@@ -270,7 +422,9 @@ fn select_event(
 			QueueEvent::AsyncEvent(AsyncNotification::App(e))
 		}),
 		3 => oper.recv(rx_ticker).map(|_| QueueEvent::Tick),
-		4 => oper.recv(rx_spinner).map(|_| QueueEvent::SpinnerUpdate),
+		4 => oper.recv(rx_watcher).map(|_| QueueEvent::Notify),
+		5 => oper.recv(rx_spinner).map(|_| QueueEvent::SpinnerUpdate),
+		6 => oper.recv(rx_clock).map(|_| QueueEvent::ClockTick),
 		_ => bail!("unknown select source"),
 	}?;
 
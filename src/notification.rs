@@ -0,0 +1,41 @@
+use crate::strings;
+use notify_rust::Notification;
+use std::{
+	thread,
+	time::{Duration, Instant},
+};
+
+/// shows a desktop notification about the finished remote `op`
+/// if it ran for at least `after_secs` seconds (`0` never notifies)
+pub fn notify_remote_op(
+	started: Option<Instant>,
+	after_secs: u64,
+	op: &str,
+	branch: &str,
+	error: Option<&str>,
+) {
+	let took_long = after_secs > 0
+		&& started.map_or(false, |started| {
+			started.elapsed() >= Duration::from_secs(after_secs)
+		});
+
+	if took_long {
+		let summary =
+			strings::notification_remote_title(op, error.is_none());
+		let body =
+			strings::notification_remote_body(op, branch, error);
+
+		// the notification daemon may take a while,
+		// never block the ui on it
+		thread::spawn(move || {
+			if let Err(e) = Notification::new()
+				.appname("gitui")
+				.summary(&summary)
+				.body(&body)
+				.show()
+			{
+				log::error!("desktop notification: {}", e);
+			}
+		});
+	}
+}
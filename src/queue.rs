@@ -1,8 +1,13 @@
-use crate::{components::AppOption, tabs::StashingOptions};
+use crate::{
+	components::{AppOption, LogFilterKind},
+	tabs::StashingOptions,
+};
 use asyncgit::sync::{
-	diff::DiffLinePosition, CommitId, CommitTags, TreeFile,
+	diff::DiffLinePosition, CommitId, CommitTags, PatchSource,
+	RepoState, TreeFile,
 };
 use bitflags::bitflags;
+use crossterm::event::KeyEvent;
 use std::{
 	cell::RefCell, collections::VecDeque, path::PathBuf, rc::Rc,
 };
@@ -29,6 +34,18 @@ pub struct ResetItem {
 	pub is_folder: bool,
 }
 
+/// what the remote edit popup asks for
+pub enum RemoteEdit {
+	/// name of a new remote
+	Add,
+	/// url of the new remote with the given name
+	AddUrl(String),
+	/// new name of the remote
+	Rename(String),
+	/// new url of the remote and its current one
+	SetUrl(String, String),
+}
+
 ///
 pub enum Action {
 	Reset(ResetItem),
@@ -39,8 +56,20 @@ pub enum Action {
 	DeleteBranch(String, bool),
 	DeleteTag(String),
 	ForcePush(String, bool),
+	ForcePushWithLease(String),
 	PullMerge { incoming: usize, rebase: bool },
-	AbortMerge,
+	AbortPendingOp(RepoState),
+	ResetToOrigHead(CommitId),
+	RewordMergeCommit,
+	CommitLargeFiles(usize),
+	IgnoreFolder(String),
+	/// every file the reset discards or deletes
+	ResetWorkdirAll(Vec<String>),
+	ResetFiles(Vec<String>),
+	DeleteRemote(String),
+	RestoreFile(CommitId, String),
+	CheckoutCommit(CommitId),
+	CheckoutBranchStashed(String, Vec<String>),
 }
 
 ///
@@ -57,6 +86,10 @@ pub enum InternalEvent {
 	StatusLastFileMoved,
 	/// open commit msg input
 	OpenCommit,
+	/// open commit msg input to reword `HEAD`
+	RewordHead,
+	/// open commit msg input to amend `HEAD`
+	AmendCommit,
 	///
 	PopupStashing(StashingOptions),
 	///
@@ -65,24 +98,45 @@ pub enum InternalEvent {
 	InspectCommit(CommitId, Option<CommitTags>),
 	///
 	CompareCommits(CommitId, Option<CommitId>),
+	/// open popup comparing the current branch with the one at reference
+	CompareBranches(String),
 	///
 	SelectCommitInRevlog(CommitId),
+	/// open bisect popup marking the given commit
+	OpenBisect(CommitId),
+	/// handle key as if it was pressed (used by the command palette)
+	RunKey(KeyEvent),
 	///
 	TagCommit(CommitId),
 	///
 	Tags,
 	///
 	BlameFile(String),
-	///
-	CreateBranch,
+	/// open popup to create a branch at the commit, at `HEAD`
+	/// (checking it out) if `None`
+	CreateBranch(Option<CommitId>),
 	///
 	RenameBranch(String, String),
+	/// local branch name and its current upstream
+	SetUpstream(String, Option<String>),
 	///
 	SelectBranch,
+	/// choose how to merge the branch into `HEAD`
+	MergeBranch(String),
+	///
+	OpenRemotes,
+	/// pick a collaborator to credit in the commit message
+	OpenCoAuthors,
+	/// append a `Co-authored-by` trailer (name, email)
+	AddCoAuthor(String, String),
 	///
-	OpenExternalEditor(Option<String>),
+	EditRemote(RemoteEdit),
+	/// file and the line to jump to or the commit message if `None`
+	OpenExternalEditor(Option<(String, Option<u32>)>),
 	///
 	Push(String, bool, bool),
+	/// list the commits a push of the branch sends first
+	OpenPushSummary(String),
 	///
 	Pull(String),
 	///
@@ -95,6 +149,42 @@ pub enum InternalEvent {
 	OpenFileFinder(Vec<TreeFile>),
 	///
 	FileFinderChanged(Option<PathBuf>),
+	/// open popup to name the change set of a staged file (path, current name, already assigned)
+	OpenChangeSet(String, String, bool),
+	///
+	AssignChangeSet(String, String),
+	/// open diff search input (current query, case sensitive)
+	OpenDiffSearch(String, bool),
+	///
+	SearchDiff(String, bool),
+	/// open the input for the line the file preview scrolls to
+	OpenGotoLine,
+	/// scroll the file preview to a line (1-based)
+	GotoLine(usize),
+	/// select the next (or previous) changed file of the status diff
+	SelectDiffFile(bool),
+	///
+	OpenCommitInBrowser(CommitId),
+	/// list the branches that contain the commit
+	BranchesContaining(CommitId),
+	/// change the working dir to the worktree at path
+	SwitchWorktree(PathBuf),
+	/// change the working dir to the recent repo at path
+	SwitchRepo(PathBuf),
+	/// open popup to create a worktree (prefilled branch)
+	OpenCreateWorktree(String),
+	/// open popup to edit a log filter (kind, current filter)
+	OpenLogFilter(LogFilterKind, String),
+	///
+	FilterLog(LogFilterKind, String),
+	/// open popup asking where to write the patch of source
+	OpenExportPatch(PatchSource),
+	///
+	OpenApplyPatch,
+	/// open the status filter input (current filter)
+	OpenStatusFilter(Option<String>),
+	///
+	FilterStatus(Option<String>),
 }
 
 /// single threaded simple queue for components to communicate with each other
@@ -0,0 +1,92 @@
+use crate::args::get_app_config_path;
+use anyhow::Result;
+use asyncgit::{sync::utils::repo_work_dir, CWD};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+/// number of repos remembered
+const MAX_RECENT_REPOS: usize = 20;
+
+/// workdirs of the recently opened repos, most recent first
+#[derive(Default, Serialize, Deserialize)]
+pub struct RecentRepos {
+	paths: Vec<PathBuf>,
+}
+
+impl RecentRepos {
+	fn get_file() -> Result<PathBuf> {
+		Ok(get_app_config_path()?.join("recent_repos.ron"))
+	}
+
+	/// empty if there is no list yet or it cannot be read
+	pub fn load() -> Self {
+		Self::get_file()
+			.and_then(|file| Ok(fs::read(file)?))
+			.and_then(|data| Ok(ron::de::from_bytes(&data)?))
+			.unwrap_or_default()
+	}
+
+	pub fn save(&self) -> Result<()> {
+		let data = to_string_pretty(self, PrettyConfig::default())?;
+		fs::write(Self::get_file()?, data)?;
+		Ok(())
+	}
+
+	pub fn paths(&self) -> &[PathBuf] {
+		&self.paths
+	}
+
+	/// moves `path` to the front, dropping the oldest beyond the limit
+	pub fn add(&mut self, path: PathBuf) {
+		self.remove(&path);
+		self.paths.insert(0, path);
+		self.paths.truncate(MAX_RECENT_REPOS);
+	}
+
+	pub fn remove(&mut self, path: &Path) {
+		self.paths.retain(|p| p != path);
+	}
+}
+
+/// workdir of the repo gitui currently runs in
+pub fn current_repo() -> Result<PathBuf> {
+	Ok(Path::new(&repo_work_dir(CWD)?).components().collect())
+}
+
+/// remembers the current repo as the most recent one
+pub fn add_current_repo() -> Result<()> {
+	let mut recent = RecentRepos::load();
+	recent.add(current_repo()?);
+	recent.save()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{RecentRepos, MAX_RECENT_REPOS};
+	use std::path::PathBuf;
+
+	#[test]
+	fn test_add() {
+		let mut recent = RecentRepos::default();
+
+		recent.add(PathBuf::from("/a"));
+		recent.add(PathBuf::from("/b"));
+		recent.add(PathBuf::from("/a"));
+
+		assert_eq!(
+			recent.paths(),
+			&[PathBuf::from("/a"), PathBuf::from("/b")]
+		);
+
+		for i in 0..MAX_RECENT_REPOS {
+			recent.add(PathBuf::from(format!("/{}", i)));
+		}
+
+		assert_eq!(recent.paths().len(), MAX_RECENT_REPOS);
+		assert!(!recent.paths().contains(&PathBuf::from("/a")));
+	}
+}
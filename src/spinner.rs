@@ -11,6 +11,7 @@ pub struct Spinner {
 	idx: usize,
 	active: bool,
 	last_char: Cell<char>,
+	last_position: Cell<(u16, u16)>,
 }
 
 impl Default for Spinner {
@@ -19,6 +20,7 @@ impl Default for Spinner {
 			idx: 0,
 			active: false,
 			last_char: Cell::new(' '),
+			last_position: Cell::new((0, 0)),
 		}
 	}
 }
@@ -35,26 +37,38 @@ impl Spinner {
 		self.active = active;
 	}
 
-	/// draws or removes spinner char depending on `pending` state
+	/// spinner char or an empty one if not active
+	pub fn current(&self) -> char {
+		if self.active {
+			SPINNER_CHARS[self.idx]
+		} else {
+			' '
+		}
+	}
+
+	/// draws or removes spinner char depending on `pending` state,
+	/// a `position` inside the ui has to be drawn by the ui as well
+	/// (using `current`) to not leave the char behind once it moved
 	pub fn draw<B: Backend>(
 		&self,
 		terminal: &mut Terminal<B>,
+		position: (u16, u16),
 	) -> io::Result<()> {
-		let idx = self.idx;
-
-		let char_to_draw =
-			if self.active { SPINNER_CHARS[idx] } else { ' ' };
+		let char_to_draw = self.current();
 
-		if self.last_char.get() != char_to_draw {
+		if self.last_char.get() != char_to_draw
+			|| self.last_position.get() != position
+		{
 			self.last_char.set(char_to_draw);
+			self.last_position.set(position);
 
 			let c = tui::buffer::Cell::default()
 				.set_char(char_to_draw)
 				.clone();
 
-			terminal
-				.backend_mut()
-				.draw(vec![(0_u16, 0_u16, &c)].into_iter())?;
+			terminal.backend_mut().draw(
+				vec![(position.0, position.1, &c)].into_iter(),
+			)?;
 
 			tui::backend::Backend::flush(terminal.backend_mut())?;
 		}
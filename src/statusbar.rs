@@ -0,0 +1,230 @@
+use crate::{
+	components::{branch_label, SharedOptions},
+	strings,
+	ui::style::SharedTheme,
+};
+use asyncgit::{
+	cached,
+	sync::{self, RepoState},
+	CWD,
+};
+use std::{borrow::Cow, cell::Cell, convert::TryFrom};
+use tui::{
+	backend::Backend,
+	layout::Rect,
+	text::{Span, Spans},
+	widgets::Paragraph,
+	Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+	Text(&'a str),
+	Branch,
+	State,
+	Pending,
+	Describe,
+}
+
+/// splits the format into text and the placeholders `{branch}`,
+/// `{state}`, `{pending}` and `{describe}`, unknown ones stay text
+fn parse_format(format: &str) -> Vec<Segment> {
+	let mut res = Vec::new();
+	// start of the text not yet pushed, unknown placeholders are
+	// kept as part of it
+	let mut text_start = 0;
+	let mut pos = 0;
+
+	while let Some(start) = format[pos..].find('{').map(|i| pos + i) {
+		let placeholder = format[start..].find('}').and_then(|end| {
+			match &format[start..=start + end] {
+				"{branch}" => Some(Segment::Branch),
+				"{state}" => Some(Segment::State),
+				"{pending}" => Some(Segment::Pending),
+				"{describe}" => Some(Segment::Describe),
+				_ => None,
+			}
+			.map(|segment| (segment, start + end + 1))
+		});
+
+		if let Some((segment, end)) = placeholder {
+			if start > text_start {
+				res.push(Segment::Text(&format[text_start..start]));
+			}
+			res.push(segment);
+			text_start = end;
+			pos = end;
+		} else {
+			pos = start + 1;
+		}
+	}
+
+	if text_start < format.len() {
+		res.push(Segment::Text(&format[text_start..]));
+	}
+
+	res
+}
+
+/// single line below the tabs showing the branch, a pending
+/// operation and the work spinner as set in `Options::status_bar`
+pub struct StatusBar {
+	branch_name: cached::BranchName,
+	state: RepoState,
+	/// nearest tag, only looked up if the format shows it
+	describe: Option<String>,
+	/// where the spinner goes, set while drawing `{pending}`
+	spinner_position: Cell<Option<(u16, u16)>>,
+	theme: SharedTheme,
+	options: SharedOptions,
+}
+
+impl StatusBar {
+	pub fn new(theme: SharedTheme, options: SharedOptions) -> Self {
+		Self {
+			branch_name: cached::BranchName::new(CWD),
+			state: RepoState::Clean,
+			describe: None,
+			spinner_position: Cell::new(None),
+			theme,
+			options,
+		}
+	}
+
+	/// forgets what belongs to the repo switched away from
+	pub fn reset_repo_state(&mut self) {
+		self.branch_name = cached::BranchName::new(CWD);
+		self.describe = None;
+	}
+
+	pub fn is_visible(&self) -> bool {
+		!self.options.borrow().status_bar.is_empty()
+	}
+
+	pub fn update(&mut self) {
+		self.branch_name.lookup().ok();
+		self.state =
+			sync::repo_state(CWD).unwrap_or(RepoState::Clean);
+
+		let shows_describe =
+			parse_format(&self.options.borrow().status_bar)
+				.contains(&Segment::Describe);
+		self.describe = if shows_describe {
+			sync::describe(CWD).ok()
+		} else {
+			None
+		};
+	}
+
+	pub fn spinner_position(&self) -> Option<(u16, u16)> {
+		self.spinner_position.get()
+	}
+
+	/// `spinner` goes where `{pending}` is, keeping the ui buffer in
+	/// sync with what `Spinner` draws there in between
+	pub fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		r: Rect,
+		spinner: char,
+	) {
+		self.spinner_position.set(None);
+
+		if !self.is_visible() {
+			return;
+		}
+
+		let options = self.options.borrow();
+		let mut column = 0;
+		let mut txt = Vec::new();
+
+		for segment in parse_format(&options.status_bar) {
+			let span = match segment {
+				Segment::Text(text) => Span::raw(Cow::from(text)),
+				Segment::Branch => Span::styled(
+					branch_label(&self.branch_name)
+						.unwrap_or_default(),
+					self.theme.branch(false, true),
+				),
+				Segment::State => Span::styled(
+					if self.state == RepoState::Clean {
+						String::new()
+					} else {
+						strings::pending_op_name(self.state)
+							.to_string()
+					},
+					self.theme.text_danger(),
+				),
+				Segment::Pending => {
+					if self.spinner_position.get().is_none() {
+						let column = u16::try_from(column)
+							.unwrap_or(u16::MAX)
+							.min(r.width.saturating_sub(1));
+						self.spinner_position
+							.set(Some((r.x + column, r.y)));
+					}
+					Span::raw(spinner.to_string())
+				}
+				Segment::Describe => Span::styled(
+					self.describe.clone().unwrap_or_default(),
+					self.theme.commit_hash(false),
+				),
+			};
+
+			column += span.content.width();
+			txt.push(span);
+		}
+
+		f.render_widget(Paragraph::new(Spans::from(txt)), r);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{parse_format, Segment};
+
+	#[test]
+	fn test_parse_format() {
+		assert_eq!(
+			parse_format("{pending} {branch} {state}"),
+			vec![
+				Segment::Pending,
+				Segment::Text(" "),
+				Segment::Branch,
+				Segment::Text(" "),
+				Segment::State,
+			]
+		);
+		assert_eq!(
+			parse_format("on {branch}!"),
+			vec![
+				Segment::Text("on "),
+				Segment::Branch,
+				Segment::Text("!"),
+			]
+		);
+		assert_eq!(
+			parse_format("{foo} {"),
+			vec![Segment::Text("{foo} {")]
+		);
+		assert_eq!(
+			parse_format("{{branch}}"),
+			vec![
+				Segment::Text("{"),
+				Segment::Branch,
+				Segment::Text("}"),
+			]
+		);
+		assert_eq!(
+			parse_format("{branch} ({describe})"),
+			vec![
+				Segment::Branch,
+				Segment::Text(" ("),
+				Segment::Describe,
+				Segment::Text(")"),
+			]
+		);
+		assert_eq!(parse_format(""), vec![]);
+	}
+}
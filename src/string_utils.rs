@@ -1,35 +1,253 @@
-///
+use std::ops::Range;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// keeps the last `width` display columns of `s`
 pub fn trim_length_left(s: &str, width: usize) -> &str {
-	let len = s.len();
-	if len > width {
-		for i in len - width..len {
-			if s.is_char_boundary(i) {
-				return &s[i..];
-			}
+	let mut columns = 0;
+	for (idx, c) in s.char_indices().rev() {
+		columns += c.width().unwrap_or_default();
+		if columns > width {
+			return &s[idx + c.len_utf8()..];
 		}
 	}
 
 	s
 }
 
-//TODO: allow customize tabsize
-pub fn tabs_to_spaces(input: String) -> String {
+/// expands tabs to spaces up to the next tab stop,
+/// tab stops are placed every `tab_width` columns
+pub fn tabs_to_spaces(input: String, tab_width: usize) -> String {
 	if input.contains('\t') {
-		input.replace("\t", "  ")
+		let tab_width = tab_width.max(1);
+		let mut res = String::with_capacity(input.len());
+		let mut column = 0;
+
+		for c in input.chars() {
+			match c {
+				'\t' => {
+					let spaces = tab_width - column % tab_width;
+					res.extend(std::iter::repeat(' ').take(spaces));
+					column += spaces;
+				}
+				'\n' => {
+					res.push(c);
+					column = 0;
+				}
+				_ => {
+					res.push(c);
+					column += c.width().unwrap_or_default();
+				}
+			}
+		}
+
+		res
 	} else {
 		input
 	}
 }
 
+/// drops the first `columns` display columns of `s`
+pub fn skip_columns(s: &str, columns: usize) -> &str {
+	let mut skipped = 0;
+	for (idx, c) in s.char_indices() {
+		if skipped >= columns {
+			return &s[idx..];
+		}
+		skipped += c.width().unwrap_or_default();
+	}
+
+	""
+}
+
+/// byte ranges of all non overlapping occurrences of `pattern`,
+/// ignoring ascii case unless `case_sensitive` is set
+pub fn find_all(
+	s: &str,
+	pattern: &str,
+	case_sensitive: bool,
+) -> Vec<Range<usize>> {
+	if pattern.is_empty() {
+		return Vec::new();
+	}
+
+	let to_range =
+		|(idx, m): (usize, &str)| idx..idx.saturating_add(m.len());
+
+	if case_sensitive {
+		s.match_indices(pattern).map(to_range).collect()
+	} else {
+		s.to_ascii_lowercase()
+			.match_indices(&pattern.to_ascii_lowercase())
+			.map(to_range)
+			.collect()
+	}
+}
+
+/// hard wraps every line but the first (the subject) of a commit
+/// message at `width` columns, words longer than `width` stay intact,
+/// so does a closing block of trailers like `Signed-off-by: ..`
+pub fn wrap_commit_body(msg: &str, width: usize) -> String {
+	let lines = msg.lines().collect::<Vec<_>>();
+	let trailers_start = trailers_start(&lines);
+
+	let mut res =
+		lines.first().copied().unwrap_or_default().to_string();
+
+	for (idx, line) in lines.iter().enumerate().skip(1) {
+		res.push('\n');
+
+		if idx < trailers_start {
+			res.push_str(&wrap_line(line, width));
+		} else {
+			res.push_str(line);
+		}
+	}
+
+	res
+}
+
+/// index of the first line of the last paragraph if that one only
+/// consists of trailers, `lines.len()` otherwise
+fn trailers_start(lines: &[&str]) -> usize {
+	let paragraph_start = lines
+		.iter()
+		.rposition(|line| line.trim().is_empty())
+		.map_or(lines.len(), |idx| idx + 1);
+
+	let paragraph = &lines[paragraph_start..];
+
+	if !paragraph.is_empty()
+		&& paragraph.iter().all(|l| is_trailer(l))
+	{
+		paragraph_start
+	} else {
+		lines.len()
+	}
+}
+
+/// `Token: value` where the token has no whitespace
+fn is_trailer(line: &str) -> bool {
+	line.split_once(": ").map_or(false, |(token, _)| {
+		!token.is_empty()
+			&& token
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '-')
+	})
+}
+
+fn wrap_line(line: &str, width: usize) -> String {
+	let mut res = String::with_capacity(line.len());
+	let mut column = 0;
+
+	for (idx, word) in line.split(' ').enumerate() {
+		let word_width = word.width();
+
+		if idx > 0 {
+			if column > 0
+				&& !word.is_empty()
+				&& column + 1 + word_width > width
+			{
+				res.push('\n');
+				column = 0;
+			} else {
+				res.push(' ');
+				column += 1;
+			}
+		}
+
+		res.push_str(word);
+		column += word_width;
+	}
+
+	res
+}
+
 #[cfg(test)]
 mod test {
 	use pretty_assertions::assert_eq;
 
-	use crate::string_utils::trim_length_left;
+	use crate::string_utils::{
+		find_all, skip_columns, tabs_to_spaces, trim_length_left,
+		wrap_commit_body,
+	};
 
 	#[test]
 	fn test_trim() {
 		assert_eq!(trim_length_left("👍foo", 3), "foo");
 		assert_eq!(trim_length_left("👍foo", 4), "foo");
+		assert_eq!(trim_length_left("👍foo", 5), "👍foo");
+		assert_eq!(trim_length_left("日本語.rs", 5), "語.rs");
+		assert_eq!(trim_length_left("äöü", 2), "öü");
+	}
+
+	#[test]
+	fn test_tabs_to_spaces() {
+		assert_eq!(tabs_to_spaces("\tfoo".to_string(), 4), "    foo");
+		assert_eq!(tabs_to_spaces("ab\tc".to_string(), 4), "ab  c");
+		assert_eq!(
+			tabs_to_spaces("abcd\tx\n\ty".to_string(), 4),
+			"abcd    x\n    y"
+		);
+		assert_eq!(tabs_to_spaces("a\tb".to_string(), 2), "a b");
+	}
+
+	#[test]
+	fn test_skip_columns() {
+		assert_eq!(skip_columns("foobar", 0), "foobar");
+		assert_eq!(skip_columns("foobar", 3), "bar");
+		assert_eq!(skip_columns("foo", 5), "");
+		assert_eq!(skip_columns("👍foo", 2), "foo");
+	}
+
+	#[test]
+	fn test_find_all() {
+		assert_eq!(
+			find_all("foo Foo foo", "foo", true),
+			vec![0..3, 8..11]
+		);
+		assert_eq!(
+			find_all("foo Foo foo", "FOO", false),
+			vec![0..3, 4..7, 8..11]
+		);
+		assert!(find_all("foo", "", false).is_empty());
+	}
+
+	#[test]
+	fn test_wrap_commit_body() {
+		let subject =
+			"subject line that is way longer than the limit";
+
+		assert_eq!(wrap_commit_body(subject, 10), subject);
+		assert_eq!(
+			wrap_commit_body("subject\n\nfoo bar baz", 7),
+			"subject\n\nfoo bar\nbaz"
+		);
+		assert_eq!(
+			wrap_commit_body("subject\n\n  foo verylongword x", 5),
+			"subject\n\n  foo\nverylongword\nx"
+		);
+		assert_eq!(wrap_commit_body("", 5), "");
+	}
+
+	#[test]
+	fn test_wrap_commit_body_trailers() {
+		let trailers = "Signed-off-by: Some One <some@one.com>\n\
+			Co-authored-by: Other Person <other@person.com>";
+
+		assert_eq!(
+			wrap_commit_body(
+				&format!("subject\n\nfoo bar baz\n\n{}", trailers),
+				7
+			),
+			format!("subject\n\nfoo bar\nbaz\n\n{}", trailers)
+		);
+		assert_eq!(
+			wrap_commit_body("subject\n\nfoo: bar baz\nfoo bar", 7),
+			"subject\n\nfoo:\nbar baz\nfoo bar"
+		);
+		assert_eq!(
+			wrap_commit_body("subject\n\nFixes: foo bar baz", 7),
+			"subject\n\nFixes: foo bar baz"
+		);
 	}
 }
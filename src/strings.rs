@@ -1,4 +1,7 @@
-use asyncgit::sync::CommitId;
+use asyncgit::sync::{
+	CommitId, DiffStats, FileSizeHint, MergeMode, RepoState,
+};
+use bytesize::ByteSize;
 
 use crate::keys::SharedKeyConfig;
 
@@ -9,12 +12,13 @@ pub mod order {
 
 pub static PUSH_POPUP_MSG: &str = "Push";
 pub static FORCE_PUSH_POPUP_MSG: &str = "Force Push";
+pub static FORCE_PUSH_LEASE_POPUP_MSG: &str = "Force Push (lease)";
 pub static PULL_POPUP_MSG: &str = "Pull";
 pub static PUSH_POPUP_PROGRESS_NONE: &str = "preparing...";
 pub static PUSH_POPUP_STATES_ADDING: &str = "adding objects (1/3)";
 pub static PUSH_POPUP_STATES_DELTAS: &str = "deltas (2/3)";
 pub static PUSH_POPUP_STATES_PUSHING: &str = "pushing (3/3)";
-pub static PUSH_POPUP_STATES_TRANSFER: &str = "transfer";
+pub static PUSH_POPUP_STATES_TRANSFER: &str = "receiving objects";
 pub static PUSH_POPUP_STATES_DONE: &str = "done";
 
 pub static PUSH_TAGS_POPUP_MSG: &str = "Push Tags";
@@ -22,21 +26,149 @@ pub static PUSH_TAGS_STATES_FETCHING: &str = "fetching";
 pub static PUSH_TAGS_STATES_PUSHING: &str = "pushing";
 pub static PUSH_TAGS_STATES_DONE: &str = "done";
 
+pub static WORKTREE_MAIN: &str = "(main)";
+
+pub static BISECT_COMMIT: &str = "Commit";
+pub static BISECT_FIRST_BAD: &str = "First bad commit";
+
 pub mod symbol {
 	pub const WHITESPACE: &str = "\u{00B7}"; //·
 	pub const CHECKMARK: &str = "\u{2713}"; //✓
+	pub const CROSSMARK: &str = "\u{2717}"; //✗
+	pub const QUESTION_MARK: &str = "?";
+	pub const WARNING: &str = "\u{26a0}"; //⚠
 	pub const SPACE: &str = "\u{02FD}"; //˽
 	pub const EMPTY_SPACE: &str = " ";
 	pub const FOLDER_ICON_COLLAPSED: &str = "\u{25b8}"; //▸
 	pub const FOLDER_ICON_EXPANDED: &str = "\u{25be}"; //▾
 	pub const EMPTY_STR: &str = "";
+	pub const SHALLOW_BOUNDARY: &str = "\u{22a5}"; //⊥
 }
 
+pub fn log_shallow_boundary() -> String {
+	format!("{} shallow ", symbol::SHALLOW_BOUNDARY)
+}
 pub fn title_branches() -> String {
 	"Branches".to_string()
 }
-pub fn title_tags() -> String {
-	"Tags".to_string()
+pub fn title_tags(by_version: bool) -> String {
+	if by_version {
+		"Tags (by version)".to_string()
+	} else {
+		"Tags".to_string()
+	}
+}
+pub fn title_worktrees() -> String {
+	"Worktrees".to_string()
+}
+pub fn title_remotes() -> String {
+	"Remotes".to_string()
+}
+pub fn title_command_palette() -> String {
+	"Commands".to_string()
+}
+pub fn command_palette_hits(count: usize) -> String {
+	format!("Hits: {}", count)
+}
+pub fn title_recent_repos() -> String {
+	"Recent Repositories".to_string()
+}
+pub fn detached_head(short_id: &str) -> String {
+	format!("(detached @ {})", short_id)
+}
+pub fn title_co_authors() -> String {
+	"Co-Authors".to_string()
+}
+pub fn title_merge_branch(branch: &str) -> String {
+	format!("Merge '{}'", branch)
+}
+pub const fn merge_mode_name(mode: MergeMode) -> &'static str {
+	match mode {
+		MergeMode::FastForwardOnly => "ff-only",
+		MergeMode::NoFastForward => "no-ff",
+		MergeMode::Squash => "squash",
+	}
+}
+pub const fn merge_mode_desc(mode: MergeMode) -> &'static str {
+	match mode {
+		MergeMode::FastForwardOnly => {
+			"move HEAD forward, never merge"
+		}
+		MergeMode::NoFastForward => "always create a merge commit",
+		MergeMode::Squash => "stage the changes to commit them",
+	}
+}
+pub fn title_branches_containing(id: &CommitId) -> String {
+	format!("Branches containing {}", id.get_short_string())
+}
+pub fn branches_containing_none() -> String {
+	"no branch contains this commit".to_string()
+}
+pub fn title_compare_branches() -> String {
+	"Compare Branches".to_string()
+}
+pub fn compare_branches_only_in(
+	branch: &str,
+	count: usize,
+	limit: usize,
+) -> String {
+	let more = if count >= limit { "+" } else { "" };
+	format!("Only in {} ({}{})", branch, count, more)
+}
+pub fn title_push_summary(
+	branch: &str,
+	count: usize,
+	limit: usize,
+) -> String {
+	let more = if count >= limit { "+" } else { "" };
+	format!("Push {} ({}{} commits)", branch, count, more)
+}
+pub fn title_bisect() -> String {
+	"Bisect".to_string()
+}
+pub fn bisect_not_started(key_config: &SharedKeyConfig) -> String {
+	format!(
+		"Mark this commit good [{}] or bad [{}] to start bisecting",
+		key_config.get_hint(key_config.bisect_good),
+		key_config.get_hint(key_config.bisect_bad),
+	)
+}
+pub fn bisect_range(
+	bad: Option<&CommitId>,
+	good: &[CommitId],
+) -> String {
+	let good = good
+		.iter()
+		.map(CommitId::get_short_string)
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	format!(
+		"Bad: {}  Good: {}",
+		bad.map_or_else(
+			|| "-".to_string(),
+			CommitId::get_short_string
+		),
+		if good.is_empty() { "-" } else { &good },
+	)
+}
+pub fn bisect_remaining(remaining: usize) -> String {
+	// each step halves the candidates
+	let mut steps = 0;
+	let mut left = remaining;
+	while left > 1 {
+		left = (left + 1) / 2;
+		steps += 1;
+	}
+
+	format!("Remaining: {} (about {} steps)", remaining, steps)
+}
+pub fn title_submodules(updating: bool) -> String {
+	if updating {
+		"Submodules (updating...)".to_string()
+	} else {
+		"Submodules".to_string()
+	}
 }
 pub fn title_status(_key_config: &SharedKeyConfig) -> String {
 	"Unstaged Changes".to_string()
@@ -86,8 +218,15 @@ pub fn commit_title() -> String {
 pub fn commit_title_merge() -> String {
 	"Commit (Merge)".to_string()
 }
-pub fn commit_title_amend() -> String {
-	"Commit (Amend)".to_string()
+pub fn commit_title_amend(staged_files: usize) -> String {
+	if staged_files == 0 {
+		"Commit (Amend message)".to_string()
+	} else {
+		format!("Commit (Amend + {} staged)", staged_files)
+	}
+}
+pub fn commit_title_reword() -> String {
+	"Commit (Reword HEAD)".to_string()
 }
 pub fn commit_msg(_key_config: &SharedKeyConfig) -> String {
 	"type commit message..".to_string()
@@ -95,14 +234,79 @@ pub fn commit_msg(_key_config: &SharedKeyConfig) -> String {
 pub fn commit_first_line_warning(count: usize) -> String {
 	format!("[subject length: {}]", count)
 }
+pub fn commit_amend_pushed_warning() -> String {
+	"[HEAD is pushed already]".to_string()
+}
+pub fn commit_sign_off_hint() -> String {
+	"[sign-off]".to_string()
+}
+pub fn commit_author_hint(name: &str, email: &str) -> String {
+	format!("[author: {} <{}>]", name, email)
+}
+pub fn commit_author_popup_title() -> String {
+	"Author".to_string()
+}
+pub fn commit_author_popup_msg() -> String {
+	"name <email>, leave empty to author it yourself".to_string()
+}
+pub fn file_size_hint(hint: FileSizeHint) -> String {
+	match hint {
+		FileSizeHint::Large(size) => {
+			format!("{} {}", symbol::WARNING, ByteSize::b(size))
+		}
+		FileSizeHint::LfsPointer => String::from("[lfs]"),
+	}
+}
+pub fn diff_large_hidden(
+	lines: usize,
+	key_config: &SharedKeyConfig,
+) -> String {
+	format!(
+		"large diff ({} lines), press [{}] to load",
+		lines,
+		key_config.get_hint(key_config.enter)
+	)
+}
+pub fn diff_binary_file() -> String {
+	"Binary file differs: ".to_string()
+}
+pub fn diff_mode_change(old: u32, new: u32) -> String {
+	format!("[{:o} -> {:o}]", old, new)
+}
+/// executable bit flips are the common case and get a short hint
+pub fn file_mode_change(old: u32, new: u32) -> String {
+	match (old, new) {
+		(0o100_644, 0o100_755) => String::from("[+x]"),
+		(0o100_755, 0o100_644) => String::from("[-x]"),
+		_ => diff_mode_change(old, new),
+	}
+}
+pub fn last_fetch(ago: &str) -> String {
+	format!("fetched {} ago", ago)
+}
+pub fn diff_stats(stats: &DiffStats) -> String {
+	let plural = |n: usize| if n == 1 { "" } else { "s" };
+	format!(
+		"{} file{} changed, {} insertion{}(+), {} deletion{}(-)",
+		stats.files_changed,
+		plural(stats.files_changed),
+		stats.insertions,
+		plural(stats.insertions),
+		stats.deletions,
+		plural(stats.deletions),
+	)
+}
 pub const fn branch_name_invalid() -> &'static str {
 	"[invalid name]"
 }
-pub fn commit_editor_msg(_key_config: &SharedKeyConfig) -> String {
-	r##"
-# Edit your commit message
-# Lines starting with '#' will be ignored"##
-		.to_string()
+pub fn commit_editor_msg(
+	_key_config: &SharedKeyConfig,
+	comment_char: char,
+) -> String {
+	format!(
+		"\n{0} Edit your commit message\n{0} Lines starting with '{0}' will be ignored",
+		comment_char
+	)
 }
 pub fn stash_popup_title(_key_config: &SharedKeyConfig) -> String {
 	"Stash".to_string()
@@ -146,16 +350,149 @@ pub fn confirm_msg_merge(
 	}
 }
 
-pub fn confirm_title_abortmerge() -> String {
-	"Abort merge?".to_string()
+pub const fn pending_op_name(state: RepoState) -> &'static str {
+	match state {
+		RepoState::Merge => "merge",
+		RepoState::CherryPick => "cherry-pick",
+		RepoState::Revert => "revert",
+		RepoState::Rebase => "rebase",
+		RepoState::Clean | RepoState::Other => "operation",
+	}
+}
+pub fn notification_remote_title(op: &str, success: bool) -> String {
+	format!(
+		"gitui: {} {}",
+		op,
+		if success { "finished" } else { "failed" }
+	)
+}
+pub fn notification_remote_body(
+	op: &str,
+	branch: &str,
+	error: Option<&str>,
+) -> String {
+	error.map_or_else(
+		|| format!("{} of '{}' done", op, branch),
+		ToString::to_string,
+	)
+}
+pub fn pending_op(state: RepoState, ids: &str) -> String {
+	if ids.is_empty() {
+		format!("Pending {}", pending_op_name(state))
+	} else {
+		format!("Pending {} ({})", pending_op_name(state), ids)
+	}
 }
-pub fn confirm_msg_abortmerge() -> String {
+pub fn confirm_title_abort_pending_op(state: RepoState) -> String {
+	format!("Abort {}?", pending_op_name(state))
+}
+pub fn confirm_msg_abort_pending_op() -> String {
 	"This will revert all uncommitted changes. Are you sure?"
 		.to_string()
 }
+pub fn confirm_title_reset_orig_head() -> String {
+	"Reset to ORIG_HEAD?".to_string()
+}
+pub fn confirm_msg_reset_orig_head(id: &CommitId) -> String {
+	format!(
+		"Move HEAD back to ORIG_HEAD ({})?\nThe index is reset, the working tree stays untouched.",
+		id.get_short_string()
+	)
+}
+pub fn confirm_title_reword_merge() -> String {
+	"Reword merge commit?".to_string()
+}
+pub fn confirm_msg_reword_merge() -> String {
+	"HEAD is a merge commit.\nReword its message anyway?".to_string()
+}
+pub fn confirm_title_commit_large_files() -> String {
+	"Commit large files?".to_string()
+}
+pub fn confirm_msg_commit_large_files(count: usize) -> String {
+	format!(
+		"large files staged: {}\nthey are not tracked by git-lfs, commit anyway?",
+		count
+	)
+}
+pub fn confirm_title_ignore_folder() -> String {
+	"Ignore folder".to_string()
+}
+pub fn confirm_msg_ignore_folder(path: &str) -> String {
+	format!("Confirm ignoring the whole folder: '{}/' ?", path)
+}
+pub fn confirm_title_reset_all() -> String {
+	"Discard ALL changes".to_string()
+}
+pub fn confirm_msg_reset_all(files: &[String]) -> String {
+	format!(
+		"discard all unstaged changes and delete all untracked files?\n{}\n\nthis cannot be undone!",
+		file_list(files)
+	)
+}
+pub fn confirm_title_restore_file() -> String {
+	"Restore file".to_string()
+}
+pub fn confirm_msg_restore_file(path: &str, id: &CommitId) -> String {
+	format!(
+		"restore '{}' as of {}?\nlocal changes to it are lost!",
+		path,
+		id.get_short_string()
+	)
+}
+pub fn confirm_title_checkout_commit() -> String {
+	"Checkout commit".to_string()
+}
+pub fn confirm_msg_checkout_commit(id: &CommitId) -> String {
+	format!(
+		"checkout {} with uncommitted changes?\nthey are carried over unless they conflict.",
+		id.get_short_string()
+	)
+}
+pub fn confirm_title_checkout_branch() -> String {
+	"Checkout branch".to_string()
+}
+/// one indented line per file, cut off after a few
+fn file_list(files: &[String]) -> String {
+	const MAX_FILES: usize = 10;
+
+	let mut list = files
+		.iter()
+		.take(MAX_FILES)
+		.map(|f| format!("  {}", f))
+		.collect::<Vec<_>>()
+		.join("\n");
+	if files.len() > MAX_FILES {
+		list.push_str(&format!(
+			"\n  ... and {} more",
+			files.len() - MAX_FILES
+		));
+	}
+
+	list
+}
+pub fn confirm_msg_checkout_branch(
+	branch_ref: &str,
+	files: &[String],
+) -> String {
+	format!(
+		"checking out {} would overwrite local changes in:\n{}\n\nstash them, checkout and restore them afterwards?",
+		branch_ref.trim_start_matches("refs/heads/"),
+		file_list(files)
+	)
+}
 pub fn confirm_msg_reset() -> String {
 	"confirm file reset?".to_string()
 }
+pub fn confirm_msg_reset_files(count: usize) -> String {
+	format!("confirm reset of {} marked files?", count)
+}
+pub fn reset_files_failed(failures: &[String]) -> String {
+	format!(
+		"reset failed for {} files:\n{}",
+		failures.len(),
+		failures.join("\n")
+	)
+}
 pub fn confirm_msg_reset_lines(lines: usize) -> String {
 	format!(
 		"are you sure you want to discard {} selected lines?",
@@ -222,6 +559,15 @@ pub fn confirm_msg_delete_tag(
 ) -> String {
 	format!("Confirm deleting Tag: '{}' ?", tag_name)
 }
+pub fn confirm_title_delete_remote() -> String {
+	"Remove Remote".to_string()
+}
+pub fn confirm_msg_delete_remote(name: &str) -> String {
+	format!(
+		"Confirm removing remote: '{}' ?\nits remote branches are deleted and local branches stop tracking it.",
+		name
+	)
+}
 pub fn confirm_title_force_push(
 	_key_config: &SharedKeyConfig,
 ) -> String {
@@ -236,9 +582,53 @@ pub fn confirm_msg_force_push(
         branch_ref
     )
 }
+pub fn confirm_title_force_push_lease(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Force Push With Lease".to_string()
+}
+pub fn confirm_msg_force_push_lease(
+	_key_config: &SharedKeyConfig,
+	branch_ref: &str,
+) -> String {
+	format!(
+		"Confirm force push to branch '{}' ?  It is aborted if the remote branch changed since the last fetch.",
+		branch_ref
+	)
+}
 pub fn log_title(_key_config: &SharedKeyConfig) -> String {
 	"Commit".to_string()
 }
+pub fn copy_hash_failed(hash: &str) -> String {
+	format!("copy to clipboard failed, hash:\n{}", hash)
+}
+pub fn cherry_pick_conflict(picked: usize, count: usize) -> String {
+	format!(
+		"cherry-pick stopped at a conflict after {} of {} commits,\nresolve it and commit or abort it in the status tab",
+		picked, count
+	)
+}
+pub fn copy_path_failed(path: &str) -> String {
+	format!("copy to clipboard failed, path:\n{}", path)
+}
+pub fn log_title_filtered(
+	key_config: &SharedKeyConfig,
+	author: Option<&str>,
+	path: Option<&str>,
+	date: Option<&str>,
+) -> String {
+	let mut title = log_title(key_config);
+	if let Some(author) = author {
+		title.push_str(&format!(" [author: {}]", author));
+	}
+	if let Some(path) = path {
+		title.push_str(&format!(" [path: {}]", path));
+	}
+	if let Some(date) = date {
+		title.push_str(&format!(" [date: {}]", date));
+	}
+	title
+}
 pub fn blame_title(_key_config: &SharedKeyConfig) -> String {
 	"Blame".to_string()
 }
@@ -250,6 +640,107 @@ pub fn tag_commit_popup_title(
 pub fn tag_commit_popup_msg(_key_config: &SharedKeyConfig) -> String {
 	"type tag".to_string()
 }
+pub fn change_set_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Change set".to_string()
+}
+pub fn change_set_popup_msg(_key_config: &SharedKeyConfig) -> String {
+	"commit message (empty to remove file from its set)".to_string()
+}
+pub fn diff_search_popup_title(case_sensitive: bool) -> String {
+	if case_sensitive {
+		"Search diff (case sensitive)".to_string()
+	} else {
+		"Search diff".to_string()
+	}
+}
+pub fn diff_search_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"search text (empty to end search)".to_string()
+}
+pub fn goto_line_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Go to line".to_string()
+}
+pub fn goto_line_popup_msg(_key_config: &SharedKeyConfig) -> String {
+	"line number".to_string()
+}
+pub fn goto_line_invalid(input: &str) -> String {
+	format!("not a line number: '{}'", input)
+}
+pub fn status_filter_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Filter files".to_string()
+}
+pub fn status_filter_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"fuzzy filter (empty to show all)".to_string()
+}
+/// shown in the file tree titles while a filter is active
+pub fn status_filter_note(filter: &str) -> String {
+	format!(" [filter: {}]", filter)
+}
+/// shown in the diff title while a search is active
+pub fn diff_search_status(
+	query: &str,
+	current: Option<usize>,
+	count: usize,
+) -> String {
+	if count == 0 {
+		format!("[/{}: no matches]", query)
+	} else {
+		format!(
+			"[/{}: {}/{}]",
+			query,
+			current.map_or_else(
+				|| String::from("-"),
+				|idx| { (idx + 1).to_string() }
+			),
+			count
+		)
+	}
+}
+pub fn log_filter_author_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Filter by author".to_string()
+}
+pub fn log_filter_author_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"author name or email (empty to show all)".to_string()
+}
+pub fn log_filter_path_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Filter by path".to_string()
+}
+pub fn log_filter_path_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"file or folder path (empty to show all)".to_string()
+}
+pub fn log_filter_date_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Filter by date".to_string()
+}
+pub fn log_filter_date_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"YYYY-MM-DD..YYYY-MM-DD (empty to show all)".to_string()
+}
+pub fn log_filter_date_invalid(date: &str) -> String {
+	format!(
+		"invalid date range: '{}'\nexpected e.g. 2023-01-01..2023-06-30",
+		date
+	)
+}
 pub fn stashlist_title(_key_config: &SharedKeyConfig) -> String {
 	"Stashes".to_string()
 }
@@ -267,16 +758,64 @@ pub fn stashing_options_title(
 pub fn loading_text(_key_config: &SharedKeyConfig) -> String {
 	"Loading ...".to_string()
 }
+pub fn syntax_highlighting_skipped() -> String {
+	" (too large to highlight)".to_string()
+}
 pub fn create_branch_popup_title(
 	_key_config: &SharedKeyConfig,
 ) -> String {
 	"Branch".to_string()
 }
+pub fn create_branch_at_popup_title(id: &CommitId) -> String {
+	format!("Branch at {}", id.get_short_string())
+}
 pub fn create_branch_popup_msg(
 	_key_config: &SharedKeyConfig,
 ) -> String {
 	"type branch name".to_string()
 }
+pub fn create_worktree_branch_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Worktree Branch".to_string()
+}
+pub fn create_worktree_branch_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"new or existing branch name".to_string()
+}
+pub fn create_worktree_path_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Worktree Path".to_string()
+}
+pub fn create_worktree_path_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"path of the new worktree".to_string()
+}
+pub fn export_patch_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Export Patch".to_string()
+}
+pub fn export_patch_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"path of the patch file".to_string()
+}
+pub fn apply_patch_popup_title(to_index: bool) -> String {
+	if to_index {
+		"Apply Patch (to index)".to_string()
+	} else {
+		"Apply Patch (to working dir)".to_string()
+	}
+}
+pub fn apply_patch_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"path of the patch file".to_string()
+}
 pub fn username_popup_title(_key_config: &SharedKeyConfig) -> String {
 	"Username".to_string()
 }
@@ -300,6 +839,34 @@ pub fn rename_branch_popup_msg(
 ) -> String {
 	"new branch name".to_string()
 }
+pub fn remote_add_popup_title() -> String {
+	"Add Remote".to_string()
+}
+pub fn remote_add_popup_msg() -> String {
+	"name of the remote".to_string()
+}
+pub fn remote_rename_popup_title() -> String {
+	"Rename Remote".to_string()
+}
+pub fn remote_rename_popup_msg() -> String {
+	"new name of the remote".to_string()
+}
+pub fn remote_url_popup_title() -> String {
+	"Remote Url".to_string()
+}
+pub fn remote_url_popup_msg() -> String {
+	"url of the remote".to_string()
+}
+pub fn set_upstream_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Set Upstream".to_string()
+}
+pub fn set_upstream_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"upstream branch, leave empty to unset".to_string()
+}
 
 pub mod commit {
 	use crate::keys::SharedKeyConfig;
@@ -315,9 +882,22 @@ pub mod commit {
 	pub fn details_date() -> String {
 		"Date: ".to_string()
 	}
+	pub fn details_author_date() -> String {
+		"Authored: ".to_string()
+	}
+	pub fn details_commit_date() -> String {
+		"Committed: ".to_string()
+	}
+	/// absolute local time followed by the age
+	pub fn details_time(time: &str, age: &str) -> String {
+		format!("{} ({})", time, age)
+	}
 	pub fn details_tags() -> String {
 		"Tags: ".to_string()
 	}
+	pub fn details_parents() -> String {
+		"Parents: ".to_string()
+	}
 	pub fn details_message() -> String {
 		"Subject: ".to_string()
 	}
@@ -346,7 +926,7 @@ pub mod commit {
 
 pub mod commands {
 	use crate::components::CommandText;
-	use crate::keys::SharedKeyConfig;
+	use crate::keys::{KeyConfig, SharedKeyConfig};
 
 	static CMD_GROUP_GENERAL: &str = "-- General --";
 	static CMD_GROUP_DIFF: &str = "-- Diff --";
@@ -367,6 +947,30 @@ pub mod commands {
 			CMD_GROUP_GENERAL,
 		)
 	}
+	pub fn open_command_palette(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commands [{}]",
+				key_config.get_hint(key_config.open_command_palette),
+			),
+			"search and run commands",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn command_palette_run(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Run [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"run selected command",
+			CMD_GROUP_GENERAL,
+		)
+	}
 	pub fn find_file(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -377,6 +981,17 @@ pub mod commands {
 			CMD_GROUP_GENERAL,
 		)
 	}
+	pub fn restore_file(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Restore [{}]",
+				key_config.get_hint(key_config.restore_file)
+			),
+			"restore selected file in the working dir as of this revision",
+			CMD_GROUP_GENERAL,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.restore_file))
+	}
 	pub fn toggle_tabs_direct(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -415,9 +1030,19 @@ pub mod commands {
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn navigate_commit_message(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn help_search(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Search [{}]",
+				key_config.get_hint(key_config.help_search)
+			),
+			"filter the help by command name or description",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn navigate_commit_message(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Nav [{}{}]",
@@ -443,6 +1068,19 @@ pub mod commands {
 			CMD_GROUP_GENERAL,
 		)
 	}
+	pub fn tree_collapse_expand_all(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Collapse/Expand all [{}{}]",
+				key_config.get_hint(key_config.tree_collapse_all),
+				key_config.get_hint(key_config.tree_expand_all),
+			),
+			"collapse all folders but the selected one or expand all",
+			CMD_GROUP_GENERAL,
+		)
+	}
 	pub fn scroll(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -468,6 +1106,36 @@ pub mod commands {
 			CMD_GROUP_GENERAL,
 		)
 	}
+	pub fn log_cherry_pick(
+		key_config: &SharedKeyConfig,
+		marked: usize,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Cherry-pick {} [{}]",
+				marked,
+				key_config.get_hint(key_config.log_cherry_pick),
+			),
+			"cherry-pick the marked commits onto HEAD, oldest first",
+			CMD_GROUP_LOG,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.log_cherry_pick))
+	}
+	pub fn commit_list_relative_time(
+		key_config: &SharedKeyConfig,
+		relative: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} time [{}]",
+				if relative { "Absolute" } else { "Relative" },
+				key_config
+					.get_hint(key_config.log_toggle_relative_time),
+			),
+			"toggle between relative and absolute commit times",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn copy(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -478,13 +1146,121 @@ pub mod commands {
 			CMD_GROUP_DIFF,
 		)
 	}
+	pub fn diff_load_large(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Load diff [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"render the whole large diff",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_copy_patch(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Patch [{}]",
+				key_config.get_hint(key_config.diff_copy_patch),
+			),
+			"copy selected hunk or whole diff as patch to clipboard",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_copy_permalink(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Link [{}]",
+				key_config.get_hint(key_config.diff_copy_permalink),
+			),
+			"copy a web link to the selected line to clipboard",
+			CMD_GROUP_DIFF,
+		)
+	}
 	pub fn copy_hash(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
 				"Copy Hash [{}]",
 				key_config.get_hint(key_config.copy),
 			),
-			"copy selected commit hash to clipboard",
+			"copy selected commit short hash to clipboard",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_path(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Path [{}{}]",
+				key_config.get_hint(key_config.copy),
+				key_config.get_hint(key_config.copy_absolute_path),
+			),
+			"copy selected path (relative or absolute) to clipboard",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn open_in_browser(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Browse [{}]",
+				key_config.get_hint(key_config.open_in_browser),
+			),
+			"open commit on the remote's website",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn commit_goto_parent(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Parent [{}]",
+				key_config.get_hint(key_config.commit_goto_parent),
+			),
+			"go to the highlighted parent (the first one by default)",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn commit_next_parent(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Next Parent [{}]",
+				key_config.get_hint(key_config.commit_next_parent),
+			),
+			"highlight the next parent of a merge commit and diff against it",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn branches_containing(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Branches [{}]",
+				key_config
+					.get_hint(key_config.log_branches_containing),
+			),
+			"list the branches containing the commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_full_hash(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Full Hash [{}]",
+				key_config.get_hint(key_config.copy_full_hash),
+			),
+			"copy selected commit full hash to clipboard",
 			CMD_GROUP_LOG,
 		)
 	}
@@ -497,6 +1273,7 @@ pub mod commands {
 			"push tags to remote",
 			CMD_GROUP_LOG,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.push))
 	}
 	pub fn diff_home_end(
 		key_config: &SharedKeyConfig,
@@ -536,6 +1313,7 @@ pub mod commands {
 			"reverts selected hunk",
 			CMD_GROUP_DIFF,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.status_reset_item))
 	}
 	pub fn diff_lines_revert(
 		key_config: &SharedKeyConfig,
@@ -548,247 +1326,1124 @@ pub mod commands {
 			"resets selected lines",
 			CMD_GROUP_DIFF,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.diff_reset_lines))
 	}
-	pub fn diff_lines_stage(
+	pub fn diff_scroll_horizontal(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Stage lines [{}]",
-				key_config.get_hint(key_config.diff_stage_lines),
+				"Scroll sideways [{}{}]",
+				key_config.get_hint(key_config.diff_scroll_left),
+				key_config.get_hint(key_config.diff_scroll_right),
 			),
-			"stage selected lines",
+			"scroll long lines left and right",
 			CMD_GROUP_DIFF,
 		)
 	}
-	pub fn diff_lines_unstage(
+	pub fn status_filter(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Unstage lines [{}]",
-				key_config.get_hint(key_config.diff_stage_lines),
+				"Filter [{}]",
+				key_config.get_hint(key_config.status_filter),
 			),
-			"unstage selected lines",
-			CMD_GROUP_DIFF,
+			"fuzzy filter the changed files by path",
+			CMD_GROUP_CHANGES,
 		)
 	}
-	pub fn diff_hunk_remove(
+	pub fn status_filter_confirm(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Remove hunk [{}]",
+				"Done [{}]",
 				key_config.get_hint(key_config.enter),
 			),
-			"removes selected hunk from stage",
-			CMD_GROUP_DIFF,
+			"close the filter input and keep the filter",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn close_popup(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_search(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Close [{}]",
-				key_config.get_hint(key_config.exit_popup),
+				"Search [{}]",
+				key_config.get_hint(key_config.diff_search),
 			),
-			"close overlay (e.g commit, help)",
-			CMD_GROUP_GENERAL,
+			"search for text in the diff",
+			CMD_GROUP_DIFF,
 		)
 	}
-	pub fn close_msg(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn export_patch(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Close [{}]",
-				key_config.get_hint(key_config.enter),
+				"Export [{}]",
+				key_config.get_hint(key_config.export_patch),
 			),
-			"close msg popup (e.g msg)",
-			CMD_GROUP_GENERAL,
+			"write the diff (or whole commit) to a patch file",
+			CMD_GROUP_DIFF,
 		)
-		.hide_help()
 	}
-	pub fn validate_msg(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn export_patch_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Validate [{}]",
+				"Export [{}]",
 				key_config.get_hint(key_config.enter),
 			),
-			"validate msg",
+			"write the patch file",
 			CMD_GROUP_GENERAL,
 		)
-		.hide_help()
 	}
-
-	pub fn abort_merge(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn apply_patch(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Abort merge [{}]",
-				key_config.get_hint(key_config.abort_merge),
+				"Apply Patch [{}]",
+				key_config.get_hint(key_config.apply_patch),
 			),
-			"abort ongoing merge",
-			CMD_GROUP_GENERAL,
+			"apply a patch file to the working dir or index",
+			CMD_GROUP_CHANGES,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.apply_patch))
 	}
-	pub fn select_staging(
+	pub fn apply_patch_confirm(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"To stage [{}]",
-				key_config.get_hint(key_config.toggle_workarea),
+				"Apply [{}]",
+				key_config.get_hint(key_config.enter),
 			),
-			"focus/select staging area",
+			"apply the patch file",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn select_unstaged(
+	pub fn apply_patch_target(
 		key_config: &SharedKeyConfig,
+		to_index: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"To unstaged [{}]",
-				key_config.get_hint(key_config.toggle_workarea),
+				"To {} [{}]",
+				if to_index { "index" } else { "working dir" },
+				key_config.get_hint(key_config.apply_patch_target),
 			),
-			"focus/select unstaged area",
+			"toggle applying to the working dir or the index",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn undo_commit(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_search_next(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Undo Commit [{}]",
-				key_config.get_hint(key_config.undo_commit),
+				"Next/Prev match [{}{}]",
+				key_config.get_hint(key_config.diff_search_next),
+				key_config.get_hint(key_config.diff_search_prev),
 			),
-			"undo last commit",
-			CMD_GROUP_GENERAL,
+			"jump to the next or previous search match",
+			CMD_GROUP_DIFF,
 		)
 	}
-	pub fn commit_open(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_next_file(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Commit [{}]",
-				key_config.get_hint(key_config.open_commit),
+				"Next/Prev file [{}{}]",
+				key_config.get_hint(key_config.diff_next_file),
+				key_config.get_hint(key_config.diff_prev_file),
 			),
-			"open commit popup (available in non-empty stage)",
-			CMD_GROUP_COMMIT,
+			"show the diff of the next or previous changed file",
+			CMD_GROUP_DIFF,
 		)
 	}
-	pub fn commit_open_editor(
+	pub fn goto_line_confirm(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
+		CommandText::new(
+			format!("Go [{}]", key_config.get_hint(key_config.enter),),
+			"scroll the file to the line",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn goto_line(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Open editor [{}]",
-				key_config.get_hint(key_config.open_commit_editor),
+				"Go to line [{}]",
+				key_config.get_hint(key_config.goto_line),
 			),
-			"open commit editor (available in commit popup)",
-			CMD_GROUP_COMMIT,
+			"scroll the file to a line number",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn commit_enter(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn scroll_page(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Commit [{}]",
-				key_config.get_hint(key_config.enter),
+				"Jump [{},{},{},{}]",
+				key_config.get_hint(key_config.page_up),
+				key_config.get_hint(key_config.page_down),
+				key_config.get_hint(key_config.home),
+				key_config.get_hint(key_config.end),
 			),
-			"commit (available when commit message is non-empty)",
-			CMD_GROUP_COMMIT,
+			"scroll a page or to the top or bottom",
+			CMD_GROUP_GENERAL,
 		)
-		.hide_help()
 	}
-	pub fn commit_amend(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_search_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Amend [{}]",
-				key_config.get_hint(key_config.commit_amend),
+				"Search [{}]",
+				key_config.get_hint(key_config.enter),
 			),
-			"amend last commit (available in commit popup)",
-			CMD_GROUP_COMMIT,
+			"search diff for the text",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn edit_item(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_search_case(
+		key_config: &SharedKeyConfig,
+		case_sensitive: bool,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Edit [{}]",
-				key_config.get_hint(key_config.edit_file),
+				"Case {} [{}]",
+				if case_sensitive {
+					"sensitive"
+				} else {
+					"ignored"
+				},
+				key_config.get_hint(key_config.diff_search_case),
 			),
-			"edit the currently selected file in an external editor",
-			CMD_GROUP_CHANGES,
+			"toggle case sensitive search",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn stage_item(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_lines_stage(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Stage [{}]",
-				key_config.get_hint(key_config.enter),
+				"Stage lines [{}]",
+				key_config.get_hint(key_config.diff_stage_lines),
 			),
-			"stage currently selected file or entire path",
-			CMD_GROUP_CHANGES,
+			"stage selected lines",
+			CMD_GROUP_DIFF,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.diff_stage_lines))
 	}
-	pub fn stage_all(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_lines_unstage(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Stage All [{}]",
-				key_config.get_hint(key_config.status_stage_all),
+				"Unstage lines [{}]",
+				key_config.get_hint(key_config.diff_stage_lines),
 			),
-			"stage all changes (in unstaged files)",
-			CMD_GROUP_CHANGES,
+			"unstage selected lines",
+			CMD_GROUP_DIFF,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.diff_stage_lines))
 	}
-	pub fn unstage_item(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn diff_hunk_remove(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Unstage [{}]",
+				"Remove hunk [{}]",
 				key_config.get_hint(key_config.enter),
 			),
-			"unstage currently selected file or entire path",
-			CMD_GROUP_CHANGES,
+			"removes selected hunk from stage",
+			CMD_GROUP_DIFF,
 		)
 	}
-	pub fn unstage_all(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn close_popup(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Unstage all [{}]",
-				key_config.get_hint(key_config.status_stage_all),
+				"Close [{}]",
+				key_config.get_hint(key_config.exit_popup),
 			),
-			"unstage all files (in staged files)",
-			CMD_GROUP_CHANGES,
+			"close overlay (e.g commit, help)",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn reset_item(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn close_msg(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Close [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"close msg popup (e.g msg)",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn validate_msg(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Validate [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"validate msg",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+
+	pub fn abort_pending_op(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Abort [{}]",
+				key_config.get_hint(key_config.abort_merge),
+			),
+			"abort the pending merge, cherry-pick or revert",
+			CMD_GROUP_GENERAL,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.abort_merge))
+	}
+	pub fn reset_orig_head(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Undo reset [{}]",
+				key_config.get_hint(key_config.reset_orig_head),
+			),
+			"reset back to ORIG_HEAD (available after reset/rebase/merge moved HEAD)",
+			CMD_GROUP_GENERAL,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.reset_orig_head))
+	}
+	pub fn reword_head(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reword HEAD [{}]",
+				key_config.get_hint(key_config.reword_head),
+			),
+			"edit the message of the last commit without changing its content",
+			CMD_GROUP_GENERAL,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.reword_head))
+	}
+	pub fn change_set_assign(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Change set [{}]",
+				key_config.get_hint(key_config.change_set_assign),
+			),
+			"assign staged file to a named change set",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn change_set_commit(
+		key_config: &SharedKeyConfig,
+		count: usize,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commit sets ({}) [{}]",
+				count,
+				key_config.get_hint(key_config.change_set_commit),
+			),
+			"commit each change set as a separate commit",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.change_set_commit))
+	}
+	pub fn log_filter_author(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter author [{}]",
+				key_config.get_hint(key_config.log_filter_author),
+			),
+			"only show commits by a matching author",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_filter_path(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter path [{}]",
+				key_config.get_hint(key_config.log_filter_path),
+			),
+			"only show commits touching a path",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_filter_date(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter date [{}]",
+				key_config.get_hint(key_config.log_filter_date),
+			),
+			"only show commits within a date range",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_filter_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"filter log",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn change_set_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Assign [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"assign file to change set",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn select_staging(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"To stage [{}]",
+				key_config.get_hint(key_config.toggle_workarea),
+			),
+			"focus/select staging area",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn select_unstaged(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"To unstaged [{}]",
+				key_config.get_hint(key_config.toggle_workarea),
+			),
+			"focus/select unstaged area",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn undo_commit(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Undo Commit [{}]",
+				key_config.get_hint(key_config.undo_commit),
+			),
+			"undo last commit",
+			CMD_GROUP_GENERAL,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.undo_commit))
+	}
+	pub fn commit_open(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commit [{}]",
+				key_config.get_hint(key_config.open_commit),
+			),
+			"open commit popup (available in non-empty stage)",
+			CMD_GROUP_COMMIT,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.open_commit))
+	}
+	pub fn commit_open_editor(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Open editor [{}]",
+				key_config.get_hint(key_config.open_commit_editor),
+			),
+			"open commit editor (available in commit popup)",
+			CMD_GROUP_COMMIT,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.open_commit_editor))
+	}
+	pub fn commit_enter(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commit [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"commit (available when commit message is non-empty)",
+			CMD_GROUP_COMMIT,
+		)
+		.hide_help()
+	}
+	pub fn commit_amend(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Amend [{}]",
+				key_config.get_hint(key_config.commit_amend),
+			),
+			"amend last commit with the staged changes",
+			CMD_GROUP_COMMIT,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.commit_amend))
+	}
+	pub fn commit_new(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"New Commit [{}]",
+				key_config.get_hint(key_config.commit_new),
+			),
+			"commit the staged changes instead of amending",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_author(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Author [{}]",
+				key_config.get_hint(key_config.commit_author),
+			),
+			"set the author of the commit, the committer stays you",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_co_author(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Co-Author [{}]",
+				key_config.get_hint(key_config.commit_co_author),
+			),
+			"add a 'Co-authored-by' trailer for a collaborator",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_author_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Set Author [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"use the author for the following commits",
+			CMD_GROUP_COMMIT,
+		)
+		.hide_help()
+	}
+	pub fn commit_keep_author(
+		key_config: &SharedKeyConfig,
+		enabled: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Keep author {} [{}]",
+				if enabled { "off" } else { "on" },
+				key_config.get_hint(key_config.commit_amend),
+			),
+			"toggle keeping the original author of the amended commit, the author becomes you otherwise",
+			CMD_GROUP_COMMIT,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.commit_amend))
+	}
+	pub fn commit_sign_off(
+		key_config: &SharedKeyConfig,
+		enabled: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Sign-off {} [{}]",
+				if enabled { "off" } else { "on" },
+				key_config.get_hint(key_config.commit_sign_off),
+			),
+			"toggle adding a 'Signed-off-by' trailer to the commit message",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn edit_item(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit [{}]",
+				key_config.get_hint(key_config.edit_file),
+			),
+			"edit the currently selected file in an external editor",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.edit_file))
+	}
+	pub fn stage_item(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Stage [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"stage currently selected file or entire path",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn stage_all(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Stage All [{}]",
+				key_config.get_hint(key_config.status_stage_all),
+			),
+			"stage all changes (in unstaged files)",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_stage_all))
+	}
+	pub fn unstage_item(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Unstage [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"unstage currently selected file or entire path",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn unstage_all(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Unstage all [{}]",
+				key_config.get_hint(key_config.status_stage_all),
+			),
+			"unstage all files (in staged files)",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_stage_all))
+	}
+	pub fn reset_all(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reset all [{}]",
+				key_config.get_hint(key_config.status_reset_all),
+			),
+			"discard all unstaged changes and untracked files",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_reset_all))
+	}
+	pub fn reset_item(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
 				"Reset [{}]",
 				key_config.get_hint(key_config.status_reset_item),
 			),
-			"revert changes in selected file or entire path",
-			CMD_GROUP_CHANGES,
+			"revert changes in selected file or entire path",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_reset_item))
+	}
+	pub fn mark_file(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark [{}]",
+				key_config.get_hint(key_config.status_mark_file),
+			),
+			"mark file to stage, unstage or reset it with the other marked ones",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn ignore_item(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Ignore [{}]",
+				key_config.get_hint(key_config.status_ignore_file),
+			),
+			"add file or folder to the nearest .gitignore",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_ignore_file))
+	}
+
+	pub fn take_ours(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Take ours [{}]",
+				key_config.get_hint(key_config.status_take_ours),
+			),
+			"resolve conflicted file using our version",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_take_ours))
+	}
+	pub fn take_theirs(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Take theirs [{}]",
+				key_config.get_hint(key_config.status_take_theirs),
+			),
+			"resolve conflicted file using their version",
+			CMD_GROUP_CHANGES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.status_take_theirs))
+	}
+	pub fn diff_focus_left(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Back [{}]",
+				key_config.get_hint(key_config.focus_left),
+			),
+			"view and select changed files",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn diff_focus_right(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Diff [{}]",
+				key_config.get_hint(key_config.focus_right),
+			),
+			"inspect file diff",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn quit(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Quit [{}]",
+				key_config.get_hint(key_config.exit),
+			),
+			"quit gitui application",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn confirm_action(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Confirm [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"confirm action",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn stashing_save(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Save [{}]",
+				key_config.get_hint(key_config.stashing_save),
+			),
+			"opens stash name input popup",
+			CMD_GROUP_STASHING,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.stashing_save))
+	}
+	pub fn stashing_toggle_indexed(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Toggle Staged [{}]",
+				key_config.get_hint(key_config.stashing_toggle_index),
+			),
+			"toggle including staged files into stash",
+			CMD_GROUP_STASHING,
+		)
+	}
+	pub fn stashing_toggle_untracked(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Toggle Untracked [{}]",
+				key_config
+					.get_hint(key_config.stashing_toggle_untracked),
+			),
+			"toggle including untracked files into stash",
+			CMD_GROUP_STASHING,
+		)
+	}
+	pub fn stashing_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Stash [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"save files to stash",
+			CMD_GROUP_STASHING,
+		)
+	}
+	pub fn stashlist_apply(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Apply [{}]",
+				key_config.get_hint(key_config.stash_apply),
+			),
+			"apply selected stash",
+			CMD_GROUP_STASHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.stash_apply))
+	}
+	pub fn stashlist_drop(
+		key_config: &SharedKeyConfig,
+		marked: usize,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Drop{} [{}]",
+				if marked == 0 {
+					String::default()
+				} else {
+					format!(" {}", marked)
+				},
+				key_config.get_hint(key_config.stash_drop),
+			),
+			"drop selected stash",
+			CMD_GROUP_STASHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.stash_drop))
+	}
+	pub fn stashlist_pop(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Pop [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"pop selected stash",
+			CMD_GROUP_STASHES,
+		)
+	}
+	pub fn stashlist_inspect(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Inspect [{}]",
+				key_config.get_hint(key_config.focus_right),
+			),
+			"open stash commit details (allows to diff files)",
+			CMD_GROUP_STASHES,
+		)
+	}
+	pub fn log_details_toggle(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Details [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"open details of selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+
+	pub fn commit_details_open(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Inspect [{}]",
+				key_config.get_hint(key_config.focus_right),
+			),
+			"inspect selected commit in detail",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn ignore_item(key_config: &SharedKeyConfig) -> CommandText {
+
+	pub fn blame_file(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Ignore [{}]",
-				key_config.get_hint(key_config.status_ignore_file),
+				"Blame [{}]",
+				key_config.get_hint(key_config.blame),
 			),
-			"Add file or path to .gitignore",
-			CMD_GROUP_CHANGES,
+			"open blame view of selected file",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_tag_commit(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Tag [{}]",
+				key_config.get_hint(key_config.log_tag_commit),
+			),
+			"tag commit",
+			CMD_GROUP_LOG,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.log_tag_commit))
+	}
+	pub fn log_create_branch(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Branch [{}]",
+				key_config.get_hint(key_config.log_create_branch),
+			),
+			"create a branch at the commit without checking it out",
+			CMD_GROUP_LOG,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.log_create_branch))
+	}
+	pub fn log_checkout_commit(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Checkout [{}]",
+				key_config.get_hint(key_config.log_checkout_commit),
+			),
+			"checkout commit detaching HEAD",
+			CMD_GROUP_LOG,
+		)
+		.unbound(KeyConfig::is_unbound(
+			key_config.log_checkout_commit,
+		))
+	}
+	pub fn inspect_file_tree(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Files [{}]",
+				key_config.get_hint(key_config.open_file_tree),
+			),
+			"inspect file tree at specific revision",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn tag_commit_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Tag [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"tag commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn create_branch_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Create Branch [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"create branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.hide_help()
+	}
+	pub fn open_branch_create_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Create [{}]",
+				key_config.get_hint(key_config.create_branch),
+			),
+			"open create branch popup",
+			CMD_GROUP_BRANCHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.create_branch))
+	}
+	pub fn rename_branch_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Rename Branch [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"rename branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.hide_help()
+	}
+	pub fn rename_branch_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Rename Branch [{}]",
+				key_config.get_hint(key_config.rename_branch),
+			),
+			"rename branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.rename_branch))
+	}
+	pub fn set_upstream_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Set Upstream [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"set or unset the upstream",
+			CMD_GROUP_BRANCHES,
+		)
+		.hide_help()
+	}
+	pub fn set_upstream_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Upstream [{}]",
+				key_config.get_hint(key_config.set_upstream),
+			),
+			"set or unset the upstream of the branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.set_upstream))
+	}
+	pub fn delete_branch_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Delete [{}]",
+				key_config.get_hint(key_config.delete_branch),
+			),
+			"delete a branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.delete_branch))
+	}
+	pub fn merge_branch_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Merge [{}]",
+				key_config.get_hint(key_config.merge_branch),
+			),
+			"merge a branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.merge_branch))
+	}
+	pub fn merge_branch_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Merge [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"merge the branch the selected way",
+			CMD_GROUP_BRANCHES,
 		)
 	}
 
-	pub fn diff_focus_left(
+	pub fn branch_popup_rebase(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Back [{}]",
-				key_config.get_hint(key_config.focus_left),
+				"Rebase [{}]",
+				key_config.get_hint(key_config.rebase_branch),
 			),
-			"view and select changed files",
+			"rebase a branch",
+			CMD_GROUP_BRANCHES,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.rebase_branch))
+	}
+
+	pub fn compare_with_head(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Compare [{}]",
+				key_config.get_hint(key_config.compare_commits),
+			),
+			"compare with head",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn compare_branches(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Compare branches [{}]",
+				key_config.get_hint(key_config.compare_branches),
+			),
+			"compare the current branch with the selected one",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn compare_branches_sides(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Set side [{}{}]",
+				key_config.get_hint(key_config.move_left),
+				key_config.get_hint(key_config.move_right),
+			),
+			"compare the selected branch on the left or right side",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn compare_branches_diff(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Diff [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"show the diff between the tips of both sides",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn push_summary_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Push [{}]",
+				key_config.get_hint(key_config.enter),
+			),
+			"push the listed commits",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_focus_right(
+
+	pub fn push_summary_diff(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
@@ -796,395 +2451,410 @@ pub mod commands {
 				"Diff [{}]",
 				key_config.get_hint(key_config.focus_right),
 			),
-			"inspect file diff",
+			"show the combined diff of the listed commits",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn quit(key_config: &SharedKeyConfig) -> CommandText {
+
+	pub fn bisect_open(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Quit [{}]",
-				key_config.get_hint(key_config.exit),
+				"Bisect [{}]",
+				key_config.get_hint(key_config.bisect),
+			),
+			"mark the selected commit good or bad",
+			CMD_GROUP_LOG,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.bisect))
+	}
+
+	pub fn bisect_mark(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Good/Bad [{}{}]",
+				key_config.get_hint(key_config.bisect_good),
+				key_config.get_hint(key_config.bisect_bad),
 			),
-			"quit gitui application",
-			CMD_GROUP_GENERAL,
+			"mark commit and check out the next one to test",
+			CMD_GROUP_LOG,
+		)
+		.unbound(
+			KeyConfig::is_unbound(key_config.bisect_good)
+				&& KeyConfig::is_unbound(key_config.bisect_bad),
 		)
 	}
-	pub fn confirm_action(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+
+	pub fn bisect_reset(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Confirm [{}]",
-				key_config.get_hint(key_config.enter),
+				"Reset [{}]",
+				key_config.get_hint(key_config.bisect_reset),
 			),
-			"confirm action",
-			CMD_GROUP_GENERAL,
+			"end bisect and go back to where it started",
+			CMD_GROUP_LOG,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.bisect_reset))
 	}
-	pub fn stashing_save(
+
+	pub fn compare_commits(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Save [{}]",
-				key_config.get_hint(key_config.stashing_save),
+				"Compare Commits [{}]",
+				key_config.get_hint(key_config.compare_commits),
 			),
-			"opens stash name input popup",
-			CMD_GROUP_STASHING,
+			"compare the oldest and the newest marked commit",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn stashing_toggle_indexed(
+
+	pub fn select_branch_popup(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Toggle Staged [{}]",
-				key_config.get_hint(key_config.stashing_toggle_index),
+				"Checkout [{}]",
+				key_config.get_hint(key_config.enter),
 			),
-			"toggle including staged files into stash",
-			CMD_GROUP_STASHING,
+			"checkout branch",
+			CMD_GROUP_BRANCHES,
 		)
 	}
-	pub fn stashing_toggle_untracked(
+	pub fn toggle_branch_popup(
 		key_config: &SharedKeyConfig,
+		local: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Toggle Untracked [{}]",
-				key_config
-					.get_hint(key_config.stashing_toggle_untracked),
+				"{} [{}]",
+				if local { "Remote" } else { "Local" },
+				key_config.get_hint(key_config.tab_toggle),
 			),
-			"toggle including untracked files into stash",
-			CMD_GROUP_STASHING,
+			"toggle branch type (remote/local)",
+			CMD_GROUP_BRANCHES,
 		)
 	}
-	pub fn stashing_confirm_msg(
+	pub fn open_branch_select_popup(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Stash [{}]",
-				key_config.get_hint(key_config.enter),
+				"Branches [{}]",
+				key_config.get_hint(key_config.select_branch),
 			),
-			"save files to stash",
-			CMD_GROUP_STASHING,
+			"open branch popup",
+			CMD_GROUP_BRANCHES,
 		)
 	}
-	pub fn stashlist_apply(
+
+	pub fn open_tags_popup(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Apply [{}]",
-				key_config.get_hint(key_config.stash_apply),
+				"Tags [{}]",
+				key_config.get_hint(key_config.tags),
 			),
-			"apply selected stash",
-			CMD_GROUP_STASHES,
+			"open tags popup",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn stashlist_drop(
+	pub fn delete_tag_popup(
 		key_config: &SharedKeyConfig,
-		marked: usize,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Drop{} [{}]",
-				if marked == 0 {
-					String::default()
-				} else {
-					format!(" {}", marked)
-				},
-				key_config.get_hint(key_config.stash_drop),
+				"Delete [{}]",
+				key_config.get_hint(key_config.delete_tag),
 			),
-			"drop selected stash",
-			CMD_GROUP_STASHES,
+			"delete a tag",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.delete_tag))
 	}
-	pub fn stashlist_pop(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn checkout_tag(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Pop [{}]",
-				key_config.get_hint(key_config.enter),
+				"Checkout [{}]",
+				key_config.get_hint(key_config.log_checkout_commit),
 			),
-			"pop selected stash",
-			CMD_GROUP_STASHES,
+			"checkout the tagged commit detaching HEAD",
+			CMD_GROUP_LOG,
 		)
+		.unbound(KeyConfig::is_unbound(
+			key_config.log_checkout_commit,
+		))
 	}
-	pub fn stashlist_inspect(
+	pub fn toggle_tag_sort(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Inspect [{}]",
-				key_config.get_hint(key_config.focus_right),
+				"Sort [{}]",
+				key_config.get_hint(key_config.toggle_tag_sort),
 			),
-			"open stash commit details (allows to diff files)",
-			CMD_GROUP_STASHES,
+			"sort tags by date or by version",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn log_details_toggle(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn select_tag(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Details [{}]",
-				key_config.get_hint(key_config.enter),
+				"Select commit [{}]",
+				key_config.get_hint(key_config.select_tag),
 			),
-			"open details of selected commit",
+			"Select commit in revlog",
 			CMD_GROUP_LOG,
 		)
 	}
 
-	pub fn commit_details_open(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn open_remotes(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Inspect [{}]",
-				key_config.get_hint(key_config.focus_right),
+				"Remotes [{}]",
+				key_config.get_hint(key_config.open_remotes),
 			),
-			"inspect selected commit in detail",
+			"add, rename, edit the url of or remove remotes",
 			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn blame_file(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn remote_add(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Blame [{}]",
-				key_config.get_hint(key_config.blame),
+				"Add [{}]",
+				key_config.get_hint(key_config.remote_add),
 			),
-			"open blame view of selected file",
-			CMD_GROUP_LOG,
+			"add a remote",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.remote_add))
 	}
-	pub fn log_tag_commit(
+	pub fn remote_rename(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Tag [{}]",
-				key_config.get_hint(key_config.log_tag_commit),
+				"Rename [{}]",
+				key_config.get_hint(key_config.remote_rename),
 			),
-			"tag commit",
-			CMD_GROUP_LOG,
+			"rename the remote and its remote branches",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.remote_rename))
 	}
-	pub fn inspect_file_tree(
+	pub fn remote_set_url(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Files [{}]",
-				key_config.get_hint(key_config.open_file_tree),
+				"Edit url [{}]",
+				key_config.get_hint(key_config.remote_set_url),
 			),
-			"inspect file tree at specific revision",
-			CMD_GROUP_LOG,
+			"change the url of the remote",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.remote_set_url))
 	}
-	pub fn tag_commit_confirm_msg(
+	pub fn remote_remove(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Tag [{}]",
-				key_config.get_hint(key_config.enter),
+				"Remove [{}]",
+				key_config.get_hint(key_config.remote_remove),
 			),
-			"tag commit",
-			CMD_GROUP_LOG,
+			"remove the remote",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.remote_remove))
 	}
-	pub fn create_branch_confirm_msg(
+	pub fn remote_edit_confirm_msg(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Create Branch [{}]",
-				key_config.get_hint(key_config.enter),
+				"Confirm [{}]",
+				key_config.get_hint(key_config.enter)
 			),
-			"create branch",
-			CMD_GROUP_BRANCHES,
+			"apply the remote change",
+			CMD_GROUP_GENERAL,
 		)
 		.hide_help()
 	}
-	pub fn open_branch_create_popup(
+	pub fn open_worktrees(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Create [{}]",
-				key_config.get_hint(key_config.create_branch),
+				"Worktrees [{}]",
+				key_config.get_hint(key_config.open_worktrees),
 			),
-			"open create branch popup",
-			CMD_GROUP_BRANCHES,
+			"list worktrees to switch between",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn rename_branch_confirm_msg(
+
+	pub fn open_recent_repos(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Rename Branch [{}]",
-				key_config.get_hint(key_config.enter),
+				"Recent [{}]",
+				key_config.get_hint(key_config.open_recent_repos),
 			),
-			"rename branch",
-			CMD_GROUP_BRANCHES,
+			"list recently opened repositories to switch between",
+			CMD_GROUP_GENERAL,
 		)
-		.hide_help()
 	}
-	pub fn rename_branch_popup(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn refresh(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Rename Branch [{}]",
-				key_config.get_hint(key_config.rename_branch),
+				"Refresh [{}]",
+				key_config.get_hint(key_config.refresh),
 			),
-			"rename branch",
-			CMD_GROUP_BRANCHES,
+			"refresh status and log without waiting for the next tick",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn delete_branch_popup(
+
+	pub fn select_co_author(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Delete [{}]",
-				key_config.get_hint(key_config.delete_branch),
+				"Add [{}]",
+				key_config.get_hint(key_config.enter),
 			),
-			"delete a branch",
-			CMD_GROUP_BRANCHES,
+			"credit the selected collaborator in the commit message",
+			CMD_GROUP_COMMIT,
 		)
 	}
-	pub fn merge_branch_popup(
+	pub fn select_recent_repo(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Merge [{}]",
-				key_config.get_hint(key_config.merge_branch),
+				"Switch [{}]",
+				key_config.get_hint(key_config.enter),
 			),
-			"merge a branch",
-			CMD_GROUP_BRANCHES,
+			"switch to selected repository",
+			CMD_GROUP_GENERAL,
 		)
 	}
 
-	pub fn branch_popup_rebase(
+	pub fn remove_recent_repo(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Rebase [{}]",
-				key_config.get_hint(key_config.rebase_branch),
+				"Remove [{}]",
+				key_config.get_hint(key_config.remove_recent_repo),
 			),
-			"rebase a branch",
-			CMD_GROUP_BRANCHES,
+			"remove selected repository from the list",
+			CMD_GROUP_GENERAL,
 		)
 	}
 
-	pub fn compare_with_head(
+	pub fn open_create_worktree(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Compare [{}]",
-				key_config.get_hint(key_config.compare_commits),
+				"Worktree [{}]",
+				key_config.get_hint(key_config.create_worktree),
 			),
-			"compare with head",
+			"create a worktree for a branch",
 			CMD_GROUP_BRANCHES,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.create_worktree))
 	}
 
-	pub fn compare_commits(
+	pub fn create_worktree_confirm_msg(
 		key_config: &SharedKeyConfig,
+		create: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Compare Commits [{}]",
-				key_config.get_hint(key_config.compare_commits),
+				"{} [{}]",
+				if create { "Create" } else { "Next" },
+				key_config.get_hint(key_config.enter),
 			),
-			"compare two marked commits",
-			CMD_GROUP_LOG,
+			"create worktree",
+			CMD_GROUP_BRANCHES,
 		)
+		.hide_help()
 	}
 
-	pub fn select_branch_popup(
+	pub fn open_submodules(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Checkout [{}]",
-				key_config.get_hint(key_config.enter),
+				"Submodules [{}]",
+				key_config.get_hint(key_config.open_submodules),
 			),
-			"checkout branch",
-			CMD_GROUP_BRANCHES,
+			"list submodules to init or update",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn toggle_branch_popup(
+
+	pub fn submodule_update(
 		key_config: &SharedKeyConfig,
-		local: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"{} [{}]",
-				if local { "Remote" } else { "Local" },
-				key_config.get_hint(key_config.tab_toggle),
+				"Update [{}]",
+				key_config.get_hint(key_config.submodule_update),
 			),
-			"toggle branch type (remote/local)",
-			CMD_GROUP_BRANCHES,
+			"init and update selected submodule",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.submodule_update))
 	}
-	pub fn open_branch_select_popup(
+
+	pub fn submodule_update_all(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Branches [{}]",
-				key_config.get_hint(key_config.select_branch),
+				"Update all [{}]",
+				key_config.get_hint(key_config.submodule_update_all),
 			),
-			"open branch popup",
-			CMD_GROUP_BRANCHES,
+			"init and update all submodules",
+			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(
+			key_config.submodule_update_all,
+		))
 	}
 
-	pub fn open_tags_popup(
+	pub fn select_worktree(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Tags [{}]",
-				key_config.get_hint(key_config.tags),
+				"Switch [{}]",
+				key_config.get_hint(key_config.enter),
 			),
-			"open tags popup",
+			"switch to selected worktree",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn delete_tag_popup(
+
+	pub fn status_create_branch_here(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Delete [{}]",
-				key_config.get_hint(key_config.delete_tag),
+				"Create Branch [{}]",
+				key_config.get_hint(key_config.push),
 			),
-			"delete a tag",
+			"HEAD is detached: create a branch at it to push or pull",
 			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.push))
 	}
-	pub fn select_tag(key_config: &SharedKeyConfig) -> CommandText {
-		CommandText::new(
-			format!(
-				"Select commit [{}]",
-				key_config.get_hint(key_config.select_tag),
-			),
-			"Select commit in revlog",
-			CMD_GROUP_LOG,
-		)
-	}
-
 	pub fn status_push(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -1194,6 +2864,7 @@ pub mod commands {
 			"push to origin",
 			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.push))
 	}
 	pub fn status_force_push(
 		key_config: &SharedKeyConfig,
@@ -1206,6 +2877,20 @@ pub mod commands {
 			"force push to origin",
 			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.force_push))
+	}
+	pub fn status_force_push_lease(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Force Push Lease [{}]",
+				key_config.get_hint(key_config.force_push_lease),
+			),
+			"force push to origin unless it changed since the last fetch",
+			CMD_GROUP_GENERAL,
+		)
+		.unbound(KeyConfig::is_unbound(key_config.force_push_lease))
 	}
 	pub fn status_pull(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
@@ -1216,5 +2901,6 @@ pub mod commands {
 			"fetch/merge",
 			CMD_GROUP_GENERAL,
 		)
+		.unbound(KeyConfig::is_unbound(key_config.pull))
 	}
 }
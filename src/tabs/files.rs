@@ -10,6 +10,7 @@ use crate::{
 	components::{
 		visibility_blocking, CommandBlocking, CommandInfo, Component,
 		DrawableComponent, EventState, RevisionFilesComponent,
+		SharedOptions,
 	},
 	keys::SharedKeyConfig,
 	queue::Queue,
@@ -34,6 +35,7 @@ impl FilesTab {
 		queue: &Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			visible: false,
@@ -42,6 +44,7 @@ impl FilesTab {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options,
 			),
 			theme,
 			key_config,
@@ -74,6 +77,11 @@ impl FilesTab {
 	pub fn file_finder_update(&mut self, file: &Option<PathBuf>) {
 		self.files.find_file(file);
 	}
+
+	///
+	pub fn goto_line(&self, line: usize) {
+		self.files.goto_line(line);
+	}
 }
 
 impl DrawableComponent for FilesTab {
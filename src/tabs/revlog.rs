@@ -1,17 +1,20 @@
 use crate::{
 	components::{
-		visibility_blocking, CommandBlocking, CommandInfo,
-		CommitDetailsComponent, CommitList, Component,
-		DrawableComponent, EventState,
+		checkout_commit, parse_date_range, visibility_blocking,
+		CommandBlocking, CommandInfo, CommitDetailsComponent,
+		CommitList, Component, DrawableComponent, EventState,
+		LogFilterKind, SharedOptions,
 	},
 	keys::SharedKeyConfig,
-	queue::{InternalEvent, Queue},
-	strings,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
 use asyncgit::{
+	asyncjob::AsyncMultiJob,
 	cached,
+	commit_signatures::AsyncSignaturesJob,
 	sync::{self, CommitId},
 	AsyncGitNotification, AsyncLog, AsyncTags, CommitFilesParams,
 	FetchStatus, CWD,
@@ -27,6 +30,11 @@ use tui::{
 };
 
 const SLICE_SIZE: usize = 1200;
+/// after jumping past the loaded commits (e.g. `End`) only the ones
+/// around the selection are loaded, scrolling on loads the rest
+const JUMP_SLICE_SIZE: usize = 400;
+/// signatures verified side by side, each runs `gpg`
+const SIGNATURE_JOBS: usize = 4;
 
 ///
 pub struct Revlog {
@@ -34,9 +42,15 @@ pub struct Revlog {
 	list: CommitList,
 	git_log: AsyncLog,
 	git_tags: AsyncTags,
+	git_signatures: AsyncMultiJob<AsyncSignaturesJob>,
 	queue: Queue,
 	visible: bool,
 	branch_name: cached::BranchName,
+	/// false while `HEAD` is unborn
+	has_head: bool,
+	author_filter: Option<String>,
+	path_filter: Option<String>,
+	date_filter: Option<(String, Option<i64>, Option<i64>)>,
 	key_config: SharedKeyConfig,
 }
 
@@ -47,6 +61,7 @@ impl Revlog {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
@@ -60,11 +75,20 @@ impl Revlog {
 				&strings::log_title(&key_config),
 				theme,
 				key_config.clone(),
+				options,
 			),
 			git_log: AsyncLog::new(sender, None),
 			git_tags: AsyncTags::new(sender),
+			git_signatures: AsyncMultiJob::new(
+				sender.clone(),
+				SIGNATURE_JOBS,
+			),
 			visible: false,
 			branch_name: cached::BranchName::new(CWD),
+			has_head: false,
+			author_filter: None,
+			path_filter: None,
+			date_filter: None,
 			key_config,
 		}
 	}
@@ -73,6 +97,7 @@ impl Revlog {
 	pub fn any_work_pending(&self) -> bool {
 		self.git_log.is_pending()
 			|| self.git_tags.is_pending()
+			|| self.git_signatures.is_pending()
 			|| self.commit_details.any_work_pending()
 	}
 
@@ -82,7 +107,12 @@ impl Revlog {
 			let log_changed =
 				self.git_log.fetch()? == FetchStatus::Started;
 
-			self.list.set_count_total(self.git_log.count()?);
+			self.list.set_count_total(self.git_log.count());
+			self.has_head = sync::get_head(CWD).is_ok();
+
+			// a resize drops the loaded commits, refetched below
+			let width = self.list.current_size().0.into();
+			self.list.items().set_msg_width(width);
 
 			let selection = self.list.selection();
 			let selection_max = self.list.selection_max();
@@ -92,8 +122,17 @@ impl Revlog {
 				self.fetch_commits()?;
 			}
 
+			if log_changed {
+				// a fetch may have deepened a shallow clone
+				self.list.set_shallow_boundaries(
+					sync::shallow_boundaries(CWD)?,
+				);
+			}
+
 			self.git_tags.request(Duration::from_secs(3), false)?;
 
+			self.verify_visible_signatures();
+
 			self.list.set_branch(
 				self.branch_name.lookup().map(Some).unwrap_or(None),
 			);
@@ -121,12 +160,28 @@ impl Revlog {
 			match ev {
 				AsyncGitNotification::CommitFiles
 				| AsyncGitNotification::Log => self.update()?,
+				AsyncGitNotification::CommitDiffStats => {
+					self.commit_details.update_git(ev);
+				}
 				AsyncGitNotification::Tags => {
 					if let Some(tags) = self.git_tags.last()? {
 						self.list.set_tags(tags);
 						self.update()?;
 					}
 				}
+				AsyncGitNotification::Signatures => {
+					let signatures = self
+						.git_signatures
+						.take_finished()
+						.iter()
+						.filter_map(AsyncSignaturesJob::result)
+						.collect::<Vec<_>>();
+
+					if !signatures.is_empty() {
+						self.list.set_signatures(signatures);
+						self.update()?;
+					}
+				}
 				_ => (),
 			}
 		}
@@ -134,29 +189,160 @@ impl Revlog {
 		Ok(())
 	}
 
-	fn fetch_commits(&mut self) -> Result<()> {
-		let want_min =
-			self.list.selection().saturating_sub(SLICE_SIZE / 2);
+	/// only show commits matching `value` for the filter `kind`,
+	/// an empty `value` removes that filter
+	pub fn set_filter(
+		&mut self,
+		kind: LogFilterKind,
+		value: String,
+	) -> Result<()> {
+		let value = if value.is_empty() { None } else { Some(value) };
+
+		match kind {
+			LogFilterKind::Author => self.author_filter = value,
+			LogFilterKind::Path => self.path_filter = value,
+			LogFilterKind::Date => {
+				if let Some(value) = value {
+					if let Some((since, until)) =
+						parse_date_range(&value)
+					{
+						self.date_filter =
+							Some((value, since, until));
+					} else {
+						self.queue.push(InternalEvent::ShowErrorMsg(
+							strings::log_filter_date_invalid(&value),
+						));
+						return Ok(());
+					}
+				} else {
+					self.date_filter = None;
+				}
+			}
+		}
 
-		let commits = sync::get_commits_info(
-			CWD,
-			&self.git_log.get_slice(want_min, SLICE_SIZE)?,
-			self.list.current_size().0.into(),
+		self.apply_filters()
+	}
+
+	/// forgets filters and marks, they belong to the repo switched
+	/// away from
+	pub fn reset_repo_state(&mut self) -> Result<()> {
+		self.author_filter = None;
+		self.path_filter = None;
+		self.date_filter = None;
+		self.list.clear_marked();
+		self.branch_name = cached::BranchName::new(CWD);
+
+		self.apply_filters()
+	}
+
+	fn apply_filters(&mut self) -> Result<()> {
+		let filters = self
+			.author_filter
+			.as_deref()
+			.map(sync::filter_by_author)
+			.into_iter()
+			.chain(
+				self.path_filter.as_deref().map(sync::filter_by_path),
+			)
+			.chain(self.date_filter.as_ref().map(
+				|(_, since, until)| {
+					sync::filter_by_time(*since, *until)
+				},
+			))
+			.collect();
+		self.git_log.set_filter(sync::filter_all(filters));
+
+		let title = strings::log_title_filtered(
+			&self.key_config,
+			self.author_filter.as_deref(),
+			self.path_filter.as_deref(),
+			self.date_filter.as_ref().map(|(date, ..)| date.as_str()),
 		);
+		self.list.set_title(&title);
+		self.list.clear();
+		self.list.select_entry(0);
+
+		self.update()
+	}
 
-		if let Ok(commits) = commits {
-			self.list.items().set_items(want_min, commits);
+	/// only the commits not loaded yet are looked up
+	fn fetch_commits(&mut self) -> Result<()> {
+		let selection = self.list.selection();
+		let width = self.list.current_size().0.into();
+
+		let slice_size = if self.list.items().contains(selection) {
+			SLICE_SIZE
+		} else {
+			JUMP_SLICE_SIZE
+		};
+		let want_min = selection.saturating_sub(slice_size / 2);
+
+		let ids = self.git_log.get_slice(want_min, slice_size)?;
+		let missing = self.list.items().missing(&ids);
+
+		if let Ok(commits) =
+			sync::get_commits_info(CWD, &missing, width)
+		{
+			self.list.items().update_items(want_min, &ids, commits);
 		}
 
 		Ok(())
 	}
 
+	fn verify_visible_signatures(&mut self) {
+		if self.git_signatures.is_pending() {
+			return;
+		}
+
+		for id in self.list.visible_unverified() {
+			self.git_signatures.spawn(AsyncSignaturesJob::new(id));
+		}
+	}
+
 	fn selected_commit(&self) -> Option<CommitId> {
 		self.list.selected_entry().map(|e| e.id)
 	}
 
-	fn copy_commit_hash(&self) -> Result<()> {
-		self.list.copy_entry_hash()?;
+	fn copy_commit_hash(&self, full: bool) {
+		if let Some(hash) = self.list.selected_entry_hash(full) {
+			if let Err(e) = crate::clipboard::copy_string(&hash) {
+				// keep the hash reachable without a clipboard
+				log::error!(
+					"copy to clipboard error: {} ({})",
+					e,
+					hash
+				);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					strings::copy_hash_failed(&hash),
+				));
+			}
+		}
+	}
+
+	/// sorted by their position in the log, oldest first
+	fn marked_oldest_first(&self) -> Result<Vec<CommitId>> {
+		Ok(self.git_log.oldest_first(self.list.marked())?)
+	}
+
+	/// a conflicting commit stays pending for the status tab, the
+	/// ones marked after it are not picked
+	fn cherry_pick_marked(&mut self) -> Result<()> {
+		let ids = self.marked_oldest_first()?;
+		let picked = sync::cherry_pick_commits(CWD, &ids)?;
+
+		self.list.clear_marked();
+
+		if picked.len() < ids.len() {
+			self.queue.push(InternalEvent::ShowErrorMsg(
+				strings::cherry_pick_conflict(
+					picked.len(),
+					ids.len(),
+				),
+			));
+		}
+
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
 		Ok(())
 	}
 
@@ -225,12 +411,83 @@ impl Component for Revlog {
 					self.commit_details.toggle_visible()?;
 					self.update()?;
 					return Ok(EventState::Consumed);
+				} else if k == self.key_config.commit_goto_parent
+					&& self.commit_details.is_visible()
+				{
+					return self
+						.commit_details
+						.selected_parent()
+						.map_or(Ok(EventState::NotConsumed), |id| {
+							self.queue.push(
+								InternalEvent::SelectCommitInRevlog(
+									id,
+								),
+							);
+							Ok(EventState::Consumed)
+						});
+				} else if k == self.key_config.commit_next_parent
+					&& self.commit_details.is_visible()
+				{
+					return Ok(self
+						.commit_details
+						.select_next_parent()?
+						.into());
 				} else if k == self.key_config.copy {
-					self.copy_commit_hash()?;
+					self.copy_commit_hash(false);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.copy_full_hash {
+					self.copy_commit_hash(true);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.open_in_browser {
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::OpenCommitInBrowser(
+									id,
+								),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
+				} else if k == self.key_config.log_branches_containing
+				{
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::BranchesContaining(id),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
+				} else if k == self.key_config.reword_head
+					&& self.has_head
+				{
+					self.queue.push(InternalEvent::RewordHead);
 					return Ok(EventState::Consumed);
 				} else if k == self.key_config.push {
 					self.queue.push(InternalEvent::PushTags);
 					return Ok(EventState::Consumed);
+				} else if k == self.key_config.log_checkout_commit {
+					if let Some(id) = self.selected_commit() {
+						try_or_popup!(
+							self,
+							"checkout error:",
+							checkout_commit(&self.queue, id)
+						);
+					}
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.log_create_branch {
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::CreateBranch(Some(id)),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
 				} else if k == self.key_config.log_tag_commit {
 					return self.selected_commit().map_or(
 						Ok(EventState::NotConsumed),
@@ -270,9 +527,41 @@ impl Component for Revlog {
 							Ok(EventState::Consumed)
 						},
 					);
+				} else if k == self.key_config.bisect {
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue
+								.push(InternalEvent::OpenBisect(id));
+							Ok(EventState::Consumed)
+						},
+					);
 				} else if k == self.key_config.tags {
 					self.queue.push(InternalEvent::Tags);
 					return Ok(EventState::Consumed);
+				} else if k == self.key_config.log_filter_author {
+					self.queue.push(InternalEvent::OpenLogFilter(
+						LogFilterKind::Author,
+						self.author_filter
+							.clone()
+							.unwrap_or_default(),
+					));
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.log_filter_path {
+					self.queue.push(InternalEvent::OpenLogFilter(
+						LogFilterKind::Path,
+						self.path_filter.clone().unwrap_or_default(),
+					));
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.log_filter_date {
+					self.queue.push(InternalEvent::OpenLogFilter(
+						LogFilterKind::Date,
+						self.date_filter
+							.as_ref()
+							.map(|(date, ..)| date.clone())
+							.unwrap_or_default(),
+					));
+					return Ok(EventState::Consumed);
 				} else if k == self.key_config.compare_commits
 					&& self.list.marked_count() > 0
 				{
@@ -285,17 +574,30 @@ impl Component for Revlog {
 							),
 						);
 						return Ok(EventState::Consumed);
-					} else if self.list.marked_count() == 2 {
-						//compare two marked commits
-						let marked = self.list.marked();
+					}
+
+					// compare the outermost marked commits
+					let marked = self.marked_oldest_first()?;
+					if let (Some(oldest), Some(newest)) =
+						(marked.first(), marked.last())
+					{
 						self.queue.push(
 							InternalEvent::CompareCommits(
-								marked[0],
-								Some(marked[1]),
+								*newest,
+								Some(*oldest),
 							),
 						);
-						return Ok(EventState::Consumed);
 					}
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.log_cherry_pick
+					&& self.list.marked_count() > 0
+				{
+					try_or_popup!(
+						self,
+						"cherry-pick error:",
+						self.cherry_pick_marked()
+					);
+					return Ok(EventState::Consumed);
 				}
 			}
 		}
@@ -325,6 +627,22 @@ impl Component for Revlog {
 				|| force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::commit_goto_parent(&self.key_config),
+			self.commit_details.parent_count() > 0,
+			(self.visible && self.commit_details.is_visible())
+				|| force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::commit_next_parent(&self.key_config),
+			true,
+			(self.visible
+				&& self.commit_details.is_visible()
+				&& self.commit_details.parent_count() > 1)
+				|| force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::open_branch_select_popup(
 				&self.key_config,
@@ -343,7 +661,17 @@ impl Component for Revlog {
 		out.push(CommandInfo::new(
 			strings::commands::compare_commits(&self.key_config),
 			true,
-			(self.visible && self.list.marked_count() == 2)
+			(self.visible && self.list.marked_count() >= 2)
+				|| force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::log_cherry_pick(
+				&self.key_config,
+				self.list.marked_count(),
+			),
+			true,
+			(self.visible && self.list.marked_count() > 0)
 				|| force_all,
 		));
 
@@ -353,18 +681,72 @@ impl Component for Revlog {
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::copy_full_hash(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::open_in_browser(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::branches_containing(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::reword_head(&self.key_config),
+			self.has_head,
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::log_tag_commit(&self.key_config),
 			self.selected_commit().is_some(),
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::log_create_branch(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::log_checkout_commit(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::open_tags_popup(&self.key_config),
 			true,
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::log_filter_author(&self.key_config),
+			true,
+			self.visible || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::log_filter_path(&self.key_config),
+			true,
+			self.visible || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::log_filter_date(&self.key_config),
+			true,
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::push_tags(&self.key_config),
 			true,
@@ -377,6 +759,12 @@ impl Component for Revlog {
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::bisect_open(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
 		visibility_blocking(self)
 	}
 
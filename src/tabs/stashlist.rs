@@ -2,6 +2,7 @@ use crate::{
 	components::{
 		visibility_blocking, CommandBlocking, CommandInfo,
 		CommitList, Component, DrawableComponent, EventState,
+		SharedOptions,
 	},
 	keys::SharedKeyConfig,
 	queue::{Action, InternalEvent, Queue},
@@ -20,6 +21,7 @@ pub struct StashList {
 	visible: bool,
 	queue: Queue,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl StashList {
@@ -28,6 +30,7 @@ impl StashList {
 		queue: &Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			visible: false,
@@ -35,9 +38,11 @@ impl StashList {
 				&strings::stashlist_title(&key_config),
 				theme,
 				key_config.clone(),
+				options.clone(),
 			),
 			queue: queue.clone(),
 			key_config,
+			options,
 		}
 	}
 
@@ -146,7 +151,7 @@ impl Component for StashList {
 				self.list.selected_entry().is_some();
 			out.push(CommandInfo::new(
 				strings::commands::stashlist_pop(&self.key_config),
-				selection_valid,
+				selection_valid && !self.options.borrow().read_only,
 				true,
 			));
 			out.push(CommandInfo::new(
@@ -184,7 +189,9 @@ impl Component for StashList {
 			}
 
 			if let Event::Key(k) = ev {
-				if k == self.key_config.enter {
+				if k == self.key_config.enter
+					&& !self.options.borrow().read_only
+				{
 					self.pop_stash();
 				} else if k == self.key_config.stash_apply {
 					self.apply_stash();
@@ -1,10 +1,10 @@
 use crate::{
 	accessors,
 	components::{
-		command_pump, event_pump, visibility_blocking,
-		ChangesComponent, CommandBlocking, CommandInfo, Component,
-		DiffComponent, DrawableComponent, EventState,
-		FileTreeItemKind, SharedOptions,
+		branch_label, command_pump, event_pump, time_ago_string,
+		visibility_blocking, ChangesComponent, CommandBlocking,
+		CommandInfo, Component, DiffComponent, DrawableComponent,
+		EventState, FileTreeItemKind, SharedOptions,
 	},
 	keys::SharedKeyConfig,
 	queue::{Action, InternalEvent, NeedsUpdate, Queue, ResetItem},
@@ -13,17 +13,23 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
+	asyncjob::AsyncSingleJob,
 	cached,
+	diff_stats::AsyncDiffStatsJob,
 	sync::BranchCompare,
-	sync::{self, status::StatusType, RepoState},
+	sync::{
+		self, status::StatusType, CommitId, DiffStats, RepoState,
+	},
 	AsyncDiff, AsyncGitNotification, AsyncStatus, DiffParams,
-	DiffType, StatusParams, CWD,
+	DiffType, StatusItem, StatusItemType, StatusParams, CWD,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
+use easy_cast::Cast;
 use itertools::Itertools;
 use std::convert::Into;
 use std::convert::TryFrom;
+use std::time::SystemTime;
 use tui::{
 	layout::{Alignment, Constraint, Direction, Layout},
 	style::{Color, Style},
@@ -67,7 +73,15 @@ pub struct Status {
 	git_status_workdir: AsyncStatus,
 	git_status_stage: AsyncStatus,
 	git_branch_state: Option<BranchCompare>,
+	git_last_fetch: Option<SystemTime>,
+	git_diff_stats: Option<DiffStats>,
+	git_diff_stats_job: AsyncSingleJob<AsyncDiffStatsJob>,
 	git_branch_name: cached::BranchName,
+	/// `None` while `HEAD` is unborn
+	git_head: Option<CommitId>,
+	git_orig_head: Option<CommitId>,
+	/// fuzzy filter applied to both file trees
+	filter: Option<String>,
 	queue: Queue,
 	git_action_executed: bool,
 	options: SharedOptions,
@@ -80,46 +94,62 @@ impl DrawableComponent for Status {
 		f: &mut tui::Frame<B>,
 		rect: tui::layout::Rect,
 	) -> Result<()> {
+		let layout = self.options.borrow().status_layout;
+
+		let split = if self.focus == Focus::Diff {
+			// give the diff more room while it is focused
+			layout.split() * 3 / 5
+		} else {
+			layout.split()
+		};
+
 		let chunks = Layout::default()
 			.direction(Direction::Horizontal)
 			.constraints(
-				if self.focus == Focus::Diff {
-					[
-						Constraint::Percentage(30),
-						Constraint::Percentage(70),
-					]
-				} else {
-					[
-						Constraint::Percentage(50),
-						Constraint::Percentage(50),
-					]
-				}
+				[
+					Constraint::Percentage(split),
+					Constraint::Percentage(100 - split),
+				]
 				.as_ref(),
 			)
 			.split(rect);
 
+		let (workdir_size, stage_size) =
+			if self.diff_target == DiffTarget::WorkingDir {
+				(60, 40)
+			} else {
+				(40, 60)
+			};
+
 		let left_chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				if self.diff_target == DiffTarget::WorkingDir {
+				if layout.stage_on_top {
 					[
-						Constraint::Percentage(60),
-						Constraint::Percentage(40),
+						Constraint::Percentage(stage_size),
+						Constraint::Percentage(workdir_size),
 					]
 				} else {
 					[
-						Constraint::Percentage(40),
-						Constraint::Percentage(60),
+						Constraint::Percentage(workdir_size),
+						Constraint::Percentage(stage_size),
 					]
 				}
 				.as_ref(),
 			)
 			.split(chunks[0]);
 
-		self.index_wd.draw(f, left_chunks[0])?;
-		self.index.draw(f, left_chunks[1])?;
+		let (workdir_rect, stage_rect) = if layout.stage_on_top {
+			(left_chunks[1], left_chunks[0])
+		} else {
+			(left_chunks[0], left_chunks[1])
+		};
+
+		self.index_wd.draw(f, workdir_rect)?;
+		self.index.draw(f, stage_rect)?;
 		self.diff.draw(f, chunks[1])?;
-		self.draw_branch_state(f, &left_chunks);
+		self.draw_branch_state(f, workdir_rect, stage_rect);
+		self.draw_diff_stats(f, stage_rect);
 		Self::draw_repo_state(f, left_chunks[0])?;
 
 		Ok(())
@@ -149,6 +179,7 @@ impl Status {
 				queue.clone(),
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			index: ChangesComponent::new(
 				&strings::title_index(&key_config),
@@ -157,11 +188,13 @@ impl Status {
 				queue.clone(),
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			diff: DiffComponent::new(
 				queue.clone(),
 				theme,
 				key_config.clone(),
+				options.clone(),
 				false,
 			),
 			git_diff: AsyncDiff::new(sender),
@@ -169,7 +202,13 @@ impl Status {
 			git_status_stage: AsyncStatus::new(sender.clone()),
 			git_action_executed: false,
 			git_branch_state: None,
+			git_last_fetch: None,
+			git_diff_stats: None,
+			git_diff_stats_job: AsyncSingleJob::new(sender.clone()),
 			git_branch_name: cached::BranchName::new(CWD),
+			git_head: None,
+			git_orig_head: None,
+			filter: None,
 			key_config,
 			options,
 		}
@@ -178,9 +217,11 @@ impl Status {
 	fn draw_branch_state<B: tui::backend::Backend>(
 		&self,
 		f: &mut tui::Frame<B>,
-		chunks: &[tui::layout::Rect],
+		workdir_rect: tui::layout::Rect,
+		stage_rect: tui::layout::Rect,
 	) {
-		if let Some(branch_name) = self.git_branch_name.last() {
+		if let Some(branch_name) = branch_label(&self.git_branch_name)
+		{
 			let ahead_behind = self
 				.git_branch_state
 				.as_ref()
@@ -191,18 +232,28 @@ impl Status {
 					)
 				});
 
+			let last_fetch = self.git_last_fetch.map_or_else(
+				String::new,
+				|time| {
+					format!(
+						"({}) ",
+						strings::last_fetch(&time_ago_string(time))
+					)
+				},
+			);
+
 			let w = Paragraph::new(format!(
-				"{}{{{}}}",
-				ahead_behind, branch_name
+				"{}{}{{{}}}",
+				ahead_behind, last_fetch, branch_name
 			))
 			.alignment(Alignment::Right);
 
 			let mut rect = if self.index_wd.focused() {
-				let mut rect = chunks[0];
+				let mut rect = workdir_rect;
 				rect.y += rect.height.saturating_sub(1);
 				rect
 			} else {
-				chunks[1]
+				stage_rect
 			};
 
 			rect.x += 1;
@@ -215,6 +266,27 @@ impl Status {
 		}
 	}
 
+	fn draw_diff_stats<B: tui::backend::Backend>(
+		&self,
+		f: &mut tui::Frame<B>,
+		stage_rect: tui::layout::Rect,
+	) {
+		if let Some(stats) =
+			self.git_diff_stats.filter(|s| s.files_changed > 0)
+		{
+			let msg = strings::diff_stats(&stats);
+			let msg_length: u16 = msg.len().cast();
+
+			let mut rect = stage_rect;
+			rect.y += rect.height.saturating_sub(1);
+			rect.height = rect.height.min(1);
+			rect.x += 1;
+			rect.width = msg_length.min(rect.width.saturating_sub(2));
+
+			f.render_widget(Paragraph::new(msg), rect);
+		}
+	}
+
 	fn draw_repo_state<B: tui::backend::Backend>(
 		f: &mut tui::Frame<B>,
 		r: tui::layout::Rect,
@@ -222,16 +294,12 @@ impl Status {
 		if let Ok(state) = sync::repo_state(CWD) {
 			if state != RepoState::Clean {
 				let ids =
-					sync::mergehead_ids(CWD).unwrap_or_default();
-				let ids = format!(
-					"({})",
-					ids.iter()
-						.map(|id| sync::CommitId::get_short_string(
-							id
-						))
-						.join(",")
-				);
-				let txt = format!("{:?} {}", state, ids);
+					sync::pending_op_ids(CWD).unwrap_or_default();
+				let ids = ids
+					.iter()
+					.map(sync::CommitId::get_short_string)
+					.join(",");
+				let txt = strings::pending_op(state, &ids);
 				let txt_len = u16::try_from(txt.len())?;
 				let w = Paragraph::new(txt)
 					.style(Style::default().fg(Color::Red))
@@ -265,6 +333,23 @@ impl Status {
 		self.focus == Focus::Diff
 	}
 
+	/// the upper and the lower list in the current layout
+	fn stacked_lists(&self) -> (Focus, Focus) {
+		if self.options.borrow().status_layout.stage_on_top {
+			(Focus::Stage, Focus::WorkDir)
+		} else {
+			(Focus::WorkDir, Focus::Stage)
+		}
+	}
+
+	fn is_list_empty(&self, f: Focus) -> bool {
+		match f {
+			Focus::WorkDir => self.index_wd.is_empty(),
+			Focus::Stage => self.index.is_empty(),
+			Focus::Diff => true,
+		}
+	}
+
 	fn switch_focus(&mut self, f: Focus) -> Result<bool> {
 		if self.focus != f {
 			self.focus = f;
@@ -316,24 +401,45 @@ impl Status {
 		None
 	}
 
+	fn is_conflict_selected(&self) -> bool {
+		let idx = match self.diff_target {
+			DiffTarget::Stage => &self.index,
+			DiffTarget::WorkingDir => &self.index_wd,
+		};
+
+		idx.selection().map_or(false, |item| {
+			matches!(
+				item.kind,
+				FileTreeItemKind::File(StatusItem {
+					status: StatusItemType::Conflicted,
+					..
+				})
+			)
+		})
+	}
+
 	///
 	pub fn update(&mut self) -> Result<()> {
 		self.git_branch_name.lookup().map(Some).unwrap_or(None);
 
 		if self.is_visible() {
 			let config = self.options.borrow().status_show_untracked;
+			let threshold =
+				self.options.borrow().large_file_threshold();
 
 			self.git_diff.refresh()?;
-			self.git_status_workdir.fetch(&StatusParams::new(
-				StatusType::WorkingDir,
-				config,
-			))?;
-			self.git_status_stage.fetch(&StatusParams::new(
-				StatusType::Stage,
-				config,
-			))?;
+			// both scans are independent jobs running in parallel
+			self.git_status_workdir.fetch(
+				&StatusParams::new(StatusType::WorkingDir, config)
+					.with_size_hints(threshold),
+			)?;
+			self.git_status_stage.fetch(
+				&StatusParams::new(StatusType::Stage, config)
+					.with_size_hints(threshold),
+			)?;
 
 			self.branch_compare();
+			self.update_heads();
 		}
 
 		Ok(())
@@ -344,6 +450,7 @@ impl Status {
 		self.git_diff.is_pending()
 			|| self.git_status_stage.is_pending()
 			|| self.git_status_workdir.is_pending()
+			|| self.git_diff_stats_job.is_pending()
 	}
 
 	///
@@ -354,6 +461,15 @@ impl Status {
 		match ev {
 			AsyncGitNotification::Diff => self.update_diff()?,
 			AsyncGitNotification::Status => self.update_status()?,
+			AsyncGitNotification::DiffStats => {
+				if let Some(stats) = self
+					.git_diff_stats_job
+					.take_last()
+					.and_then(|job| job.result())
+				{
+					self.git_diff_stats = stats.ok();
+				}
+			}
 			AsyncGitNotification::Push
 			| AsyncGitNotification::Fetch
 			| AsyncGitNotification::CommitFiles => {
@@ -367,10 +483,23 @@ impl Status {
 
 	fn update_status(&mut self) -> Result<()> {
 		let stage_status = self.git_status_stage.last()?;
-		self.index.set_items(&stage_status.items)?;
+		self.index.set_items(
+			&stage_status.items,
+			stage_status.size_hints,
+		)?;
 
 		let workdir_status = self.git_status_workdir.last()?;
-		self.index_wd.set_items(&workdir_status.items)?;
+		self.index_wd.set_items(
+			&workdir_status.items,
+			workdir_status.size_hints,
+		)?;
+		self.index.set_unresolved_conflicts(
+			self.index_wd.has_unresolved_conflicts(),
+		);
+
+		self.git_diff_stats_job.spawn(AsyncDiffStatsJob::new(
+			self.options.borrow().status_show_untracked,
+		));
 
 		self.update_diff()?;
 
@@ -395,7 +524,9 @@ impl Status {
 	///
 	pub fn update_diff(&mut self) -> Result<()> {
 		if let Some((path, is_stage)) = self.selected_path() {
-			let diff_type = if is_stage {
+			let diff_type = if self.is_conflict_selected() {
+				DiffType::Conflict
+			} else if is_stage {
 				DiffType::Stage
 			} else {
 				DiffType::WorkDir
@@ -471,12 +602,17 @@ impl Status {
 	}
 
 	fn push(&self, force: bool) {
-		if self.can_push() {
+		if self.is_detached() {
+			self.queue.push(InternalEvent::CreateBranch(None));
+		} else if self.can_push() {
 			if let Some(branch) = self.git_branch_name.last() {
 				if force {
 					self.queue.push(InternalEvent::ConfirmAction(
 						Action::ForcePush(branch, force),
 					));
+				} else if self.git_branch_state.is_some() {
+					self.queue
+						.push(InternalEvent::OpenPushSummary(branch));
 				} else {
 					self.queue.push(InternalEvent::Push(
 						branch, force, false,
@@ -486,6 +622,14 @@ impl Status {
 		}
 	}
 
+	fn push_with_lease(&self) {
+		if let Some(branch) = self.git_branch_name.last() {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::ForcePushWithLease(branch),
+			));
+		}
+	}
+
 	fn pull(&self) {
 		if let Some(branch) = self.git_branch_name.last() {
 			self.queue.push(InternalEvent::Pull(branch));
@@ -506,6 +650,97 @@ impl Status {
 				sync::branch_compare_upstream(CWD, branch.as_str())
 					.ok()
 			});
+
+		self.git_last_fetch = self
+			.git_branch_name
+			.last()
+			.and_then(|branch| {
+				sync::get_branch_remote(CWD, branch.as_str())
+					.ok()
+					.flatten()
+			})
+			.and_then(|remote| {
+				sync::last_fetch_time(CWD, &remote).ok().flatten()
+			});
+	}
+
+	/// only offer going back to `ORIG_HEAD` if it differs from `HEAD`
+	fn update_heads(&mut self) {
+		let head = sync::get_head(CWD).ok();
+
+		self.git_head = head;
+		self.git_orig_head = sync::orig_head(CWD)
+			.ok()
+			.flatten()
+			.filter(|id| head != Some(*id));
+	}
+
+	///
+	pub fn assign_change_set(&mut self, path: &str, name: &str) {
+		self.index.assign_change_set(path, name);
+	}
+
+	/// forgets what belongs to the repo switched away from
+	pub fn reset_repo_state(&mut self) -> Result<()> {
+		self.index.clear_change_sets();
+		self.index_wd.clear_change_sets();
+		self.set_filter(None)?;
+
+		self.git_branch_name = cached::BranchName::new(CWD);
+		self.git_branch_state = None;
+		self.git_last_fetch = None;
+		self.git_diff_stats = None;
+
+		Ok(())
+	}
+
+	/// see `FileTreeComponent::set_filter`
+	pub fn set_filter(
+		&mut self,
+		filter: Option<String>,
+	) -> Result<()> {
+		self.index_wd.set_filter(filter.clone())?;
+		self.index.set_filter(filter.clone())?;
+		self.filter = filter;
+
+		Ok(())
+	}
+
+	/// see `DiffComponent::search`
+	pub fn search_diff(
+		&mut self,
+		query: String,
+		case_sensitive: bool,
+	) {
+		self.diff.search(query, case_sensitive);
+	}
+
+	/// selects the next (or previous) file of the tree the diff
+	/// belongs to while keeping the diff focused
+	pub fn select_diff_file(&mut self, forward: bool) {
+		let wrap = self.options.borrow().diff_file_wrap;
+
+		match self.diff_target {
+			DiffTarget::Stage => {
+				self.index.select_file(forward, wrap)
+			}
+			DiffTarget::WorkingDir => {
+				self.index_wd.select_file(forward, wrap)
+			}
+		};
+	}
+
+	pub fn reset_to_orig_head(&self, id: CommitId) {
+		try_or_popup!(
+			self,
+			"reset to ORIG_HEAD failed:",
+			sync::reset_to_orig_head(CWD, id)
+		);
+	}
+
+	/// push and pull need a branch
+	fn is_detached(&self) -> bool {
+		self.git_branch_name.detached().is_some()
 	}
 
 	fn can_push(&self) -> bool {
@@ -514,13 +749,40 @@ impl Status {
 			.map_or(true, |state| state.ahead > 0)
 	}
 
-	fn can_abort_merge() -> bool {
-		sync::repo_state(CWD).unwrap_or(RepoState::Clean)
-			== RepoState::Merge
+	/// the pending merge, cherry-pick or revert if any
+	fn abortable_state() -> Option<RepoState> {
+		sync::repo_state(CWD)
+			.ok()
+			.filter(|state| state.is_abortable())
 	}
 
-	pub fn abort_merge(&self) {
-		try_or_popup!(self, "abort merge", sync::abort_merge(CWD));
+	pub fn abort_pending_op(&self, state: RepoState) {
+		match state {
+			RepoState::Merge => {
+				try_or_popup!(
+					self,
+					"abort merge",
+					sync::abort_merge(CWD)
+				);
+			}
+			RepoState::CherryPick => {
+				try_or_popup!(
+					self,
+					"abort cherry-pick",
+					sync::abort_cherry_pick(CWD)
+				);
+			}
+			RepoState::Revert => {
+				try_or_popup!(
+					self,
+					"abort revert",
+					sync::abort_revert(CWD)
+				);
+			}
+			RepoState::Clean
+			| RepoState::Rebase
+			| RepoState::Other => (),
+		}
 	}
 
 	fn commands_nav(
@@ -591,21 +853,38 @@ impl Component for Status {
 				!focus_on_diff,
 			));
 
+			if self.is_detached() {
+				out.push(CommandInfo::new(
+					strings::commands::status_create_branch_here(
+						&self.key_config,
+					),
+					true,
+					!focus_on_diff,
+				));
+			} else {
+				out.push(CommandInfo::new(
+					strings::commands::status_push(&self.key_config),
+					self.can_push(),
+					!focus_on_diff,
+				));
+			}
 			out.push(CommandInfo::new(
-				strings::commands::status_push(&self.key_config),
-				self.can_push(),
-				!focus_on_diff,
+				strings::commands::status_force_push(
+					&self.key_config,
+				),
+				!self.is_detached(),
+				self.can_push() && !focus_on_diff,
 			));
 			out.push(CommandInfo::new(
-				strings::commands::status_force_push(
+				strings::commands::status_force_push_lease(
 					&self.key_config,
 				),
-				true,
+				!self.is_detached(),
 				self.can_push() && !focus_on_diff,
 			));
 			out.push(CommandInfo::new(
 				strings::commands::status_pull(&self.key_config),
-				true,
+				!self.is_detached(),
 				!focus_on_diff,
 			));
 
@@ -616,9 +895,40 @@ impl Component for Status {
 			));
 
 			out.push(CommandInfo::new(
-				strings::commands::abort_merge(&self.key_config),
+				strings::commands::commit_amend(&self.key_config),
+				true,
+				!focus_on_diff,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::reword_head(&self.key_config),
+				self.git_head.is_some(),
+				!focus_on_diff,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::apply_patch(&self.key_config),
 				true,
-				Self::can_abort_merge() || force_all,
+				!focus_on_diff,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::status_filter(&self.key_config),
+				true,
+				!focus_on_diff,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::reset_orig_head(&self.key_config),
+				true,
+				(self.git_orig_head.is_some() && !focus_on_diff)
+					|| force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::abort_pending_op(&self.key_config),
+				true,
+				Self::abortable_state().is_some() || force_all,
 			));
 		}
 
@@ -657,9 +967,15 @@ impl Component for Status {
 						|| self.is_focus_on_diff())
 				{
 					if let Some((path, _)) = self.selected_path() {
+						let line = if self.is_focus_on_diff() {
+							self.diff.selected_file_line()
+						} else {
+							None
+						};
+
 						self.queue.push(
 							InternalEvent::OpenExternalEditor(Some(
-								path,
+								(path, line),
 							)),
 						);
 					}
@@ -680,15 +996,17 @@ impl Component for Status {
 					})
 					.map(Into::into)
 				} else if k == self.key_config.move_down
-					&& self.focus == Focus::WorkDir
-					&& !self.index.is_empty()
+					&& self.focus == self.stacked_lists().0
+					&& !self.is_list_empty(self.stacked_lists().1)
 				{
-					self.switch_focus(Focus::Stage).map(Into::into)
+					self.switch_focus(self.stacked_lists().1)
+						.map(Into::into)
 				} else if k == self.key_config.move_up
-					&& self.focus == Focus::Stage
-					&& !self.index_wd.is_empty()
+					&& self.focus == self.stacked_lists().1
+					&& !self.is_list_empty(self.stacked_lists().0)
 				{
-					self.switch_focus(Focus::WorkDir).map(Into::into)
+					self.switch_focus(self.stacked_lists().0)
+						.map(Into::into)
 				} else if k == self.key_config.select_branch
 					&& !self.is_focus_on_diff()
 				{
@@ -696,10 +1014,18 @@ impl Component for Status {
 					Ok(EventState::Consumed)
 				} else if k == self.key_config.force_push
 					&& !self.is_focus_on_diff()
+					&& !self.is_detached()
 					&& self.can_push()
 				{
 					self.push(true);
 					Ok(EventState::Consumed)
+				} else if k == self.key_config.force_push_lease
+					&& !self.is_focus_on_diff()
+					&& !self.is_detached()
+					&& self.can_push()
+				{
+					self.push_with_lease();
+					Ok(EventState::Consumed)
 				} else if k == self.key_config.push
 					&& !self.is_focus_on_diff()
 				{
@@ -718,12 +1044,50 @@ impl Component for Status {
 						NeedsUpdate::ALL,
 					));
 					Ok(EventState::Consumed)
-				} else if k == self.key_config.abort_merge
-					&& Self::can_abort_merge()
+				} else if k == self.key_config.commit_amend
+					&& !self.is_focus_on_diff()
+				{
+					self.queue.push(InternalEvent::AmendCommit);
+					Ok(EventState::Consumed)
+				} else if k == self.key_config.reword_head
+					&& !self.is_focus_on_diff()
+					&& self.git_head.is_some()
 				{
-					self.queue.push(InternalEvent::ConfirmAction(
-						Action::AbortMerge,
+					self.queue.push(InternalEvent::RewordHead);
+					Ok(EventState::Consumed)
+				} else if k == self.key_config.apply_patch
+					&& !self.is_focus_on_diff()
+				{
+					self.queue.push(InternalEvent::OpenApplyPatch);
+					Ok(EventState::Consumed)
+				} else if k == self.key_config.status_filter
+					&& !self.is_focus_on_diff()
+				{
+					self.queue.push(InternalEvent::OpenStatusFilter(
+						self.filter.clone(),
 					));
+					Ok(EventState::Consumed)
+				} else if k == self.key_config.reset_orig_head
+					&& !self.is_focus_on_diff()
+				{
+					if let Some(id) = self.git_orig_head {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::ResetToOrigHead(id),
+							),
+						);
+						Ok(EventState::Consumed)
+					} else {
+						Ok(EventState::NotConsumed)
+					}
+				} else if k == self.key_config.abort_merge {
+					if let Some(state) = Self::abortable_state() {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::AbortPendingOp(state),
+							),
+						);
+					}
 
 					Ok(EventState::Consumed)
 				} else {
@@ -11,7 +11,9 @@ pub use scrolllist::{draw_list, draw_list_block};
 pub use stateful_paragraph::{
 	ParagraphState, ScrollPos, StatefulParagraph,
 };
-pub use syntax_text::{AsyncSyntaxJob, SyntaxText};
+pub use syntax_text::{
+	AsyncFileContentJob, AsyncSyntaxJob, SyntaxText,
+};
 use tui::layout::{Constraint, Direction, Layout, Rect};
 
 use crate::keys::SharedKeyConfig;
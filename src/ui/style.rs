@@ -2,7 +2,7 @@
 #![allow(clippy::use_self)]
 
 use anyhow::Result;
-use asyncgit::{DiffLineType, StatusItemType};
+use asyncgit::{sync::SignatureStatus, DiffLineType, StatusItemType};
 use ron::{
 	de::from_bytes,
 	ser::{to_string_pretty, PrettyConfig},
@@ -188,12 +188,15 @@ impl Theme {
 		selected: bool,
 	) -> Style {
 		let style = match typ {
-			DiffLineType::Add => {
+			DiffLineType::Add | DiffLineType::ConflictTheirs => {
 				Style::default().fg(self.diff_line_add)
 			}
-			DiffLineType::Delete => {
+			DiffLineType::Delete | DiffLineType::ConflictOurs => {
 				Style::default().fg(self.diff_line_delete)
 			}
+			DiffLineType::ConflictMarker => Style::default()
+				.fg(self.diff_file_moved)
+				.add_modifier(Modifier::BOLD),
 			DiffLineType::Header => Style::default()
 				.fg(self.disabled_fg)
 				.add_modifier(Modifier::BOLD),
@@ -207,6 +210,19 @@ impl Theme {
 		self.apply_select(style, selected)
 	}
 
+	/// keeps its background on selected lines to stay visible
+	pub fn diff_search_match(&self, selected: bool) -> Style {
+		let style = Style::default()
+			.fg(Color::Black)
+			.bg(self.diff_file_modified);
+
+		if selected {
+			style.add_modifier(Modifier::BOLD)
+		} else {
+			style
+		}
+	}
+
 	pub fn text_danger(&self) -> Style {
 		Style::default().fg(self.danger_fg)
 	}
@@ -241,6 +257,27 @@ impl Theme {
 		style
 	}
 
+	pub fn commit_signature(
+		&self,
+		status: Option<SignatureStatus>,
+		selected: bool,
+	) -> Style {
+		let style = match status {
+			Some(SignatureStatus::Good) => {
+				Style::default().fg(self.diff_file_added)
+			}
+			Some(SignatureStatus::Bad) => {
+				Style::default().fg(self.danger_fg)
+			}
+			Some(SignatureStatus::Unverifiable) => {
+				Style::default().fg(self.disabled_fg)
+			}
+			Some(SignatureStatus::None) | None => Style::default(),
+		};
+
+		self.apply_select(style, selected)
+	}
+
 	pub fn commit_time(&self, selected: bool) -> Style {
 		self.apply_select(
 			Style::default().fg(self.commit_time),
@@ -1,6 +1,7 @@
 use asyncgit::{
 	asyncjob::{AsyncJob, RunParams},
-	ProgressPercent,
+	sync::{self, TreeFile},
+	ProgressPercent, CWD,
 };
 use lazy_static::lazy_static;
 use scopetime::scope_time;
@@ -20,7 +21,10 @@ use syntect::{
 };
 use tui::text::{Span, Spans};
 
-use crate::{AsyncAppNotification, SyntaxHighlightProgress};
+use crate::{
+	string_utils::tabs_to_spaces, AsyncAppNotification,
+	SyntaxHighlightProgress,
+};
 
 struct SyntaxLine {
 	items: Vec<(Style, usize, Range<usize>)>,
@@ -268,3 +272,80 @@ impl AsyncJob for AsyncSyntaxJob {
 		))
 	}
 }
+
+enum ContentJobState {
+	Request((TreeFile, usize)),
+	Response(asyncgit::Result<String>),
+}
+
+/// reads the content of a tree file off the ui thread
+#[derive(Clone)]
+pub struct AsyncFileContentJob {
+	path: String,
+	state: Arc<Mutex<Option<ContentJobState>>>,
+}
+
+impl AsyncFileContentJob {
+	pub fn new(
+		path: String,
+		file: TreeFile,
+		tab_width: usize,
+	) -> Self {
+		Self {
+			path,
+			state: Arc::new(Mutex::new(Some(
+				ContentJobState::Request((file, tab_width)),
+			))),
+		}
+	}
+
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+
+	pub fn result(&self) -> Option<asyncgit::Result<String>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					ContentJobState::Request(_) => None,
+					ContentJobState::Response(content) => {
+						Some(content)
+					}
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncFileContentJob {
+	type Notification = AsyncAppNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> asyncgit::Result<Self::Notification> {
+		let mut state_mutex = self.state.lock()?;
+
+		if let Some(state) = state_mutex.take() {
+			*state_mutex = Some(match state {
+				ContentJobState::Request((file, tab_width)) => {
+					ContentJobState::Response(
+						sync::tree_file_content(CWD, &file).map(
+							|content| {
+								tabs_to_spaces(content, tab_width)
+							},
+						),
+					)
+				}
+				ContentJobState::Response(res) => {
+					ContentJobState::Response(res)
+				}
+			});
+		}
+
+		Ok(AsyncAppNotification::FileContent)
+	}
+}
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use asyncgit::sync::{utils::repo_work_dir, IgnoreMatcher};
+use crossbeam_channel::{
+	bounded, never, tick, Receiver, TrySendError,
+};
+use notify::{
+	watcher, DebouncedEvent, RecommendedWatcher, RecursiveMode,
+	Watcher,
+};
+use std::{
+	path::{Component, Path},
+	sync::mpsc::channel,
+	thread,
+	time::{Duration, Instant},
+};
+
+/// changes to a path within this window result in one event
+static DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// files in `.git` that change when other tools commit, checkout or stage
+static GIT_DIR_FILES: &[&str] = &["HEAD", "index"];
+
+/// notifies when files of the repo change, by watching its workdir
+/// or where that is not supported by polling
+pub struct RepoWatcher {
+	receiver: Receiver<()>,
+	ticker: Receiver<Instant>,
+	/// dropping it stops watching and ends the forwarding thread
+	_watcher: Option<RecommendedWatcher>,
+}
+
+impl RepoWatcher {
+	///
+	pub fn new(repo_path: &str, poll_interval: Duration) -> Self {
+		match Self::watch(repo_path) {
+			Ok((watcher, receiver)) => Self {
+				receiver,
+				ticker: never(),
+				_watcher: Some(watcher),
+			},
+			Err(e) => {
+				// e.g. network filesystems
+				log::error!("watcher failed, polling instead: {}", e);
+
+				Self {
+					receiver: never(),
+					ticker: tick(poll_interval),
+					_watcher: None,
+				}
+			}
+		}
+	}
+
+	fn watch(
+		repo_path: &str,
+	) -> Result<(RecommendedWatcher, Receiver<()>)> {
+		let workdir = repo_work_dir(repo_path)?;
+		let ignore = IgnoreMatcher::new(repo_path)?;
+
+		let (tx, rx) = channel();
+
+		let mut watcher = watcher(tx, DEBOUNCE)?;
+		watcher.watch(workdir, RecursiveMode::Recursive)?;
+
+		// a single pending notification is enough, it covers all
+		// changes that come in before the app got to refresh
+		let (sender, receiver) = bounded(1);
+
+		thread::spawn(move || {
+			for ev in rx {
+				if is_relevant(&ev, &ignore) {
+					if let Err(TrySendError::Disconnected(_)) =
+						sender.try_send(())
+					{
+						break;
+					}
+				}
+			}
+		});
+
+		Ok((watcher, receiver))
+	}
+
+	/// a change was seen
+	pub const fn receiver(&self) -> &Receiver<()> {
+		&self.receiver
+	}
+
+	/// time to poll for changes
+	pub const fn ticker(&self) -> &Receiver<Instant> {
+		&self.ticker
+	}
+}
+
+fn is_relevant(ev: &DebouncedEvent, ignore: &IgnoreMatcher) -> bool {
+	let is_relevant_path = |path: &Path| {
+		!is_git_internal(path) && !ignore.is_ignored(path)
+	};
+
+	match ev {
+		// sent before debouncing, the debounced event follows
+		DebouncedEvent::NoticeWrite(_)
+		| DebouncedEvent::NoticeRemove(_) => false,
+		DebouncedEvent::Create(path)
+		| DebouncedEvent::Write(path)
+		| DebouncedEvent::Chmod(path)
+		| DebouncedEvent::Remove(path) => is_relevant_path(path),
+		DebouncedEvent::Rename(from, to) => {
+			is_relevant_path(from) || is_relevant_path(to)
+		}
+		DebouncedEvent::Rescan => true,
+		DebouncedEvent::Error(e, path) => {
+			log::error!("watcher error: {} ({:?})", e, path);
+			false
+		}
+	}
+}
+
+/// anything in `.git` but the few files telling us the status changed
+fn is_git_internal(path: &Path) -> bool {
+	let mut components = path.components();
+
+	while let Some(component) = components.next() {
+		if component == Component::Normal(".git".as_ref()) {
+			let rest = components.as_path();
+			return !GIT_DIR_FILES
+				.iter()
+				.any(|f| rest == Path::new(f));
+		}
+	}
+
+	false
+}
+
+#[cfg(test)]
+mod tests {
+	use super::is_git_internal;
+	use std::path::Path;
+
+	#[test]
+	fn test_is_git_internal() {
+		assert!(!is_git_internal(Path::new("/repo/src/main.rs")));
+		assert!(!is_git_internal(Path::new("/repo/.git/index")));
+		assert!(!is_git_internal(Path::new("/repo/.git/HEAD")));
+		assert!(is_git_internal(Path::new(
+			"/repo/.git/objects/ab/cd"
+		)));
+		assert!(is_git_internal(Path::new("/repo/.git/index.lock")));
+	}
+}